@@ -0,0 +1,114 @@
+//! Desserialização de webhooks: enum `untagged` x classificação pelos campos
+//!
+//! Execute com `cargo bench --bench webhook_parse`.
+
+use chatguru::types::{WebhookPayload, WebhookPayloadRef};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use serde_json::{json, Map, Value};
+
+/// Webhook no formato ChatGuru com `custom_fields` campos personalizados
+fn chatguru_body(custom_fields: usize) -> String {
+    let campos: Map<String, Value> = (0..custom_fields)
+        .map(|i| {
+            (
+                format!("Campo personalizado {}", i),
+                json!(format!("Valor do campo {} com algum texto", i)),
+            )
+        })
+        .collect();
+
+    json!({
+        "campanha_id": "1001",
+        "campanha_nome": "Atendimento",
+        "origem": "whatsapp",
+        "email": "joao@example.com",
+        "nome": "João Silva",
+        "tags": ["cliente", "vip"],
+        "texto_mensagem": "Olá, preciso de ajuda com meu pedido",
+        "campos_personalizados": campos,
+        "bot_context": {"ChatGuru": false},
+        "responsavel_nome": "Ana",
+        "responsavel_email": "ana@example.com",
+        "link_chat": "https://s15.chatguru.app/chats#chat_bench",
+        "celular": "5511999999999",
+        "phone_id": "62558780e2923cc4705beee1",
+        "chat_id": "chat_bench",
+        "chat_created": "2024-01-15 10:30:00"
+    })
+    .to_string()
+}
+
+/// Webhook no formato legado (`event_type`)
+fn event_type_body(custom_fields: usize) -> String {
+    let custom_data: Map<String, Value> = (0..custom_fields)
+        .map(|i| (format!("campo_{}", i), json!(i)))
+        .collect();
+
+    json!({
+        "id": "evt_bench",
+        "event_type": "new_lead",
+        "timestamp": "2024-01-15T10:30:00Z",
+        "data": {
+            "lead_name": "Maria",
+            "phone": "5511988887777",
+            "annotation": "Quero um orçamento",
+            "custom_data": custom_data
+        }
+    })
+    .to_string()
+}
+
+fn bench_chatguru(c: &mut Criterion) {
+    let mut group = c.benchmark_group("chatguru_payload");
+    for custom_fields in [0, 50, 500] {
+        let body = chatguru_body(custom_fields);
+        group.throughput(Throughput::Bytes(body.len() as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("untagged", custom_fields),
+            &body,
+            |b, body| b.iter(|| serde_json::from_str::<WebhookPayload>(black_box(body)).unwrap()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("parse", custom_fields),
+            &body,
+            |b, body| b.iter(|| WebhookPayload::parse(black_box(body)).unwrap()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("classify", custom_fields),
+            &body,
+            |b, body| b.iter(|| WebhookPayload::classify(black_box(body.as_bytes())).unwrap()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("borrowed", custom_fields),
+            &body,
+            |b, body| {
+                b.iter(|| serde_json::from_str::<WebhookPayloadRef>(black_box(body)).unwrap())
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_event_type(c: &mut Criterion) {
+    let mut group = c.benchmark_group("event_type_payload");
+    for custom_fields in [0, 500] {
+        let body = event_type_body(custom_fields);
+        group.throughput(Throughput::Bytes(body.len() as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("untagged", custom_fields),
+            &body,
+            |b, body| b.iter(|| serde_json::from_str::<WebhookPayload>(black_box(body)).unwrap()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("parse", custom_fields),
+            &body,
+            |b, body| b.iter(|| WebhookPayload::parse(black_box(body)).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_chatguru, bench_event_type);
+criterion_main!(benches);
@@ -0,0 +1,274 @@
+//! `chatguru-cli`: operações avulsas na API do ChatGuru (feature `cli`)
+//!
+//! Lê as credenciais das mesmas variáveis de ambiente de
+//! `ChatGuruClient::from_env` (`CHATGURU_API_TOKEN`, `CHATGURU_ACCOUNT_ID`,
+//! `CHATGURU_API_ENDPOINT` e `CHATGURU_PHONE_ID`). `parse-webhook` não acessa
+//! a API e dispensa as credenciais.
+//!
+//! ```text
+//! chatguru-cli send 5511999999999 "Olá!" --phone-id abc123
+//! chatguru-cli annotate chat_123 5511999999999 "Tarefa criada: TASK-456"
+//! chatguru-cli register-chat 5511999999999 "Maria Silva" --text "Olá, Maria!"
+//! chatguru-cli chat-status 5511999999999
+//! chatguru-cli parse-webhook webhook.json --json
+//! ```
+
+use chatguru::types::WebhookPayload;
+use chatguru::ChatGuruClient;
+use std::io::Read;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: chatguru-cli <command> [args]
+
+Commands:
+  send <phone> <message> [--phone-id ID]
+      Send a WhatsApp message
+  annotate <chat_id> <phone> <text>
+      Add a note to a chat
+  register-chat <phone> <name> [--text MESSAGE] [--phone-id ID]
+      Create a chat for a number (action=chat_add)
+  chat-status <phone>
+      Show whether a chat exists, its status and assigned agent
+  parse-webhook <file|-> [--json]
+      Parse a webhook body and show its format and main fields
+
+Credentials are read from CHATGURU_API_TOKEN, CHATGURU_ACCOUNT_ID,
+CHATGURU_API_ENDPOINT (optional) and CHATGURU_PHONE_ID (optional).";
+
+/// Argumentos posicionais e opções `--nome valor` / `--flag`
+struct Args {
+    positional: Vec<String>,
+    options: Vec<(String, Option<String>)>,
+}
+
+impl Args {
+    /// Separa as opções dos posicionais; `flags` lista as opções sem valor
+    fn parse(mut args: impl Iterator<Item = String>, flags: &[&str]) -> Result<Self, String> {
+        let mut positional = Vec::new();
+        let mut options = Vec::new();
+
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) if flags.contains(&name) => options.push((name.to_string(), None)),
+                Some(name) if !name.is_empty() => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("Option --{} requires a value", name))?;
+                    options.push((name.to_string(), Some(value)));
+                }
+                _ => positional.push(arg),
+            }
+        }
+
+        Ok(Self {
+            positional,
+            options,
+        })
+    }
+
+    /// Exige exatamente um posicional para cada nome em `names`
+    fn expect<const N: usize>(&self, names: [&str; N]) -> Result<[&str; N], String> {
+        let values: Vec<&str> = self.positional.iter().map(String::as_str).collect();
+        values.try_into().map_err(|_| {
+            format!(
+                "Expected {} argument(s): {}",
+                N,
+                names
+                    .iter()
+                    .map(|name| format!("<{}>", name))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            )
+        })
+    }
+
+    /// Valor de uma opção
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .find(|(option, _)| option == name)
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.options.iter().any(|(option, _)| option == name)
+    }
+
+    /// Rejeita opções que o comando não aceita
+    fn allow(self, allowed: &[&str]) -> Result<Self, String> {
+        match self
+            .options
+            .iter()
+            .find(|(option, _)| !allowed.contains(&option.as_str()))
+        {
+            Some((option, _)) => Err(format!("Unknown option --{}", option)),
+            None => Ok(self),
+        }
+    }
+}
+
+/// Falha do comando: uso incorreto (código 2) ou erro na execução (código 1)
+enum Failure {
+    Usage(String),
+    Error(String),
+}
+
+impl From<chatguru::ChatGuruError> for Failure {
+    fn from(error: chatguru::ChatGuruError) -> Self {
+        Failure::Error(error.to_string())
+    }
+}
+
+fn client() -> Result<ChatGuruClient, Failure> {
+    Ok(ChatGuruClient::try_from_env()?)
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let Some(command) = args.next() else {
+        eprintln!("{}", USAGE);
+        return ExitCode::from(2);
+    };
+
+    let result = match command.as_str() {
+        "send" => send(args).await,
+        "annotate" => annotate(args).await,
+        "register-chat" => register_chat(args).await,
+        "chat-status" => chat_status(args).await,
+        "parse-webhook" => parse_webhook(args),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        other => Err(Failure::Usage(format!("Unknown command {:?}", other))),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(Failure::Usage(message)) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            ExitCode::from(2)
+        }
+        Err(Failure::Error(message)) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn send(args: impl Iterator<Item = String>) -> Result<(), Failure> {
+    let args = Args::parse(args, &[])
+        .and_then(|args| args.allow(&["phone-id"]))
+        .map_err(Failure::Usage)?;
+    let [phone, message] = args.expect(["phone", "message"]).map_err(Failure::Usage)?;
+
+    client()?
+        .send_confirmation_message(phone, args.option("phone-id"), message)
+        .await?;
+    println!("Message sent");
+    Ok(())
+}
+
+async fn annotate(args: impl Iterator<Item = String>) -> Result<(), Failure> {
+    let args = Args::parse(args, &[])
+        .and_then(|args| args.allow(&[]))
+        .map_err(Failure::Usage)?;
+    let [chat_id, phone, text] = args
+        .expect(["chat_id", "phone", "text"])
+        .map_err(Failure::Usage)?;
+
+    client()?.add_annotation(chat_id, phone, text).await?;
+    println!("Note added");
+    Ok(())
+}
+
+async fn register_chat(args: impl Iterator<Item = String>) -> Result<(), Failure> {
+    let args = Args::parse(args, &[])
+        .and_then(|args| args.allow(&["text", "phone-id"]))
+        .map_err(Failure::Usage)?;
+    let [phone, name] = args.expect(["phone", "name"]).map_err(Failure::Usage)?;
+
+    let phone = chatguru::types::PhoneNumber::from(phone);
+    phone.validate()?;
+
+    let mut params = vec![("chat_number", phone.digits()), ("name", name)];
+    if let Some(text) = args.option("text") {
+        params.push(("text", text));
+    }
+    if let Some(phone_id) = args.option("phone-id") {
+        params.push(("phone_id", phone_id));
+    }
+
+    let body = client()?.execute_raw("chat_add", &params).await?;
+    println!("{}", body.trim());
+    Ok(())
+}
+
+async fn chat_status(args: impl Iterator<Item = String>) -> Result<(), Failure> {
+    let args = Args::parse(args, &[])
+        .and_then(|args| args.allow(&[]))
+        .map_err(Failure::Usage)?;
+    let [phone] = args.expect(["phone"]).map_err(Failure::Usage)?;
+
+    let status = client()?.get_chat_status(phone).await?;
+    println!("exists:         {}", status.exists);
+    println!("archived:       {}", status.archived);
+    println!(
+        "status:         {}",
+        status.status.as_deref().unwrap_or("-")
+    );
+    println!(
+        "assigned agent: {}",
+        status.assigned_agent.as_deref().unwrap_or("-")
+    );
+    println!(
+        "last activity:  {}",
+        status
+            .last_activity
+            .map(|at| at.to_rfc3339())
+            .unwrap_or_else(|| "-".to_string())
+    );
+    Ok(())
+}
+
+fn parse_webhook(args: impl Iterator<Item = String>) -> Result<(), Failure> {
+    let args = Args::parse(args, &["json"])
+        .and_then(|args| args.allow(&["json"]))
+        .map_err(Failure::Usage)?;
+    let [path] = args.expect(["file"]).map_err(Failure::Usage)?;
+
+    let mut body = Vec::new();
+    let read = if path == "-" {
+        std::io::stdin().read_to_end(&mut body).map(|_| ())
+    } else {
+        std::fs::read(path).map(|content| body = content)
+    };
+    read.map_err(|e| Failure::Error(format!("Failed to read {}: {}", path, e)))?;
+
+    let parsed = WebhookPayload::parse_report(&body).map_err(|e| Failure::Error(e.to_string()))?;
+    let payload = &parsed.payload;
+
+    if args.flag("json") {
+        let json =
+            serde_json::to_string_pretty(payload).map_err(|e| Failure::Error(e.to_string()))?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    let text = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+    println!("format:   {:?}", payload.schema_version());
+    for mismatch in &parsed.rejected {
+        println!("rejected: {}", mismatch);
+    }
+    println!("contact:  {}", payload.get_contact_name());
+    println!("phone:    {}", text(payload.get_phone_number()));
+    println!("chat_id:  {}", text(payload.get_chat_id()));
+    println!("message:  {}", text(payload.get_message_text()));
+    if payload.has_media() {
+        println!("media:    {}", text(payload.get_media_type()));
+        println!("url:      {}", text(payload.get_media_url()));
+    }
+    Ok(())
+}
@@ -0,0 +1,380 @@
+//! Cliente síncrono (blocking) da API do ChatGuru
+//!
+//! Para ferramentas sem runtime assíncrono (scripts, jobs em lote). Envolve o
+//! [`ChatGuruClient`](crate::ChatGuruClient) assíncrono e executa cada
+//! chamada em um runtime Tokio interno, de modo que montagem de parâmetros,
+//! retry, rate limit, circuit breaker e classificação de erros são os
+//! mesmos nos dois clientes.
+//!
+//! Os envios, consultas de chat, funis, outbox e ações brutas têm versões
+//! síncronas aqui. Para as demais operações do cliente assíncrono (ex:
+//! `send_annotated`, `execute_raw_with_options`), use [`ChatGuruClient::block_on`] com
+//! [`ChatGuruClient::as_async`].
+//!
+//! Disponível com a feature `blocking`. Não use dentro de um runtime Tokio:
+//! bloquear uma thread do runtime causa pânico.
+//!
+//! # Exemplo
+//!
+//! ```rust,ignore
+//! use chatguru::blocking::ChatGuruClient;
+//!
+//! let client = ChatGuruClient::builder()
+//!     .api_token(std::env::var("CHATGURU_API_TOKEN")?)
+//!     .api_endpoint("https://api.chatguru.app")
+//!     .account_id(std::env::var("CHATGURU_ACCOUNT_ID")?)
+//!     .build_blocking()?;
+//!
+//! client.send_confirmation_message("5511999999999", None, "Pedido enviado!")?;
+//! ```
+
+use crate::client::{
+    BulkSendResult, ChatGuruClientBuilder, ChatMessage, ChatRef, ChatStatus, Contact, FlushReport,
+    Funnel, HealthStatus, InteractiveMessage, OutgoingMessage, RegisteredSend, SentMessage,
+};
+use crate::error::{ChatGuruError, Result};
+use crate::media::{MediaAttachment, MediaFile};
+use crate::templates::{MessageTemplate, TemplateContext};
+use crate::types::{PhoneNumber, WebhookPayload};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Cliente síncrono para a API do ChatGuru
+///
+/// Criado com [`ChatGuruClientBuilder::build_blocking`] ou a partir de um
+/// cliente assíncrono existente com [`ChatGuruClient::from_async`].
+#[derive(Clone)]
+pub struct ChatGuruClient {
+    inner: crate::ChatGuruClient,
+    runtime: Arc<Runtime>,
+}
+
+impl ChatGuruClient {
+    /// Cria o cliente com token, endpoint e conta, usando os demais valores padrão
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se algum valor estiver vazio.
+    pub fn new(api_token: String, api_endpoint: String, account_id: String) -> Result<Self> {
+        ChatGuruClientBuilder::new()
+            .api_token(api_token)
+            .api_endpoint(api_endpoint)
+            .account_id(account_id)
+            .build_blocking()
+    }
+
+    /// Cria um builder para configurar o cliente (finalize com `build_blocking()`)
+    pub fn builder() -> ChatGuruClientBuilder {
+        ChatGuruClientBuilder::new()
+    }
+
+    /// Cria o cliente a partir das mesmas variáveis de ambiente do cliente assíncrono
+    pub fn try_from_env() -> Result<Self> {
+        ChatGuruClientBuilder::from_env()?.build_blocking()
+    }
+
+    /// Envolve um cliente assíncrono já configurado
+    ///
+    /// # Erros
+    ///
+    /// Retorna `InternalError` se o runtime interno não puder ser criado.
+    pub fn from_async(inner: crate::ChatGuruClient) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| {
+                ChatGuruError::InternalError(format!("Failed to start blocking runtime: {}", e))
+            })?;
+
+        Ok(Self {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Cliente assíncrono envolvido
+    pub fn as_async(&self) -> &crate::ChatGuruClient {
+        &self.inner
+    }
+
+    /// Retorna o phone_id usado quando nenhum é informado na chamada
+    pub fn default_phone_id(&self) -> &str {
+        self.inner.default_phone_id()
+    }
+
+    /// Adiciona uma anotação ao chat (ver [`crate::ChatGuruClient::add_annotation`])
+    pub fn add_annotation(
+        &self,
+        chat_id: &str,
+        phone_number: impl Into<PhoneNumber>,
+        annotation_text: &str,
+    ) -> Result<()> {
+        self.block_on(
+            self.inner
+                .add_annotation(chat_id, phone_number, annotation_text),
+        )
+    }
+
+    /// Envia uma mensagem de texto (ver [`crate::ChatGuruClient::send_confirmation_message`])
+    pub fn send_confirmation_message(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        phone_id: Option<&str>,
+        message: &str,
+    ) -> Result<()> {
+        self.block_on(
+            self.inner
+                .send_confirmation_message(phone_number, phone_id, message),
+        )
+    }
+
+    /// Envia uma mídia (ver [`crate::ChatGuruClient::send_media_message`])
+    pub fn send_media_message(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        phone_id: Option<&str>,
+        media: MediaAttachment,
+    ) -> Result<()> {
+        self.block_on(self.inner.send_media_message(phone_number, phone_id, media))
+    }
+
+    /// Renderiza um template e envia o resultado (ver [`crate::ChatGuruClient::send_templated`])
+    pub fn send_templated(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        template: &MessageTemplate,
+        context: &TemplateContext,
+    ) -> Result<()> {
+        self.block_on(self.inner.send_templated(phone_number, template, context))
+    }
+
+    /// Baixa a mídia de um webhook (ver [`crate::ChatGuruClient::download_media`])
+    pub fn download_media(&self, payload: &WebhookPayload) -> Result<MediaFile> {
+        self.block_on(self.inner.download_media(payload))
+    }
+
+    /// Envia várias mensagens em paralelo (ver [`crate::ChatGuruClient::send_bulk`])
+    ///
+    /// Com o runtime interno de uma thread, os envios se intercalam durante a
+    /// espera pela rede; `concurrency` continua limitando os envios simultâneos.
+    pub fn send_bulk(
+        &self,
+        messages: Vec<OutgoingMessage>,
+        concurrency: usize,
+    ) -> Vec<BulkSendResult> {
+        self.block_on(self.inner.send_bulk(messages, concurrency))
+    }
+
+    /// Envia uma mensagem e retorna o ID atribuído (ver [`crate::ChatGuruClient::send_message`])
+    pub fn send_message(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        phone_id: Option<&str>,
+        text: &str,
+    ) -> Result<SentMessage> {
+        self.block_on(self.inner.send_message(phone_number, phone_id, text))
+    }
+
+    /// Responde a uma mensagem, citando-a (ver [`crate::ChatGuruClient::send_reply`])
+    pub fn send_reply(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        text: &str,
+        reply_to_message_id: &str,
+    ) -> Result<()> {
+        self.block_on(
+            self.inner
+                .send_reply(phone_number, text, reply_to_message_id),
+        )
+    }
+
+    /// Envia uma mensagem, cadastrando o chat antes se necessário
+    /// (ver [`crate::ChatGuruClient::send_or_register`])
+    pub fn send_or_register(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        name: &str,
+        message: &str,
+    ) -> Result<RegisteredSend> {
+        self.block_on(self.inner.send_or_register(phone_number, name, message))
+    }
+
+    /// Envia um texto longo em partes (ver [`crate::ChatGuruClient::send_long_message`])
+    pub fn send_long_message(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        phone_id: Option<&str>,
+        message: &str,
+    ) -> Vec<Result<()>> {
+        self.block_on(
+            self.inner
+                .send_long_message(phone_number, phone_id, message),
+        )
+    }
+
+    /// Envia uma mensagem com botões ou lista
+    /// (ver [`crate::ChatGuruClient::send_interactive_message`])
+    pub fn send_interactive_message(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        message: InteractiveMessage,
+    ) -> Result<()> {
+        self.block_on(self.inner.send_interactive_message(phone_number, message))
+    }
+
+    /// Envia uma mensagem uma única vez por chave
+    /// (ver [`crate::ChatGuruClient::send_confirmation_message_idempotent`])
+    pub fn send_confirmation_message_idempotent(
+        &self,
+        idempotency_key: &str,
+        phone_number: impl Into<PhoneNumber>,
+        phone_id: Option<&str>,
+        message: &str,
+    ) -> Result<bool> {
+        self.block_on(self.inner.send_confirmation_message_idempotent(
+            idempotency_key,
+            phone_number,
+            phone_id,
+            message,
+        ))
+    }
+
+    /// Adiciona uma anotação uma única vez por chave
+    /// (ver [`crate::ChatGuruClient::add_annotation_idempotent`])
+    pub fn add_annotation_idempotent(
+        &self,
+        idempotency_key: &str,
+        chat_id: &str,
+        phone_number: impl Into<PhoneNumber>,
+        annotation_text: &str,
+    ) -> Result<bool> {
+        self.block_on(self.inner.add_annotation_idempotent(
+            idempotency_key,
+            chat_id,
+            phone_number,
+            annotation_text,
+        ))
+    }
+
+    /// Consulta a situação do chat de um número (ver [`crate::ChatGuruClient::get_chat_status`])
+    pub fn get_chat_status(&self, phone_number: impl Into<PhoneNumber>) -> Result<ChatStatus> {
+        self.block_on(self.inner.get_chat_status(phone_number))
+    }
+
+    /// Altera o nome do chat (ver [`crate::ChatGuruClient::update_chat_name`])
+    pub fn update_chat_name(&self, phone_number: impl Into<PhoneNumber>, name: &str) -> Result<()> {
+        self.block_on(self.inner.update_chat_name(phone_number, name))
+    }
+
+    /// Transfere o chat para um atendente (ver [`crate::ChatGuruClient::assign_chat`])
+    pub fn assign_chat(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        user_email_or_id: &str,
+    ) -> Result<()> {
+        self.block_on(self.inner.assign_chat(phone_number, user_email_or_id))
+    }
+
+    /// Busca os dados do contato (ver [`crate::ChatGuruClient::get_contact`])
+    pub fn get_contact(&self, phone_number: impl Into<PhoneNumber>) -> Result<Contact> {
+        self.block_on(self.inner.get_contact(phone_number))
+    }
+
+    /// Lista as mensagens de um chat (ver [`crate::ChatGuruClient::get_chat_messages`])
+    pub fn get_chat_messages(
+        &self,
+        chat: impl Into<ChatRef>,
+        since: Option<DateTime<Utc>>,
+        limit: usize,
+    ) -> Result<Vec<ChatMessage>> {
+        self.block_on(self.inner.get_chat_messages(chat, since, limit))
+    }
+
+    /// Lista os funis e suas etapas (ver [`crate::ChatGuruClient::list_funnels`])
+    pub fn list_funnels(&self) -> Result<Vec<Funnel>> {
+        self.block_on(self.inner.list_funnels())
+    }
+
+    /// Move o chat para uma etapa de funil
+    /// (ver [`crate::ChatGuruClient::move_chat_to_funnel_stage`])
+    pub fn move_chat_to_funnel_stage(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        funnel_id: &str,
+        stage_id: &str,
+    ) -> Result<()> {
+        self.block_on(
+            self.inner
+                .move_chat_to_funnel_stage(phone_number, funnel_id, stage_id),
+        )
+    }
+
+    /// Grava uma mensagem na outbox (ver [`crate::ChatGuruClient::queue_message`])
+    pub fn queue_message(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        phone_id: Option<&str>,
+        text: &str,
+    ) -> Result<String> {
+        self.block_on(self.inner.queue_message(phone_number, phone_id, text))
+    }
+
+    /// Grava uma anotação na outbox (ver [`crate::ChatGuruClient::queue_annotation`])
+    pub fn queue_annotation(
+        &self,
+        chat_id: &str,
+        phone_number: impl Into<PhoneNumber>,
+        text: &str,
+    ) -> Result<String> {
+        self.block_on(self.inner.queue_annotation(chat_id, phone_number, text))
+    }
+
+    /// Envia os itens pendentes da outbox (ver [`crate::ChatGuruClient::flush`])
+    pub fn flush(&self) -> Result<FlushReport> {
+        self.block_on(self.inner.flush())
+    }
+
+    /// Executa uma ação ainda não encapsulada (ver [`crate::ChatGuruClient::execute_raw`])
+    pub fn execute_raw(&self, action: &str, params: &[(&str, &str)]) -> Result<String> {
+        self.block_on(self.inner.execute_raw(action, params))
+    }
+
+    /// Executa uma ação e interpreta a resposta como JSON
+    /// (ver [`crate::ChatGuruClient::execute_action`])
+    pub fn execute_action(&self, action: &str, params: HashMap<&str, &str>) -> Result<Value> {
+        self.block_on(self.inner.execute_action(action, params))
+    }
+
+    /// Verifica se a API responde e aceita as credenciais
+    /// (ver [`crate::ChatGuruClient::health_check`])
+    pub fn health_check(&self) -> HealthStatus {
+        self.block_on(self.inner.health_check())
+    }
+
+    /// Valida o token e a conta (ver [`crate::ChatGuruClient::validate_credentials`])
+    pub fn validate_credentials(&self) -> Result<()> {
+        self.block_on(self.inner.validate_credentials())
+    }
+
+    /// Executa um future no runtime interno, esperando o resultado
+    ///
+    /// Dá acesso às operações sem versão síncrona.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let options = RequestOptions::new().phone_id("linha-vendas");
+    /// let sent = client.block_on(client.as_async().send_annotated(
+    ///     "5511999999999",
+    ///     "Pedido enviado!",
+    ///     &options,
+    /// ))?;
+    /// ```
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
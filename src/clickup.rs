@@ -0,0 +1,358 @@
+//! Integração ChatGuru → ClickUp
+//!
+//! O fluxo mais comum dos serviços que usam este crate é: receber o webhook,
+//! criar uma tarefa no ClickUp com os dados do contato e anotar no chat que
+//! a tarefa foi criada. [`ClickUpBridge`] faz esse fluxo em uma chamada
+//! ([`ClickUpBridge::handle`]), ou em partes para quem precisa ajustar a
+//! tarefa antes de criá-la.
+//!
+//! Disponível com a feature `clickup`.
+//!
+//! # Exemplo
+//!
+//! ```rust,ignore
+//! use chatguru::clickup::ClickUpBridge;
+//!
+//! let bridge = ClickUpBridge::new(std::env::var("CLICKUP_API_TOKEN")?, "901234567")
+//!     .tag("chatguru");
+//!
+//! if let WebhookPayload::ChatGuru(ref p) = payload {
+//!     let task = bridge.handle(&client, p).await?;
+//!     println!("Tarefa {} criada: {}", task.id, task.url);
+//! }
+//! ```
+
+use crate::client::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::secret::{describe_error, SecretString};
+use crate::types::ChatGuruPayload;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// URL padrão da API do ClickUp
+pub const CLICKUP_API_URL: &str = "https://api.clickup.com/api/v2";
+
+/// Tamanho máximo do título gerado a partir da mensagem, em caracteres
+const MAX_TITLE_CHARS: usize = 80;
+
+/// Mídia do webhook referenciada na tarefa
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClickUpAttachment {
+    /// URL do arquivo no ChatGuru
+    pub url: String,
+    /// MIME type, quando conhecido
+    pub mime_type: Option<String>,
+}
+
+/// Corpo de criação de tarefa da API do ClickUp (`POST /list/{id}/task`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ClickUpTask {
+    /// Título da tarefa
+    pub name: String,
+    /// Descrição em markdown
+    pub markdown_description: String,
+    /// Tags da tarefa
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Prioridade (1 = urgente ... 4 = baixa)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
+    /// Mídias do webhook (listadas na descrição; não enviadas como anexo)
+    #[serde(skip)]
+    pub attachments: Vec<ClickUpAttachment>,
+}
+
+/// Tarefa criada no ClickUp
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CreatedTask {
+    /// ID da tarefa
+    pub id: String,
+    /// Link da tarefa
+    #[serde(default)]
+    pub url: String,
+}
+
+/// Cria tarefas no ClickUp a partir de webhooks do ChatGuru
+#[derive(Clone)]
+pub struct ClickUpBridge {
+    api_token: SecretString,
+    list_id: String,
+    api_url: String,
+    tags: Vec<String>,
+    priority: Option<u8>,
+    http_client: reqwest::Client,
+}
+
+impl fmt::Debug for ClickUpBridge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClickUpBridge")
+            .field("api_token", &self.api_token)
+            .field("list_id", &self.list_id)
+            .field("api_url", &self.api_url)
+            .field("tags", &self.tags)
+            .field("priority", &self.priority)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ClickUpBridge {
+    /// Cria a integração para a lista informada
+    pub fn new(api_token: impl Into<SecretString>, list_id: impl Into<String>) -> Self {
+        Self {
+            api_token: api_token.into(),
+            list_id: list_id.into(),
+            api_url: CLICKUP_API_URL.to_string(),
+            tags: Vec::new(),
+            priority: None,
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Usa outra URL para a API (ex: servidor de testes)
+    pub fn api_url(mut self, url: impl Into<String>) -> Self {
+        self.api_url = url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Adiciona uma tag a todas as tarefas criadas
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Define a prioridade das tarefas (1 = urgente ... 4 = baixa)
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.priority = Some(priority.clamp(1, 4));
+        self
+    }
+
+    /// Reutiliza um cliente HTTP existente
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = client;
+        self
+    }
+
+    /// Monta a tarefa a partir do payload
+    ///
+    /// O título é `nome: início da mensagem` (ou o nome da campanha quando
+    /// não há mensagem); a descrição traz contato, mensagem, campos
+    /// personalizados (em ordem alfabética) e os links das mídias. As tags
+    /// do chat são somadas às tags configuradas.
+    pub fn task_from_payload(&self, payload: &ChatGuruPayload) -> ClickUpTask {
+        let mut payload = payload.clone();
+        payload.normalize_media_fields();
+
+        let attachments: Vec<ClickUpAttachment> = payload
+            .media_url
+            .iter()
+            .map(|url| ClickUpAttachment {
+                url: url.clone(),
+                mime_type: payload.media_type.clone(),
+            })
+            .collect();
+
+        let mut tags = self.tags.clone();
+        for tag in &payload.tags {
+            if !tags.contains(tag) {
+                tags.push(tag.clone());
+            }
+        }
+
+        ClickUpTask {
+            name: task_title(&payload),
+            markdown_description: task_description(&payload, &attachments),
+            tags,
+            priority: self.priority,
+            attachments,
+        }
+    }
+
+    /// Cria a tarefa na lista configurada
+    ///
+    /// # Erros
+    ///
+    /// * `NetworkError` - falha de conexão com o ClickUp
+    /// * `InternalError` - o ClickUp recusou a tarefa (status diferente de 2xx)
+    /// * `SerializationError` - resposta sem o ID da tarefa
+    pub async fn create_task(&self, task: &ClickUpTask) -> Result<CreatedTask> {
+        let url = format!("{}/list/{}/task", self.api_url, self.list_id);
+
+        let response = self
+            .http_client
+            .post(&url)
+            .header("Authorization", self.api_token.expose_secret())
+            .json(task)
+            .send()
+            .await
+            .map_err(|e| ChatGuruError::NetworkError(describe_error(e)))?;
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        if !status.is_success() {
+            return Err(ChatGuruError::InternalError(format!(
+                "ClickUp API error (status {}): {}",
+                status.as_u16(),
+                clickup_error(&body)
+            )));
+        }
+
+        let created: CreatedTask = serde_json::from_str(&body)?;
+        tracing::info!("ClickUp task {} created", created.id);
+        Ok(created)
+    }
+
+    /// Texto da anotação que registra a tarefa no chat
+    pub fn confirmation_annotation(&self, task: &CreatedTask) -> String {
+        if task.url.is_empty() {
+            format!("Tarefa criada no ClickUp: {}", task.id)
+        } else {
+            format!("Tarefa criada no ClickUp: {}\n{}", task.id, task.url)
+        }
+    }
+
+    /// Cria a tarefa a partir do payload e anota o resultado no chat
+    ///
+    /// Sem `chat_id` no payload, a tarefa é criada mas a anotação não é feita.
+    ///
+    /// # Erros
+    ///
+    /// Os de [`create_task`](Self::create_task) e de
+    /// [`ChatGuruClient::add_annotation`].
+    pub async fn handle(
+        &self,
+        client: &ChatGuruClient,
+        payload: &ChatGuruPayload,
+    ) -> Result<CreatedTask> {
+        let task = self.create_task(&self.task_from_payload(payload)).await?;
+
+        match payload.chat_id.as_deref() {
+            Some(chat_id) => {
+                client
+                    .add_annotation(
+                        chat_id,
+                        payload.celular.as_str(),
+                        &self.confirmation_annotation(&task),
+                    )
+                    .await?;
+            }
+            None => tracing::warn!(
+                "ClickUp task {} created for payload without chat_id, annotation skipped",
+                task.id
+            ),
+        }
+
+        Ok(task)
+    }
+}
+
+fn task_title(payload: &ChatGuruPayload) -> String {
+    let name = payload.nome.trim();
+    let name = if name.is_empty() {
+        payload.celular.trim()
+    } else {
+        name
+    };
+
+    let message = payload
+        .texto_mensagem
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim();
+    let subject = if message.is_empty() {
+        payload.campanha_nome.trim()
+    } else {
+        message
+    };
+
+    let title = match (name.is_empty(), subject.is_empty()) {
+        (false, false) => format!("{}: {}", name, subject),
+        (false, true) => name.to_string(),
+        (true, false) => subject.to_string(),
+        (true, true) => "Contato via ChatGuru".to_string(),
+    };
+
+    if title.chars().count() <= MAX_TITLE_CHARS {
+        return title;
+    }
+    let truncated: String = title.chars().take(MAX_TITLE_CHARS - 1).collect();
+    format!("{}…", truncated.trim_end())
+}
+
+fn task_description(payload: &ChatGuruPayload, attachments: &[ClickUpAttachment]) -> String {
+    let mut lines = vec!["## Contato".to_string()];
+    let mut field = |label: &str, value: &str| {
+        if !value.trim().is_empty() {
+            lines.push(format!("- **{}:** {}", label, value.trim()));
+        }
+    };
+    field("Nome", &payload.nome);
+    field("Celular", &payload.celular);
+    field("E-mail", &payload.email);
+    field("Campanha", &payload.campanha_nome);
+    field("Origem", &payload.origem);
+    field(
+        "Responsável",
+        payload.responsavel_nome.as_deref().unwrap_or_default(),
+    );
+    match payload.chat_link() {
+        Some(link) => field("Chat", &link.as_markdown("Abrir no ChatGuru")),
+        None => field("Chat", &payload.link_chat),
+    }
+
+    if !payload.texto_mensagem.trim().is_empty() {
+        lines.push(String::new());
+        lines.push("## Mensagem".to_string());
+        lines.extend(
+            payload
+                .texto_mensagem
+                .trim()
+                .lines()
+                .map(|line| format!("> {}", line)),
+        );
+    }
+
+    let custom_fields: BTreeMap<&String, &Value> = payload
+        .campos_personalizados
+        .iter()
+        .filter(|(_, value)| !value.is_null())
+        .collect();
+    if !custom_fields.is_empty() {
+        lines.push(String::new());
+        lines.push("## Campos personalizados".to_string());
+        for (name, value) in custom_fields {
+            let value = match value {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            lines.push(format!("- **{}:** {}", name, value));
+        }
+    }
+
+    if !attachments.is_empty() {
+        lines.push(String::new());
+        lines.push("## Anexos".to_string());
+        for attachment in attachments {
+            let label = attachment.mime_type.as_deref().unwrap_or("arquivo");
+            lines.push(format!("- [{}]({})", label, attachment.url));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Mensagem de erro do corpo de resposta do ClickUp (`{"err": "...", "ECODE": "..."}`)
+fn clickup_error(body: &str) -> String {
+    serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|value| {
+            let err = value.get("err")?.as_str()?.to_string();
+            Some(match value.get("ECODE").and_then(Value::as_str) {
+                Some(code) => format!("{} ({})", err, code),
+                None => err,
+            })
+        })
+        .unwrap_or_else(|| body.trim().to_string())
+}
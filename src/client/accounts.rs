@@ -0,0 +1,158 @@
+use super::{ChatGuruClient, ChatGuruClientBuilder};
+use crate::error::{ChatGuruError, Result};
+use crate::types::WebhookPayload;
+use reqwest::Client;
+use std::collections::HashMap;
+
+/// Registro de clientes para várias contas e linhas (phone_id) do ChatGuru
+///
+/// Cada conta é registrada a partir de um [`ChatGuruClientBuilder`] com as
+/// próprias credenciais; todos os clientes compartilham o mesmo pool de
+/// conexões HTTP. Cada phone_id registrado resolve para um cliente cujo
+/// phone_id padrão é a própria linha, de modo que respostas a um webhook
+/// saem pela linha que recebeu a mensagem.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::client::AccountManager;
+/// use chatguru::ChatGuruClient;
+///
+/// let mut accounts = AccountManager::new();
+/// accounts.register(
+///     ChatGuruClient::builder()
+///         .api_token(token_vendas)
+///         .api_endpoint("https://s15.chatguru.app")
+///         .account_id("conta_vendas")
+///         .default_phone_id("linha_vendas_1"),
+///     ["linha_vendas_2"],
+/// )?;
+///
+/// if let Some(client) = accounts.client_for_payload(&payload) {
+///     client.send_confirmation_message(&phone, None, "Recebemos sua mensagem!").await?;
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AccountManager {
+    http_client: Client,
+    by_account: HashMap<String, ChatGuruClient>,
+    by_phone_id: HashMap<String, ChatGuruClient>,
+}
+
+impl AccountManager {
+    /// Cria um registro vazio com um pool de conexões com os timeouts padrão
+    pub fn new() -> Self {
+        let http_client = ChatGuruClientBuilder::new()
+            .build_http_client()
+            .unwrap_or_else(|_| Client::new());
+        Self::with_http_client(http_client)
+    }
+
+    /// Cria um registro vazio usando o `reqwest::Client` informado como pool compartilhado
+    ///
+    /// O cliente HTTP informado substitui timeouts, proxy e TLS configurados
+    /// nos builders registrados (veja [`ChatGuruClientBuilder::http_client`]).
+    pub fn with_http_client(http_client: Client) -> Self {
+        Self {
+            http_client,
+            by_account: HashMap::new(),
+            by_phone_id: HashMap::new(),
+        }
+    }
+
+    /// Registra uma conta e suas linhas
+    ///
+    /// O phone_id padrão do builder é sempre registrado; `phone_ids` adiciona
+    /// outras linhas da mesma conta. Registrar novamente uma conta ou linha
+    /// substitui o registro anterior.
+    ///
+    /// # Erros
+    ///
+    /// Retorna os mesmos erros de [`ChatGuruClientBuilder::build`].
+    pub fn register<I, S>(&mut self, builder: ChatGuruClientBuilder, phone_ids: I) -> Result<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let client = builder.http_client(self.http_client.clone()).build()?;
+
+        for phone_id in phone_ids {
+            let phone_id = phone_id.into();
+            self.by_phone_id
+                .insert(phone_id.clone(), client.with_default_phone_id(phone_id));
+        }
+        self.by_phone_id
+            .insert(client.default_phone_id().to_string(), client.clone());
+
+        tracing::debug!(
+            "Registered ChatGuru account {} ({} phone lines)",
+            client.account_id(),
+            self.phone_ids_of(client.account_id()).count()
+        );
+        self.by_account
+            .insert(client.account_id().to_string(), client);
+
+        Ok(())
+    }
+
+    /// Cliente da linha informada, com ela como phone_id padrão
+    pub fn client_for_phone_id(&self, phone_id: &str) -> Option<&ChatGuruClient> {
+        self.by_phone_id.get(phone_id)
+    }
+
+    /// Cliente da conta informada, com o phone_id padrão do registro
+    pub fn client_for_account(&self, account_id: &str) -> Option<&ChatGuruClient> {
+        self.by_account.get(account_id)
+    }
+
+    /// Cliente da linha que recebeu o webhook
+    ///
+    /// Usa o `phone_id` do payload no formato ChatGuru; os demais formatos
+    /// não informam a linha e retornam `None`.
+    pub fn client_for_payload(&self, payload: &WebhookPayload) -> Option<&ChatGuruClient> {
+        match payload {
+            WebhookPayload::ChatGuru(p) => p
+                .phone_id
+                .as_deref()
+                .and_then(|phone_id| self.client_for_phone_id(phone_id)),
+            _ => None,
+        }
+    }
+
+    /// Como [`client_for_phone_id`](Self::client_for_phone_id), mas retorna
+    /// `ValidationError` para linhas não registradas
+    pub fn require_phone_id(&self, phone_id: &str) -> Result<&ChatGuruClient> {
+        self.client_for_phone_id(phone_id).ok_or_else(|| {
+            ChatGuruError::ValidationError(format!("Unknown ChatGuru phone_id: {}", phone_id))
+        })
+    }
+
+    /// IDs das contas registradas
+    pub fn account_ids(&self) -> impl Iterator<Item = &str> {
+        self.by_account.keys().map(String::as_str)
+    }
+
+    /// phone_ids registrados para a conta informada
+    pub fn phone_ids_of<'a>(&'a self, account_id: &'a str) -> impl Iterator<Item = &'a str> {
+        self.by_phone_id
+            .iter()
+            .filter(move |(_, client)| client.account_id() == account_id)
+            .map(|(phone_id, _)| phone_id.as_str())
+    }
+
+    /// Quantidade de contas registradas
+    pub fn len(&self) -> usize {
+        self.by_account.len()
+    }
+
+    /// Retorna `true` se nenhuma conta foi registrada
+    pub fn is_empty(&self) -> bool {
+        self.by_account.is_empty()
+    }
+}
+
+impl Default for AccountManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
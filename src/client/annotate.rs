@@ -0,0 +1,187 @@
+//! Anotação automática das mensagens enviadas pelo bot
+//!
+//! Com [`ChatGuruClientBuilder::annotate_sends`](super::ChatGuruClientBuilder::annotate_sends),
+//! cada `message_send` aceito pela API é seguido de uma anotação no chat
+//! (`note_add`) dizendo o que foi enviado e por quê (template e ID de
+//! correlação), para o atendente entender o histórico sem consultar logs.
+
+use super::request::{read_response, synthetic_response, ChatGuruRequest};
+use super::response::ApiResponse;
+use super::{ChatGuruClient, RequestOptions};
+use crate::error::{ChatGuruError, Result};
+use crate::text::truncate_with_ellipsis;
+use crate::types::PhoneNumber;
+
+/// Tamanho máximo do trecho da mensagem reproduzido na anotação
+const NOTE_PREVIEW_CHARS: usize = 280;
+
+/// Contexto de um envio, registrado na anotação automática
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SendNote {
+    enabled: bool,
+    template: Option<String>,
+    correlation_id: Option<String>,
+}
+
+impl SendNote {
+    pub(crate) fn new(template: Option<String>, correlation_id: Option<String>) -> Self {
+        Self {
+            enabled: false,
+            template,
+            correlation_id,
+        }
+    }
+
+    /// Texto da anotação do envio de `text`; sem ID de correlação, usa o da chamada
+    fn render(&self, text: &str, request_id: &str) -> String {
+        let preview = truncate_with_ellipsis(text, NOTE_PREVIEW_CHARS);
+
+        let mut note = String::from("🤖 Mensagem automática enviada");
+        if let Some(ref template) = self.template {
+            note.push_str(&format!("\nTemplate: {}", template));
+        }
+        note.push_str(&format!(
+            "\nCorrelação: {}",
+            self.correlation_id.as_deref().unwrap_or(request_id)
+        ));
+        note.push_str(&format!("\nTexto: {}", preview));
+        note
+    }
+}
+
+/// Falha da anotação de um envio aceito, anexada à resposta do `message_send`
+struct NoteFailure(ChatGuruError);
+
+/// Resultado de [`ChatGuruClient::send_annotated`]
+///
+/// O envio e a anotação são independentes: uma anotação que falha não
+/// desfaz a mensagem já entregue, e fica registrada aqui.
+#[derive(Debug, Default)]
+pub struct AnnotatedSend {
+    /// Partes da mensagem aceitas pela API (textos longos são divididos)
+    pub parts: usize,
+    /// Erros das anotações que não puderam ser registradas, um por parte
+    pub annotation_errors: Vec<ChatGuruError>,
+}
+
+impl AnnotatedSend {
+    /// Indica se todas as partes foram enviadas e anotadas
+    pub fn is_complete(&self) -> bool {
+        self.annotation_errors.is_empty()
+    }
+}
+
+impl ChatGuruClient {
+    /// Envia uma mensagem e anota o envio no chat, informando as falhas parciais
+    ///
+    /// Faz a anotação mesmo sem
+    /// [`annotate_sends`](super::ChatGuruClientBuilder::annotate_sends). O
+    /// template e o ID de correlação da anotação vêm de
+    /// [`RequestOptions::template`] e [`RequestOptions::correlation_id`] (sem
+    /// ele, é usado o [`REQUEST_ID_HEADER`](super::REQUEST_ID_HEADER) do envio).
+    /// Diferente de [`send_confirmation_message`](Self::send_confirmation_message),
+    /// erros da API no envio são retornados.
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - telefone inválido
+    /// * `ApiError`, `ChatNotFound`, `RateLimited`, `NetworkError`, ... - falha no envio
+    ///
+    /// Falhas da anotação não são erros: ficam em [`AnnotatedSend::annotation_errors`].
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let options = RequestOptions::new()
+    ///     .template("pedido_confirmado")
+    ///     .correlation_id(format!("pedido:{}", order_id));
+    ///
+    /// let report = client
+    ///     .send_annotated("5511999999999", "Seu pedido foi confirmado!", &options)
+    ///     .await?;
+    /// if !report.is_complete() {
+    ///     tracing::warn!("Mensagem enviada sem anotação: {:?}", report.annotation_errors);
+    /// }
+    /// ```
+    pub async fn send_annotated(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        message: &str,
+        options: &RequestOptions,
+    ) -> Result<AnnotatedSend> {
+        let phone_number = phone_number.into();
+        phone_number.validate()?;
+
+        let mut report = AnnotatedSend::default();
+        for chunk in self.message_chunks(message) {
+            let mut request = ChatGuruRequest::new("message_send")
+                .param("text", chunk)
+                .param("chat_number", phone_number.digits())
+                .options(options);
+            request.send_note_mut().enabled = true;
+
+            let mut response = self.post_action(&request).await?;
+            let failure = response.extensions_mut().remove::<NoteFailure>();
+            read_response("message_send", response).await?;
+
+            report.parts += 1;
+            if let Some(NoteFailure(error)) = failure {
+                report.annotation_errors.push(error);
+            }
+        }
+        Ok(report)
+    }
+
+    /// Indica se o envio deve ser anotado no chat
+    pub(super) fn should_annotate(&self, request: &ChatGuruRequest) -> bool {
+        request.action() == "message_send" && (self.annotate_sends || request.send_note().enabled)
+    }
+
+    /// Anota no chat um envio aceito pela API
+    ///
+    /// Devolve a resposta do envio com o mesmo status e corpo; se a anotação
+    /// falhar, o erro é logado e anexado à resposta.
+    pub(super) async fn annotate_sent(
+        &self,
+        request: &ChatGuruRequest,
+        request_id: &str,
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response> {
+        if !response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status().as_u16();
+        let body = response.text().await.map_err(|e| {
+            ChatGuruError::NetworkError(format!("Failed to read message_send response: {}", e))
+        })?;
+        let mut rebuilt = synthetic_response(status, body.clone(), request_id);
+        if ApiResponse::parse(&body).is_some_and(|response| response.is_error()) {
+            return Ok(rebuilt);
+        }
+
+        let (Some(chat_number), Some(text)) = (
+            request.param_value("chat_number"),
+            request.param_value("text"),
+        ) else {
+            return Ok(rebuilt);
+        };
+
+        let note = request
+            .follow_up("note_add")
+            .param("note_text", request.send_note().render(text, request_id))
+            .param("chat_number", chat_number);
+        // A anotação passa de novo por post_action: a chamada recursiva precisa de Box
+        let result = Box::pin(self.execute(&note)).await;
+
+        if let Err(e) = result {
+            tracing::warn!(
+                "Message to {} sent, but its annotation failed: {}",
+                PhoneNumber::from(chat_number).masked(),
+                e
+            );
+            rebuilt.extensions_mut().insert(NoteFailure(e));
+        }
+        Ok(rebuilt)
+    }
+}
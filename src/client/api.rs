@@ -0,0 +1,370 @@
+use super::{
+    BulkSendResult, ChatGuruClient, ChatStatus, FlushReport, OutgoingMessage, RegisteredSend,
+    SentMessage,
+};
+use crate::error::Result;
+use crate::media::{MediaAttachment, MediaFile};
+use crate::templates::{MessageTemplate, TemplateContext};
+use crate::types::{PhoneNumber, WebhookPayload};
+use futures_core::future::BoxFuture;
+
+/// Operações da API do ChatGuru, para receber o cliente como dependência
+///
+/// Serviços que recebem `Arc<dyn ChatGuruApi>` (ou um genérico
+/// `T: ChatGuruApi`) em vez de [`ChatGuruClient`] podem ser testados com o
+/// `MockChatGuruClient` (feature `test-util`), sem acessar a rede.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use std::sync::Arc;
+/// use chatguru::client::ChatGuruApi;
+///
+/// struct Notificador {
+///     chatguru: Arc<dyn ChatGuruApi>,
+/// }
+///
+/// impl Notificador {
+///     async fn confirmar(&self, telefone: &str) -> chatguru::Result<()> {
+///         self.chatguru
+///             .send_confirmation_message(telefone.into(), None, "Recebido!")
+///             .await
+///     }
+/// }
+/// ```
+pub trait ChatGuruApi: Send + Sync {
+    /// Adiciona uma anotação ao chat (ver [`ChatGuruClient::add_annotation`])
+    fn add_annotation<'a>(
+        &'a self,
+        chat_id: &'a str,
+        phone_number: PhoneNumber,
+        annotation_text: &'a str,
+    ) -> BoxFuture<'a, Result<()>>;
+
+    /// Envia uma mensagem de texto (ver [`ChatGuruClient::send_confirmation_message`])
+    fn send_confirmation_message<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        message: &'a str,
+    ) -> BoxFuture<'a, Result<()>>;
+
+    /// Envia uma mídia (ver [`ChatGuruClient::send_media_message`])
+    fn send_media_message<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        media: MediaAttachment,
+    ) -> BoxFuture<'a, Result<()>>;
+
+    /// Baixa a mídia de um webhook (ver [`ChatGuruClient::download_media`])
+    fn download_media<'a>(
+        &'a self,
+        payload: &'a WebhookPayload,
+    ) -> BoxFuture<'a, Result<MediaFile>>;
+
+    /// Envia uma mensagem de texto e retorna o ID atribuído (ver [`ChatGuruClient::send_message`])
+    fn send_message<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        text: &'a str,
+    ) -> BoxFuture<'a, Result<SentMessage>>;
+
+    /// Responde a uma mensagem, citando-a (ver [`ChatGuruClient::send_reply`])
+    fn send_reply<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        text: &'a str,
+        reply_to_message_id: &'a str,
+    ) -> BoxFuture<'a, Result<()>>;
+
+    /// Envia uma mensagem, cadastrando o chat antes se necessário
+    /// (ver [`ChatGuruClient::send_or_register`])
+    fn send_or_register<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        name: &'a str,
+        message: &'a str,
+    ) -> BoxFuture<'a, Result<RegisteredSend>>;
+
+    /// Envia várias mensagens em paralelo (ver [`ChatGuruClient::send_bulk`])
+    fn send_bulk(
+        &self,
+        messages: Vec<OutgoingMessage>,
+        concurrency: usize,
+    ) -> BoxFuture<'_, Vec<BulkSendResult>>;
+
+    /// Envia uma mensagem uma única vez por chave
+    /// (ver [`ChatGuruClient::send_confirmation_message_idempotent`])
+    fn send_confirmation_message_idempotent<'a>(
+        &'a self,
+        idempotency_key: &'a str,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        message: &'a str,
+    ) -> BoxFuture<'a, Result<bool>>;
+
+    /// Adiciona uma anotação uma única vez por chave
+    /// (ver [`ChatGuruClient::add_annotation_idempotent`])
+    fn add_annotation_idempotent<'a>(
+        &'a self,
+        idempotency_key: &'a str,
+        chat_id: &'a str,
+        phone_number: PhoneNumber,
+        annotation_text: &'a str,
+    ) -> BoxFuture<'a, Result<bool>>;
+
+    /// Grava uma mensagem na outbox (ver [`ChatGuruClient::queue_message`])
+    fn queue_message<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        text: &'a str,
+    ) -> BoxFuture<'a, Result<String>>;
+
+    /// Grava uma anotação na outbox (ver [`ChatGuruClient::queue_annotation`])
+    fn queue_annotation<'a>(
+        &'a self,
+        chat_id: &'a str,
+        phone_number: PhoneNumber,
+        text: &'a str,
+    ) -> BoxFuture<'a, Result<String>>;
+
+    /// Envia os itens pendentes da outbox (ver [`ChatGuruClient::flush`])
+    fn flush(&self) -> BoxFuture<'_, Result<FlushReport>>;
+
+    /// Consulta a situação do chat de um número (ver [`ChatGuruClient::get_chat_status`])
+    fn get_chat_status(&self, phone_number: PhoneNumber) -> BoxFuture<'_, Result<ChatStatus>>;
+
+    /// Transfere o chat para um atendente (ver [`ChatGuruClient::assign_chat`])
+    fn assign_chat<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        user_email_or_id: &'a str,
+    ) -> BoxFuture<'a, Result<()>>;
+
+    /// Executa uma ação ainda não encapsulada (ver [`ChatGuruClient::execute_raw`])
+    fn execute_raw<'a>(
+        &'a self,
+        action: &'a str,
+        params: &'a [(&'a str, &'a str)],
+    ) -> BoxFuture<'a, Result<String>>;
+
+    /// Renderiza um template e envia o resultado como mensagem
+    fn send_templated<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        template: &'a MessageTemplate,
+        context: &'a TemplateContext,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let message = template.render(context)?;
+            self.send_confirmation_message(phone_number, None, &message)
+                .await
+        })
+    }
+}
+
+impl ChatGuruApi for ChatGuruClient {
+    fn add_annotation<'a>(
+        &'a self,
+        chat_id: &'a str,
+        phone_number: PhoneNumber,
+        annotation_text: &'a str,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(ChatGuruClient::add_annotation(
+            self,
+            chat_id,
+            phone_number,
+            annotation_text,
+        ))
+    }
+
+    fn send_confirmation_message<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        message: &'a str,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(ChatGuruClient::send_confirmation_message(
+            self,
+            phone_number,
+            phone_id,
+            message,
+        ))
+    }
+
+    fn send_media_message<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        media: MediaAttachment,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(ChatGuruClient::send_media_message(
+            self,
+            phone_number,
+            phone_id,
+            media,
+        ))
+    }
+
+    fn download_media<'a>(
+        &'a self,
+        payload: &'a WebhookPayload,
+    ) -> BoxFuture<'a, Result<MediaFile>> {
+        Box::pin(ChatGuruClient::download_media(self, payload))
+    }
+
+    fn send_message<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        text: &'a str,
+    ) -> BoxFuture<'a, Result<SentMessage>> {
+        Box::pin(ChatGuruClient::send_message(
+            self,
+            phone_number,
+            phone_id,
+            text,
+        ))
+    }
+
+    fn send_reply<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        text: &'a str,
+        reply_to_message_id: &'a str,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(ChatGuruClient::send_reply(
+            self,
+            phone_number,
+            text,
+            reply_to_message_id,
+        ))
+    }
+
+    fn send_or_register<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        name: &'a str,
+        message: &'a str,
+    ) -> BoxFuture<'a, Result<RegisteredSend>> {
+        Box::pin(ChatGuruClient::send_or_register(
+            self,
+            phone_number,
+            name,
+            message,
+        ))
+    }
+
+    fn send_bulk(
+        &self,
+        messages: Vec<OutgoingMessage>,
+        concurrency: usize,
+    ) -> BoxFuture<'_, Vec<BulkSendResult>> {
+        Box::pin(ChatGuruClient::send_bulk(self, messages, concurrency))
+    }
+
+    fn send_confirmation_message_idempotent<'a>(
+        &'a self,
+        idempotency_key: &'a str,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        message: &'a str,
+    ) -> BoxFuture<'a, Result<bool>> {
+        Box::pin(ChatGuruClient::send_confirmation_message_idempotent(
+            self,
+            idempotency_key,
+            phone_number,
+            phone_id,
+            message,
+        ))
+    }
+
+    fn add_annotation_idempotent<'a>(
+        &'a self,
+        idempotency_key: &'a str,
+        chat_id: &'a str,
+        phone_number: PhoneNumber,
+        annotation_text: &'a str,
+    ) -> BoxFuture<'a, Result<bool>> {
+        Box::pin(ChatGuruClient::add_annotation_idempotent(
+            self,
+            idempotency_key,
+            chat_id,
+            phone_number,
+            annotation_text,
+        ))
+    }
+
+    fn queue_message<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        text: &'a str,
+    ) -> BoxFuture<'a, Result<String>> {
+        Box::pin(ChatGuruClient::queue_message(
+            self,
+            phone_number,
+            phone_id,
+            text,
+        ))
+    }
+
+    fn queue_annotation<'a>(
+        &'a self,
+        chat_id: &'a str,
+        phone_number: PhoneNumber,
+        text: &'a str,
+    ) -> BoxFuture<'a, Result<String>> {
+        Box::pin(ChatGuruClient::queue_annotation(
+            self,
+            chat_id,
+            phone_number,
+            text,
+        ))
+    }
+
+    fn flush(&self) -> BoxFuture<'_, Result<FlushReport>> {
+        Box::pin(ChatGuruClient::flush(self))
+    }
+
+    fn get_chat_status(&self, phone_number: PhoneNumber) -> BoxFuture<'_, Result<ChatStatus>> {
+        Box::pin(ChatGuruClient::get_chat_status(self, phone_number))
+    }
+
+    fn assign_chat<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        user_email_or_id: &'a str,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(ChatGuruClient::assign_chat(
+            self,
+            phone_number,
+            user_email_or_id,
+        ))
+    }
+
+    fn execute_raw<'a>(
+        &'a self,
+        action: &'a str,
+        params: &'a [(&'a str, &'a str)],
+    ) -> BoxFuture<'a, Result<String>> {
+        Box::pin(ChatGuruClient::execute_raw(self, action, params))
+    }
+
+    fn send_templated<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        template: &'a MessageTemplate,
+        context: &'a TemplateContext,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(ChatGuruClient::send_templated(
+            self,
+            phone_number,
+            template,
+            context,
+        ))
+    }
+}
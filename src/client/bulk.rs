@@ -0,0 +1,145 @@
+use super::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::types::PhoneNumber;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// Mensagem a ser enviada por [`ChatGuruClient::send_bulk`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutgoingMessage {
+    /// Telefone do destinatário
+    pub phone: PhoneNumber,
+    /// Linha usada no envio (None = phone_id padrão do cliente)
+    pub phone_id: Option<String>,
+    /// Texto da mensagem
+    pub text: String,
+}
+
+impl OutgoingMessage {
+    /// Cria uma mensagem para o telefone informado
+    pub fn new(phone: impl Into<PhoneNumber>, text: impl Into<String>) -> Self {
+        Self {
+            phone: phone.into(),
+            phone_id: None,
+            text: text.into(),
+        }
+    }
+
+    /// Envia por uma linha específica em vez do phone_id padrão
+    pub fn phone_id(mut self, phone_id: impl Into<String>) -> Self {
+        self.phone_id = Some(phone_id.into());
+        self
+    }
+}
+
+/// Resultado do envio de uma mensagem em lote
+#[derive(Debug)]
+pub struct BulkSendResult {
+    /// Mensagem enviada
+    pub message: OutgoingMessage,
+    /// Resultado do envio
+    pub result: Result<()>,
+}
+
+impl BulkSendResult {
+    /// Indica se a mensagem foi aceita pela API
+    pub fn is_success(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+impl ChatGuruClient {
+    /// Envia várias mensagens em paralelo, com no máximo `concurrency` envios
+    /// simultâneos
+    ///
+    /// Cada mensagem tem seu próprio resultado (uma falha não cancela as
+    /// demais), retornados na mesma ordem da entrada. Os envios passam pelo
+    /// rate limiter e pela política de retry do cliente; diferente de
+    /// [`send_confirmation_message`](Self::send_confirmation_message), respostas
+    /// de erro da API são retornadas como erro (`ApiError`, `ChatNotFound`, etc).
+    ///
+    /// Precisa ser chamado dentro de um runtime Tokio.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// use chatguru::client::OutgoingMessage;
+    ///
+    /// let messages = contatos
+    ///     .iter()
+    ///     .map(|c| OutgoingMessage::new(c.telefone.as_str(), "Seu pedido foi confirmado!"))
+    ///     .collect();
+    ///
+    /// let results = client.send_bulk(messages, 10).await;
+    /// let failed = results.iter().filter(|r| !r.is_success()).count();
+    /// ```
+    pub async fn send_bulk(
+        &self,
+        messages: Vec<OutgoingMessage>,
+        concurrency: usize,
+    ) -> Vec<BulkSendResult> {
+        let total = messages.len();
+        let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+        let mut tasks = JoinSet::new();
+
+        for (index, message) in messages.iter().cloned().enumerate() {
+            let client = self.clone();
+            let semaphore = semaphore.clone();
+
+            tasks.spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                (index, client.send_outgoing(&message).await)
+            });
+        }
+
+        let mut results: Vec<Option<Result<()>>> = (0..total).map(|_| None).collect();
+        while let Some(joined) = tasks.join_next().await {
+            match joined {
+                Ok((index, result)) => results[index] = Some(result),
+                Err(e) => tracing::error!("Bulk send task failed: {}", e),
+            }
+        }
+
+        let results: Vec<BulkSendResult> = messages
+            .into_iter()
+            .zip(results)
+            .map(|(message, result)| BulkSendResult {
+                message,
+                result: result.unwrap_or_else(|| {
+                    Err(ChatGuruError::InternalError(
+                        "Bulk send task did not complete".to_string(),
+                    ))
+                }),
+            })
+            .collect();
+
+        let failed = results.iter().filter(|r| !r.is_success()).count();
+        tracing::info!(
+            "Bulk send finished: {} sent, {} failed",
+            total - failed,
+            failed
+        );
+
+        results
+    }
+
+    async fn send_outgoing(&self, message: &OutgoingMessage) -> Result<()> {
+        message.phone.validate()?;
+
+        let phone_id = message
+            .phone_id
+            .as_deref()
+            .unwrap_or(&self.default_phone_id);
+        self.call_action(
+            "message_send",
+            phone_id,
+            &[
+                ("text", &message.text),
+                ("chat_number", message.phone.digits()),
+            ],
+        )
+        .await
+        .map(|_| ())
+    }
+}
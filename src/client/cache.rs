@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Cache das respostas das ações de leitura, por TTL e limitado em tamanho
+///
+/// Configurado com [`ChatGuruClientBuilder::response_cache`](super::ChatGuruClientBuilder::response_cache).
+/// Guarda o corpo das respostas de sucesso; quando a capacidade é atingida,
+/// a entrada mais antiga é descartada. Entradas de um chat são descartadas
+/// quando uma ação de escrita é enviada para o mesmo chat.
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    ttl: Duration,
+    capacity: usize,
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    body: String,
+    chat: Option<String>,
+    stored_at: Instant,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(ttl: Duration, capacity: usize) -> Self {
+        Self {
+            ttl,
+            capacity: capacity.max(1),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Corpo guardado para a chave, se ainda dentro do TTL
+    pub(crate) fn get(&self, key: &str) -> Option<String> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.get(key) {
+            Some(entry) if entry.stored_at.elapsed() < self.ttl => Some(entry.body.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Guarda o corpo da resposta, associado ao chat consultado (se houver)
+    pub(crate) fn insert(&self, key: String, chat: Option<&str>, body: String) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if !entries.contains_key(&key) && entries.len() >= self.capacity {
+            entries.retain(|_, entry| entry.stored_at.elapsed() < self.ttl);
+            if entries.len() >= self.capacity {
+                let oldest = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.stored_at)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+
+        entries.insert(
+            key,
+            CacheEntry {
+                body,
+                chat: chat.map(str::to_string),
+                stored_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Descarta as respostas guardadas do chat
+    pub(crate) fn invalidate_chat(&self, chat: &str) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|_, entry| entry.chat.as_deref() != Some(chat));
+    }
+
+    /// Descarta todas as respostas guardadas
+    pub(crate) fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
@@ -0,0 +1,260 @@
+//! Gravação e reprodução das chamadas à API (feature `record`)
+//!
+//! Um [`Cassette`] em modo gravação deixa as chamadas irem à API e guarda
+//! cada par requisição/resposta em um arquivo JSON; em modo reprodução, serve
+//! as respostas do arquivo sem acessar a rede. Assim os testes de integração
+//! do CI cobrem o formato real das respostas sem credenciais.
+
+use super::request::{synthetic_response, ChatGuruRequest};
+use crate::error::{ChatGuruError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tokio::sync::Mutex;
+
+/// Modo de um [`Cassette`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Chama a API e grava as respostas no arquivo
+    Record,
+    /// Responde com as gravações do arquivo, sem acessar a rede
+    Replay,
+}
+
+/// Par requisição/resposta gravado
+///
+/// O token e o `account_id` não são gravados; os demais parâmetros (incluindo
+/// números de telefone e textos) vão para o arquivo como foram enviados.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedInteraction {
+    /// Ação chamada (`message_send`, `note_add`, ...)
+    pub action: String,
+    /// Linha usada na chamada
+    pub phone_id: String,
+    /// Parâmetros específicos da ação
+    pub params: BTreeMap<String, String>,
+    /// Status HTTP da resposta
+    pub status: u16,
+    /// Corpo da resposta
+    pub body: String,
+}
+
+impl RecordedInteraction {
+    fn matches(&self, request: &ChatGuruRequest, phone_id: &str) -> bool {
+        self.action == request.action()
+            && self.phone_id == phone_id
+            && self.params.len() == request.param_pairs().count()
+            && request
+                .param_pairs()
+                .all(|(name, value)| self.params.get(name).map(String::as_str) == Some(value))
+    }
+}
+
+#[derive(Debug, Default)]
+struct Tape {
+    interactions: Vec<RecordedInteraction>,
+    played: Vec<bool>,
+}
+
+/// Gravador/reprodutor das chamadas à API, no estilo VCR
+///
+/// Cada requisição é comparada pela ação, `phone_id` e parâmetros; na
+/// reprodução, gravações iguais são servidas na ordem em que foram feitas,
+/// uma vez cada. Requisições sem gravação correspondente falham com
+/// `InternalError`.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use std::sync::Arc;
+/// use chatguru::client::Cassette;
+///
+/// // Com credenciais reais, uma vez: CHATGURU_RECORD=1 cargo test
+/// let cassette = if std::env::var("CHATGURU_RECORD").is_ok() {
+///     Cassette::record("tests/fixtures/envio.json")
+/// } else {
+///     Cassette::replay("tests/fixtures/envio.json")?
+/// };
+///
+/// let client = ChatGuruClient::builder()
+///     .api_token(std::env::var("CHATGURU_API_TOKEN").unwrap_or("replay".into()))
+///     // ...
+///     .cassette(Arc::new(cassette))
+///     .build()?;
+///
+/// client.send_confirmation_message("5511999999999", None, "Olá!").await?;
+/// ```
+#[derive(Debug)]
+pub struct Cassette {
+    mode: CassetteMode,
+    path: PathBuf,
+    tape: Mutex<Tape>,
+}
+
+impl Cassette {
+    /// Cassette em modo gravação; o arquivo é sobrescrito na primeira gravação
+    pub fn record(path: impl AsRef<Path>) -> Self {
+        Self {
+            mode: CassetteMode::Record,
+            path: path.as_ref().to_path_buf(),
+            tape: Mutex::new(Tape::default()),
+        }
+    }
+
+    /// Cassette em modo reprodução, carregando as gravações do arquivo
+    ///
+    /// # Erros
+    ///
+    /// Retorna `InternalError` se o arquivo não puder ser lido, e
+    /// `SerializationError` se o conteúdo não for uma gravação válida.
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let bytes = std::fs::read(&path).map_err(|e| {
+            ChatGuruError::InternalError(format!(
+                "Failed to read cassette {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let interactions: Vec<RecordedInteraction> = serde_json::from_slice(&bytes)?;
+
+        tracing::info!(
+            "Cassette loaded from {} ({} interactions)",
+            path.display(),
+            interactions.len()
+        );
+
+        Ok(Self {
+            mode: CassetteMode::Replay,
+            path,
+            tape: Mutex::new(Tape {
+                played: vec![false; interactions.len()],
+                interactions,
+            }),
+        })
+    }
+
+    /// Modo do cassette
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// Caminho do arquivo de gravações
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Gravações feitas (ou carregadas), na ordem
+    pub async fn interactions(&self) -> Vec<RecordedInteraction> {
+        self.tape.lock().await.interactions.clone()
+    }
+
+    /// Gravações ainda não reproduzidas (sempre vazio em modo gravação)
+    ///
+    /// Útil ao fim de um teste para confirmar que todas as chamadas gravadas
+    /// foram feitas.
+    pub async fn unplayed(&self) -> Vec<RecordedInteraction> {
+        let tape = self.tape.lock().await;
+        tape.interactions
+            .iter()
+            .zip(&tape.played)
+            .filter(|(_, played)| !**played)
+            .map(|(interaction, _)| interaction.clone())
+            .collect()
+    }
+
+    /// Resposta gravada para a requisição
+    pub(super) async fn play(
+        &self,
+        request: &ChatGuruRequest,
+        phone_id: &str,
+        request_id: &str,
+    ) -> Result<reqwest::Response> {
+        let mut tape = self.tape.lock().await;
+        let Tape {
+            ref interactions,
+            ref mut played,
+        } = *tape;
+
+        let found = interactions
+            .iter()
+            .zip(played.iter_mut())
+            .find(|(interaction, played)| !**played && interaction.matches(request, phone_id));
+
+        match found {
+            Some((interaction, played)) => {
+                *played = true;
+                tracing::debug!(
+                    "ChatGuru {} replayed from {}",
+                    request.action(),
+                    self.path.display()
+                );
+                Ok(synthetic_response(
+                    interaction.status,
+                    interaction.body.clone(),
+                    request_id,
+                ))
+            }
+            None => Err(ChatGuruError::InternalError(format!(
+                "No unplayed interaction for {} in cassette {}",
+                request.action(),
+                self.path.display()
+            ))),
+        }
+    }
+
+    /// Grava a resposta recebida e a devolve para o chamador
+    pub(super) async fn record_response(
+        &self,
+        request: &ChatGuruRequest,
+        phone_id: &str,
+        request_id: &str,
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response> {
+        let status = response.status().as_u16();
+        let body = response.text().await.map_err(|e| {
+            ChatGuruError::NetworkError(format!(
+                "Failed to read {} response: {}",
+                request.action(),
+                e
+            ))
+        })?;
+
+        let mut tape = self.tape.lock().await;
+        tape.interactions.push(RecordedInteraction {
+            action: request.action().to_string(),
+            phone_id: phone_id.to_string(),
+            params: request
+                .param_pairs()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            status,
+            body: body.clone(),
+        });
+        tape.played.push(true);
+        self.persist(&tape.interactions).await?;
+
+        Ok(synthetic_response(status, body, request_id))
+    }
+
+    async fn persist(&self, interactions: &[RecordedInteraction]) -> Result<()> {
+        let bytes = serde_json::to_vec_pretty(interactions)?;
+
+        let tmp_path = self.path.with_extension("tmp");
+        let write = async {
+            if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            tokio::fs::write(&tmp_path, &bytes).await?;
+            tokio::fs::rename(&tmp_path, &self.path).await
+        };
+
+        write.await.map_err(|e| {
+            ChatGuruError::InternalError(format!(
+                "Failed to write cassette {}: {}",
+                self.path.display(),
+                e
+            ))
+        })
+    }
+}
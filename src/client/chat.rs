@@ -0,0 +1,219 @@
+use super::request::ChatGuruRequest;
+use super::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::types::{parse_timestamp, PhoneNumber};
+use chrono::{DateTime, TimeZone, Utc};
+use serde_json::Value;
+
+/// Situação de um chat no ChatGuru, retornada por [`ChatGuruClient::get_chat_status`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChatStatus {
+    /// Se existe chat para o número na linha consultada
+    pub exists: bool,
+    /// Se o chat está arquivado
+    pub archived: bool,
+    /// Atendente responsável (e-mail, nome ou ID, como informado pela API)
+    pub assigned_agent: Option<String>,
+    /// Data da última atividade no chat
+    pub last_activity: Option<DateTime<Utc>>,
+    /// Situação bruta informada pela API (`aberto`, `fechado`, ...)
+    pub status: Option<String>,
+}
+
+impl ChatStatus {
+    /// Status de um chat inexistente
+    pub fn not_found() -> Self {
+        Self::default()
+    }
+
+    /// Interpreta o corpo JSON da resposta de `chat_status`
+    ///
+    /// Aceita os campos no nível raiz ou dentro de `chat`/`data`.
+    pub(crate) fn from_body(body: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(body)?;
+        let chat = ["chat", "data"]
+            .iter()
+            .find_map(|key| value.get(key).filter(|v| v.is_object()))
+            .unwrap_or(&value);
+
+        let field = |names: &[&str]| names.iter().find_map(|name| chat.get(name));
+        let text = |names: &[&str]| {
+            field(names).and_then(|v| match v {
+                Value::String(s) if !s.is_empty() => Some(s.clone()),
+                Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            })
+        };
+
+        Ok(Self {
+            exists: field(&["exists", "chat_exists"])
+                .map(is_truthy)
+                .unwrap_or(true),
+            archived: field(&["archived", "arquivado", "is_archived"])
+                .map(is_truthy)
+                .unwrap_or(false),
+            assigned_agent: text(&[
+                "assigned_agent",
+                "assigned_user",
+                "user_email",
+                "responsavel",
+                "atendente",
+            ]),
+            last_activity: field(&[
+                "last_activity",
+                "last_message_at",
+                "updated_at",
+                "data_ultima_mensagem",
+            ])
+            .and_then(parse_datetime),
+            status: text(&["status", "chat_status", "situacao"]),
+        })
+    }
+}
+
+pub(super) fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_i64().is_some_and(|n| n != 0),
+        Value::String(s) => matches!(
+            s.to_ascii_lowercase().as_str(),
+            "true" | "1" | "yes" | "sim" | "s"
+        ),
+        _ => false,
+    }
+}
+
+/// Aceita RFC 3339, `AAAA-MM-DD HH:MM:SS` (UTC) ou timestamp Unix em segundos
+pub(super) fn parse_datetime(value: &Value) -> Option<DateTime<Utc>> {
+    match value {
+        Value::Number(n) => Utc.timestamp_opt(n.as_i64()?, 0).single(),
+        Value::String(s) => parse_timestamp(s).ok(),
+        _ => None,
+    }
+}
+
+impl ChatGuruClient {
+    /// Consulta a situação do chat de um número (`action=chat_status`)
+    ///
+    /// Permite decidir antes do `message_send` se o envio vai funcionar, em
+    /// vez de depender do aviso de "Chat não existe". Um chat inexistente
+    /// retorna `Ok` com [`ChatStatus::exists`] igual a `false`.
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - número de telefone inválido
+    /// * `SerializationError` - resposta da API em formato inesperado
+    /// * `ApiError`, `RateLimited`, `Unauthorized`, `NetworkError` ou `CircuitOpen` - falha na chamada
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let status = client.get_chat_status("5511999999999").await?;
+    /// if status.exists {
+    ///     client.send_confirmation_message("5511999999999", None, "Olá!").await?;
+    /// }
+    /// ```
+    pub async fn get_chat_status(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+    ) -> Result<ChatStatus> {
+        let phone_number = phone_number.into();
+        phone_number.validate()?;
+
+        let request = ChatGuruRequest::new("chat_status")
+            .param("chat_number", phone_number.digits())
+            .cacheable();
+
+        match self.execute(&request).await {
+            Ok(body) => ChatStatus::from_body(&body),
+            Err(e) if e.is_chat_not_found() => Ok(ChatStatus::not_found()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Altera o nome do chat de um número (`action=chat_update_name`)
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - número de telefone inválido ou nome vazio
+    /// * `ChatNotFound` - não existe chat para o número
+    /// * `ApiError`, `RateLimited`, `Unauthorized`, `NetworkError` ou `CircuitOpen` - falha na chamada
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// client.update_chat_name("5511999999999", "João Silva - Empresa X").await?;
+    /// ```
+    pub async fn update_chat_name(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        name: &str,
+    ) -> Result<()> {
+        let phone_number = phone_number.into();
+        phone_number.validate()?;
+
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ChatGuruError::ValidationError(
+                "Chat name must not be empty".to_string(),
+            ));
+        }
+
+        self.call_action(
+            "chat_update_name",
+            &self.default_phone_id,
+            &[("chat_number", phone_number.digits()), ("chat_name", name)],
+        )
+        .await?;
+
+        tracing::debug!("Chat {} renamed", phone_number.masked());
+        Ok(())
+    }
+
+    /// Atribui o chat de um número a um atendente (`action=chat_update_user`)
+    ///
+    /// `user` pode ser o e-mail do atendente (enviado como `user_email`) ou o
+    /// ID do usuário no ChatGuru (enviado como `user_id`).
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - número de telefone inválido ou atendente vazio
+    /// * `ChatNotFound` - não existe chat para o número
+    /// * `ApiError`, `RateLimited`, `Unauthorized`, `NetworkError` ou `CircuitOpen` - falha na chamada
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// client.assign_chat("5511999999999", "vendas@empresa.com.br").await?;
+    /// ```
+    pub async fn assign_chat(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        user_email_or_id: &str,
+    ) -> Result<()> {
+        let phone_number = phone_number.into();
+        phone_number.validate()?;
+
+        let user = user_email_or_id.trim();
+        if user.is_empty() {
+            return Err(ChatGuruError::ValidationError(
+                "Assigned user must not be empty".to_string(),
+            ));
+        }
+        let user_param = if user.contains('@') {
+            "user_email"
+        } else {
+            "user_id"
+        };
+
+        self.call_action(
+            "chat_update_user",
+            &self.default_phone_id,
+            &[("chat_number", phone_number.digits()), (user_param, user)],
+        )
+        .await?;
+
+        tracing::debug!("Chat {} assigned to {}", phone_number.masked(), user);
+        Ok(())
+    }
+}
@@ -0,0 +1,312 @@
+use super::chat::{is_truthy, parse_datetime};
+use super::contact::parse_tags;
+use super::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::types::PhoneNumber;
+use chrono::{DateTime, Utc};
+use futures_core::future::BoxFuture;
+use futures_core::Stream;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Quantidade de chats pedida por página em [`ChatGuruClient::list_chats`]
+pub const DEFAULT_CHAT_PAGE_SIZE: u32 = 100;
+
+/// Filtros de [`ChatGuruClient::list_chats`]
+///
+/// Filtros não definidos não são enviados à API.
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::client::ChatFilter;
+/// use chrono::{Duration, Utc};
+///
+/// let filter = ChatFilter::new()
+///     .tag("cliente")
+///     .agent("vendas@empresa.com.br")
+///     .since(Utc::now() - Duration::days(1))
+///     .status("aberto");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatFilter {
+    tag: Option<String>,
+    agent: Option<String>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    status: Option<String>,
+    page_size: u32,
+}
+
+impl Default for ChatFilter {
+    fn default() -> Self {
+        Self {
+            tag: None,
+            agent: None,
+            since: None,
+            until: None,
+            status: None,
+            page_size: DEFAULT_CHAT_PAGE_SIZE,
+        }
+    }
+}
+
+impl ChatFilter {
+    /// Filtro vazio (todos os chats da linha padrão do cliente)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apenas chats com a tag informada
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    /// Apenas chats do atendente (e-mail ou ID do usuário no ChatGuru)
+    pub fn agent(mut self, agent: impl Into<String>) -> Self {
+        self.agent = Some(agent.into());
+        self
+    }
+
+    /// Apenas chats com atividade a partir da data informada
+    pub fn since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Apenas chats com atividade até a data informada
+    pub fn until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Apenas chats com a situação informada (`aberto`, `fechado`, ...)
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    /// Define quantos chats são pedidos por página (padrão: 100)
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Parâmetros da ação `chat_list` para a página informada (a partir de 1)
+    fn params(&self, page: u32) -> Vec<(&'static str, String)> {
+        let format_date = |date: &DateTime<Utc>| date.format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let mut params = vec![
+            ("page", page.to_string()),
+            ("page_size", self.page_size.to_string()),
+        ];
+        if let Some(ref tag) = self.tag {
+            params.push(("tag", tag.clone()));
+        }
+        if let Some(ref agent) = self.agent {
+            let name = if agent.contains('@') {
+                "user_email"
+            } else {
+                "user_id"
+            };
+            params.push((name, agent.clone()));
+        }
+        if let Some(ref since) = self.since {
+            params.push(("date_from", format_date(since)));
+        }
+        if let Some(ref until) = self.until {
+            params.push(("date_to", format_date(until)));
+        }
+        if let Some(ref status) = self.status {
+            params.push(("status", status.clone()));
+        }
+        params
+    }
+}
+
+/// Resumo de um chat, como retornado pela listagem do ChatGuru
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatSummary {
+    /// ID do chat no ChatGuru
+    pub chat_id: Option<String>,
+    /// Telefone do contato
+    pub phone: PhoneNumber,
+    /// Nome do chat/contato
+    pub name: Option<String>,
+    /// Tags aplicadas ao chat
+    pub tags: Vec<String>,
+    /// Atendente responsável (e-mail, nome ou ID, como informado pela API)
+    pub assigned_agent: Option<String>,
+    /// Situação bruta informada pela API (`aberto`, `fechado`, ...)
+    pub status: Option<String>,
+    /// Se o chat está arquivado
+    pub archived: bool,
+    /// Data da última atividade no chat
+    pub last_activity: Option<DateTime<Utc>>,
+}
+
+impl ChatSummary {
+    /// Interpreta uma página da resposta de `chat_list`
+    ///
+    /// Aceita a lista no nível raiz ou dentro de `chats`/`data`. Itens sem
+    /// telefone são ignorados.
+    fn page_from_body(body: &str) -> Result<Vec<Self>> {
+        let value: Value = serde_json::from_str(body)?;
+        let chats = ["chats", "data"]
+            .iter()
+            .find_map(|key| value.get(key).and_then(Value::as_array))
+            .or_else(|| value.as_array())
+            .ok_or_else(|| {
+                ChatGuruError::SerializationError(format!(
+                    "Unexpected chat_list response: {}",
+                    body
+                ))
+            })?;
+
+        Ok(chats.iter().filter_map(Self::from_value).collect())
+    }
+
+    fn from_value(chat: &Value) -> Option<Self> {
+        let field = |names: &[&str]| names.iter().find_map(|name| chat.get(name));
+        let text = |names: &[&str]| {
+            field(names).and_then(|v| match v {
+                Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+                Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            })
+        };
+
+        Some(Self {
+            chat_id: text(&["chat_id", "id"]),
+            phone: PhoneNumber::from(text(&["chat_number", "celular", "phone"])?),
+            name: text(&["name", "nome", "chat_name"]),
+            tags: field(&["tags", "etiquetas"])
+                .map(parse_tags)
+                .unwrap_or_default(),
+            assigned_agent: text(&[
+                "assigned_agent",
+                "assigned_user",
+                "user_email",
+                "responsavel",
+                "atendente",
+            ]),
+            status: text(&["status", "chat_status", "situacao"]),
+            archived: field(&["archived", "arquivado", "is_archived"])
+                .map(is_truthy)
+                .unwrap_or(false),
+            last_activity: field(&[
+                "last_activity",
+                "last_message_at",
+                "updated_at",
+                "data_ultima_mensagem",
+            ])
+            .and_then(parse_datetime),
+        })
+    }
+}
+
+/// Stream de [`ChatGuruClient::list_chats`], que busca as páginas sob demanda
+struct ChatPages {
+    client: ChatGuruClient,
+    filter: ChatFilter,
+    next_page: u32,
+    buffered: VecDeque<ChatSummary>,
+    pending: Option<BoxFuture<'static, Result<Vec<ChatSummary>>>>,
+    done: bool,
+}
+
+impl ChatPages {
+    fn fetch(&self) -> BoxFuture<'static, Result<Vec<ChatSummary>>> {
+        let client = self.client.clone();
+        let params = self.filter.params(self.next_page);
+        Box::pin(async move {
+            let params: Vec<(&str, &str)> = params
+                .iter()
+                .map(|(name, value)| (*name, value.as_str()))
+                .collect();
+            let body = client
+                .call_action("chat_list", &client.default_phone_id, &params)
+                .await?;
+            ChatSummary::page_from_body(&body)
+        })
+    }
+}
+
+impl Stream for ChatPages {
+    type Item = Result<ChatSummary>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(chat) = this.buffered.pop_front() {
+                return Poll::Ready(Some(Ok(chat)));
+            }
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            let mut pending = match this.pending.take() {
+                Some(pending) => pending,
+                None => this.fetch(),
+            };
+
+            match pending.as_mut().poll(cx) {
+                Poll::Pending => {
+                    this.pending = Some(pending);
+                    return Poll::Pending;
+                }
+                Poll::Ready(Ok(page)) => {
+                    tracing::debug!("Chat listing page {}: {} chats", this.next_page, page.len());
+                    // Página incompleta (ou vazia) é a última
+                    this.done = page.len() < this.filter.page_size as usize;
+                    this.next_page += 1;
+                    this.buffered.extend(page);
+                }
+                Poll::Ready(Err(e)) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+    }
+}
+
+impl ChatGuruClient {
+    /// Lista os chats da linha padrão do cliente, página por página (`action=chat_list`)
+    ///
+    /// As páginas são buscadas sob demanda, conforme o stream é consumido, e
+    /// passam pelo rate limiter e pela política de retry do cliente. A
+    /// listagem termina na primeira página incompleta; um erro encerra o
+    /// stream depois de ser entregue.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// use chatguru::client::ChatFilter;
+    /// use futures::StreamExt;
+    ///
+    /// let filter = ChatFilter::new().tag("cliente").since(ontem);
+    /// let mut chats = std::pin::pin!(client.list_chats(filter));
+    ///
+    /// while let Some(chat) = chats.next().await {
+    ///     let chat = chat?;
+    ///     reconciliar(&chat.phone, &chat.tags).await?;
+    /// }
+    /// ```
+    pub fn list_chats(
+        &self,
+        filter: ChatFilter,
+    ) -> impl Stream<Item = Result<ChatSummary>> + Send + 'static {
+        ChatPages {
+            client: self.clone(),
+            filter,
+            next_page: 1,
+            buffered: VecDeque::new(),
+            pending: None,
+            done: false,
+        }
+    }
+}
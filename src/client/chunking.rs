@@ -0,0 +1,151 @@
+use super::ChatGuruClient;
+use crate::error::Result;
+use crate::text::{grapheme_len, truncate_graphemes};
+use crate::types::PhoneNumber;
+
+/// Tamanho máximo padrão de uma mensagem, em caracteres (limite do WhatsApp)
+pub const DEFAULT_MAX_MESSAGE_LENGTH: usize = 4096;
+
+/// Divide um texto em partes de até `max_len` caracteres
+///
+/// Quebra preferencialmente em linhas em branco, depois em quebras de linha e
+/// depois em espaços; só corta no meio de uma palavra quando ela sozinha
+/// excede o limite, e nunca no meio de um emoji (os caracteres são contados
+/// por grafemas, veja [`crate::text`]). Com `numbered`, cada parte recebe o sufixo ` (1/3)`,
+/// já descontado do limite. Textos dentro do limite retornam uma única parte,
+/// sem sufixo.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::client::split_message;
+///
+/// let parts = split_message(&relatorio, 1000, true);
+/// assert!(parts.iter().all(|p| chatguru::text::grapheme_len(p) <= 1000));
+/// ```
+pub fn split_message(text: &str, max_len: usize, numbered: bool) -> Vec<String> {
+    let max_len = max_len.max(1);
+    if grapheme_len(text) <= max_len {
+        return vec![text.to_string()];
+    }
+    if !numbered {
+        return split_chunks(text, max_len);
+    }
+
+    // O sufixo depende do total de partes; aumenta a reserva até caber
+    let mut digits = 1;
+    loop {
+        let suffix_len = " (/)".len() + 2 * digits;
+        let chunks = split_chunks(text, max_len.saturating_sub(suffix_len).max(1));
+        let total = chunks.len();
+
+        if total.to_string().len() <= digits || suffix_len >= max_len {
+            return chunks
+                .into_iter()
+                .enumerate()
+                .map(|(i, chunk)| format!("{} ({}/{})", chunk, i + 1, total))
+                .collect();
+        }
+        digits += 1;
+    }
+}
+
+fn split_chunks(text: &str, limit: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut rest = text.trim();
+
+    while grapheme_len(rest) > limit {
+        let window = truncate_graphemes(rest, limit);
+        let window_end = window.len();
+
+        // Evita partes muito curtas: só usa a quebra preferida se ela estiver
+        // na segunda metade da janela
+        let half = window.len() / 2;
+        let breaks = [
+            window.rfind("\n\n"),
+            window.rfind('\n'),
+            window.rfind(char::is_whitespace),
+        ];
+        let cut = breaks
+            .iter()
+            .flatten()
+            .copied()
+            .find(|&index| index >= half)
+            .or(breaks[2].filter(|&index| index > 0))
+            .unwrap_or(window_end);
+
+        chunks.push(rest[..cut].trim_end().to_string());
+        rest = rest[cut..].trim_start();
+    }
+
+    if !rest.is_empty() {
+        chunks.push(rest.to_string());
+    }
+    chunks
+}
+
+impl ChatGuruClient {
+    /// Divide a mensagem conforme o limite e a numeração configurados no builder
+    pub(crate) fn message_chunks(&self, message: &str) -> Vec<String> {
+        split_message(message, self.max_message_length, self.number_chunks)
+    }
+
+    /// Envia um texto longo em várias mensagens, na ordem
+    ///
+    /// Divide o texto com [`split_message`] usando o limite configurado em
+    /// [`max_message_length`](super::ChatGuruClientBuilder::max_message_length)
+    /// e retorna o resultado de cada parte. Diferente de
+    /// [`send_confirmation_message`](Self::send_confirmation_message), falhas
+    /// da API são retornadas como erro; o envio para na primeira falha, para
+    /// não deixar buracos na conversa, e as partes seguintes não aparecem na lista.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let results = client.send_long_message("5511999999999", None, &relatorio).await;
+    /// if let Some(Err(e)) = results.last() {
+    ///     tracing::error!("Relatório enviado até a parte {}: {}", results.len() - 1, e);
+    /// }
+    /// ```
+    pub async fn send_long_message(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        phone_id: Option<&str>,
+        message: &str,
+    ) -> Vec<Result<()>> {
+        let phone_number = phone_number.into();
+        if let Err(e) = phone_number.validate() {
+            return vec![Err(e)];
+        }
+
+        let phone_id = phone_id.unwrap_or(&self.default_phone_id);
+        let chunks = self.message_chunks(message);
+        let total = chunks.len();
+        let mut results = Vec::with_capacity(total);
+
+        for chunk in &chunks {
+            let result = self
+                .call_action(
+                    "message_send",
+                    phone_id,
+                    &[("text", chunk), ("chat_number", phone_number.digits())],
+                )
+                .await
+                .map(|_| ());
+
+            let failed = result.is_err();
+            results.push(result);
+            if failed {
+                break;
+            }
+        }
+
+        tracing::debug!(
+            "Long message to {}: {}/{} parts sent",
+            phone_number.masked(),
+            results.iter().filter(|r| r.is_ok()).count(),
+            total
+        );
+        results
+    }
+}
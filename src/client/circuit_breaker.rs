@@ -0,0 +1,253 @@
+use super::RetryOutcome;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Estado do [`CircuitBreaker`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requisições liberadas normalmente
+    Closed,
+    /// Requisições bloqueadas até o fim do cool-down
+    Open,
+    /// Cool-down encerrado: uma requisição de teste decide se o circuito fecha
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct BreakerState {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    /// Início da requisição de teste em andamento (estado half-open)
+    probe_started_at: Option<Instant>,
+}
+
+/// Circuit breaker para as chamadas à API do ChatGuru
+///
+/// Após `failure_threshold` falhas consecutivas (erros de rede ou `5xx`) o
+/// circuito abre e as chamadas falham imediatamente com
+/// [`ChatGuruError::CircuitOpen`](crate::ChatGuruError::CircuitOpen), sem
+/// aguardar timeouts. Passado o `cool_down`, uma única requisição de teste é
+/// liberada: se ela funcionar o circuito fecha, senão volta a abrir. Uma
+/// requisição de teste cancelada (ex: future descartado por timeout) libera
+/// o teste na hora; sem resultado registrado após outro `cool_down`, ela é
+/// considerada perdida e outra é liberada.
+///
+/// Respostas `4xx` indicam que a API está no ar e não contam como falha.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+///
+/// let client = ChatGuruClient::builder()
+///     // ...
+///     .circuit_breaker(5, Duration::from_secs(30))
+///     .build()?;
+/// ```
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cool_down: Duration,
+    state: Mutex<BreakerState>,
+}
+
+impl CircuitBreaker {
+    /// Cria um circuit breaker que abre após `failure_threshold` falhas
+    /// consecutivas e permanece aberto por `cool_down`
+    pub fn new(failure_threshold: u32, cool_down: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cool_down,
+            state: Mutex::new(BreakerState {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                opened_at: None,
+                probe_started_at: None,
+            }),
+        }
+    }
+
+    /// Estado atual do circuito
+    pub fn state(&self) -> CircuitState {
+        let mut inner = self.lock();
+        self.refresh(&mut inner);
+        inner.state
+    }
+
+    /// Fecha o circuito manualmente, zerando o contador de falhas
+    pub fn reset(&self) {
+        let mut inner = self.lock();
+        inner.state = CircuitState::Closed;
+        inner.consecutive_failures = 0;
+        inner.opened_at = None;
+        inner.probe_started_at = None;
+    }
+
+    /// Verifica se uma requisição pode ser feita agora
+    ///
+    /// No estado half-open apenas a primeira chamada é liberada, até que seu
+    /// resultado seja registrado com [`record`](Self::record) ou passe o
+    /// `cool_down`.
+    pub fn allow_request(&self) -> bool {
+        self.permit().map(BreakerPermit::detach).is_some()
+    }
+
+    /// Como [`allow_request`](Self::allow_request), retornando uma permissão
+    /// que libera a requisição de teste se for descartada sem resultado
+    pub(crate) fn permit(&self) -> Option<BreakerPermit<'_>> {
+        let mut inner = self.lock();
+        self.refresh(&mut inner);
+
+        let probe = match inner.state {
+            CircuitState::Closed => None,
+            CircuitState::Open => return None,
+            CircuitState::HalfOpen => {
+                let in_flight = inner
+                    .probe_started_at
+                    .is_some_and(|started_at| started_at.elapsed() < self.cool_down);
+                if in_flight {
+                    return None;
+                }
+                let started_at = Instant::now();
+                inner.probe_started_at = Some(started_at);
+                Some(started_at)
+            }
+        };
+
+        Some(BreakerPermit {
+            breaker: self,
+            probe,
+        })
+    }
+
+    /// Registra o resultado de uma requisição liberada por [`allow_request`](Self::allow_request)
+    pub fn record(&self, outcome: &RetryOutcome) {
+        let failed = match outcome {
+            RetryOutcome::Network { .. } => true,
+            RetryOutcome::Status(status) => (500..600).contains(status),
+        };
+
+        let mut inner = self.lock();
+        inner.probe_started_at = None;
+
+        if !failed {
+            if inner.state != CircuitState::Closed {
+                tracing::info!("ChatGuru circuit breaker closed");
+            }
+            inner.state = CircuitState::Closed;
+            inner.consecutive_failures = 0;
+            inner.opened_at = None;
+            return;
+        }
+
+        inner.consecutive_failures += 1;
+        let should_open = inner.state == CircuitState::HalfOpen
+            || inner.consecutive_failures >= self.failure_threshold;
+
+        if should_open && inner.state != CircuitState::Open {
+            tracing::warn!(
+                "ChatGuru circuit breaker opened after {} consecutive failures, cooling down for {}s",
+                inner.consecutive_failures,
+                self.cool_down.as_secs()
+            );
+            inner.state = CircuitState::Open;
+            inner.opened_at = Some(Instant::now());
+        }
+    }
+
+    fn refresh(&self, inner: &mut BreakerState) {
+        if inner.state != CircuitState::Open {
+            return;
+        }
+
+        let cooled_down = inner
+            .opened_at
+            .is_none_or(|opened_at| opened_at.elapsed() >= self.cool_down);
+
+        if cooled_down {
+            inner.state = CircuitState::HalfOpen;
+            inner.probe_started_at = None;
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Permissão para uma requisição, retornada por [`CircuitBreaker::permit`]
+///
+/// Descartada sem [`record`](Self::record) (ex: future cancelado durante o
+/// rate limiter ou por timeout do chamador), libera a requisição de teste do
+/// estado half-open para a próxima chamada.
+#[must_use]
+pub(crate) struct BreakerPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    probe: Option<Instant>,
+}
+
+impl BreakerPermit<'_> {
+    /// Registra o resultado da requisição (ver [`CircuitBreaker::record`])
+    pub(crate) fn record(mut self, outcome: &RetryOutcome) {
+        self.probe = None;
+        self.breaker.record(outcome);
+    }
+
+    /// Mantém a requisição de teste até [`CircuitBreaker::record`] ou o fim do `cool_down`
+    fn detach(mut self) {
+        self.probe = None;
+    }
+}
+
+impl Drop for BreakerPermit<'_> {
+    fn drop(&mut self) {
+        let Some(started_at) = self.probe else {
+            return;
+        };
+
+        let mut inner = self.breaker.lock();
+        if inner.probe_started_at == Some(started_at) {
+            tracing::debug!("ChatGuru circuit breaker probe cancelled, releasing it");
+            inner.probe_started_at = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CircuitBreaker, CircuitState};
+    use crate::client::RetryOutcome;
+    use std::time::Duration;
+
+    fn half_open(cool_down: Duration) -> CircuitBreaker {
+        let breaker = CircuitBreaker::new(1, cool_down);
+        breaker.record(&RetryOutcome::Status(503));
+        std::thread::sleep(cool_down);
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        breaker
+    }
+
+    #[test]
+    fn dropped_permit_releases_probe() {
+        let breaker = half_open(Duration::from_millis(20));
+
+        let probe = breaker.permit().expect("probe allowed");
+        assert!(breaker.permit().is_none());
+        drop(probe);
+
+        let probe = breaker.permit().expect("probe released");
+        probe.record(&RetryOutcome::Status(200));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn unrecorded_probe_expires_after_cool_down() {
+        let breaker = half_open(Duration::from_millis(20));
+
+        assert!(breaker.allow_request());
+        assert!(!breaker.allow_request());
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(breaker.allow_request());
+    }
+}
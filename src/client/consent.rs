@@ -0,0 +1,162 @@
+//! Registro de consentimento (LGPD): contatos que pediram para não receber mensagens
+//!
+//! Com um [`ConsentRegistry`] configurado em
+//! [`ChatGuruClientBuilder::consent_registry`](super::ChatGuruClientBuilder::consent_registry),
+//! todo envio ao contato (`message_send` e `message_file_send`) é conferido
+//! antes da chamada e falha com [`ChatGuruError::OptedOut`] se o contato
+//! tiver pedido o descadastro.
+
+use super::request::{is_send_action, ChatGuruRequest};
+use super::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::types::{PhoneNumber, WebhookPayload};
+use crate::webhook::{ConsentClassifier, ConsentIntent};
+use futures_core::future::BoxFuture;
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+/// Armazenamento dos contatos descadastrados
+///
+/// Os números são informados só com dígitos, com código do país. A
+/// implementação padrão é [`InMemoryConsentRegistry`]; implemente este trait
+/// para guardar as decisões em um banco compartilhado entre instâncias, como
+/// a legislação exige que elas sobrevivam a reinícios.
+pub trait ConsentRegistry: Send + Sync {
+    /// Indica se o contato pediu para não receber mensagens
+    fn is_opted_out<'a>(&'a self, phone: &'a str) -> BoxFuture<'a, Result<bool>>;
+
+    /// Registra o descadastro do contato
+    fn opt_out<'a>(&'a self, phone: &'a str) -> BoxFuture<'a, Result<()>>;
+
+    /// Registra que o contato voltou a aceitar mensagens
+    fn opt_in<'a>(&'a self, phone: &'a str) -> BoxFuture<'a, Result<()>>;
+}
+
+/// Registro em memória, perdido quando o processo termina
+#[derive(Debug, Default)]
+pub struct InMemoryConsentRegistry {
+    opted_out: Mutex<HashSet<String>>,
+}
+
+impl InMemoryConsentRegistry {
+    /// Cria um registro vazio
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Contatos descadastrados
+    pub fn opted_out(&self) -> Vec<String> {
+        let mut phones: Vec<_> = self.phones().iter().cloned().collect();
+        phones.sort_unstable();
+        phones
+    }
+
+    fn phones(&self) -> std::sync::MutexGuard<'_, HashSet<String>> {
+        self.opted_out.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl ConsentRegistry for InMemoryConsentRegistry {
+    fn is_opted_out<'a>(&'a self, phone: &'a str) -> BoxFuture<'a, Result<bool>> {
+        let opted_out = self.phones().contains(phone);
+        Box::pin(async move { Ok(opted_out) })
+    }
+
+    fn opt_out<'a>(&'a self, phone: &'a str) -> BoxFuture<'a, Result<()>> {
+        self.phones().insert(phone.to_string());
+        Box::pin(async { Ok(()) })
+    }
+
+    fn opt_in<'a>(&'a self, phone: &'a str) -> BoxFuture<'a, Result<()>> {
+        self.phones().remove(phone);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+impl ChatGuruClient {
+    /// Registra o pedido de descadastro (ou de volta) contido na mensagem recebida
+    ///
+    /// Usa o [`ConsentClassifier`] padrão; retorna a decisão registrada, ou
+    /// `None` se a mensagem não é uma resposta de consentimento ou se não há
+    /// [`ConsentRegistry`] configurado.
+    ///
+    /// # Erros
+    ///
+    /// Retorna o erro do [`ConsentRegistry`] configurado.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// async fn webhook(payload: WebhookPayload) -> chatguru::Result<()> {
+    ///     if client.record_consent_reply(&payload).await?.is_some() {
+    ///         // Resposta de consentimento: não abre tarefa
+    ///         return Ok(());
+    ///     }
+    ///     // ...
+    /// }
+    /// ```
+    pub async fn record_consent_reply(
+        &self,
+        payload: &WebhookPayload,
+    ) -> Result<Option<ConsentIntent>> {
+        self.record_consent_reply_with(payload, &ConsentClassifier::default())
+            .await
+    }
+
+    /// Como [`record_consent_reply`](Self::record_consent_reply), com outro classificador
+    pub async fn record_consent_reply_with(
+        &self,
+        payload: &WebhookPayload,
+        classifier: &ConsentClassifier,
+    ) -> Result<Option<ConsentIntent>> {
+        let Some(ref registry) = self.consent_registry else {
+            return Ok(None);
+        };
+        let (Some(intent), Some(phone)) = (
+            classifier.classify_payload(payload),
+            payload.get_phone_number(),
+        ) else {
+            return Ok(None);
+        };
+
+        let phone = PhoneNumber::from(phone);
+        match intent {
+            ConsentIntent::OptOut => registry.opt_out(phone.digits()).await?,
+            ConsentIntent::OptIn => {
+                for variant in phone.brazil_ninth_digit_variants() {
+                    registry.opt_in(variant.digits()).await?;
+                }
+            }
+        }
+        tracing::info!("Contact {} consent recorded: {:?}", phone.masked(), intent);
+
+        Ok(Some(intent))
+    }
+
+    /// Recusa envios a contatos descadastrados no [`ConsentRegistry`] configurado
+    pub(super) async fn check_consent(&self, request: &ChatGuruRequest) -> Result<()> {
+        let Some(ref registry) = self.consent_registry else {
+            return Ok(());
+        };
+        if !is_send_action(request.action()) {
+            return Ok(());
+        }
+        let Some(chat_number) = request.param_value("chat_number") else {
+            return Ok(());
+        };
+
+        // O mesmo celular pode aparecer com e sem o nono dígito
+        let phone = PhoneNumber::from(chat_number);
+        for variant in phone.brazil_ninth_digit_variants() {
+            if registry.is_opted_out(variant.digits()).await? {
+                tracing::info!(
+                    "ChatGuru {} to {} skipped: contact opted out",
+                    request.action(),
+                    phone.masked()
+                );
+                return Err(ChatGuruError::OptedOut(phone.masked()));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use super::request::ChatGuruRequest;
+use super::ChatGuruClient;
+use crate::error::Result;
+use crate::types::custom_fields::normalize_key;
+use crate::types::PhoneNumber;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Dados do contato (lead) no ChatGuru, retornados por [`ChatGuruClient::get_contact`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Contact {
+    /// Telefone do contato
+    pub phone: PhoneNumber,
+    /// ID do chat no ChatGuru
+    pub chat_id: Option<String>,
+    /// Nome do contato
+    pub name: Option<String>,
+    /// E-mail do contato
+    pub email: Option<String>,
+    /// Tags (etiquetas) aplicadas ao contato
+    pub tags: Vec<String>,
+    /// Campos personalizados, com os nomes como cadastrados no ChatGuru
+    pub custom_fields: HashMap<String, Value>,
+    /// Etapa do funil em que o contato está
+    pub funnel_stage: Option<String>,
+}
+
+impl Contact {
+    /// Valor de um campo personalizado, com comparação aproximada do nome
+    ///
+    /// Mesma regra de [`ChatGuruPayload::get_custom`](crate::types::ChatGuruPayload::get_custom).
+    pub fn custom_field(&self, key: &str) -> Option<&Value> {
+        if let Some(value) = self.custom_fields.get(key) {
+            return Some(value);
+        }
+
+        let wanted = normalize_key(key);
+        self.custom_fields
+            .iter()
+            .find(|(name, _)| normalize_key(name) == wanted)
+            .map(|(_, value)| value)
+    }
+
+    /// Interpreta o corpo JSON da resposta de `contact_get`
+    ///
+    /// Aceita os campos no nível raiz ou dentro de `contact`/`chat`/`data`.
+    pub(crate) fn from_body(phone: &PhoneNumber, body: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(body)?;
+        let contact = ["contact", "chat", "data"]
+            .iter()
+            .find_map(|key| value.get(key).filter(|v| v.is_object()))
+            .unwrap_or(&value);
+
+        let field = |names: &[&str]| names.iter().find_map(|name| contact.get(name));
+        let text = |names: &[&str]| {
+            field(names).and_then(|v| match v {
+                Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+                Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            })
+        };
+
+        Ok(Self {
+            phone: text(&["chat_number", "celular", "phone"])
+                .map(PhoneNumber::from)
+                .unwrap_or_else(|| phone.clone()),
+            chat_id: text(&["chat_id", "id"]),
+            name: text(&["name", "nome", "chat_name"]),
+            email: text(&["email"]),
+            tags: field(&["tags", "etiquetas"])
+                .map(parse_tags)
+                .unwrap_or_default(),
+            custom_fields: field(&["campos_personalizados", "custom_fields"])
+                .and_then(Value::as_object)
+                .map(|fields| {
+                    fields
+                        .iter()
+                        .map(|(name, value)| (name.clone(), value.clone()))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            funnel_stage: text(&["funnel_stage", "etapa_funil", "funil_etapa", "etapa"]),
+        })
+    }
+}
+
+/// Aceita lista de textos, lista de objetos com `name`/`nome` ou texto separado por vírgulas
+pub(super) fn parse_tags(value: &Value) -> Vec<String> {
+    let tags: Vec<String> = match value {
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| match item {
+                Value::String(s) => Some(s.clone()),
+                Value::Object(tag) => tag
+                    .get("name")
+                    .or_else(|| tag.get("nome"))
+                    .and_then(Value::as_str)
+                    .map(String::from),
+                _ => None,
+            })
+            .collect(),
+        Value::String(s) => s.split(',').map(String::from).collect(),
+        _ => Vec::new(),
+    };
+
+    tags.into_iter()
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+impl ChatGuruClient {
+    /// Consulta os dados do contato de um número (`action=contact_get`)
+    ///
+    /// Retorna nome, e-mail, tags, campos personalizados e etapa do funil
+    /// como estão no ChatGuru, para enriquecer eventos sem esperar o próximo
+    /// webhook do contato.
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - número de telefone inválido
+    /// * `ChatNotFound` - não existe contato para o número
+    /// * `SerializationError` - resposta da API em formato inesperado
+    /// * `ApiError`, `RateLimited`, `Unauthorized`, `NetworkError` ou `CircuitOpen` - falha na chamada
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let contact = client.get_contact("5511999999999").await?;
+    /// println!("{:?} está na etapa {:?}", contact.name, contact.funnel_stage);
+    /// ```
+    pub async fn get_contact(&self, phone_number: impl Into<PhoneNumber>) -> Result<Contact> {
+        let phone_number = phone_number.into();
+        phone_number.validate()?;
+
+        let request = ChatGuruRequest::new("contact_get")
+            .param("chat_number", phone_number.digits())
+            .cacheable();
+        let body = self.execute(&request).await?;
+
+        Contact::from_body(&phone_number, &body)
+    }
+}
@@ -0,0 +1,259 @@
+//! Moderação do conteúdo das mensagens enviadas e recebidas
+//!
+//! Um [`ContentFilter`] configurado em
+//! [`ChatGuruClientBuilder::content_filter`](super::ChatGuruClientBuilder::content_filter)
+//! examina o texto (`text`) e a legenda (`caption`) de toda mensagem enviada
+//! ao contato, podendo deixá-la passar, alterá-la ou recusá-la com
+//! [`ChatGuruError::Blocked`]. O mesmo filtro pode ser aplicado às mensagens
+//! recebidas com [`ContentFilter::filter_payload`].
+
+use super::request::{is_send_action, ChatGuruRequest};
+use super::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::types::redact::redact_text;
+use crate::types::WebhookPayload;
+use regex::Regex;
+use std::borrow::Cow;
+
+/// Parâmetros com o conteúdo das mensagens enviadas
+const CONTENT_PARAMS: &[&str] = &["text", "caption"];
+
+/// Decisão de um [`ContentFilter`] sobre um texto
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterDecision {
+    /// O texto pode ser usado como está
+    Allow,
+    /// Usar o texto alterado no lugar do original
+    Replace(String),
+    /// Recusar a mensagem, com o motivo
+    Block(String),
+}
+
+/// Moderação do conteúdo das mensagens
+///
+/// A verificação é síncrona e roda antes de cada envio; para moderação por
+/// serviço externo, use um [`Middleware`](super::Middleware).
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::client::{ContentFilter, FilterDecision};
+///
+/// struct SemCaixaAlta;
+///
+/// impl ContentFilter for SemCaixaAlta {
+///     fn check(&self, text: &str) -> FilterDecision {
+///         if text.chars().any(char::is_lowercase) {
+///             FilterDecision::Allow
+///         } else {
+///             FilterDecision::Replace(text.to_lowercase())
+///         }
+///     }
+/// }
+/// ```
+pub trait ContentFilter: Send + Sync {
+    /// Decide sobre o texto de uma mensagem
+    fn check(&self, text: &str) -> FilterDecision;
+
+    /// Aplica o filtro à mensagem de um webhook recebido
+    ///
+    /// Um texto alterado substitui o original no payload.
+    ///
+    /// # Erros
+    ///
+    /// Retorna `Blocked` se o filtro recusar a mensagem.
+    fn filter_payload(&self, payload: &mut WebhookPayload) -> Result<()> {
+        let Some(text) = payload.get_message_text() else {
+            return Ok(());
+        };
+        match self.check(&text) {
+            FilterDecision::Allow => Ok(()),
+            FilterDecision::Replace(text) => {
+                set_message_text(payload, text);
+                Ok(())
+            }
+            FilterDecision::Block(reason) => Err(ChatGuruError::Blocked { reason }),
+        }
+    }
+}
+
+/// Filtro que aceita qualquer conteúdo (padrão do cliente)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopContentFilter;
+
+impl ContentFilter for NoopContentFilter {
+    fn check(&self, _text: &str) -> FilterDecision {
+        FilterDecision::Allow
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Rule {
+    Block { pattern: Regex, reason: String },
+    Replace { pattern: Regex, replacement: String },
+    RedactPii,
+}
+
+/// Filtro por expressões regulares
+///
+/// As regras são aplicadas na ordem em que foram adicionadas: uma regra de
+/// bloqueio que casar recusa a mensagem; as de substituição alteram o texto
+/// visto pelas regras seguintes.
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::client::{ContentFilter, FilterDecision, RegexContentFilter};
+///
+/// let filter = RegexContentFilter::new()
+///     .block(r"(?i)\b(idiota|imbecil)\b", "profanity")
+///     .unwrap()
+///     .replace(r"https?://\S+", "[link removido]")
+///     .unwrap()
+///     .redact_pii();
+///
+/// assert_eq!(
+///     filter.check("Veja https://exemplo.com e ligue 11 99999-9999"),
+///     FilterDecision::Replace("Veja [link removido] e ligue [telefone]".to_string())
+/// );
+/// assert_eq!(
+///     filter.check("Seu idiota"),
+///     FilterDecision::Block("profanity".to_string())
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RegexContentFilter {
+    rules: Vec<Rule>,
+}
+
+impl RegexContentFilter {
+    /// Filtro sem regras
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Recusa mensagens em que `pattern` casar, com o motivo informado
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se a expressão regular for inválida.
+    pub fn block(mut self, pattern: &str, reason: impl Into<String>) -> Result<Self> {
+        self.rules.push(Rule::Block {
+            pattern: compile(pattern)?,
+            reason: reason.into(),
+        });
+        Ok(self)
+    }
+
+    /// Substitui os trechos em que `pattern` casar (aceita `$1`, `$nome`)
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se a expressão regular for inválida.
+    pub fn replace(mut self, pattern: &str, replacement: impl Into<String>) -> Result<Self> {
+        self.rules.push(Rule::Replace {
+            pattern: compile(pattern)?,
+            replacement: replacement.into(),
+        });
+        Ok(self)
+    }
+
+    /// Substitui e-mails, telefones, CPFs e CNPJs por marcadores (ver
+    /// [`redact_text`])
+    pub fn redact_pii(mut self) -> Self {
+        self.rules.push(Rule::RedactPii);
+        self
+    }
+}
+
+impl ContentFilter for RegexContentFilter {
+    fn check(&self, text: &str) -> FilterDecision {
+        let mut current = Cow::Borrowed(text);
+
+        for rule in &self.rules {
+            match rule {
+                Rule::Block { pattern, reason } if pattern.is_match(&current) => {
+                    return FilterDecision::Block(reason.clone());
+                }
+                Rule::Block { .. } => {}
+                Rule::Replace {
+                    pattern,
+                    replacement,
+                } => {
+                    if let Cow::Owned(replaced) =
+                        pattern.replace_all(&current, replacement.as_str())
+                    {
+                        current = Cow::Owned(replaced);
+                    }
+                }
+                Rule::RedactPii => {
+                    let redacted = redact_text(&current);
+                    if redacted != current {
+                        current = Cow::Owned(redacted);
+                    }
+                }
+            }
+        }
+
+        match current {
+            Cow::Borrowed(_) => FilterDecision::Allow,
+            Cow::Owned(text) => FilterDecision::Replace(text),
+        }
+    }
+}
+
+fn compile(pattern: &str) -> Result<Regex> {
+    Regex::new(pattern).map_err(|e| {
+        ChatGuruError::ValidationError(format!("Invalid content filter pattern: {}", e))
+    })
+}
+
+fn set_message_text(payload: &mut WebhookPayload, text: String) {
+    match payload {
+        WebhookPayload::ChatGuru(p) => p.texto_mensagem = text,
+        WebhookPayload::EventType(p) => p.data.annotation = Some(text),
+        WebhookPayload::Generic(p) => p.mensagem = Some(text),
+    }
+}
+
+impl ChatGuruClient {
+    /// Aplica o [`ContentFilter`] configurado ao conteúdo de um envio
+    ///
+    /// Retorna a chamada com o texto alterado, ou `None` se ela pode seguir
+    /// como está.
+    pub(super) fn apply_content_filter(
+        &self,
+        request: &ChatGuruRequest,
+    ) -> Result<Option<ChatGuruRequest>> {
+        let Some(ref filter) = self.content_filter else {
+            return Ok(None);
+        };
+        if !is_send_action(request.action()) {
+            return Ok(None);
+        }
+
+        let mut filtered: Option<ChatGuruRequest> = None;
+        for name in CONTENT_PARAMS {
+            let Some(text) = request.param_value(name) else {
+                continue;
+            };
+            match filter.check(text) {
+                FilterDecision::Allow => {}
+                FilterDecision::Replace(text) => {
+                    filtered
+                        .get_or_insert_with(|| request.clone())
+                        .set_param(name, text);
+                }
+                FilterDecision::Block(reason) => {
+                    tracing::info!(
+                        "ChatGuru {} blocked by content filter: {}",
+                        request.action(),
+                        reason
+                    );
+                    return Err(ChatGuruError::Blocked { reason });
+                }
+            }
+        }
+        Ok(filtered)
+    }
+}
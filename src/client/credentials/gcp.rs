@@ -0,0 +1,209 @@
+use super::{CredentialsProvider, TokenCache, DEFAULT_CREDENTIALS_CACHE_TTL};
+use crate::error::{ChatGuruError, Result};
+use crate::gcp::{self, TOKEN_REFRESH_MARGIN};
+use crate::secret::{describe_error, SecretString};
+use base64::Engine;
+use futures_core::future::BoxFuture;
+use serde::Deserialize;
+use std::fmt;
+use std::time::Duration;
+
+/// URL padrão da API do Secret Manager
+pub const SECRET_MANAGER_API_URL: &str = "https://secretmanager.googleapis.com/v1";
+
+#[derive(Deserialize)]
+struct AccessSecretVersionResponse {
+    payload: SecretPayload,
+}
+
+#[derive(Deserialize)]
+struct SecretPayload {
+    data: String,
+}
+
+/// Token da API guardado no Google Secret Manager
+///
+/// Lê `projects/{project}/secrets/{secret}/versions/{version}` (padrão:
+/// `latest`) pela API REST, autenticando com a conta de serviço do servidor
+/// de metadados. O token é guardado por [`ttl`](Self::ttl) e relido quando
+/// a API do ChatGuru o recusa, então uma nova versão do segredo passa a ser
+/// usada sem reiniciar o serviço. A conta de serviço precisa do papel
+/// `roles/secretmanager.secretAccessor`.
+///
+/// Disponível com a feature `gcp-secret-manager`.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use std::sync::Arc;
+/// use chatguru::client::GcpSecretManagerCredentials;
+///
+/// let client = ChatGuruClient::builder()
+///     .credentials_provider(Arc::new(GcpSecretManagerCredentials::new(
+///         "meu-projeto",
+///         "chatguru-api-token",
+///     )))
+///     .api_endpoint("https://api.chatguru.app/api/v1")
+///     .account_id(account_id)
+///     .build()?;
+/// ```
+pub struct GcpSecretManagerCredentials {
+    project: String,
+    secret: String,
+    version: String,
+    endpoint: String,
+    ttl: Duration,
+    access_token: Option<SecretString>,
+    http_client: reqwest::Client,
+    cache: TokenCache,
+    oauth: TokenCache,
+}
+
+impl fmt::Debug for GcpSecretManagerCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GcpSecretManagerCredentials")
+            .field("secret", &self.secret_version_name())
+            .field("endpoint", &self.endpoint)
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GcpSecretManagerCredentials {
+    /// Lê a versão `latest` do segredo `secret` no projeto `project`
+    pub fn new(project: impl Into<String>, secret: impl Into<String>) -> Self {
+        Self {
+            project: project.into(),
+            secret: secret.into(),
+            version: "latest".to_string(),
+            endpoint: SECRET_MANAGER_API_URL.to_string(),
+            ttl: DEFAULT_CREDENTIALS_CACHE_TTL,
+            access_token: None,
+            http_client: reqwest::Client::new(),
+            cache: TokenCache::default(),
+            oauth: TokenCache::default(),
+        }
+    }
+
+    /// Fixa uma versão do segredo (padrão: `latest`)
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    /// Define por quanto tempo o token lido é reaproveitado (padrão: 5 minutos)
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Usa outro endpoint (ex: regional)
+    pub fn endpoint(mut self, url: impl Into<String>) -> Self {
+        self.endpoint = url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Autentica com um token OAuth fixo em vez do servidor de metadados
+    /// (ex: `gcloud auth print-access-token` em desenvolvimento)
+    pub fn access_token(mut self, token: impl Into<SecretString>) -> Self {
+        self.access_token = Some(token.into());
+        self
+    }
+
+    /// Reutiliza um cliente HTTP existente
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = client;
+        self
+    }
+
+    /// Nome completo da versão do segredo
+    pub fn secret_version_name(&self) -> String {
+        format!(
+            "projects/{}/secrets/{}/versions/{}",
+            self.project, self.secret, self.version
+        )
+    }
+
+    /// Token OAuth para chamar o Secret Manager
+    async fn oauth_token(&self) -> Result<SecretString> {
+        if let Some(ref token) = self.access_token {
+            return Ok(token.clone());
+        }
+        self.oauth
+            .get_or_fetch(|| async {
+                let (token, expires_in) = gcp::metadata_token(&self.http_client).await?;
+                Ok((token, expires_in.saturating_sub(TOKEN_REFRESH_MARGIN)))
+            })
+            .await
+    }
+
+    /// Lê o valor atual do segredo
+    ///
+    /// # Erros
+    ///
+    /// * `NetworkError` - falha de conexão com o Secret Manager ou o servidor de metadados
+    /// * `Unauthorized` - token OAuth não obtido ou acesso ao segredo negado
+    /// * `InternalError` - o Secret Manager respondeu com outro erro
+    /// * `SerializationError` - resposta em formato inesperado ou segredo vazio
+    async fn access_secret(&self) -> Result<SecretString> {
+        let url = format!("{}/{}:access", self.endpoint, self.secret_version_name());
+        let response = self
+            .http_client
+            .get(&url)
+            .bearer_auth(self.oauth_token().await?.expose_secret())
+            .send()
+            .await
+            .map_err(|e| ChatGuruError::NetworkError(describe_error(e)))?;
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        match status.as_u16() {
+            200..=299 => {}
+            401 | 403 => {
+                self.oauth.clear();
+                return Err(ChatGuruError::Unauthorized(format!(
+                    "Secret Manager denied access to {} (status {})",
+                    self.secret_version_name(),
+                    status.as_u16()
+                )));
+            }
+            _ => {
+                return Err(ChatGuruError::InternalError(format!(
+                    "Secret Manager request for {} failed (status {})",
+                    self.secret_version_name(),
+                    status.as_u16()
+                )))
+            }
+        }
+
+        let response: AccessSecretVersionResponse = serde_json::from_str(&body)?;
+        let data = base64::engine::general_purpose::STANDARD
+            .decode(response.payload.data)
+            .map_err(|e| ChatGuruError::SerializationError(e.to_string()))?;
+        let token = String::from_utf8(data)
+            .map_err(|e| ChatGuruError::SerializationError(e.to_string()))?;
+
+        let token = token.trim();
+        if token.is_empty() {
+            return Err(ChatGuruError::SerializationError(format!(
+                "Secret {} is empty",
+                self.secret_version_name()
+            )));
+        }
+        Ok(SecretString::new(token))
+    }
+}
+
+impl CredentialsProvider for GcpSecretManagerCredentials {
+    fn token(&self) -> BoxFuture<'_, Result<SecretString>> {
+        Box::pin(async move {
+            self.cache
+                .get_or_fetch(|| async { Ok((self.access_secret().await?, self.ttl)) })
+                .await
+        })
+    }
+
+    fn invalidate(&self) {
+        self.cache.clear();
+    }
+}
@@ -0,0 +1,178 @@
+#[cfg(feature = "gcp-secret-manager")]
+mod gcp;
+#[cfg(feature = "vault")]
+mod vault;
+
+#[cfg(feature = "gcp-secret-manager")]
+pub use gcp::{GcpSecretManagerCredentials, SECRET_MANAGER_API_URL};
+#[cfg(feature = "vault")]
+pub use vault::{VaultCredentials, ENV_VAULT_ADDR, ENV_VAULT_NAMESPACE, ENV_VAULT_TOKEN};
+
+use crate::error::Result;
+use crate::secret::SecretString;
+use futures_core::future::BoxFuture;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Por quanto tempo o token obtido de um [`CredentialsProvider`] é reaproveitado
+pub const DEFAULT_CREDENTIALS_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Fonte do token da API, consultada pelo cliente a cada requisição
+///
+/// Permite trocar o token (ex: rotação no Secret Manager) sem recriar os
+/// clientes. Configure com
+/// [`ChatGuruClientBuilder::credentials_provider`](super::ChatGuruClientBuilder::credentials_provider);
+/// o cliente guarda o token em cache pelo TTL configurado e o descarta
+/// quando a API responde 401/403.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::client::CredentialsProvider;
+/// use chatguru::secret::SecretString;
+/// use futures_core::future::BoxFuture;
+///
+/// struct TokenFromFile(PathBuf);
+///
+/// impl CredentialsProvider for TokenFromFile {
+///     fn token(&self) -> BoxFuture<'_, Result<SecretString>> {
+///         Box::pin(async move {
+///             let token = tokio::fs::read_to_string(&self.0).await?;
+///             Ok(SecretString::new(token.trim()))
+///         })
+///     }
+/// }
+/// ```
+pub trait CredentialsProvider: Send + Sync {
+    /// Token atual da API
+    fn token(&self) -> BoxFuture<'_, Result<SecretString>>;
+
+    /// Descarta o token guardado, se houver, para a próxima chamada buscar um novo
+    ///
+    /// Chamado pelo cliente quando a API recusa o token. A implementação
+    /// padrão não faz nada.
+    fn invalidate(&self) {}
+}
+
+/// Token fixo, informado na construção do cliente
+///
+/// Usado quando o cliente é configurado com
+/// [`api_token`](super::ChatGuruClientBuilder::api_token).
+#[derive(Debug, Clone)]
+pub struct StaticCredentials {
+    token: SecretString,
+}
+
+impl StaticCredentials {
+    /// Cria o provider com o token informado
+    pub fn new(token: impl Into<SecretString>) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+}
+
+impl CredentialsProvider for StaticCredentials {
+    fn token(&self) -> BoxFuture<'_, Result<SecretString>> {
+        Box::pin(async move { Ok(self.token.clone()) })
+    }
+}
+
+/// Guarda o token de outro provider por um TTL
+///
+/// Chamadas simultâneas com o cache expirado fazem uma única busca.
+pub struct CachedCredentials {
+    provider: Arc<dyn CredentialsProvider>,
+    ttl: Duration,
+    cache: TokenCache,
+}
+
+impl fmt::Debug for CachedCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedCredentials")
+            .field("ttl", &self.ttl)
+            .field("cached", &self.cache.current().is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl CachedCredentials {
+    /// Envolve o provider, reaproveitando o token por `ttl`
+    pub fn new(provider: Arc<dyn CredentialsProvider>, ttl: Duration) -> Self {
+        Self {
+            provider,
+            ttl,
+            cache: TokenCache::default(),
+        }
+    }
+}
+
+impl CredentialsProvider for CachedCredentials {
+    fn token(&self) -> BoxFuture<'_, Result<SecretString>> {
+        Box::pin(async move {
+            self.cache
+                .get_or_fetch(|| async {
+                    let token = self.provider.token().await?;
+                    Ok((token, self.ttl))
+                })
+                .await
+        })
+    }
+
+    fn invalidate(&self) {
+        self.cache.clear();
+        self.provider.invalidate();
+    }
+}
+
+/// Token guardado até expirar, usado pelos providers que buscam o token remotamente
+#[derive(Default)]
+pub(crate) struct TokenCache {
+    cached: Mutex<Option<(SecretString, Instant)>>,
+    refresh: tokio::sync::Mutex<()>,
+}
+
+impl TokenCache {
+    /// Token em cache, se ainda não expirou
+    pub(crate) fn current(&self) -> Option<SecretString> {
+        self.cached
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .filter(|(_, expires_at)| Instant::now() < *expires_at)
+            .map(|(token, _)| token.clone())
+    }
+
+    /// Retorna o token em cache ou busca um novo com `fetch`, que informa
+    /// também por quanto tempo ele vale
+    ///
+    /// Chamadas simultâneas com o cache expirado fazem uma única busca.
+    pub(crate) async fn get_or_fetch<F, Fut>(&self, fetch: F) -> Result<SecretString>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<(SecretString, Duration)>>,
+    {
+        if let Some(token) = self.current() {
+            return Ok(token);
+        }
+
+        let _refresh = self.refresh.lock().await;
+        // Outra chamada pode ter renovado o token enquanto esta esperava
+        if let Some(token) = self.current() {
+            return Ok(token);
+        }
+
+        let (token, ttl) = fetch().await?;
+        tracing::debug!("ChatGuru API token refreshed from credentials provider");
+        *self.cached.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some((token.clone(), Instant::now() + ttl));
+        Ok(token)
+    }
+
+    /// Descarta o token em cache
+    pub(crate) fn clear(&self) {
+        self.cached.lock().unwrap_or_else(|e| e.into_inner()).take();
+    }
+}
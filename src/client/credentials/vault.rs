@@ -0,0 +1,211 @@
+use super::{CredentialsProvider, TokenCache, DEFAULT_CREDENTIALS_CACHE_TTL};
+use crate::error::{ChatGuruError, Result};
+use crate::secret::{describe_error, SecretString};
+use futures_core::future::BoxFuture;
+use serde_json::Value;
+use std::fmt;
+use std::time::Duration;
+
+/// Variável de ambiente com o endereço do Vault
+pub const ENV_VAULT_ADDR: &str = "VAULT_ADDR";
+
+/// Variável de ambiente com o token de acesso ao Vault
+pub const ENV_VAULT_TOKEN: &str = "VAULT_TOKEN";
+
+/// Variável de ambiente com o namespace do Vault Enterprise (opcional)
+pub const ENV_VAULT_NAMESPACE: &str = "VAULT_NAMESPACE";
+
+/// Token da API guardado no HashiCorp Vault
+///
+/// Lê o segredo em `{address}/v1/{path}` e usa o campo `field` (padrão:
+/// `api_token`). Aceita os formatos dos engines KV v1 e KV v2 (para o v2,
+/// `path` inclui o `data/`, como em `secret/data/chatguru`). O token é
+/// guardado por [`ttl`](Self::ttl), ou pelo `lease_duration` do segredo
+/// quando menor, e relido quando a API do ChatGuru o recusa.
+///
+/// Disponível com a feature `vault`.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use std::sync::Arc;
+/// use chatguru::client::VaultCredentials;
+///
+/// // VAULT_ADDR e VAULT_TOKEN do ambiente
+/// let credentials = VaultCredentials::from_env("secret/data/chatguru")?.field("token");
+///
+/// let client = ChatGuruClient::builder()
+///     .credentials_provider(Arc::new(credentials))
+///     .api_endpoint("https://api.chatguru.app/api/v1")
+///     .account_id(account_id)
+///     .build()?;
+/// ```
+pub struct VaultCredentials {
+    address: String,
+    path: String,
+    field: String,
+    vault_token: SecretString,
+    namespace: Option<String>,
+    ttl: Duration,
+    http_client: reqwest::Client,
+    cache: TokenCache,
+}
+
+impl fmt::Debug for VaultCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VaultCredentials")
+            .field("address", &self.address)
+            .field("path", &self.path)
+            .field("field", &self.field)
+            .field("vault_token", &self.vault_token)
+            .field("namespace", &self.namespace)
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+impl VaultCredentials {
+    /// Lê o segredo `path` no Vault em `address`, autenticando com `vault_token`
+    pub fn new(
+        address: impl Into<String>,
+        path: impl Into<String>,
+        vault_token: impl Into<SecretString>,
+    ) -> Self {
+        Self {
+            address: address.into().trim_end_matches('/').to_string(),
+            path: path.into().trim_matches('/').to_string(),
+            field: "api_token".to_string(),
+            vault_token: vault_token.into(),
+            namespace: None,
+            ttl: DEFAULT_CREDENTIALS_CACHE_TTL,
+            http_client: reqwest::Client::new(),
+            cache: TokenCache::default(),
+        }
+    }
+
+    /// Lê o segredo `path` com `VAULT_ADDR`, `VAULT_TOKEN` e `VAULT_NAMESPACE` do ambiente
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se `VAULT_ADDR` ou `VAULT_TOKEN` não estiverem definidas.
+    pub fn from_env(path: impl Into<String>) -> Result<Self> {
+        let read = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        let (Some(address), Some(token)) = (read(ENV_VAULT_ADDR), read(ENV_VAULT_TOKEN)) else {
+            return Err(ChatGuruError::ValidationError(format!(
+                "Missing Vault configuration: {} and {} are required",
+                ENV_VAULT_ADDR, ENV_VAULT_TOKEN
+            )));
+        };
+
+        let mut credentials = Self::new(address, path, token);
+        credentials.namespace = read(ENV_VAULT_NAMESPACE);
+        Ok(credentials)
+    }
+
+    /// Campo do segredo com o token (padrão: `api_token`)
+    pub fn field(mut self, field: impl Into<String>) -> Self {
+        self.field = field.into();
+        self
+    }
+
+    /// Namespace do Vault Enterprise (header `X-Vault-Namespace`)
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Define por quanto tempo o token lido é reaproveitado (padrão: 5 minutos)
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Reutiliza um cliente HTTP existente
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = client;
+        self
+    }
+
+    /// Lê o segredo, retornando o token e por quanto tempo ele vale
+    ///
+    /// # Erros
+    ///
+    /// * `NetworkError` - falha de conexão com o Vault
+    /// * `Unauthorized` - token do Vault recusado ou sem permissão no caminho
+    /// * `InternalError` - o Vault respondeu com outro erro (ex: caminho inexistente)
+    /// * `SerializationError` - resposta em formato inesperado ou campo ausente
+    async fn read_secret(&self) -> Result<(SecretString, Duration)> {
+        let url = format!("{}/v1/{}", self.address, self.path);
+        let mut request = self
+            .http_client
+            .get(&url)
+            .header("X-Vault-Token", self.vault_token.expose_secret());
+        if let Some(ref namespace) = self.namespace {
+            request = request.header("X-Vault-Namespace", namespace);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| ChatGuruError::NetworkError(describe_error(e)))?;
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        match status.as_u16() {
+            200..=299 => {}
+            401 | 403 => {
+                return Err(ChatGuruError::Unauthorized(format!(
+                    "Vault denied access to {} (status {})",
+                    self.path,
+                    status.as_u16()
+                )))
+            }
+            _ => {
+                return Err(ChatGuruError::InternalError(format!(
+                    "Vault request for {} failed (status {})",
+                    self.path,
+                    status.as_u16()
+                )))
+            }
+        }
+
+        let value: Value = serde_json::from_str(&body)?;
+        // KV v2 aninha os campos em `data.data`; KV v1 os coloca em `data`
+        let token = [
+            &value["data"]["data"][&self.field],
+            &value["data"][&self.field],
+        ]
+        .into_iter()
+        .find_map(Value::as_str)
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .ok_or_else(|| {
+            ChatGuruError::SerializationError(format!(
+                "Vault secret {} has no field {}",
+                self.path, self.field
+            ))
+        })?;
+
+        let ttl = match value["lease_duration"].as_u64() {
+            Some(lease) if lease > 0 => self.ttl.min(Duration::from_secs(lease)),
+            _ => self.ttl,
+        };
+        Ok((SecretString::new(token), ttl))
+    }
+}
+
+impl CredentialsProvider for VaultCredentials {
+    fn token(&self) -> BoxFuture<'_, Result<SecretString>> {
+        Box::pin(async move { self.cache.get_or_fetch(|| self.read_secret()).await })
+    }
+
+    fn invalidate(&self) {
+        self.cache.clear();
+    }
+}
@@ -0,0 +1,320 @@
+use super::length::SendWarnings;
+use super::request::{read_response, ChatGuruRequest};
+use super::{ChatGuruClient, SendWarning};
+use crate::error::{ChatGuruError, Result};
+use crate::types::{ChatEvent, ChatInfo, MessageStatus, PhoneNumber, WebhookPayload};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// Quantidade padrão de mensagens acompanhadas por um [`DeliveryTracker`]
+pub const DEFAULT_TRACKED_MESSAGES: usize = 10_000;
+
+/// Mensagem aceita pela API, retornada por [`ChatGuruClient::send_message`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SentMessage {
+    /// ID da mensagem, usado para correlacionar os webhooks de status
+    pub message_id: Option<String>,
+    /// Status inicial informado pela API
+    pub status: Option<MessageStatus>,
+    /// Ajustes feitos no envio (ex: texto cortado pelo limite de tamanho)
+    pub warnings: Vec<SendWarning>,
+}
+
+impl SentMessage {
+    /// Interpreta o corpo JSON da resposta de `message_send`
+    ///
+    /// Aceita os campos no nível raiz ou dentro de `data`.
+    pub(crate) fn from_body(body: &str) -> Self {
+        let value: Value = serde_json::from_str(body).unwrap_or_default();
+        let data = value
+            .get("data")
+            .filter(|v| v.is_object())
+            .unwrap_or(&value);
+
+        let text = |names: &[&str]| {
+            names.iter().find_map(|name| match data.get(name)? {
+                Value::String(s) if !s.is_empty() => Some(s.clone()),
+                Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            })
+        };
+
+        Self {
+            message_id: text(&["message_id", "msg_id", "id_mensagem"]),
+            status: text(&["message_status", "status"])
+                .as_deref()
+                .and_then(MessageStatus::parse),
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl ChatGuruClient {
+    /// Envia uma mensagem de texto e retorna o ID atribuído pela API
+    ///
+    /// Diferente de [`send_confirmation_message`](Self::send_confirmation_message),
+    /// envia o texto em uma única chamada e propaga qualquer falha. Use o
+    /// ID retornado com um [`DeliveryTracker`] para aguardar a entrega.
+    ///
+    /// Textos acima de
+    /// [`max_message_length`](super::ChatGuruClientBuilder::max_message_length)
+    /// são cortados sem quebrar emojis; o corte aparece em
+    /// [`SentMessage::warnings`].
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - número de telefone inválido
+    /// * `ChatNotFound` - não existe chat para o número
+    /// * `ApiError`, `RateLimited`, `Unauthorized`, `NetworkError` ou `CircuitOpen` - falha na chamada
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let sent = client.send_message("5511999999999", None, "Seu pedido saiu para entrega").await?;
+    /// if let Some(id) = sent.message_id {
+    ///     let receipt = tracker.await_delivery(&id, Duration::from_secs(60)).await?;
+    ///     println!("Status: {:?}", receipt.status);
+    /// }
+    /// ```
+    pub async fn send_message(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        phone_id: Option<&str>,
+        text: &str,
+    ) -> Result<SentMessage> {
+        let phone_number = phone_number.into();
+        phone_number.validate()?;
+
+        let request = ChatGuruRequest::new("message_send")
+            .phone_id(phone_id.unwrap_or(&self.default_phone_id))
+            .params(&[("text", text), ("chat_number", phone_number.digits())]);
+        let mut response = self.post_action(&request).await?;
+        let warnings = SendWarnings::take(&mut response);
+        let body = read_response("message_send", response).await?;
+
+        let mut sent = SentMessage::from_body(&body);
+        sent.warnings = warnings;
+        if sent.message_id.is_none() {
+            tracing::debug!("message_send response without message id: {}", body);
+        }
+        Ok(sent)
+    }
+}
+
+/// Status de uma mensagem recebido por webhook
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryReceipt {
+    /// ID da mensagem
+    pub message_id: String,
+    /// Status mais avançado recebido até agora
+    pub status: MessageStatus,
+    /// Chat da mensagem, como informado no webhook
+    pub chat: ChatInfo,
+    /// Momento em que o webhook com este status foi recebido
+    pub received_at: DateTime<Utc>,
+}
+
+/// Correlaciona os webhooks de status com as mensagens enviadas
+///
+/// Repasse cada webhook recebido para [`observe`](Self::observe) (ex: no
+/// fallback do [`WebhookRouter`](crate::webhook::WebhookRouter)) e use
+/// [`await_delivery`](Self::await_delivery) com o ID retornado por
+/// [`ChatGuruClient::send_message`].
+///
+/// Só o status mais avançado de cada mensagem é mantido (webhooks fora de
+/// ordem não fazem o status regredir). O tracker guarda até
+/// [`DEFAULT_TRACKED_MESSAGES`] mensagens; as mais antigas são descartadas.
+/// Clones compartilham o mesmo estado.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use chatguru::client::DeliveryTracker;
+///
+/// let tracker = DeliveryTracker::new();
+///
+/// // No handler de webhooks:
+/// tracker.observe(&payload);
+///
+/// // No envio:
+/// let sent = client.send_message("5511999999999", None, "Olá!").await?;
+/// let receipt = tracker
+///     .await_delivery(sent.message_id.as_deref().unwrap_or_default(), Duration::from_secs(30))
+///     .await?;
+/// ```
+#[derive(Clone)]
+pub struct DeliveryTracker {
+    inner: Arc<Mutex<TrackerState>>,
+}
+
+struct TrackerState {
+    capacity: usize,
+    messages: HashMap<String, watch::Sender<Option<DeliveryReceipt>>>,
+    order: VecDeque<String>,
+}
+
+impl Default for DeliveryTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for DeliveryTracker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state();
+        f.debug_struct("DeliveryTracker")
+            .field("capacity", &state.capacity)
+            .field("tracked", &state.messages.len())
+            .finish()
+    }
+}
+
+impl DeliveryTracker {
+    /// Cria um tracker com a capacidade padrão
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_TRACKED_MESSAGES)
+    }
+
+    /// Cria um tracker que acompanha no máximo `capacity` mensagens
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(TrackerState {
+                capacity: capacity.max(1),
+                messages: HashMap::new(),
+                order: VecDeque::new(),
+            })),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, TrackerState> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Registra o status de um webhook, se ele for de status de mensagem
+    ///
+    /// Retorna o recibo atualizado, ou `None` se o webhook não for de status.
+    pub fn observe(&self, payload: &WebhookPayload) -> Option<DeliveryReceipt> {
+        match ChatEvent::try_from(payload).ok()? {
+            ChatEvent::MessageStatusChanged {
+                chat,
+                message_id,
+                status,
+            } => Some(self.record(&message_id, status, chat)),
+            _ => None,
+        }
+    }
+
+    /// Registra um status para a mensagem, retornando o recibo atualizado
+    pub fn record(
+        &self,
+        message_id: &str,
+        status: MessageStatus,
+        chat: ChatInfo,
+    ) -> DeliveryReceipt {
+        let mut state = self.state();
+        let sender = state.entry(message_id);
+
+        let receipt = DeliveryReceipt {
+            message_id: message_id.to_string(),
+            status,
+            chat,
+            received_at: Utc::now(),
+        };
+        let mut current = receipt.clone();
+        sender.send_if_modified(|slot| match slot {
+            Some(previous) if status <= previous.status => {
+                current = previous.clone();
+                false
+            }
+            _ => {
+                *slot = Some(receipt);
+                true
+            }
+        });
+
+        tracing::debug!("Message {} status: {:?}", message_id, status);
+        current
+    }
+
+    /// Último recibo conhecido da mensagem
+    pub fn receipt(&self, message_id: &str) -> Option<DeliveryReceipt> {
+        self.state()
+            .messages
+            .get(message_id)
+            .and_then(|sender| sender.borrow().clone())
+    }
+
+    /// Deixa de acompanhar a mensagem
+    pub fn forget(&self, message_id: &str) {
+        let mut state = self.state();
+        state.messages.remove(message_id);
+        state.order.retain(|id| id != message_id);
+    }
+
+    /// Aguarda a mensagem ser entregue (`Delivered` ou `Read`) ou falhar
+    ///
+    /// Retorna imediatamente se o status já foi recebido. Com status
+    /// `Failed`, o recibo é retornado normalmente; verifique
+    /// [`MessageStatus::is_delivered`].
+    ///
+    /// # Erros
+    ///
+    /// Retorna `Timeout` se nenhum desses status chegar dentro do prazo.
+    pub async fn await_delivery(
+        &self,
+        message_id: &str,
+        timeout: Duration,
+    ) -> Result<DeliveryReceipt> {
+        let mut receiver = self.state().entry(message_id).subscribe();
+
+        let wait = async {
+            loop {
+                if let Some(receipt) = receiver.borrow_and_update().clone() {
+                    if receipt.status.is_delivered() || receipt.status == MessageStatus::Failed {
+                        return Some(receipt);
+                    }
+                }
+                // O sender foi descartado (mensagem esquecida ou removida pela capacidade)
+                if receiver.changed().await.is_err() {
+                    return None;
+                }
+            }
+        };
+
+        match tokio::time::timeout(timeout, wait).await {
+            Ok(Some(receipt)) => Ok(receipt),
+            Ok(None) => Err(ChatGuruError::Timeout(format!(
+                "Message {} is no longer tracked",
+                message_id
+            ))),
+            Err(_) => Err(ChatGuruError::Timeout(format!(
+                "Message {} not delivered within {:?}",
+                message_id, timeout
+            ))),
+        }
+    }
+}
+
+impl TrackerState {
+    /// Canal da mensagem, criado (e respeitando a capacidade) se não existir
+    fn entry(&mut self, message_id: &str) -> &watch::Sender<Option<DeliveryReceipt>> {
+        if !self.messages.contains_key(message_id) {
+            while self.messages.len() >= self.capacity {
+                let Some(oldest) = self.order.pop_front() else {
+                    break;
+                };
+                self.messages.remove(&oldest);
+            }
+            self.messages
+                .insert(message_id.to_string(), watch::channel(None).0);
+            self.order.push_back(message_id.to_string());
+        }
+        &self.messages[message_id]
+    }
+}
@@ -0,0 +1,219 @@
+//! Codificação dos parâmetros das chamadas à API
+//!
+//! Todas as chamadas montadas pelo cliente (corpo `x-www-form-urlencoded` ou
+//! query string, veja [`RequestMode`](super::RequestMode)) passam por
+//! [`encode_params`]: nomes e valores são codificados com percent-encoding
+//! estrito, em que só os caracteres não reservados da RFC 3986
+//! (`A-Z a-z 0-9 - . _ ~`) seguem literais. `+`, `&`, `=`, `#`, espaços e
+//! quebras de linha sempre viram `%XX`, então o texto das anotações chega à
+//! API exatamente como foi escrito.
+//!
+//! # Exemplo
+//!
+//! ```rust
+//! use chatguru::client::{decode_params, encode_params};
+//!
+//! let encoded = encode_params([
+//!     ("action", "note_add"),
+//!     ("note_text", "Total: 2+2 & frete\nObs: #urgente"),
+//! ]);
+//! assert_eq!(
+//!     encoded,
+//!     "action=note_add&note_text=Total%3A%202%2B2%20%26%20frete%0AObs%3A%20%23urgente"
+//! );
+//!
+//! let decoded = decode_params(&encoded).unwrap();
+//! assert_eq!(decoded[1].1, "Total: 2+2 & frete\nObs: #urgente");
+//! ```
+
+use crate::error::{ChatGuruError, Result};
+use std::borrow::Cow;
+
+const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Content-Type do corpo montado por [`encode_params`]
+pub(crate) const FORM_CONTENT_TYPE: &str = "application/x-www-form-urlencoded";
+
+/// Indica se o byte segue literal na codificação (não reservado na RFC 3986)
+fn is_unreserved(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~')
+}
+
+/// Codifica um nome ou valor de parâmetro com percent-encoding estrito
+///
+/// Cada byte UTF-8 fora de `A-Z a-z 0-9 - . _ ~` vira `%XX` (hexadecimal
+/// maiúsculo); espaços viram `%20`, nunca `+`.
+pub fn encode_component(value: &str) -> Cow<'_, str> {
+    if value.bytes().all(is_unreserved) {
+        return Cow::Borrowed(value);
+    }
+
+    let mut encoded = String::with_capacity(value.len() * 3);
+    for byte in value.bytes() {
+        if is_unreserved(byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push('%');
+            encoded.push(HEX[(byte >> 4) as usize] as char);
+            encoded.push(HEX[(byte & 0x0F) as usize] as char);
+        }
+    }
+    Cow::Owned(encoded)
+}
+
+/// Monta `nome=valor&...` com [`encode_component`] em nomes e valores, na ordem
+pub fn encode_params<'a, I>(params: I) -> String
+where
+    I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+    let mut encoded = String::new();
+    for (name, value) in params {
+        if !encoded.is_empty() {
+            encoded.push('&');
+        }
+        encoded.push_str(&encode_component(name));
+        encoded.push('=');
+        encoded.push_str(&encode_component(value));
+    }
+    encoded
+}
+
+/// Decodifica um nome ou valor codificado (`+` é lido como espaço)
+///
+/// # Erros
+///
+/// Retorna `ValidationError` para sequências `%` incompletas ou que não
+/// formam UTF-8 válido.
+pub fn decode_component(value: &str) -> Result<String> {
+    let invalid =
+        || ChatGuruError::ValidationError(format!("Invalid percent-encoded parameter: {}", value));
+    let hex = |digit: u8| (digit as char).to_digit(16);
+
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let high = bytes
+                    .get(i + 1)
+                    .copied()
+                    .and_then(hex)
+                    .ok_or_else(invalid)?;
+                let low = bytes
+                    .get(i + 2)
+                    .copied()
+                    .and_then(hex)
+                    .ok_or_else(invalid)?;
+                decoded.push((high * 16 + low) as u8);
+                i += 3;
+            }
+            b'+' => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            byte => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(decoded).map_err(|_| invalid())
+}
+
+/// Lê uma query string ou corpo `x-www-form-urlencoded`, mantendo a ordem
+///
+/// Parâmetros sem `=` são lidos com valor vazio.
+///
+/// # Erros
+///
+/// Os de [`decode_component`].
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::client::{decode_params, encode_params};
+///
+/// let encoded = encode_params([("chat_number", "5511999999999"), ("text", "Olá & até+")]);
+/// assert_eq!(
+///     decode_params(&encoded).unwrap(),
+///     vec![
+///         ("chat_number".to_string(), "5511999999999".to_string()),
+///         ("text".to_string(), "Olá & até+".to_string()),
+///     ]
+/// );
+/// ```
+pub fn decode_params(encoded: &str) -> Result<Vec<(String, String)>> {
+    encoded
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            Ok((decode_component(name)?, decode_component(value)?))
+        })
+        .collect()
+}
+
+/// Valida o nome de um parâmetro informado fora do cliente
+///
+/// Nomes aceitos usam só letras e números ASCII, `_`, `-`, `.`, `[` e `]`
+/// (ex: `chat_number`, `buttons[0][title]`).
+pub(crate) fn validate_param_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b'.' | b'[' | b']'));
+    if valid {
+        Ok(())
+    } else {
+        Err(ChatGuruError::ValidationError(format!(
+            "Invalid parameter name: {:?}",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_params, encode_params};
+
+    /// Textos pseudoaleatórios (xorshift): metade ASCII, incluindo controles e
+    /// `+&=%#`, metade qualquer ponto de código
+    fn random_texts(count: usize) -> Vec<String> {
+        let mut seed: u32 = 0x9E37_79B9;
+        (0..count)
+            .map(|_| {
+                (0..24)
+                    .filter_map(|_| {
+                        seed ^= seed << 13;
+                        seed ^= seed >> 17;
+                        seed ^= seed << 5;
+                        let code = if seed & 1 == 0 {
+                            seed % 0x80
+                        } else {
+                            seed % 0x11_0000
+                        };
+                        char::from_u32(code)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn any_text_round_trips() {
+        for text in random_texts(500) {
+            let encoded = encode_params([("note_text", text.as_str()), (text.as_str(), "x")]);
+            assert!(encoded.bytes().all(|b| b.is_ascii_graphic() && b != b'+'));
+
+            let decoded = decode_params(&encoded).unwrap();
+            assert_eq!(
+                decoded,
+                vec![
+                    ("note_text".to_string(), text.clone()),
+                    (text, "x".to_string()),
+                ]
+            );
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::error::{ChatGuruError, Result};
+use std::fmt;
+
+/// Versão da API do ChatGuru, usada no path `/api/{versão}`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum ApiVersion {
+    /// `v1` (padrão)
+    #[default]
+    V1,
+    /// Outra versão, como informada (ex: `v2`)
+    Custom(String),
+}
+
+impl ApiVersion {
+    /// Interpreta a versão; aceita tanto `"v1"` quanto `"1"`
+    pub fn parse(version: &str) -> Self {
+        let version = version.trim().trim_matches('/');
+        let version = if version.starts_with('v') {
+            version.to_string()
+        } else {
+            format!("v{}", version)
+        };
+
+        match version.as_str() {
+            "v1" => ApiVersion::V1,
+            _ => ApiVersion::Custom(version),
+        }
+    }
+
+    /// Versão como aparece no path (`v1`, `v2`, ...)
+    pub fn as_str(&self) -> &str {
+        match self {
+            ApiVersion::V1 => "v1",
+            ApiVersion::Custom(version) => version,
+        }
+    }
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ApiVersion {
+    fn from(version: &str) -> Self {
+        ApiVersion::parse(version)
+    }
+}
+
+impl From<String> for ApiVersion {
+    fn from(version: String) -> Self {
+        ApiVersion::parse(&version)
+    }
+}
+
+/// URL da API do ChatGuru, interpretada uma única vez na construção do cliente
+///
+/// Todas as ações são enviadas para [`url`](Self::url). Com [`parse`](Self::parse),
+/// um sufixo `/api/{versão}` já presente na URL é reconhecido (e define a
+/// versão); sem ele, `/api/{versão}` é acrescentado ao path informado, o que
+/// permite endpoints de homologação em outros paths. Para usar a URL
+/// exatamente como informada, use [`exact`](Self::exact).
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::client::{ApiEndpoint, ApiVersion};
+///
+/// let endpoint = ApiEndpoint::parse("https://api.chatguru.app/api/v1").unwrap();
+/// assert_eq!(endpoint.url(), "https://api.chatguru.app/api/v1");
+///
+/// let staging = ApiEndpoint::parse("https://staging.exemplo.com/chatguru/")
+///     .unwrap()
+///     .with_version(ApiVersion::V1);
+/// assert_eq!(staging.url(), "https://staging.exemplo.com/chatguru/api/v1");
+///
+/// let proxy = ApiEndpoint::exact("https://gateway.exemplo.com/cg").unwrap();
+/// assert_eq!(proxy.url(), "https://gateway.exemplo.com/cg");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiEndpoint {
+    base: String,
+    version: Option<ApiVersion>,
+    url: String,
+}
+
+impl ApiEndpoint {
+    /// Interpreta a URL, reconhecendo o sufixo `/api/{versão}` quando presente
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se a URL estiver vazia, não for `http(s)://`
+    /// ou tiver query string.
+    pub fn parse(url: &str) -> Result<Self> {
+        validate(url)?;
+        Ok(Self::from_url(url))
+    }
+
+    /// Usa a URL exatamente como informada, sem acrescentar `/api/{versão}`
+    ///
+    /// # Erros
+    ///
+    /// Os mesmos de [`parse`](Self::parse).
+    pub fn exact(url: &str) -> Result<Self> {
+        validate(url)?;
+        let base = url.trim().trim_end_matches('/').to_string();
+        Ok(Self {
+            url: base.clone(),
+            base,
+            version: None,
+        })
+    }
+
+    /// Interpreta a URL sem validá-la (construtores que não retornam erro)
+    pub(crate) fn from_url(url: &str) -> Self {
+        let url = url.trim().trim_end_matches('/');
+
+        let (base, version) = match url.rsplit_once("/api/") {
+            Some((base, version))
+                if version.starts_with('v')
+                    && version.len() > 1
+                    && version[1..].chars().all(|c| c.is_ascii_digit()) =>
+            {
+                (base, ApiVersion::parse(version))
+            }
+            _ => (url, ApiVersion::default()),
+        };
+
+        Self::versioned(base.to_string(), version)
+    }
+
+    fn versioned(base: String, version: ApiVersion) -> Self {
+        Self {
+            url: format!("{}/api/{}", base, version),
+            base,
+            version: Some(version),
+        }
+    }
+
+    /// Usa outra versão da API
+    ///
+    /// Não tem efeito em endpoints criados com [`exact`](Self::exact).
+    pub fn with_version(self, version: impl Into<ApiVersion>) -> Self {
+        match self.version {
+            Some(_) => Self::versioned(self.base, version.into()),
+            None => self,
+        }
+    }
+
+    /// Versão da API, ou `None` para endpoints criados com [`exact`](Self::exact)
+    pub fn version(&self) -> Option<&ApiVersion> {
+        self.version.as_ref()
+    }
+
+    /// URL para a qual as ações são enviadas
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl fmt::Display for ApiEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.url)
+    }
+}
+
+fn validate(url: &str) -> Result<()> {
+    let url = url.trim();
+    let invalid = |reason: &str| {
+        Err(ChatGuruError::ValidationError(format!(
+            "Invalid API endpoint {:?}: {}",
+            url, reason
+        )))
+    };
+
+    let Some((_, rest)) = url
+        .split_once("://")
+        .filter(|(scheme, _)| matches!(*scheme, "http" | "https"))
+    else {
+        return invalid("expected an http:// or https:// URL");
+    };
+    if rest.is_empty() || rest.starts_with('/') {
+        return invalid("missing host");
+    }
+    if url.contains('?') || url.contains('#') {
+        return invalid("query strings are not supported");
+    }
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use super::request::ChatGuruRequest;
+use super::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::types::PhoneNumber;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Funil de vendas/atendimento do ChatGuru, retornado por [`ChatGuruClient::list_funnels`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Funnel {
+    /// ID do funil
+    pub id: String,
+    /// Nome do funil
+    pub name: String,
+    /// Etapas, na ordem do funil
+    pub stages: Vec<FunnelStage>,
+}
+
+/// Etapa de um [`Funnel`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunnelStage {
+    /// ID da etapa
+    pub id: String,
+    /// Nome da etapa
+    pub name: String,
+}
+
+impl Funnel {
+    /// Etapa com o nome informado (sem diferenciar maiúsculas)
+    pub fn stage_named(&self, name: &str) -> Option<&FunnelStage> {
+        let name = name.trim();
+        self.stages
+            .iter()
+            .find(|stage| stage.name.trim().eq_ignore_ascii_case(name))
+    }
+
+    /// Interpreta o corpo JSON da resposta de `funnel_list`
+    ///
+    /// Aceita a lista no nível raiz ou dentro de `funnels`/`funis`/`data`.
+    pub(crate) fn list_from_body(body: &str) -> Result<Vec<Self>> {
+        let value: Value = serde_json::from_str(body)?;
+        let funnels = ["funnels", "funis", "data"]
+            .iter()
+            .find_map(|key| value.get(key).and_then(Value::as_array))
+            .or_else(|| value.as_array())
+            .ok_or_else(|| {
+                ChatGuruError::SerializationError(format!(
+                    "Unexpected funnel_list response: {}",
+                    body
+                ))
+            })?;
+
+        Ok(funnels
+            .iter()
+            .filter_map(|funnel| {
+                Some(Funnel {
+                    id: text(funnel, &["id", "funnel_id", "funil_id"])?,
+                    name: text(funnel, &["name", "nome"]).unwrap_or_default(),
+                    stages: ["stages", "etapas"]
+                        .iter()
+                        .find_map(|key| funnel.get(key).and_then(Value::as_array))
+                        .map(|stages| {
+                            stages
+                                .iter()
+                                .filter_map(|stage| {
+                                    Some(FunnelStage {
+                                        id: text(stage, &["id", "stage_id", "etapa_id"])?,
+                                        name: text(stage, &["name", "nome"]).unwrap_or_default(),
+                                    })
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                })
+            })
+            .collect())
+    }
+}
+
+fn text(value: &Value, names: &[&str]) -> Option<String> {
+    names
+        .iter()
+        .find_map(|name| value.get(name))
+        .and_then(|v| match v {
+            Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        })
+}
+
+impl ChatGuruClient {
+    /// Lista os funis da conta e suas etapas (`action=funnel_list`)
+    ///
+    /// # Erros
+    ///
+    /// * `SerializationError` - resposta da API em formato inesperado
+    /// * `ApiError`, `RateLimited`, `Unauthorized`, `NetworkError` ou `CircuitOpen` - falha na chamada
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// for funnel in client.list_funnels().await? {
+    ///     println!("{}: {:?}", funnel.name, funnel.stages);
+    /// }
+    /// ```
+    pub async fn list_funnels(&self) -> Result<Vec<Funnel>> {
+        let body = self
+            .execute(&ChatGuruRequest::new("funnel_list").cacheable())
+            .await?;
+        Funnel::list_from_body(&body)
+    }
+
+    /// Move o chat de um número para uma etapa de funil (`action=chat_update_funnel`)
+    ///
+    /// Use quando um evento externo (pagamento recebido, tarefa concluída)
+    /// deve avançar o lead no funil. Os IDs vêm de [`list_funnels`](Self::list_funnels).
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - número de telefone inválido ou IDs vazios
+    /// * `ChatNotFound` - não existe chat para o número
+    /// * `ApiError`, `RateLimited`, `Unauthorized`, `NetworkError` ou `CircuitOpen` - falha na chamada
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let funnels = client.list_funnels().await?;
+    /// let vendas = funnels.iter().find(|f| f.name == "Vendas").unwrap();
+    /// let pago = vendas.stage_named("Pagamento confirmado").unwrap();
+    ///
+    /// client
+    ///     .move_chat_to_funnel_stage("5511999999999", &vendas.id, &pago.id)
+    ///     .await?;
+    /// ```
+    pub async fn move_chat_to_funnel_stage(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        funnel_id: &str,
+        stage_id: &str,
+    ) -> Result<()> {
+        let phone_number = phone_number.into();
+        phone_number.validate()?;
+
+        let (funnel_id, stage_id) = (funnel_id.trim(), stage_id.trim());
+        if funnel_id.is_empty() || stage_id.is_empty() {
+            return Err(ChatGuruError::ValidationError(
+                "Funnel and stage IDs must not be empty".to_string(),
+            ));
+        }
+
+        self.call_action(
+            "chat_update_funnel",
+            &self.default_phone_id,
+            &[
+                ("chat_number", phone_number.digits()),
+                ("funnel_id", funnel_id),
+                ("stage_id", stage_id),
+            ],
+        )
+        .await?;
+
+        tracing::debug!(
+            "Chat {} moved to funnel {} stage {}",
+            phone_number.masked(),
+            funnel_id,
+            stage_id
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use super::request::{new_request_id, read_response, ChatGuruRequest};
+use super::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::secret::describe_error;
+use std::time::{Duration, Instant};
+
+/// Ação usada por [`ChatGuruClient::health_check`]: leve, sem parâmetros e autenticada
+const HEALTH_CHECK_ACTION: &str = "funnel_list";
+
+/// Resultado de [`ChatGuruClient::health_check`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthStatus {
+    /// A API respondeu (qualquer status HTTP)
+    pub reachable: bool,
+    /// O token e a conta foram aceitos
+    pub authenticated: bool,
+    /// Tempo até a resposta (ou até a falha de rede)
+    pub latency: Duration,
+    /// Motivo da falha, quando a verificação não passou
+    pub error: Option<String>,
+}
+
+impl HealthStatus {
+    /// Indica se a API está acessível, aceita as credenciais e respondeu sem erro
+    pub fn is_healthy(&self) -> bool {
+        self.reachable && self.authenticated && self.error.is_none()
+    }
+}
+
+impl ChatGuruClient {
+    /// Verifica se a API do ChatGuru está acessível com as credenciais do cliente
+    ///
+    /// Faz uma única chamada leve e autenticada (`action=funnel_list`), sem
+    /// retries, rate limiter ou circuit breaker, para que a latência e o
+    /// resultado reflitam o estado atual da API. Nunca falha: problemas são
+    /// informados no [`HealthStatus`]. Erros 5xx contam como não autenticado,
+    /// pois a API não chegou a validar as credenciais.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// // Probe de readiness
+    /// let health = client.health_check().await;
+    /// if !health.is_healthy() {
+    ///     return (StatusCode::SERVICE_UNAVAILABLE, format!("{:?}", health.error));
+    /// }
+    /// ```
+    pub async fn health_check(&self) -> HealthStatus {
+        let started_at = Instant::now();
+        let result = self.probe().await;
+        let latency = started_at.elapsed();
+
+        let (reachable, authenticated, error) = match result {
+            Ok(()) => (true, true, None),
+            Err(ChatGuruError::NetworkError(message)) => (false, false, Some(message)),
+            Err(e @ ChatGuruError::RateLimited { .. }) => (true, true, Some(e.to_string())),
+            Err(e) => (true, false, Some(e.to_string())),
+        };
+
+        let status = HealthStatus {
+            reachable,
+            authenticated,
+            latency,
+            error,
+        };
+        if status.is_healthy() {
+            tracing::debug!("ChatGuru health check: {:?}", status);
+        } else {
+            tracing::warn!("ChatGuru health check failed: {:?}", status);
+        }
+        status
+    }
+
+    /// Confirma que o token e o `account_id` são aceitos pela API
+    ///
+    /// Faz a mesma chamada de [`health_check`](Self::health_check), mas
+    /// retorna o erro. Excesso de chamadas (429) não é tratado como falha.
+    ///
+    /// # Erros
+    ///
+    /// * `Unauthorized` - token ou conta recusados
+    /// * `NetworkError` - a API não pôde ser acessada
+    /// * `ApiError` - a API respondeu com erro 5xx
+    pub async fn validate_credentials(&self) -> Result<()> {
+        match self.probe().await {
+            Ok(()) | Err(ChatGuruError::RateLimited { .. }) => Ok(()),
+            Err(e) => {
+                tracing::error!("ChatGuru credential validation failed: {}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// Faz uma única chamada de verificação, sem retries, rate limiter ou circuit breaker
+    ///
+    /// Erros 4xx que não sejam 401/403/429 contam como sucesso: só acontecem
+    /// depois de a API aceitar as credenciais.
+    async fn probe(&self) -> Result<()> {
+        let token = self.credentials.token().await?;
+        let response = self
+            .send_once(
+                &token,
+                &ChatGuruRequest::new(HEALTH_CHECK_ACTION),
+                &self.default_phone_id,
+                &new_request_id(),
+            )
+            .await
+            .map_err(|e| {
+                ChatGuruError::NetworkError(format!(
+                    "{} request failed: {}",
+                    HEALTH_CHECK_ACTION,
+                    describe_error(e)
+                ))
+            })?;
+
+        match read_response(HEALTH_CHECK_ACTION, response).await {
+            Ok(_) => Ok(()),
+            Err(e @ ChatGuruError::Unauthorized(_)) => {
+                self.credentials.invalidate();
+                Err(e)
+            }
+            Err(e @ ChatGuruError::RateLimited { .. }) => Err(e),
+            Err(e @ ChatGuruError::ApiError { status, .. }) if status >= 500 => Err(e),
+            Err(_) => Ok(()),
+        }
+    }
+}
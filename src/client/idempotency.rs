@@ -0,0 +1,105 @@
+use super::{ChatGuruClient, RequestOptions};
+use crate::error::Result;
+use crate::types::PhoneNumber;
+use std::future::Future;
+use std::time::Duration;
+
+/// Por quanto tempo uma chave de idempotência é lembrada por padrão
+pub const DEFAULT_IDEMPOTENCY_TTL: Duration = Duration::from_secs(3600);
+
+impl ChatGuruClient {
+    /// Envia uma mensagem no máximo uma vez por `idempotency_key`
+    ///
+    /// Se a mesma chave já foi usada com sucesso dentro do TTL (padrão: 1
+    /// hora), a chamada é ignorada e retorna `Ok(false)`. Caso contrário
+    /// envia como [`send_confirmation_message`](Self::send_confirmation_message)
+    /// e retorna `Ok(true)`. Se o envio falhar, inclusive por erro da API ou
+    /// chat não encontrado (que `send_confirmation_message` apenas loga), o
+    /// erro é retornado e a chave é liberada para que uma nova tentativa seja
+    /// feita.
+    ///
+    /// As chaves ficam no [`DedupStore`](crate::webhook::DedupStore)
+    /// configurado no builder (em memória por padrão).
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// // Reprocessar o mesmo webhook não reenvia a confirmação
+    /// let key = format!("confirmacao:{}", payload.event_fingerprint());
+    /// client
+    ///     .send_confirmation_message_idempotent(&key, "5511999999999", None, "Recebido!")
+    ///     .await?;
+    /// ```
+    pub async fn send_confirmation_message_idempotent(
+        &self,
+        idempotency_key: &str,
+        phone_number: impl Into<PhoneNumber>,
+        phone_id: Option<&str>,
+        message: &str,
+    ) -> Result<bool> {
+        let mut options = RequestOptions::new().idempotency_key(idempotency_key);
+        if let Some(phone_id) = phone_id {
+            options = options.phone_id(phone_id);
+        }
+        self.send_confirmation_message_with_options(phone_number, message, &options)
+            .await
+    }
+
+    /// Adiciona uma anotação no máximo uma vez por `idempotency_key`
+    ///
+    /// Mesma semântica de
+    /// [`send_confirmation_message_idempotent`](Self::send_confirmation_message_idempotent).
+    pub async fn add_annotation_idempotent(
+        &self,
+        idempotency_key: &str,
+        chat_id: &str,
+        phone_number: impl Into<PhoneNumber>,
+        annotation_text: &str,
+    ) -> Result<bool> {
+        let options = RequestOptions::new().idempotency_key(idempotency_key);
+        self.add_annotation_with_options(chat_id, phone_number, annotation_text, &options)
+            .await
+    }
+
+    /// Executa `operation` se a chave ainda não foi usada, liberando-a em caso de erro
+    ///
+    /// A chave é separada por `account_id`: a conta efetiva da chamada (a do
+    /// [`RequestOptions::account_id`], se informada, ou a do cliente).
+    pub(super) async fn with_idempotency<F>(
+        &self,
+        account_id: &str,
+        idempotency_key: &str,
+        operation: F,
+    ) -> Result<bool>
+    where
+        F: Future<Output = Result<()>>,
+    {
+        let key = format!("idempotency:{}:{}", account_id, idempotency_key);
+
+        if !self
+            .idempotency_store
+            .insert_if_absent(&key, self.idempotency_ttl)
+            .await?
+        {
+            tracing::info!(
+                "Skipping duplicated request with idempotency key {}",
+                idempotency_key
+            );
+            return Ok(false);
+        }
+
+        match operation.await {
+            Ok(()) => Ok(true),
+            Err(e) => {
+                if let Err(remove_error) = self.idempotency_store.remove(&key).await {
+                    tracing::error!(
+                        "Failed to release idempotency key {}: {}",
+                        idempotency_key,
+                        remove_error
+                    );
+                }
+                Err(e)
+            }
+        }
+    }
+}
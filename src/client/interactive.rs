@@ -0,0 +1,403 @@
+use super::request::ChatGuruRequest;
+use super::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::types::PhoneNumber;
+use serde::Serialize;
+
+/// Número máximo de botões de resposta rápida por mensagem
+pub const MAX_BUTTONS: usize = 3;
+
+/// Tamanho máximo do título de um botão (e do botão que abre a lista)
+pub const MAX_BUTTON_TITLE_LENGTH: usize = 20;
+
+/// Número máximo de itens somando todas as seções de uma lista
+pub const MAX_LIST_ROWS: usize = 10;
+
+/// Tamanho máximo do título de um item da lista
+pub const MAX_ROW_TITLE_LENGTH: usize = 24;
+
+/// Tamanho máximo da descrição de um item da lista
+pub const MAX_ROW_DESCRIPTION_LENGTH: usize = 72;
+
+/// Tamanho máximo do texto principal de uma mensagem interativa
+pub const MAX_INTERACTIVE_BODY_LENGTH: usize = 1024;
+
+/// Tamanho máximo do cabeçalho e do rodapé
+pub const MAX_HEADER_LENGTH: usize = 60;
+
+/// Botão de resposta rápida
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReplyButton {
+    /// ID devolvido no webhook quando o contato toca no botão
+    pub id: String,
+    /// Texto exibido no botão
+    pub title: String,
+}
+
+/// Item de uma lista
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ListRow {
+    /// ID devolvido no webhook quando o contato escolhe o item
+    pub id: String,
+    /// Texto do item
+    pub title: String,
+    /// Texto secundário exibido abaixo do título
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl ListRow {
+    /// Cria um item sem descrição
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            description: None,
+        }
+    }
+
+    /// Define a descrição do item
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Seção (grupo de itens com título) de uma lista
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ListSection {
+    /// Título da seção (obrigatório quando há mais de uma)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Itens da seção
+    pub rows: Vec<ListRow>,
+}
+
+/// Botões ou lista oferecidos pela mensagem
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InteractiveAction {
+    /// Até [`MAX_BUTTONS`] botões de resposta rápida
+    Buttons { buttons: Vec<ReplyButton> },
+    /// Menu aberto por um botão, com itens agrupados em seções
+    List {
+        /// Texto do botão que abre a lista
+        button: String,
+        sections: Vec<ListSection>,
+    },
+}
+
+/// Mensagem com botões de resposta rápida ou menu de lista
+///
+/// Criada com [`InteractiveMessage::buttons`] ou [`InteractiveMessage::list`];
+/// [`build`](InteractiveMessageBuilder::build) valida os limites do WhatsApp
+/// antes de qualquer envio. A escolha do contato chega no webhook como
+/// [`ButtonReply`](crate::types::ButtonReply).
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::client::{InteractiveMessage, ListRow};
+///
+/// let confirmacao = InteractiveMessage::buttons("Confirma o agendamento?")
+///     .button("sim", "Confirmar")
+///     .button("nao", "Remarcar")
+///     .build()
+///     .unwrap();
+///
+/// let menu = InteractiveMessage::list("Como podemos ajudar?", "Ver opções")
+///     .section("Atendimento", vec![
+///         ListRow::new("suporte", "Suporte técnico"),
+///         ListRow::new("financeiro", "Financeiro").description("Boletos e notas fiscais"),
+///     ])
+///     .build()
+///     .unwrap();
+///
+/// assert!(InteractiveMessage::buttons("Sem botões").build().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InteractiveMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    header: Option<String>,
+    body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    footer: Option<String>,
+    action: InteractiveAction,
+}
+
+impl InteractiveMessage {
+    /// Inicia uma mensagem com botões de resposta rápida
+    pub fn buttons(body: impl Into<String>) -> InteractiveMessageBuilder {
+        InteractiveMessageBuilder::new(
+            body.into(),
+            InteractiveAction::Buttons {
+                buttons: Vec::new(),
+            },
+        )
+    }
+
+    /// Inicia uma mensagem com menu de lista, aberto pelo botão `button`
+    pub fn list(body: impl Into<String>, button: impl Into<String>) -> InteractiveMessageBuilder {
+        InteractiveMessageBuilder::new(
+            body.into(),
+            InteractiveAction::List {
+                button: button.into(),
+                sections: Vec::new(),
+            },
+        )
+    }
+
+    /// Texto principal da mensagem
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Botões ou lista da mensagem
+    pub fn action(&self) -> &InteractiveAction {
+        &self.action
+    }
+
+    /// Verifica os limites do WhatsApp para botões, listas e textos
+    fn validate(&self) -> Result<()> {
+        check_length("body", &self.body, MAX_INTERACTIVE_BODY_LENGTH)?;
+        if let Some(ref header) = self.header {
+            check_length("header", header, MAX_HEADER_LENGTH)?;
+        }
+        if let Some(ref footer) = self.footer {
+            check_length("footer", footer, MAX_HEADER_LENGTH)?;
+        }
+
+        let mut ids = Vec::new();
+        match self.action {
+            InteractiveAction::Buttons { ref buttons } => {
+                if buttons.is_empty() || buttons.len() > MAX_BUTTONS {
+                    return Err(invalid(format!(
+                        "Interactive message must have 1 to {} buttons, got {}",
+                        MAX_BUTTONS,
+                        buttons.len()
+                    )));
+                }
+                for button in buttons {
+                    check_length("button title", &button.title, MAX_BUTTON_TITLE_LENGTH)?;
+                    ids.push(&button.id);
+                }
+            }
+            InteractiveAction::List {
+                ref button,
+                ref sections,
+            } => {
+                check_length("list button", button, MAX_BUTTON_TITLE_LENGTH)?;
+                let rows: usize = sections.iter().map(|section| section.rows.len()).sum();
+                if rows == 0 || rows > MAX_LIST_ROWS {
+                    return Err(invalid(format!(
+                        "Interactive list must have 1 to {} rows, got {}",
+                        MAX_LIST_ROWS, rows
+                    )));
+                }
+                for section in sections {
+                    if section.rows.is_empty() {
+                        return Err(invalid("Interactive list section has no rows".to_string()));
+                    }
+                    match section.title {
+                        Some(ref title) => {
+                            check_length("section title", title, MAX_ROW_TITLE_LENGTH)?
+                        }
+                        None if sections.len() > 1 => {
+                            return Err(invalid(
+                                "Every section needs a title when the list has more than one"
+                                    .to_string(),
+                            ))
+                        }
+                        None => {}
+                    }
+                    for row in &section.rows {
+                        check_length("row title", &row.title, MAX_ROW_TITLE_LENGTH)?;
+                        if let Some(ref description) = row.description {
+                            check_length(
+                                "row description",
+                                description,
+                                MAX_ROW_DESCRIPTION_LENGTH,
+                            )?;
+                        }
+                        ids.push(&row.id);
+                    }
+                }
+            }
+        }
+
+        for (index, id) in ids.iter().enumerate() {
+            if id.trim().is_empty() {
+                return Err(invalid(
+                    "Interactive option IDs cannot be empty".to_string(),
+                ));
+            }
+            if ids[..index].contains(id) {
+                return Err(invalid(format!("Duplicate interactive option ID: {}", id)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Builder de [`InteractiveMessage`]
+#[derive(Debug, Clone)]
+pub struct InteractiveMessageBuilder {
+    message: InteractiveMessage,
+}
+
+impl InteractiveMessageBuilder {
+    fn new(body: String, action: InteractiveAction) -> Self {
+        Self {
+            message: InteractiveMessage {
+                header: None,
+                body,
+                footer: None,
+                action,
+            },
+        }
+    }
+
+    /// Define o cabeçalho (texto em destaque acima da mensagem)
+    pub fn header(mut self, header: impl Into<String>) -> Self {
+        self.message.header = Some(header.into());
+        self
+    }
+
+    /// Define o rodapé (texto menor abaixo da mensagem)
+    pub fn footer(mut self, footer: impl Into<String>) -> Self {
+        self.message.footer = Some(footer.into());
+        self
+    }
+
+    /// Adiciona um botão de resposta rápida (ignorado em listas)
+    pub fn button(mut self, id: impl Into<String>, title: impl Into<String>) -> Self {
+        if let InteractiveAction::Buttons { ref mut buttons } = self.message.action {
+            buttons.push(ReplyButton {
+                id: id.into(),
+                title: title.into(),
+            });
+        }
+        self
+    }
+
+    /// Adiciona uma seção com título à lista (ignorado em botões)
+    pub fn section(mut self, title: impl Into<String>, rows: Vec<ListRow>) -> Self {
+        if let InteractiveAction::List {
+            ref mut sections, ..
+        } = self.message.action
+        {
+            sections.push(ListSection {
+                title: Some(title.into()),
+                rows,
+            });
+        }
+        self
+    }
+
+    /// Adiciona um item à última seção da lista, criando uma seção sem título
+    /// se necessário (ignorado em botões)
+    pub fn row(mut self, row: ListRow) -> Self {
+        if let InteractiveAction::List {
+            ref mut sections, ..
+        } = self.message.action
+        {
+            match sections.last_mut() {
+                Some(section) => section.rows.push(row),
+                None => sections.push(ListSection {
+                    title: None,
+                    rows: vec![row],
+                }),
+            }
+        }
+        self
+    }
+
+    /// Valida e retorna a mensagem
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se a mensagem não tiver opções, exceder o
+    /// número de botões/itens, tiver textos acima dos limites do WhatsApp ou
+    /// IDs vazios/repetidos.
+    pub fn build(self) -> Result<InteractiveMessage> {
+        self.message.validate()?;
+        Ok(self.message)
+    }
+}
+
+fn check_length(field: &str, value: &str, limit: usize) -> Result<()> {
+    let length = value.chars().count();
+    if value.trim().is_empty() || length > limit {
+        return Err(invalid(format!(
+            "Interactive {} must have 1 to {} characters, got {}",
+            field, limit, length
+        )));
+    }
+    Ok(())
+}
+
+fn invalid(message: String) -> ChatGuruError {
+    ChatGuruError::ValidationError(message)
+}
+
+impl ChatGuruClient {
+    /// Envia uma mensagem com botões de resposta rápida ou menu de lista
+    ///
+    /// Usa a ação `message_send` com o texto principal em `text` e os botões
+    /// (ou a lista) em JSON no parâmetro `interactive`. Envia pelo phone_id
+    /// padrão do cliente.
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - telefone inválido
+    /// * `NetworkError` - falha de rede
+    /// * `ApiError`, `ChatNotFound`, `RateLimited` ou `Unauthorized` - a API recusou o envio
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// use chatguru::client::InteractiveMessage;
+    ///
+    /// let message = InteractiveMessage::buttons("Confirma o agendamento?")
+    ///     .button("sim", "Confirmar")
+    ///     .button("nao", "Remarcar")
+    ///     .build()?;
+    ///
+    /// client.send_interactive_message("5511999999999", message).await?;
+    /// ```
+    pub async fn send_interactive_message(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        message: InteractiveMessage,
+    ) -> Result<()> {
+        let phone_number = phone_number.into();
+        phone_number.validate()?;
+        message.validate()?;
+
+        let interactive = serde_json::to_string(&message)?;
+
+        tracing::debug!("Sending interactive message to {}", phone_number.masked());
+
+        let request = ChatGuruRequest::new("message_send")
+            .param("text", message.body())
+            .param("chat_number", phone_number.digits())
+            .param("interactive", interactive);
+        let response = self.post_action(&request).await?;
+
+        match super::request::read_response("message_send", response).await {
+            Ok(response_text) => {
+                tracing::debug!(
+                    "Interactive message sent successfully to {}: {}",
+                    phone_number.masked(),
+                    response_text
+                );
+                Ok(())
+            }
+            Err(e) => {
+                tracing::error!("Failed to send interactive message: {}", e);
+                Err(e)
+            }
+        }
+    }
+}
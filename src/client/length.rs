@@ -0,0 +1,90 @@
+//! Limite de tamanho dos textos enviados à API
+//!
+//! Mensagens (`message_send`) e anotações (`note_add`) acima do limite
+//! configurado no builder são cortadas por grafemas com
+//! [`truncate_with_ellipsis`], sem quebrar emojis, e o corte é informado como
+//! [`SendWarning`] na resposta.
+
+use super::request::ChatGuruRequest;
+use super::ChatGuruClient;
+use crate::text::{grapheme_len, truncate_with_ellipsis};
+use std::borrow::Cow;
+
+/// Tamanho máximo padrão de uma anotação, em caracteres
+pub const DEFAULT_MAX_NOTE_LENGTH: usize = 4096;
+
+/// Aviso sobre um envio aceito com ajustes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SendWarning {
+    /// O texto excedia o limite e foi cortado (tamanhos em grafemas)
+    Truncated {
+        /// Parâmetro cortado (`text`, `note_text`)
+        param: String,
+        /// Tamanho do texto original
+        original_length: usize,
+        /// Tamanho do texto enviado, incluindo as reticências
+        sent_length: usize,
+    },
+}
+
+/// Avisos do envio, anexados à resposta de [`post_action`](ChatGuruClient::post_action)
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SendWarnings(pub(crate) Vec<SendWarning>);
+
+impl SendWarnings {
+    /// Retira os avisos anexados à resposta
+    pub(crate) fn take(response: &mut reqwest::Response) -> Vec<SendWarning> {
+        response
+            .extensions_mut()
+            .remove::<SendWarnings>()
+            .map(|warnings| warnings.0)
+            .unwrap_or_default()
+    }
+}
+
+impl ChatGuruClient {
+    /// Limite do parâmetro de texto da ação, se houver
+    fn text_limit(&self, action: &str) -> Option<(&'static str, usize)> {
+        match action {
+            "message_send" => Some(("text", self.max_message_length)),
+            "note_add" => Some(("note_text", self.max_note_length)),
+            _ => None,
+        }
+    }
+
+    /// Corta o texto da chamada que excede o limite configurado
+    ///
+    /// Retorna a chamada com o texto cortado e o aviso, ou `None` se ela pode
+    /// seguir como está.
+    pub(super) fn apply_length_limit(
+        &self,
+        request: &ChatGuruRequest,
+    ) -> Option<(ChatGuruRequest, SendWarning)> {
+        let (param, limit) = self.text_limit(request.action())?;
+        let text = request.param_value(param)?;
+        let Cow::Owned(truncated) = truncate_with_ellipsis(text, limit) else {
+            return None;
+        };
+
+        let original_length = grapheme_len(text);
+        let sent_length = grapheme_len(&truncated);
+        tracing::warn!(
+            "ChatGuru {} {} truncated from {} to {} characters",
+            request.action(),
+            param,
+            original_length,
+            sent_length
+        );
+
+        let mut limited = request.clone();
+        limited.set_param(param, truncated);
+        Some((
+            limited,
+            SendWarning::Truncated {
+                param: param.to_string(),
+                original_length,
+                sent_length,
+            },
+        ))
+    }
+}
@@ -0,0 +1,49 @@
+//! Envio das mensagens do [`Localizer`] no idioma do contato (feature `i18n`)
+
+use super::{ChatGuruClient, RequestOptions};
+use crate::error::{ChatGuruError, Result};
+use crate::templates::TemplateContext;
+use crate::types::PhoneNumber;
+
+impl ChatGuruClient {
+    /// Envia a mensagem `key` do [`Localizer`](crate::i18n::Localizer) configurado
+    ///
+    /// O idioma vem de [`TemplateContext::locale`] (ex: o resultado de
+    /// [`detect_locale`](crate::i18n::detect_locale)); sem texto nesse
+    /// idioma, é usado o idioma padrão do localizer. Envia como
+    /// [`send_templated`](Self::send_templated), com a chave como nome do
+    /// template.
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se não houver localizer configurado, se a
+    /// mensagem não estiver cadastrada ou se faltar valor para algum
+    /// placeholder.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let context = TemplateContext::from_payload(&chatguru_payload)
+    ///     .with("pedido", "1234")
+    ///     .locale(detect_locale(&payload).unwrap_or_else(|| DEFAULT_LOCALE.to_string()));
+    ///
+    /// client.send_localized("5511999999999", "pedido_confirmado", &context).await?;
+    /// ```
+    pub async fn send_localized(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        key: &str,
+        args: &TemplateContext,
+    ) -> Result<()> {
+        let Some(ref localizer) = self.localizer else {
+            return Err(ChatGuruError::ValidationError(
+                "No Localizer configured; use ChatGuruClientBuilder::localizer".to_string(),
+            ));
+        };
+
+        let message = localizer.render(key, args)?;
+        let options = RequestOptions::default().template(key);
+        self.send_confirmation(phone_number.into(), &message, &options)
+            .await
+    }
+}
@@ -0,0 +1,120 @@
+use super::request::ChatGuruRequest;
+use super::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::media::{MediaAttachment, MediaDownloader, MediaFile};
+use crate::types::{PhoneNumber, WebhookPayload};
+
+impl ChatGuruClient {
+    /// Baixa a mídia anexada a um webhook
+    ///
+    /// Usa o cliente HTTP já configurado, respeita o limite definido em
+    /// `max_media_size()` no builder (padrão: 16 MiB) e detecta o MIME type
+    /// pelos bytes do arquivo.
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - payload sem mídia ou arquivo acima do limite
+    /// * `NetworkError` - falha ao baixar o arquivo
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// if payload.has_media() {
+    ///     let file = client.download_media(&payload).await?;
+    ///     println!("{} ({} bytes)", file.mime, file.len());
+    /// }
+    /// ```
+    pub async fn download_media(&self, payload: &WebhookPayload) -> Result<MediaFile> {
+        let url = payload.get_media_url().ok_or_else(|| {
+            ChatGuruError::ValidationError("Webhook payload has no media attached".to_string())
+        })?;
+
+        MediaDownloader::with_client(self.client.clone())
+            .max_size(self.max_media_size)
+            .download(&url)
+            .await
+    }
+
+    /// Envia uma mídia (imagem, documento, áudio ou vídeo) via WhatsApp
+    ///
+    /// Usa a ação `message_file_send`. A mídia pode vir de uma URL pública ou
+    /// dos bytes do arquivo (enviados como URI `data:` em base64), com
+    /// legenda opcional.
+    ///
+    /// # Parâmetros
+    ///
+    /// * `phone_number` - Número de telefone do destinatário
+    /// * `phone_id` - ID do telefone ChatGuru (opcional, usa o phone_id padrão do cliente se None)
+    /// * `media` - Mídia a ser enviada
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - telefone inválido, formato não suportado ou arquivo grande demais
+    /// * `NetworkError` - falha de rede
+    /// * `ApiError`, `ChatNotFound`, `RateLimited` ou `Unauthorized` - a API recusou o envio
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// use chatguru::media::MediaAttachment;
+    ///
+    /// client.send_media_message(
+    ///     "5511999999999",
+    ///     None,
+    ///     MediaAttachment::from_url("https://exemplo.com/proposta.pdf")
+    ///         .caption("Segue a proposta"),
+    /// ).await?;
+    /// ```
+    pub async fn send_media_message(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        phone_id: Option<&str>,
+        media: MediaAttachment,
+    ) -> Result<()> {
+        let phone_id_value = phone_id.unwrap_or(&self.default_phone_id);
+
+        let phone_number = phone_number.into();
+        phone_number.validate()?;
+
+        let mime = media.validate()?;
+        let file_url = media.file_url(&mime);
+        let file_name = media.file_name();
+
+        let mut params = vec![
+            ("chat_number", phone_number.digits()),
+            ("file_url", file_url.as_str()),
+        ];
+        if let Some(caption) = media.caption_text() {
+            params.push(("caption", caption));
+        }
+        if let Some(ref file_name) = file_name {
+            params.push(("file_name", file_name.as_str()));
+        }
+
+        tracing::debug!(
+            "Sending {} media message to {}",
+            mime,
+            phone_number.masked()
+        );
+
+        let request = ChatGuruRequest::new("message_file_send")
+            .phone_id(phone_id_value)
+            .params(&params);
+        let response = self.post_action(&request).await?;
+
+        match super::request::read_response("message_file_send", response).await {
+            Ok(response_text) => {
+                tracing::debug!(
+                    "Media message sent successfully to {}: {}",
+                    phone_number.masked(),
+                    response_text
+                );
+                Ok(())
+            }
+            Err(e) => {
+                tracing::error!("Failed to send media message: {}", e);
+                Err(e)
+            }
+        }
+    }
+}
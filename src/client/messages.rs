@@ -0,0 +1,239 @@
+use super::chat::parse_datetime;
+use super::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::types::{MediaInfo, MediaTypeMap, MessageStatus, PhoneNumber};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Mensagens pedidas por página em [`ChatGuruClient::get_chat_messages`]
+const MESSAGE_PAGE_SIZE: usize = 100;
+
+/// Chat consultado por [`ChatGuruClient::get_chat_messages`]: ID do chat ou telefone
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChatRef {
+    /// ID do chat no ChatGuru (enviado como `chat_id`)
+    Id(String),
+    /// Telefone do contato (enviado como `chat_number`)
+    Phone(PhoneNumber),
+}
+
+impl ChatRef {
+    /// Interpreta o texto como telefone quando só tem dígitos e pontuação
+    /// de telefone (`+`, espaços, `-`, parênteses); caso contrário, como ID
+    pub fn parse(value: &str) -> Self {
+        let value = value.trim();
+        let looks_like_phone = value.chars().any(|c| c.is_ascii_digit())
+            && value
+                .chars()
+                .all(|c| c.is_ascii_digit() || matches!(c, '+' | ' ' | '-' | '(' | ')'));
+
+        if looks_like_phone {
+            ChatRef::Phone(PhoneNumber::from(value))
+        } else {
+            ChatRef::Id(value.to_string())
+        }
+    }
+
+    fn param(&self) -> (&'static str, &str) {
+        match self {
+            ChatRef::Id(id) => ("chat_id", id),
+            ChatRef::Phone(phone) => ("chat_number", phone.digits()),
+        }
+    }
+}
+
+impl From<&str> for ChatRef {
+    fn from(value: &str) -> Self {
+        ChatRef::parse(value)
+    }
+}
+
+impl From<String> for ChatRef {
+    fn from(value: String) -> Self {
+        ChatRef::parse(&value)
+    }
+}
+
+impl From<PhoneNumber> for ChatRef {
+    fn from(value: PhoneNumber) -> Self {
+        ChatRef::Phone(value)
+    }
+}
+
+/// Sentido de uma mensagem do chat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageDirection {
+    /// Enviada pelo contato
+    Inbound,
+    /// Enviada pela empresa (atendente, bot ou API)
+    Outbound,
+}
+
+/// Mensagem do histórico de um chat, retornada por [`ChatGuruClient::get_chat_messages`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatMessage {
+    /// ID da mensagem no ChatGuru
+    pub id: Option<String>,
+    pub direction: MessageDirection,
+    /// Texto da mensagem (ou legenda da mídia)
+    pub text: Option<String>,
+    /// Mídia anexada, quando houver
+    pub media: Option<MediaInfo>,
+    /// Quem enviou (nome do atendente ou do contato), quando informado
+    pub author: Option<String>,
+    /// Data de envio
+    pub sent_at: Option<DateTime<Utc>>,
+    /// Situação de entrega (mensagens enviadas)
+    pub status: Option<MessageStatus>,
+}
+
+impl ChatMessage {
+    /// Interpreta uma página da resposta de `message_list`
+    ///
+    /// Aceita a lista no nível raiz ou dentro de `messages`/`mensagens`/`data`.
+    fn page_from_body(body: &str) -> Result<Vec<Self>> {
+        let value: Value = serde_json::from_str(body)?;
+        let messages = ["messages", "mensagens", "data"]
+            .iter()
+            .find_map(|key| value.get(key).and_then(Value::as_array))
+            .or_else(|| value.as_array())
+            .ok_or_else(|| {
+                ChatGuruError::SerializationError(format!(
+                    "Unexpected message_list response: {}",
+                    body
+                ))
+            })?;
+
+        Ok(messages.iter().map(Self::from_value).collect())
+    }
+
+    fn from_value(message: &Value) -> Self {
+        let field = |names: &[&str]| names.iter().find_map(|name| message.get(name));
+        let text = |names: &[&str]| {
+            field(names).and_then(|v| match v {
+                Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+                Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            })
+        };
+
+        let direction = match field(&["from_me", "fromMe", "enviada"]) {
+            Some(Value::Bool(true)) => MessageDirection::Outbound,
+            Some(Value::Bool(false)) => MessageDirection::Inbound,
+            _ => match text(&["direction", "direcao"]).as_deref() {
+                Some("outbound" | "out" | "sent" | "enviada" | "saida") => {
+                    MessageDirection::Outbound
+                }
+                _ => MessageDirection::Inbound,
+            },
+        };
+
+        let body = text(&["text", "texto", "texto_mensagem", "message", "mensagem"]);
+        let tipo_mensagem = text(&["tipo_mensagem", "message_type", "type"]);
+        let media = text(&["url_arquivo", "media_url", "file_url"]).map(|url| MediaInfo {
+            mime_type: text(&["media_type", "mime_type"]).or_else(|| {
+                tipo_mensagem
+                    .as_deref()
+                    .and_then(|tipo| MediaTypeMap::default().mime_for(tipo, Some(&url)))
+            }),
+            url,
+            tipo_mensagem: tipo_mensagem.clone(),
+            caption: body.clone(),
+        });
+
+        Self {
+            id: text(&["id", "message_id", "id_mensagem"]),
+            direction,
+            text: body,
+            media,
+            author: text(&["author", "autor", "user_name", "sender_name"]),
+            sent_at: field(&["sent_at", "timestamp", "created_at", "data_envio", "data"])
+                .and_then(parse_datetime),
+            status: text(&["status", "message_status", "status_mensagem"])
+                .as_deref()
+                .and_then(MessageStatus::parse),
+        }
+    }
+}
+
+impl ChatGuruClient {
+    /// Busca o histórico de mensagens de um chat (`action=message_list`)
+    ///
+    /// `chat` pode ser o ID do chat ou o telefone do contato (veja
+    /// [`ChatRef::parse`]). Busca as páginas em sequência até reunir `limit`
+    /// mensagens ou chegar ao fim do histórico; com `since`, apenas
+    /// mensagens a partir dessa data. As mensagens vêm na ordem da API.
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - telefone inválido ou ID vazio
+    /// * `ChatNotFound` - o chat não existe
+    /// * `SerializationError` - resposta da API em formato inesperado
+    /// * `ApiError`, `RateLimited`, `Unauthorized`, `NetworkError` ou `CircuitOpen` - falha na chamada
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// use chrono::{Duration, Utc};
+    ///
+    /// let messages = client
+    ///     .get_chat_messages("5511999999999", Some(Utc::now() - Duration::days(7)), 50)
+    ///     .await?;
+    ///
+    /// for message in messages {
+    ///     println!("{:?} {:?}: {:?}", message.sent_at, message.direction, message.text);
+    /// }
+    /// ```
+    pub async fn get_chat_messages(
+        &self,
+        chat: impl Into<ChatRef>,
+        since: Option<DateTime<Utc>>,
+        limit: usize,
+    ) -> Result<Vec<ChatMessage>> {
+        let chat = chat.into();
+        match chat {
+            ChatRef::Phone(ref phone) => phone.validate()?,
+            ChatRef::Id(ref id) if id.is_empty() => {
+                return Err(ChatGuruError::ValidationError(
+                    "Chat ID must not be empty".to_string(),
+                ))
+            }
+            ChatRef::Id(_) => {}
+        }
+
+        let since = since.map(|since| since.format("%Y-%m-%d %H:%M:%S").to_string());
+        let mut messages = Vec::new();
+        let mut page = 1u32;
+
+        while messages.len() < limit {
+            let page_size = (limit - messages.len()).min(MESSAGE_PAGE_SIZE);
+            let (page_text, page_size_text) = (page.to_string(), page_size.to_string());
+
+            let mut params = vec![
+                chat.param(),
+                ("page", page_text.as_str()),
+                ("page_size", page_size_text.as_str()),
+            ];
+            if let Some(ref since) = since {
+                params.push(("date_from", since.as_str()));
+            }
+
+            let body = self
+                .call_action("message_list", &self.default_phone_id, &params)
+                .await?;
+            let batch = ChatMessage::page_from_body(&body)?;
+
+            let last_page = batch.len() < page_size;
+            messages.extend(batch.into_iter().take(limit - messages.len()));
+            if last_page {
+                break;
+            }
+            page += 1;
+        }
+
+        tracing::debug!("Fetched {} messages from chat history", messages.len());
+        Ok(messages)
+    }
+}
@@ -0,0 +1,165 @@
+use super::CircuitState;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Resultado de uma chamada à API, informado ao [`MetricsSink`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestMetrics {
+    /// Ação chamada (`note_add`, `message_send`, ...)
+    pub action: String,
+    /// Status HTTP da última tentativa (None em falhas de rede ou circuito aberto)
+    pub status: Option<u16>,
+    /// A chamada terminou com status 2xx
+    pub success: bool,
+    /// Tempo total, incluindo retries e esperas do rate limiter
+    pub latency: Duration,
+    /// Tentativas feitas (0 quando bloqueada pelo circuit breaker)
+    pub attempts: u32,
+}
+
+/// Destino das métricas do cliente
+///
+/// Implemente este trait para exportar as métricas para o sistema de
+/// monitoramento usado (Prometheus, StatsD, OpenTelemetry, ...). Todos os
+/// métodos têm implementação vazia, então basta sobrescrever os relevantes.
+/// [`InMemoryMetrics`] acumula contadores em memória.
+///
+/// Os métodos são chamados no caminho das requisições e não devem bloquear.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::client::{MetricsSink, RequestMetrics};
+///
+/// struct Prometheus { /* ... */ }
+///
+/// impl MetricsSink for Prometheus {
+///     fn record_request(&self, metrics: &RequestMetrics) {
+///         let status = if metrics.success { "ok" } else { "error" };
+///         REQUESTS.with_label_values(&[&metrics.action, status]).inc();
+///         LATENCY.with_label_values(&[&metrics.action]).observe(metrics.latency.as_secs_f64());
+///     }
+/// }
+///
+/// let client = ChatGuruClient::builder()
+///     // ...
+///     .metrics(Arc::new(Prometheus { /* ... */ }))
+///     .build()?;
+/// ```
+pub trait MetricsSink: Send + Sync {
+    /// Chamada concluída (com sucesso ou não)
+    fn record_request(&self, metrics: &RequestMetrics) {
+        let _ = metrics;
+    }
+
+    /// Uma tentativa falhou e será repetida
+    fn record_retry(&self, action: &str, attempt: u32) {
+        let _ = (action, attempt);
+    }
+
+    /// O circuit breaker mudou de estado
+    fn record_circuit_state(&self, state: CircuitState) {
+        let _ = state;
+    }
+}
+
+/// Contadores acumulados de uma ação
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActionStats {
+    /// Chamadas concluídas
+    pub requests: u64,
+    /// Chamadas com status 2xx
+    pub successes: u64,
+    /// Chamadas com erro (rede, API ou circuito aberto)
+    pub failures: u64,
+    /// Tentativas repetidas
+    pub retries: u64,
+    /// Soma das latências
+    pub total_latency: Duration,
+    /// Maior latência observada
+    pub max_latency: Duration,
+}
+
+impl ActionStats {
+    /// Latência média das chamadas
+    pub fn average_latency(&self) -> Duration {
+        match self.requests {
+            0 => Duration::ZERO,
+            n => self.total_latency / n as u32,
+        }
+    }
+
+    /// Fração de chamadas com erro (0.0 a 1.0)
+    pub fn error_rate(&self) -> f64 {
+        match self.requests {
+            0 => 0.0,
+            n => self.failures as f64 / n as f64,
+        }
+    }
+}
+
+/// [`MetricsSink`] que acumula contadores em memória, por ação
+///
+/// Útil para expor as métricas em um endpoint de status próprio ou em testes.
+#[derive(Debug, Default)]
+pub struct InMemoryMetrics {
+    actions: Mutex<HashMap<String, ActionStats>>,
+    circuit_opened: Mutex<u64>,
+}
+
+impl InMemoryMetrics {
+    /// Cria um coletor vazio
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Contadores atuais por ação
+    pub fn snapshot(&self) -> HashMap<String, ActionStats> {
+        self.actions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Quantas vezes o circuit breaker abriu
+    pub fn circuit_opened(&self) -> u64 {
+        *self
+            .circuit_opened
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn with_action(&self, action: &str, update: impl FnOnce(&mut ActionStats)) {
+        let mut actions = self.actions.lock().unwrap_or_else(|e| e.into_inner());
+        update(actions.entry(action.to_string()).or_default());
+    }
+}
+
+impl MetricsSink for InMemoryMetrics {
+    fn record_request(&self, metrics: &RequestMetrics) {
+        self.with_action(&metrics.action, |stats| {
+            stats.requests += 1;
+            if metrics.success {
+                stats.successes += 1;
+            } else {
+                stats.failures += 1;
+            }
+            stats.total_latency += metrics.latency;
+            stats.max_latency = stats.max_latency.max(metrics.latency);
+        });
+    }
+
+    fn record_retry(&self, action: &str, _attempt: u32) {
+        self.with_action(action, |stats| stats.retries += 1);
+    }
+
+    fn record_circuit_state(&self, state: CircuitState) {
+        if state == CircuitState::Open {
+            *self
+                .circuit_opened
+                .lock()
+                .unwrap_or_else(|e| e.into_inner()) += 1;
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Middlewares (interceptadores) das chamadas à API
+//!
+//! Um [`Middleware`] recebe cada chamada antes de ela ser enviada e decide o
+//! que fazer: alterar parâmetros e headers, registrar, recusar, repetir ou
+//! simplesmente repassar para o próximo da cadeia com [`Next::run`].
+
+use super::annotate::SendNote;
+use super::request::Overrides;
+use super::ChatGuruClient;
+use crate::error::Result;
+use futures_core::future::BoxFuture;
+use reqwest::header::HeaderMap;
+use std::sync::Arc;
+
+/// Chamada à API vista pelos middlewares
+///
+/// Os parâmetros não incluem os preenchidos pelo cliente (`key`,
+/// `account_id`, `phone_id` e `action`).
+#[derive(Debug, Clone)]
+pub struct ActionRequest {
+    /// Ação chamada (`message_send`, `note_add`, ...)
+    pub action: String,
+    /// Linha usada na chamada
+    pub phone_id: String,
+    /// Parâmetros específicos da ação, na ordem de envio
+    pub params: Vec<(String, String)>,
+    /// Headers HTTP adicionais da requisição
+    pub headers: HeaderMap,
+    /// ID da chamada, enviado no header [`REQUEST_ID_HEADER`](super::REQUEST_ID_HEADER)
+    pub request_id: String,
+    pub(super) cacheable: bool,
+    pub(super) overrides: Overrides,
+    pub(super) bypass_send_policy: bool,
+    pub(super) send_note: SendNote,
+}
+
+impl ActionRequest {
+    /// Valor de um parâmetro
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Define um parâmetro, substituindo o valor anterior
+    pub fn set_param(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+        let value = value.into();
+        match self.params.iter_mut().find(|(param, _)| *param == name) {
+            Some((_, current)) => *current = value,
+            None => self.params.push((name, value)),
+        }
+    }
+}
+
+/// Interceptador das chamadas à API, registrado com
+/// [`ChatGuruClientBuilder::middleware`](super::ChatGuruClientBuilder::middleware)
+///
+/// Os middlewares rodam na ordem em que foram registrados, envolvendo a
+/// chamada inteira: o envio, com rate limiter, retries e circuit breaker,
+/// acontece quando o último chama [`Next::run`]. Um middleware pode chamar
+/// `next.run` mais de uma vez (para repetir a chamada) ou nenhuma (para
+/// recusá-la com um erro).
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::client::{ActionRequest, Middleware, Next};
+/// use futures_core::future::BoxFuture;
+///
+/// struct Auditoria;
+///
+/// impl Middleware for Auditoria {
+///     fn handle<'a>(
+///         &'a self,
+///         mut request: ActionRequest,
+///         next: Next<'a>,
+///     ) -> BoxFuture<'a, chatguru::Result<reqwest::Response>> {
+///         Box::pin(async move {
+///             request.headers.insert("X-Tenant", "loja-42".parse().unwrap());
+///             let action = request.action.clone();
+///             let response = next.run(request).await;
+///             audit_log(&action, response.as_ref().map(|r| r.status().as_u16()));
+///             response
+///         })
+///     }
+/// }
+///
+/// let client = ChatGuruClient::builder()
+///     // ...
+///     .middleware(Arc::new(Auditoria))
+///     .build()?;
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Trata a chamada, normalmente repassando-a com `next.run(request)`
+    fn handle<'a>(
+        &'a self,
+        request: ActionRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<reqwest::Response>>;
+}
+
+/// Restante da cadeia de middlewares, terminando no envio da requisição
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    client: &'a ChatGuruClient,
+    middlewares: &'a [Arc<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    pub(super) fn new(client: &'a ChatGuruClient, middlewares: &'a [Arc<dyn Middleware>]) -> Self {
+        Self {
+            client,
+            middlewares,
+        }
+    }
+
+    /// Passa a chamada para o próximo middleware (ou a envia, se for o último)
+    pub fn run(self, request: ActionRequest) -> BoxFuture<'a, Result<reqwest::Response>> {
+        match self.middlewares.split_first() {
+            Some((middleware, rest)) => middleware.handle(request, Next::new(self.client, rest)),
+            None => Box::pin(self.client.dispatch_action(request)),
+        }
+    }
+}
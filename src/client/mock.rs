@@ -0,0 +1,518 @@
+use super::{
+    BulkSendResult, ChatGuruApi, ChatStatus, FlushReport, OutgoingMessage, RegisteredSend,
+    SentMessage,
+};
+use crate::error::{ChatGuruError, Result};
+use crate::media::{MediaAttachment, MediaFile};
+use crate::types::{PhoneNumber, WebhookPayload};
+use futures_core::future::BoxFuture;
+use std::collections::{HashSet, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Chamada registrada pelo [`MockChatGuruClient`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MockCall {
+    /// `add_annotation`
+    AddAnnotation {
+        /// Chat informado
+        chat_id: String,
+        /// Telefone (dígitos)
+        phone: String,
+        /// Texto da anotação
+        text: String,
+    },
+    /// `send_confirmation_message`, `send_message` e cada item de `send_bulk`
+    /// (e `send_templated`, já renderizado)
+    SendMessage {
+        /// Telefone (dígitos)
+        phone: String,
+        /// phone_id informado
+        phone_id: Option<String>,
+        /// Texto da mensagem
+        text: String,
+    },
+    /// `send_media_message`
+    SendMedia {
+        /// Telefone (dígitos)
+        phone: String,
+        /// phone_id informado
+        phone_id: Option<String>,
+        /// Mídia enviada
+        media: MediaAttachment,
+    },
+    /// `download_media`
+    DownloadMedia {
+        /// URL da mídia no payload
+        url: Option<String>,
+    },
+    /// `send_reply`
+    SendReply {
+        /// Telefone (dígitos)
+        phone: String,
+        /// Texto da resposta
+        text: String,
+        /// ID da mensagem citada
+        reply_to: String,
+    },
+    /// `send_or_register`
+    SendOrRegister {
+        /// Telefone (dígitos)
+        phone: String,
+        /// Nome usado no cadastro
+        name: String,
+        /// Texto da mensagem
+        text: String,
+    },
+    /// `queue_message`
+    QueueMessage {
+        /// Telefone (dígitos)
+        phone: String,
+        /// phone_id informado
+        phone_id: Option<String>,
+        /// Texto da mensagem
+        text: String,
+    },
+    /// `queue_annotation`
+    QueueAnnotation {
+        /// Chat informado
+        chat_id: String,
+        /// Telefone (dígitos)
+        phone: String,
+        /// Texto da anotação
+        text: String,
+    },
+    /// `flush`
+    Flush,
+    /// `get_chat_status`
+    GetChatStatus {
+        /// Telefone (dígitos)
+        phone: String,
+    },
+    /// `assign_chat`
+    AssignChat {
+        /// Telefone (dígitos)
+        phone: String,
+        /// Atendente informado
+        user: String,
+    },
+    /// `execute_raw`
+    ExecuteRaw {
+        /// Ação
+        action: String,
+        /// Parâmetros, na ordem informada
+        params: Vec<(String, String)>,
+    },
+}
+
+/// Cliente falso para testes de serviços que dependem de [`ChatGuruApi`]
+///
+/// Registra todas as chamadas e, por padrão, responde com sucesso. Erros
+/// podem ser programados com [`fail_next`](Self::fail_next), as mídias
+/// retornadas por `download_media` com [`push_media`](Self::push_media), as
+/// situações de `get_chat_status` com [`push_chat_status`](Self::push_chat_status)
+/// e as respostas de `execute_raw` com [`push_raw_response`](Self::push_raw_response).
+///
+/// Os métodos `*_idempotent` só registram a chamada na primeira vez de cada
+/// chave (retornando `false` nas seguintes), e `flush` retorna os IDs
+/// gravados por `queue_message`/`queue_annotation` desde o último flush.
+///
+/// Disponível com a feature `test-util`.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use std::sync::Arc;
+/// use chatguru::client::{MockCall, MockChatGuruClient};
+/// use chatguru::ChatGuruError;
+///
+/// let mock = Arc::new(MockChatGuruClient::new());
+/// mock.fail_next(ChatGuruError::ChatNotFound("Chat não encontrado".into()));
+///
+/// let servico = Notificador { chatguru: mock.clone() };
+/// assert!(servico.confirmar("5511999999999").await.is_err());
+///
+/// assert_eq!(mock.sent_messages(), vec!["Recebido!".to_string()]);
+/// ```
+#[derive(Debug, Default)]
+pub struct MockChatGuruClient {
+    calls: Mutex<Vec<MockCall>>,
+    errors: Mutex<VecDeque<ChatGuruError>>,
+    media: Mutex<VecDeque<MediaFile>>,
+    chat_statuses: Mutex<VecDeque<ChatStatus>>,
+    raw_responses: Mutex<VecDeque<String>>,
+    idempotency_keys: Mutex<HashSet<String>>,
+    queued: Mutex<Vec<String>>,
+    next_id: AtomicU64,
+}
+
+impl MockChatGuruClient {
+    /// Cria um mock sem respostas programadas
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Faz a próxima chamada (de qualquer operação) retornar o erro informado
+    ///
+    /// Erros programados são consumidos em ordem, um por chamada.
+    pub fn fail_next(&self, error: ChatGuruError) -> &Self {
+        lock(&self.errors).push_back(error);
+        self
+    }
+
+    /// Enfileira uma mídia para ser retornada por `download_media`
+    ///
+    /// Sem mídias programadas, `download_media` retorna `ValidationError`.
+    pub fn push_media(&self, media: MediaFile) -> &Self {
+        lock(&self.media).push_back(media);
+        self
+    }
+
+    /// Enfileira uma situação para ser retornada por `get_chat_status`
+    ///
+    /// Sem situações programadas, `get_chat_status` retorna [`ChatStatus::not_found`].
+    pub fn push_chat_status(&self, status: ChatStatus) -> &Self {
+        lock(&self.chat_statuses).push_back(status);
+        self
+    }
+
+    /// Enfileira um corpo de resposta para ser retornado por `execute_raw`
+    ///
+    /// Sem respostas programadas, `execute_raw` retorna uma string vazia.
+    pub fn push_raw_response(&self, body: impl Into<String>) -> &Self {
+        lock(&self.raw_responses).push_back(body.into());
+        self
+    }
+
+    /// Todas as chamadas recebidas, em ordem
+    pub fn calls(&self) -> Vec<MockCall> {
+        lock(&self.calls).clone()
+    }
+
+    /// Textos das mensagens enviadas, em ordem
+    pub fn sent_messages(&self) -> Vec<String> {
+        self.calls()
+            .into_iter()
+            .filter_map(|call| match call {
+                MockCall::SendMessage { text, .. } => Some(text),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Textos das anotações adicionadas, em ordem
+    pub fn annotations(&self) -> Vec<String> {
+        self.calls()
+            .into_iter()
+            .filter_map(|call| match call {
+                MockCall::AddAnnotation { text, .. } => Some(text),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Esquece as chamadas registradas e as respostas programadas
+    pub fn reset(&self) {
+        lock(&self.calls).clear();
+        lock(&self.errors).clear();
+        lock(&self.media).clear();
+        lock(&self.chat_statuses).clear();
+        lock(&self.raw_responses).clear();
+        lock(&self.idempotency_keys).clear();
+        lock(&self.queued).clear();
+    }
+
+    fn record(&self, call: MockCall) -> Result<()> {
+        lock(&self.calls).push(call);
+        match lock(&self.errors).pop_front() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// ID sequencial para mensagens e itens da outbox (`mock-1`, `mock-2`, ...)
+    fn next_id(&self) -> String {
+        format!("mock-{}", self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn sent(&self) -> SentMessage {
+        SentMessage {
+            message_id: Some(self.next_id()),
+            ..SentMessage::default()
+        }
+    }
+
+    /// Reserva a chave de idempotência, retornando `false` se ela já foi usada
+    fn claim_key(&self, idempotency_key: &str) -> bool {
+        lock(&self.idempotency_keys).insert(idempotency_key.to_string())
+    }
+
+    fn release_key(&self, idempotency_key: &str) {
+        lock(&self.idempotency_keys).remove(idempotency_key);
+    }
+
+    fn queue(&self, call: MockCall) -> Result<String> {
+        self.record(call)?;
+        let id = self.next_id();
+        lock(&self.queued).push(id.clone());
+        Ok(id)
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl ChatGuruApi for MockChatGuruClient {
+    fn add_annotation<'a>(
+        &'a self,
+        chat_id: &'a str,
+        phone_number: PhoneNumber,
+        annotation_text: &'a str,
+    ) -> BoxFuture<'a, Result<()>> {
+        let result = self.record(MockCall::AddAnnotation {
+            chat_id: chat_id.to_string(),
+            phone: phone_number.digits().to_string(),
+            text: annotation_text.to_string(),
+        });
+        Box::pin(async move { result })
+    }
+
+    fn send_confirmation_message<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        message: &'a str,
+    ) -> BoxFuture<'a, Result<()>> {
+        let result = self.record(MockCall::SendMessage {
+            phone: phone_number.digits().to_string(),
+            phone_id: phone_id.map(String::from),
+            text: message.to_string(),
+        });
+        Box::pin(async move { result })
+    }
+
+    fn send_media_message<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        media: MediaAttachment,
+    ) -> BoxFuture<'a, Result<()>> {
+        let result = self.record(MockCall::SendMedia {
+            phone: phone_number.digits().to_string(),
+            phone_id: phone_id.map(String::from),
+            media,
+        });
+        Box::pin(async move { result })
+    }
+
+    fn download_media<'a>(
+        &'a self,
+        payload: &'a WebhookPayload,
+    ) -> BoxFuture<'a, Result<MediaFile>> {
+        let result = self
+            .record(MockCall::DownloadMedia {
+                url: payload.get_media_url(),
+            })
+            .and_then(|()| {
+                lock(&self.media).pop_front().ok_or_else(|| {
+                    ChatGuruError::ValidationError("Mock has no media queued".to_string())
+                })
+            });
+        Box::pin(async move { result })
+    }
+
+    fn send_message<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        text: &'a str,
+    ) -> BoxFuture<'a, Result<SentMessage>> {
+        let result = self
+            .record(MockCall::SendMessage {
+                phone: phone_number.digits().to_string(),
+                phone_id: phone_id.map(String::from),
+                text: text.to_string(),
+            })
+            .map(|()| self.sent());
+        Box::pin(async move { result })
+    }
+
+    fn send_reply<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        text: &'a str,
+        reply_to_message_id: &'a str,
+    ) -> BoxFuture<'a, Result<()>> {
+        let result = self.record(MockCall::SendReply {
+            phone: phone_number.digits().to_string(),
+            text: text.to_string(),
+            reply_to: reply_to_message_id.to_string(),
+        });
+        Box::pin(async move { result })
+    }
+
+    fn send_or_register<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        name: &'a str,
+        message: &'a str,
+    ) -> BoxFuture<'a, Result<RegisteredSend>> {
+        let result = self
+            .record(MockCall::SendOrRegister {
+                phone: phone_number.digits().to_string(),
+                name: name.to_string(),
+                text: message.to_string(),
+            })
+            .map(|()| RegisteredSend {
+                sent: self.sent(),
+                registered: false,
+            });
+        Box::pin(async move { result })
+    }
+
+    fn send_bulk(
+        &self,
+        messages: Vec<OutgoingMessage>,
+        _concurrency: usize,
+    ) -> BoxFuture<'_, Vec<BulkSendResult>> {
+        let results = messages
+            .into_iter()
+            .map(|message| {
+                let result = self.record(MockCall::SendMessage {
+                    phone: message.phone.digits().to_string(),
+                    phone_id: message.phone_id.clone(),
+                    text: message.text.clone(),
+                });
+                BulkSendResult { message, result }
+            })
+            .collect();
+        Box::pin(async move { results })
+    }
+
+    fn send_confirmation_message_idempotent<'a>(
+        &'a self,
+        idempotency_key: &'a str,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        message: &'a str,
+    ) -> BoxFuture<'a, Result<bool>> {
+        Box::pin(async move {
+            if !self.claim_key(idempotency_key) {
+                return Ok(false);
+            }
+            match self
+                .send_confirmation_message(phone_number, phone_id, message)
+                .await
+            {
+                Ok(()) => Ok(true),
+                Err(e) => {
+                    self.release_key(idempotency_key);
+                    Err(e)
+                }
+            }
+        })
+    }
+
+    fn add_annotation_idempotent<'a>(
+        &'a self,
+        idempotency_key: &'a str,
+        chat_id: &'a str,
+        phone_number: PhoneNumber,
+        annotation_text: &'a str,
+    ) -> BoxFuture<'a, Result<bool>> {
+        Box::pin(async move {
+            if !self.claim_key(idempotency_key) {
+                return Ok(false);
+            }
+            match self
+                .add_annotation(chat_id, phone_number, annotation_text)
+                .await
+            {
+                Ok(()) => Ok(true),
+                Err(e) => {
+                    self.release_key(idempotency_key);
+                    Err(e)
+                }
+            }
+        })
+    }
+
+    fn queue_message<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        phone_id: Option<&'a str>,
+        text: &'a str,
+    ) -> BoxFuture<'a, Result<String>> {
+        let result = self.queue(MockCall::QueueMessage {
+            phone: phone_number.digits().to_string(),
+            phone_id: phone_id.map(String::from),
+            text: text.to_string(),
+        });
+        Box::pin(async move { result })
+    }
+
+    fn queue_annotation<'a>(
+        &'a self,
+        chat_id: &'a str,
+        phone_number: PhoneNumber,
+        text: &'a str,
+    ) -> BoxFuture<'a, Result<String>> {
+        let result = self.queue(MockCall::QueueAnnotation {
+            chat_id: chat_id.to_string(),
+            phone: phone_number.digits().to_string(),
+            text: text.to_string(),
+        });
+        Box::pin(async move { result })
+    }
+
+    fn flush(&self) -> BoxFuture<'_, Result<FlushReport>> {
+        let result = self.record(MockCall::Flush).map(|()| FlushReport {
+            sent: std::mem::take(&mut *lock(&self.queued)),
+            failed: Vec::new(),
+        });
+        Box::pin(async move { result })
+    }
+
+    fn get_chat_status(&self, phone_number: PhoneNumber) -> BoxFuture<'_, Result<ChatStatus>> {
+        let result = self
+            .record(MockCall::GetChatStatus {
+                phone: phone_number.digits().to_string(),
+            })
+            .map(|()| {
+                lock(&self.chat_statuses)
+                    .pop_front()
+                    .unwrap_or_else(ChatStatus::not_found)
+            });
+        Box::pin(async move { result })
+    }
+
+    fn assign_chat<'a>(
+        &'a self,
+        phone_number: PhoneNumber,
+        user_email_or_id: &'a str,
+    ) -> BoxFuture<'a, Result<()>> {
+        let result = self.record(MockCall::AssignChat {
+            phone: phone_number.digits().to_string(),
+            user: user_email_or_id.to_string(),
+        });
+        Box::pin(async move { result })
+    }
+
+    fn execute_raw<'a>(
+        &'a self,
+        action: &'a str,
+        params: &'a [(&'a str, &'a str)],
+    ) -> BoxFuture<'a, Result<String>> {
+        let result = self
+            .record(MockCall::ExecuteRaw {
+                action: action.to_string(),
+                params: params
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect(),
+            })
+            .map(|()| lock(&self.raw_responses).pop_front().unwrap_or_default());
+        Box::pin(async move { result })
+    }
+}
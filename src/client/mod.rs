@@ -0,0 +1,634 @@
+mod accounts;
+mod annotate;
+mod api;
+mod builder;
+mod bulk;
+mod cache;
+#[cfg(feature = "record")]
+mod cassette;
+mod chat;
+mod chat_list;
+mod chunking;
+mod circuit_breaker;
+mod consent;
+mod contact;
+mod content_filter;
+mod credentials;
+mod delivery;
+mod encoding;
+mod endpoint;
+mod funnel;
+mod health;
+mod idempotency;
+mod interactive;
+mod length;
+#[cfg(feature = "i18n")]
+mod localized;
+mod media;
+mod messages;
+mod metrics;
+mod middleware;
+#[cfg(feature = "test-util")]
+mod mock;
+mod options;
+mod outbox;
+mod policy;
+mod rate_limit;
+mod register;
+mod reply;
+mod request;
+pub(crate) mod response;
+pub(crate) mod retry;
+mod routing;
+mod shutdown;
+
+pub use accounts::AccountManager;
+pub use annotate::AnnotatedSend;
+pub use api::ChatGuruApi;
+pub use bulk::{BulkSendResult, OutgoingMessage};
+#[cfg(feature = "record")]
+pub use cassette::{Cassette, CassetteMode, RecordedInteraction};
+pub use chat::ChatStatus;
+pub use chat_list::{ChatFilter, ChatSummary, DEFAULT_CHAT_PAGE_SIZE};
+pub use chunking::{split_message, DEFAULT_MAX_MESSAGE_LENGTH};
+pub use circuit_breaker::{CircuitBreaker, CircuitState};
+pub use consent::{ConsentRegistry, InMemoryConsentRegistry};
+pub use contact::Contact;
+pub use content_filter::{ContentFilter, FilterDecision, NoopContentFilter, RegexContentFilter};
+pub use credentials::{
+    CachedCredentials, CredentialsProvider, StaticCredentials, DEFAULT_CREDENTIALS_CACHE_TTL,
+};
+#[cfg(feature = "gcp-secret-manager")]
+pub use credentials::{GcpSecretManagerCredentials, SECRET_MANAGER_API_URL};
+#[cfg(feature = "vault")]
+pub use credentials::{VaultCredentials, ENV_VAULT_ADDR, ENV_VAULT_NAMESPACE, ENV_VAULT_TOKEN};
+pub use delivery::{DeliveryReceipt, DeliveryTracker, SentMessage, DEFAULT_TRACKED_MESSAGES};
+pub use encoding::{decode_component, decode_params, encode_component, encode_params};
+pub use endpoint::{ApiEndpoint, ApiVersion};
+pub use funnel::{Funnel, FunnelStage};
+pub use health::HealthStatus;
+pub use idempotency::DEFAULT_IDEMPOTENCY_TTL;
+pub use interactive::{
+    InteractiveAction, InteractiveMessage, InteractiveMessageBuilder, ListRow, ListSection,
+    ReplyButton, MAX_BUTTONS, MAX_BUTTON_TITLE_LENGTH, MAX_HEADER_LENGTH,
+    MAX_INTERACTIVE_BODY_LENGTH, MAX_LIST_ROWS, MAX_ROW_DESCRIPTION_LENGTH, MAX_ROW_TITLE_LENGTH,
+};
+pub use length::{SendWarning, DEFAULT_MAX_NOTE_LENGTH};
+pub use messages::{ChatMessage, ChatRef, MessageDirection};
+pub use metrics::{ActionStats, InMemoryMetrics, MetricsSink, RequestMetrics};
+pub use middleware::{ActionRequest, Middleware, Next};
+#[cfg(feature = "test-util")]
+pub use mock::{MockCall, MockChatGuruClient};
+pub use options::RequestOptions;
+#[cfg(feature = "redis")]
+pub use outbox::RedisOutboxStore;
+#[cfg(feature = "sqlite")]
+pub use outbox::SqliteOutboxStore;
+pub use outbox::{
+    AnnotationBuffer, DeliveryStatus, FileOutboxStore, FlushReport, InMemoryOutboxStore,
+    OutboxKind, OutboxMessage, OutboxStore, OutboxWorker,
+};
+pub use policy::{
+    PolicyDecision, PolicyViolation, SendPolicy, ViolationAction, DEFAULT_POLICY_OFFSET_SECS,
+};
+pub use rate_limit::{RateLimit, RateLimiter};
+pub use register::{RegisteredSend, DEFAULT_CHAT_REGISTER_TIMEOUT};
+pub use request::{RequestMode, REQUEST_ID_HEADER};
+pub use retry::{RetryOutcome, RetryPolicy};
+pub use routing::{LineSelection, PhoneLineRouter, DEFAULT_LINE_COOL_DOWN};
+pub use shutdown::{Shutdown, ShutdownReport};
+
+pub use builder::{
+    ChatGuruClientBuilder, DEFAULT_API_ENDPOINT, DEFAULT_API_VERSION, DEFAULT_CONNECT_TIMEOUT,
+    DEFAULT_PHONE_ID, DEFAULT_TIMEOUT, ENV_ACCOUNT_ID, ENV_API_ENDPOINT, ENV_API_TOKEN,
+    ENV_PHONE_ID,
+};
+
+use crate::error::{ChatGuruError, Result};
+use crate::templates::{MessageTemplate, TemplateContext};
+use crate::types::PhoneNumber;
+use crate::webhook::DedupStore;
+use cache::ResponseCache;
+use request::ChatGuruRequest;
+use reqwest::Client;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// Cliente HTTP para a API do ChatGuru
+///
+/// Fornece métodos para adicionar anotações e enviar mensagens de confirmação
+/// via WhatsApp através da API do ChatGuru.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::ChatGuruClient;
+///
+/// let client = ChatGuruClient::new(
+///     "api_token".to_string(),
+///     "https://api.chatguru.app/api/v1".to_string(),
+///     "account_id".to_string()
+/// );
+///
+/// client.add_annotation(
+///     "chat_123",
+///     "5511999999999",
+///     "Tarefa criada no ClickUp"
+/// ).await?;
+/// ```
+#[derive(Clone)]
+pub struct ChatGuruClient {
+    client: Client,
+    credentials: Arc<dyn CredentialsProvider>,
+    endpoint: ApiEndpoint,
+    account_id: String,
+    default_phone_id: String,
+    request_mode: RequestMode,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    metrics: Option<Arc<dyn MetricsSink>>,
+    max_media_size: usize,
+    max_message_length: usize,
+    max_note_length: usize,
+    number_chunks: bool,
+    outbox: Arc<dyn OutboxStore>,
+    outbox_lock: Arc<Mutex<()>>,
+    idempotency_store: Arc<dyn DedupStore>,
+    idempotency_ttl: Duration,
+    shutdown: Shutdown,
+    response_cache: Option<Arc<ResponseCache>>,
+    dry_run: bool,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    line_router: Option<Arc<PhoneLineRouter>>,
+    send_policy: Option<Arc<SendPolicy>>,
+    consent_registry: Option<Arc<dyn ConsentRegistry>>,
+    content_filter: Option<Arc<dyn ContentFilter>>,
+    annotate_sends: bool,
+    #[cfg(feature = "i18n")]
+    localizer: Option<Arc<crate::i18n::Localizer>>,
+    #[cfg(feature = "record")]
+    cassette: Option<Arc<Cassette>>,
+}
+
+impl fmt::Debug for ChatGuruClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChatGuruClient")
+            .field("endpoint", &self.endpoint)
+            .field("account_id", &self.account_id)
+            .field("default_phone_id", &self.default_phone_id)
+            .field("request_mode", &self.request_mode)
+            .field("retry_policy", &self.retry_policy)
+            .field("max_media_size", &self.max_media_size)
+            .field("max_message_length", &self.max_message_length)
+            .field("max_note_length", &self.max_note_length)
+            .field("dry_run", &self.dry_run)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ChatGuruClient {
+    /// Cria uma nova instância do cliente ChatGuru
+    ///
+    /// # Parâmetros
+    ///
+    /// * `api_token` - Token de autenticação da API ChatGuru
+    /// * `api_endpoint` - URL base da API (ex: `https://api.chatguru.app/api/v1`)
+    /// * `account_id` - ID da conta ChatGuru
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let client = ChatGuruClient::new(
+    ///     std::env::var("CHATGURU_API_TOKEN")?,
+    ///     "https://api.chatguru.app/api/v1".to_string(),
+    ///     std::env::var("CHATGURU_ACCOUNT_ID")?
+    /// );
+    /// ```
+    pub fn new(api_token: String, api_endpoint: String, account_id: String) -> Self {
+        ChatGuruClientBuilder::new()
+            .api_token(api_token)
+            .api_endpoint(api_endpoint)
+            .account_id(account_id)
+            .build_lenient()
+    }
+
+    /// Cria o cliente e confirma as credenciais com uma chamada leve à API
+    ///
+    /// Use na inicialização do serviço para descobrir um token ou
+    /// `account_id` errado no deploy, e não na primeira mensagem enviada.
+    /// Veja [`validate_credentials`](Self::validate_credentials).
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - parâmetros vazios
+    /// * `Unauthorized` - token ou conta recusados pela API
+    /// * `NetworkError` ou `ApiError` - a API não pôde confirmar as credenciais
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let client = ChatGuruClient::new_validated(
+    ///     std::env::var("CHATGURU_API_TOKEN")?,
+    ///     "https://api.chatguru.app/api/v1".to_string(),
+    ///     std::env::var("CHATGURU_ACCOUNT_ID")?,
+    /// )
+    /// .await?;
+    /// ```
+    pub async fn new_validated(
+        api_token: String,
+        api_endpoint: String,
+        account_id: String,
+    ) -> Result<Self> {
+        let client = ChatGuruClientBuilder::new()
+            .api_token(api_token)
+            .api_endpoint(api_endpoint)
+            .account_id(account_id)
+            .build()?;
+        client.validate_credentials().await?;
+        Ok(client)
+    }
+
+    /// Cria um builder para configurar o cliente
+    ///
+    /// Use quando precisar de phone_id padrão, timeouts, user agent ou versão
+    /// da API diferentes dos valores padrão.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let client = ChatGuruClient::builder()
+    ///     .api_token(token)
+    ///     .api_endpoint("https://api.chatguru.app")
+    ///     .account_id(account_id)
+    ///     .default_phone_id(phone_id)
+    ///     .build()?;
+    /// ```
+    pub fn builder() -> ChatGuruClientBuilder {
+        ChatGuruClientBuilder::new()
+    }
+
+    /// Cria o cliente a partir das variáveis de ambiente
+    ///
+    /// Variáveis lidas:
+    ///
+    /// * `CHATGURU_API_TOKEN` - Token da API (obrigatória)
+    /// * `CHATGURU_ACCOUNT_ID` - ID da conta (obrigatória)
+    /// * `CHATGURU_API_ENDPOINT` - URL base (padrão: `https://api.chatguru.app/api/v1`)
+    /// * `CHATGURU_PHONE_ID` - phone_id padrão (opcional)
+    ///
+    /// # Panics
+    ///
+    /// Entra em pânico se alguma variável obrigatória estiver ausente.
+    /// Use [`ChatGuruClient::try_from_env`] para tratar o erro.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let client = ChatGuruClient::from_env();
+    /// ```
+    pub fn from_env() -> Self {
+        match Self::try_from_env() {
+            Ok(client) => client,
+            Err(e) => panic!(
+                "Failed to configure ChatGuru client from environment: {}",
+                e
+            ),
+        }
+    }
+
+    /// Cria o cliente a partir das variáveis de ambiente, retornando erro
+    ///
+    /// Mesmas variáveis de [`ChatGuruClient::from_env`].
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` com os nomes das variáveis ausentes.
+    pub fn try_from_env() -> Result<Self> {
+        ChatGuruClientBuilder::from_env()?.build()
+    }
+
+    /// Retorna o phone_id usado quando nenhum é informado na chamada
+    pub fn default_phone_id(&self) -> &str {
+        &self.default_phone_id
+    }
+
+    /// Retorna o ID da conta ChatGuru do cliente
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// Indica se o cliente está em modo dry run (ver [`ChatGuruClientBuilder::dry_run`])
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Cópia do cliente com outro phone_id padrão, compartilhando o restante da configuração
+    pub(crate) fn with_default_phone_id(&self, phone_id: impl Into<String>) -> Self {
+        Self {
+            default_phone_id: phone_id.into(),
+            ..self.clone()
+        }
+    }
+
+    /// Estado do circuit breaker, se configurado
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.circuit_breaker.as_ref().map(|breaker| breaker.state())
+    }
+
+    /// Endpoint da API usado pelo cliente
+    pub fn endpoint(&self) -> &ApiEndpoint {
+        &self.endpoint
+    }
+
+    /// Descarta as respostas guardadas pelo cache de respostas, se configurado
+    ///
+    /// Veja [`ChatGuruClientBuilder::response_cache`].
+    pub fn clear_response_cache(&self) {
+        if let Some(ref cache) = self.response_cache {
+            cache.clear();
+        }
+    }
+
+    /// Adiciona uma anotação ao chat no ChatGuru
+    ///
+    /// Usa a API do ChatGuru para adicionar uma nota/anotação visível no chat.
+    ///
+    /// # Parâmetros
+    ///
+    /// * `chat_id` - ID do chat onde adicionar a anotação
+    /// * `phone_number` - Número de telefone do contato (ex: `"5511999999999"` ou [`PhoneNumber`])
+    /// * `annotation_text` - Texto da anotação a ser adicionada
+    ///
+    /// # Retorno
+    ///
+    /// Retorna `Ok(())` se a anotação foi adicionada com sucesso, ou um erro caso contrário.
+    /// Nota: Erros de "chat não encontrado" são logados como warning mas não falham o processo.
+    /// Números de telefone inválidos retornam `ValidationError` sem chamar a API.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// client.add_annotation(
+    ///     "chat_abc123",
+    ///     "5511999999999",
+    ///     "Tarefa criada no ClickUp: TASK-456"
+    /// ).await?;
+    /// ```
+    pub async fn add_annotation(
+        &self,
+        chat_id: &str,
+        phone_number: impl Into<PhoneNumber>,
+        annotation_text: &str,
+    ) -> Result<()> {
+        self.annotate(
+            chat_id,
+            phone_number.into(),
+            annotation_text,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// [`add_annotation`](Self::add_annotation) com os ajustes de `options`
+    async fn annotate(
+        &self,
+        chat_id: &str,
+        phone_number: PhoneNumber,
+        annotation_text: &str,
+        options: &RequestOptions,
+    ) -> Result<()> {
+        // Validar número de telefone
+        phone_number.validate()?;
+        let clean_phone = phone_number.digits();
+
+        tracing::debug!("Adding annotation to chat {}: {}", chat_id, annotation_text);
+
+        // Fazer a requisição POST
+        let request = ChatGuruRequest::new("note_add")
+            .param("note_text", annotation_text)
+            .param("chat_number", clean_phone)
+            .options(options);
+        let response = self.post_action(&request).await?;
+
+        match request::read_response("note_add", response).await {
+            Ok(response_text) => {
+                tracing::debug!(
+                    "Annotation added successfully to chat {}: {}",
+                    chat_id,
+                    response_text
+                );
+
+                // Logar como o legado
+                tracing::info!("Mensagem enviada com sucesso: {}", annotation_text);
+            }
+            // Com chave de idempotência, a falha libera a chave para nova tentativa
+            Err(e) if options.idempotency_key.is_some() => return Err(e),
+            // Apenas logar warning se for erro de chat não encontrado
+            Err(e) if e.is_chat_not_found() => {
+                tracing::warn!(
+                    "Chat not found for annotation (phone: {}). This is normal for inactive chats.",
+                    phone_number.masked()
+                );
+            }
+            Err(e) => {
+                tracing::error!("Failed to add annotation: {}", e);
+            }
+        }
+
+        // Não falhar o processo se a anotação falhar
+        Ok(())
+    }
+
+    /// Envia uma mensagem de confirmação via WhatsApp
+    ///
+    /// Usa a API do ChatGuru para enviar mensagem direta ao usuário.
+    ///
+    /// **NOTA**: Só funciona se já existe um chat ativo com o número.
+    ///
+    /// Mensagens maiores que o limite do builder
+    /// ([`max_message_length`](ChatGuruClientBuilder::max_message_length), padrão
+    /// 4096 caracteres) são divididas e enviadas em partes, na ordem.
+    ///
+    /// # Parâmetros
+    ///
+    /// * `phone_number` - Número de telefone do destinatário (ex: `"5511999999999"` ou [`PhoneNumber`])
+    /// * `phone_id` - ID do telefone ChatGuru (opcional, usa o phone_id padrão do cliente se None)
+    /// * `message` - Texto da mensagem a ser enviada
+    ///
+    /// # Retorno
+    ///
+    /// Retorna `Ok(())` se a mensagem foi enviada com sucesso, ou um erro caso contrário.
+    /// Nota: Erros de "chat não existe" são logados como warning mas não falham o processo.
+    /// Números de telefone inválidos retornam `ValidationError` sem chamar a API.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// client.send_confirmation_message(
+    ///     "5511999999999",
+    ///     None,
+    ///     "✅ Sua solicitação foi registrada com sucesso!"
+    /// ).await?;
+    /// ```
+    pub async fn send_confirmation_message(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        phone_id: Option<&str>,
+        message: &str,
+    ) -> Result<()> {
+        let mut options = RequestOptions::default();
+        if let Some(phone_id) = phone_id {
+            options = options.phone_id(phone_id);
+        }
+        self.send_confirmation(phone_number.into(), message, &options)
+            .await
+    }
+
+    /// [`send_confirmation_message`](Self::send_confirmation_message) com os ajustes de `options`
+    async fn send_confirmation(
+        &self,
+        phone_number: PhoneNumber,
+        message: &str,
+        options: &RequestOptions,
+    ) -> Result<()> {
+        // Validar número de telefone
+        phone_number.validate()?;
+
+        // Sem linha explícita, o roteador de linhas (se houver) escolhe
+        let routed;
+        let options = match (&options.phone_id, &self.line_router) {
+            (None, Some(router)) => {
+                match router.route(&phone_number, options.campaign.as_deref()) {
+                    Some(phone_id) => {
+                        routed = options.clone().phone_id(phone_id);
+                        &routed
+                    }
+                    None => options,
+                }
+            }
+            _ => options,
+        };
+
+        tracing::debug!(
+            "Sending confirmation message to {}: {}",
+            phone_number.masked(),
+            message
+        );
+
+        // Mensagens acima do limite são enviadas em partes, na ordem
+        for chunk in self.message_chunks(message) {
+            self.send_confirmation_chunk(&phone_number, &chunk, options)
+                .await?;
+        }
+
+        // Não falhar o processo se o envio falhar
+        Ok(())
+    }
+
+    /// Envia uma parte de [`send_confirmation_message`](Self::send_confirmation_message),
+    /// apenas logando falhas da API
+    async fn send_confirmation_chunk(
+        &self,
+        phone_number: &PhoneNumber,
+        chunk: &str,
+        options: &RequestOptions,
+    ) -> Result<()> {
+        // Enviar mensagem imediatamente (sem agendamento)
+        // Removido send_date para envio imediato
+        let request = ChatGuruRequest::new("message_send")
+            .param("text", chunk)
+            .param("chat_number", phone_number.digits())
+            .options(options);
+        let response = match self.post_action(&request).await {
+            Ok(response) => response,
+            Err(e) => {
+                self.report_line_failure(options, &e);
+                return Err(e);
+            }
+        };
+
+        match request::read_response("message_send", response).await {
+            Ok(response_text) => {
+                tracing::debug!(
+                    "Confirmation message sent successfully to {}: {}",
+                    phone_number.masked(),
+                    response_text
+                );
+
+                // Logar como o legado
+                tracing::info!("Mensagem enviada com sucesso: {}", chunk);
+            }
+            // Com chave de idempotência, a falha libera a chave para nova tentativa
+            Err(e) if options.idempotency_key.is_some() => {
+                self.report_line_failure(options, &e);
+                return Err(e);
+            }
+            // Apenas logar warning se for erro de chat não encontrado
+            Err(e) if e.is_chat_not_found() => {
+                tracing::warn!(
+                    "Chat not found for message (phone: {}). This is normal - user may not have active chat.",
+                    phone_number.masked()
+                );
+            }
+            Err(e) => {
+                tracing::error!("Failed to send confirmation message: {}", e);
+                self.report_line_failure(options, &e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Retira a linha do envio da rotação do roteador após uma falha transitória
+    fn report_line_failure(&self, options: &RequestOptions, error: &ChatGuruError) {
+        if let Some(ref router) = self.line_router {
+            if error.is_retryable() {
+                router.mark_down(
+                    options
+                        .phone_id
+                        .as_deref()
+                        .unwrap_or(&self.default_phone_id),
+                );
+            }
+        }
+    }
+
+    /// Renderiza um template e envia o resultado como mensagem
+    ///
+    /// Usa a variante do idioma definido no contexto (se houver) e envia com
+    /// o phone_id padrão, como [`send_confirmation_message`](Self::send_confirmation_message).
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se faltar valor para algum placeholder.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// use chatguru::templates::{MessageTemplate, TemplateContext};
+    ///
+    /// let template = MessageTemplate::named("task_created", "✅ {nome}, tarefa {task_id} criada!");
+    /// let context = TemplateContext::new()
+    ///     .with("nome", "João")
+    ///     .with("task_id", "TASK-456");
+    ///
+    /// client.send_templated("5511999999999", &template, &context).await?;
+    /// ```
+    pub async fn send_templated(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        template: &MessageTemplate,
+        context: &TemplateContext,
+    ) -> Result<()> {
+        let message = template.render(context)?;
+        let mut options = RequestOptions::default();
+        if let Some(name) = template.name() {
+            options = options.template(name);
+        }
+        self.send_confirmation(phone_number.into(), &message, &options)
+            .await
+    }
+}
@@ -0,0 +1,204 @@
+use super::annotate::SendNote;
+use super::request::{self, ChatGuruRequest};
+use super::{ChatGuruClient, RetryPolicy};
+use crate::error::Result;
+use crate::types::PhoneNumber;
+use std::time::Duration;
+
+/// Ajustes de uma única chamada, sem criar outro cliente
+///
+/// Os campos não definidos usam a configuração do cliente. Aceito pelos
+/// métodos `*_with_options`.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::client::RequestOptions;
+///
+/// let options = RequestOptions::new()
+///     .phone_id("linha_vendas")
+///     .timeout(Duration::from_secs(60))
+///     .idempotency_key(format!("boas-vindas:{}", chat_id));
+///
+/// client
+///     .send_confirmation_message_with_options("5511999999999", "Bem-vindo!", &options)
+///     .await?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    pub(super) phone_id: Option<String>,
+    pub(super) account_id: Option<String>,
+    pub(super) timeout: Option<Duration>,
+    pub(super) retry_policy: Option<RetryPolicy>,
+    pub(super) idempotency_key: Option<String>,
+    pub(super) campaign: Option<String>,
+    pub(super) template: Option<String>,
+    pub(super) correlation_id: Option<String>,
+}
+
+impl RequestOptions {
+    /// Opções vazias (tudo como configurado no cliente)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Envia pela linha informada em vez da linha padrão do cliente
+    pub fn phone_id(mut self, phone_id: impl Into<String>) -> Self {
+        self.phone_id = Some(phone_id.into());
+        self
+    }
+
+    /// Usa outra conta com o mesmo token (ex: subcontas de uma revenda)
+    pub fn account_id(mut self, account_id: impl Into<String>) -> Self {
+        self.account_id = Some(account_id.into());
+        self
+    }
+
+    /// Timeout de cada tentativa desta chamada
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Política de retry desta chamada
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Campanha da mensagem, usada pelo [`PhoneLineRouter`](super::PhoneLineRouter)
+    /// para escolher a linha quando `phone_id` não é informado
+    pub fn campaign(mut self, campaign: impl Into<String>) -> Self {
+        self.campaign = Some(campaign.into());
+        self
+    }
+
+    /// Template da mensagem, registrado na anotação automática do envio (ver
+    /// [`annotate_sends`](super::ChatGuruClientBuilder::annotate_sends))
+    pub fn template(mut self, template: impl Into<String>) -> Self {
+        self.template = Some(template.into());
+        self
+    }
+
+    /// ID de correlação registrado na anotação automática do envio (padrão:
+    /// o ID da chamada)
+    pub fn correlation_id(mut self, correlation_id: impl Into<String>) -> Self {
+        self.correlation_id = Some(correlation_id.into());
+        self
+    }
+
+    /// Faz a chamada no máximo uma vez por chave (ver
+    /// [`send_confirmation_message_idempotent`](ChatGuruClient::send_confirmation_message_idempotent))
+    ///
+    /// Com chave, falhas da API (inclusive chat não encontrado) retornam erro
+    /// em vez de só serem logadas, liberando a chave para uma nova tentativa.
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+}
+
+impl ChatGuruClient {
+    /// Como [`send_confirmation_message`](Self::send_confirmation_message), com
+    /// ajustes para esta chamada
+    ///
+    /// Retorna `Ok(false)` se a chamada foi ignorada porque a chave de
+    /// idempotência já tinha sido usada, e `Ok(true)` nos demais casos.
+    ///
+    /// # Erros
+    ///
+    /// Os de [`send_confirmation_message`](Self::send_confirmation_message).
+    pub async fn send_confirmation_message_with_options(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        message: &str,
+        options: &RequestOptions,
+    ) -> Result<bool> {
+        let phone_number = phone_number.into();
+        self.with_options(
+            options,
+            self.send_confirmation(phone_number, message, options),
+        )
+        .await
+    }
+
+    /// Como [`add_annotation`](Self::add_annotation), com ajustes para esta chamada
+    ///
+    /// Retorna `Ok(false)` se a chamada foi ignorada porque a chave de
+    /// idempotência já tinha sido usada, e `Ok(true)` nos demais casos.
+    ///
+    /// # Erros
+    ///
+    /// Os de [`add_annotation`](Self::add_annotation).
+    pub async fn add_annotation_with_options(
+        &self,
+        chat_id: &str,
+        phone_number: impl Into<PhoneNumber>,
+        annotation_text: &str,
+        options: &RequestOptions,
+    ) -> Result<bool> {
+        let phone_number = phone_number.into();
+        self.with_options(
+            options,
+            self.annotate(chat_id, phone_number, annotation_text, options),
+        )
+        .await
+    }
+
+    /// Como [`execute_raw`](Self::execute_raw), com ajustes para esta chamada
+    ///
+    /// Com chave de idempotência, uma chamada repetida retorna `Ok(None)`.
+    ///
+    /// # Erros
+    ///
+    /// Os de [`execute_raw`](Self::execute_raw).
+    pub async fn execute_raw_with_options(
+        &self,
+        action: &str,
+        params: &[(&str, &str)],
+        options: &RequestOptions,
+    ) -> Result<Option<String>> {
+        let request = request::raw_request(action, params)?.options(options);
+
+        let Some(ref key) = options.idempotency_key else {
+            return self.execute(&request).await.map(Some);
+        };
+
+        let account_id = options.account_id.as_deref().unwrap_or(&self.account_id);
+        let mut body = None;
+        self.with_idempotency(account_id, key, async {
+            body = Some(self.execute(&request).await?);
+            Ok(())
+        })
+        .await?;
+        Ok(body)
+    }
+
+    /// Executa `operation`, respeitando a chave de idempotência das opções
+    async fn with_options<F>(&self, options: &RequestOptions, operation: F) -> Result<bool>
+    where
+        F: std::future::Future<Output = Result<()>>,
+    {
+        let account_id = options.account_id.as_deref().unwrap_or(&self.account_id);
+        match options.idempotency_key {
+            Some(ref key) => self.with_idempotency(account_id, key, operation).await,
+            None => operation.await.map(|()| true),
+        }
+    }
+}
+
+impl ChatGuruRequest {
+    /// Aplica os ajustes de [`RequestOptions`] à chamada
+    pub(crate) fn options(mut self, options: &RequestOptions) -> Self {
+        if let Some(ref phone_id) = options.phone_id {
+            self = self.phone_id(phone_id.clone());
+        }
+        *self.send_note_mut() =
+            SendNote::new(options.template.clone(), options.correlation_id.clone());
+        self.with_overrides(
+            options.account_id.clone(),
+            options.timeout,
+            options.retry_policy.clone(),
+        )
+    }
+}
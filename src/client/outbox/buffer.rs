@@ -0,0 +1,111 @@
+use super::{FlushReport, OutboxKind, OutboxMessage, OutboxWorker};
+use crate::client::ChatGuruClient;
+use crate::error::Result;
+use crate::types::PhoneNumber;
+use chrono::Utc;
+use std::time::Duration;
+
+/// Agrupa anotações do mesmo chat em uma única nota por janela de tempo
+///
+/// Anotações adicionadas com [`add`](Self::add) vão para a outbox do cliente
+/// (veja [`ChatGuruClient::queue_annotation`]), que junta os textos pendentes
+/// de cada chat. A nota combinada só é enviada quando a janela, contada a
+/// partir da primeira anotação do grupo, termina; assim 3–5 anotações em
+/// poucos segundos chegam ao atendente como uma só.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+///
+/// let buffer = client.annotation_buffer(Duration::from_secs(10));
+/// let worker = buffer.start(Duration::from_secs(1));
+///
+/// buffer.add("chat_123", "5511999999999", "Tarefa criada: TASK-456").await?;
+/// buffer.add("chat_123", "5511999999999", "Responsável: Maria").await?;
+///
+/// // No encerramento do serviço (envia as notas restantes)
+/// worker.shutdown().await;
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnnotationBuffer {
+    client: ChatGuruClient,
+    window: Duration,
+}
+
+impl AnnotationBuffer {
+    /// Cria um buffer que agrupa as anotações de cada chat por `window`
+    pub fn new(client: ChatGuruClient, window: Duration) -> Self {
+        Self { client, window }
+    }
+
+    /// Duração da janela de agrupamento
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Adiciona uma anotação ao grupo pendente do chat
+    ///
+    /// Retorna o ID do item da outbox com a nota combinada.
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se o telefone for inválido, ou o erro do
+    /// [`OutboxStore`](crate::client::OutboxStore) configurado.
+    pub async fn add(
+        &self,
+        chat_id: &str,
+        phone_number: impl Into<PhoneNumber>,
+        text: &str,
+    ) -> Result<String> {
+        self.client
+            .queue_annotation(chat_id, phone_number, text)
+            .await
+    }
+
+    /// Envia as notas cujos grupos já completaram a janela
+    pub async fn flush_due(&self) -> Result<FlushReport> {
+        let window = chrono::Duration::from_std(self.window).unwrap_or(chrono::Duration::MAX);
+        let now = Utc::now();
+
+        self.client
+            .flush_matching(|m| is_annotation(m) && now - m.queued_at >= window)
+            .await
+    }
+
+    /// Envia todas as notas pendentes, sem esperar o fim das janelas
+    pub async fn flush_all(&self) -> Result<FlushReport> {
+        self.client.flush_matching(is_annotation).await
+    }
+
+    /// Inicia uma tarefa que chama [`flush_due`](Self::flush_due) a cada `tick`
+    ///
+    /// No [`shutdown`](OutboxWorker::shutdown) as notas restantes são
+    /// enviadas com [`flush_all`](Self::flush_all). Precisa ser chamado
+    /// dentro de um runtime Tokio.
+    pub fn start(&self, tick: Duration) -> OutboxWorker {
+        let buffer = self.clone();
+        let client_shutdown = self.client.shutdown_handle();
+        OutboxWorker::spawn("Annotation buffer", tick, client_shutdown, move |last| {
+            let buffer = buffer.clone();
+            async move {
+                if last {
+                    buffer.flush_all().await
+                } else {
+                    buffer.flush_due().await
+                }
+            }
+        })
+    }
+}
+
+fn is_annotation(message: &OutboxMessage) -> bool {
+    message.kind == OutboxKind::Annotation
+}
+
+impl ChatGuruClient {
+    /// Cria um [`AnnotationBuffer`] sobre a outbox deste cliente
+    pub fn annotation_buffer(&self, window: Duration) -> AnnotationBuffer {
+        AnnotationBuffer::new(self.clone(), window)
+    }
+}
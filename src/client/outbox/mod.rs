@@ -0,0 +1,465 @@
+mod buffer;
+mod store;
+mod worker;
+
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+
+pub use buffer::AnnotationBuffer;
+#[cfg(feature = "redis")]
+pub use redis::RedisOutboxStore;
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteOutboxStore;
+pub use store::{FileOutboxStore, InMemoryOutboxStore, OutboxStore};
+pub use worker::OutboxWorker;
+
+use super::request::ChatGuruRequest;
+use super::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::types::PhoneNumber;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Tipo de item na outbox
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutboxKind {
+    /// Mensagem enviada ao contato (`message_send`)
+    Message,
+    /// Anotação interna no chat (`note_add`)
+    Annotation,
+}
+
+/// Situação de entrega de um item da outbox
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", content = "error", rename_all = "snake_case")]
+pub enum DeliveryStatus {
+    /// Aguardando envio
+    Pending,
+    /// Aceito pela API do ChatGuru
+    Sent,
+    /// Última tentativa falhou (será tentado novamente no próximo flush)
+    Failed(String),
+}
+
+/// Item enfileirado na outbox do cliente
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutboxMessage {
+    /// Identificador do item
+    pub id: String,
+    /// Mensagem ou anotação
+    pub kind: OutboxKind,
+    /// Telefone do contato (dígitos com código do país)
+    pub phone: String,
+    /// Linha usada no envio (None = phone_id padrão do cliente)
+    pub phone_id: Option<String>,
+    /// Chat de destino (anotações)
+    pub chat_id: Option<String>,
+    /// Texto a enviar
+    pub text: String,
+    /// Momento em que o item foi enfileirado
+    pub queued_at: DateTime<Utc>,
+    /// Situação de entrega
+    pub status: DeliveryStatus,
+    /// Momento em que a API aceitou o envio
+    pub sent_at: Option<DateTime<Utc>>,
+    /// Quantidade de tentativas de envio
+    pub attempts: u32,
+    /// Não enviar antes deste momento (envios adiados pela
+    /// [`SendPolicy`](crate::client::SendPolicy) ou por
+    /// [`queue_message_at`](ChatGuruClient::queue_message_at))
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_before: Option<DateTime<Utc>>,
+    /// Item reservado por um flush em andamento até este momento
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claimed_until: Option<DateTime<Utc>>,
+}
+
+impl OutboxMessage {
+    /// Indica se o item ainda precisa ser enviado
+    pub fn is_pending(&self) -> bool {
+        !matches!(self.status, DeliveryStatus::Sent)
+    }
+
+    /// Indica se o item já pode ser enviado em `now`
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.not_before.is_none_or(|not_before| not_before <= now)
+    }
+
+    /// Indica se outro flush está enviando o item em `now`
+    fn is_claimed(&self, now: DateTime<Utc>) -> bool {
+        self.claimed_until.is_some_and(|until| until > now)
+    }
+
+    /// Chave do chat usada para agrupar anotações
+    fn chat_key(&self) -> &str {
+        self.chat_id.as_deref().unwrap_or(&self.phone)
+    }
+}
+
+/// Resultado de [`ChatGuruClient::flush`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlushReport {
+    /// IDs enviados com sucesso
+    pub sent: Vec<String>,
+    /// IDs que falharam, com o erro
+    pub failed: Vec<(String, String)>,
+}
+
+impl FlushReport {
+    /// Indica se todos os itens foram enviados
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Por quanto tempo um flush reserva o item que está enviando
+///
+/// Passado o prazo (ex: o processo caiu durante o envio), outro flush pode
+/// enviar o item.
+const OUTBOX_CLAIM_LEASE: Duration = Duration::from_secs(300);
+
+fn next_outbox_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    format!(
+        "out-{:x}-{:x}",
+        Utc::now().timestamp_millis(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+impl ChatGuruClient {
+    /// Enfileira uma mensagem para envio posterior com [`flush`](Self::flush)
+    ///
+    /// Mensagens idênticas (mesmo telefone e texto) ainda pendentes não são
+    /// duplicadas: o ID do item existente é retornado.
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se o telefone for inválido, ou o erro do
+    /// [`OutboxStore`] configurado.
+    pub async fn queue_message(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        phone_id: Option<&str>,
+        text: &str,
+    ) -> Result<String> {
+        self.enqueue_message(phone_number.into(), phone_id, text, None)
+            .await
+    }
+
+    /// Enfileira uma mensagem que o [`flush`](Self::flush) só envia a partir de `not_before`
+    ///
+    /// Como em [`queue_message`](Self::queue_message), uma mensagem idêntica
+    /// ainda pendente não é duplicada. O envio agendado não passa de novo pela
+    /// [`SendPolicy`](super::SendPolicy).
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se o telefone for inválido, ou o erro do
+    /// [`OutboxStore`] configurado.
+    pub async fn queue_message_at(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        phone_id: Option<&str>,
+        text: &str,
+        not_before: DateTime<Utc>,
+    ) -> Result<String> {
+        self.enqueue_message(phone_number.into(), phone_id, text, Some(not_before))
+            .await
+    }
+
+    async fn enqueue_message(
+        &self,
+        phone: PhoneNumber,
+        phone_id: Option<&str>,
+        text: &str,
+        not_before: Option<DateTime<Utc>>,
+    ) -> Result<String> {
+        phone.validate()?;
+
+        let _guard = self.outbox_lock.lock().await;
+
+        if let Some(existing) = self.outbox.list().await?.into_iter().find(|m| {
+            m.kind == OutboxKind::Message
+                && m.is_pending()
+                && m.phone == phone.digits()
+                && m.text == text
+        }) {
+            return Ok(existing.id);
+        }
+
+        let message = OutboxMessage {
+            id: next_outbox_id(),
+            kind: OutboxKind::Message,
+            phone: phone.digits().to_string(),
+            phone_id: phone_id.map(String::from),
+            chat_id: None,
+            text: text.to_string(),
+            queued_at: Utc::now(),
+            status: DeliveryStatus::Pending,
+            sent_at: None,
+            attempts: 0,
+            not_before,
+            claimed_until: None,
+        };
+        self.outbox.save(&message).await?;
+
+        Ok(message.id)
+    }
+
+    /// Enfileira uma anotação, agrupando-a com as pendentes do mesmo chat
+    ///
+    /// Se já existe uma anotação pendente para o chat, o texto é acrescentado
+    /// a ela (em nova linha) e o ID existente é retornado; textos repetidos
+    /// são ignorados. Assim o atendente recebe uma única nota por flush.
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se o telefone for inválido, ou o erro do
+    /// [`OutboxStore`] configurado.
+    pub async fn queue_annotation(
+        &self,
+        chat_id: &str,
+        phone_number: impl Into<PhoneNumber>,
+        text: &str,
+    ) -> Result<String> {
+        let phone = phone_number.into();
+        phone.validate()?;
+
+        let _guard = self.outbox_lock.lock().await;
+
+        if let Some(mut existing) = self.outbox.list().await?.into_iter().find(|m| {
+            m.kind == OutboxKind::Annotation
+                && matches!(m.status, DeliveryStatus::Pending)
+                && m.chat_key() == chat_id
+        }) {
+            if !existing.text.lines().any(|line| line == text) {
+                existing.text.push('\n');
+                existing.text.push_str(text);
+                self.outbox.save(&existing).await?;
+            }
+            return Ok(existing.id);
+        }
+
+        let message = OutboxMessage {
+            id: next_outbox_id(),
+            kind: OutboxKind::Annotation,
+            phone: phone.digits().to_string(),
+            phone_id: None,
+            chat_id: Some(chat_id.to_string()),
+            text: text.to_string(),
+            queued_at: Utc::now(),
+            status: DeliveryStatus::Pending,
+            sent_at: None,
+            attempts: 0,
+            not_before: None,
+            claimed_until: None,
+        };
+        self.outbox.save(&message).await?;
+
+        Ok(message.id)
+    }
+
+    /// Itens ainda não entregues, em ordem de enfileiramento
+    pub async fn pending(&self) -> Result<Vec<OutboxMessage>> {
+        let mut pending: Vec<_> = self
+            .outbox
+            .list()
+            .await?
+            .into_iter()
+            .filter(OutboxMessage::is_pending)
+            .collect();
+        pending.sort_by_key(|m| m.queued_at);
+        Ok(pending)
+    }
+
+    /// Itens já aceitos pela API, em ordem de envio
+    pub async fn delivered(&self) -> Result<Vec<OutboxMessage>> {
+        let mut delivered: Vec<_> = self
+            .outbox
+            .list()
+            .await?
+            .into_iter()
+            .filter(|m| !m.is_pending())
+            .collect();
+        delivered.sort_by_key(|m| m.sent_at);
+        Ok(delivered)
+    }
+
+    /// Consulta um item da outbox pelo ID
+    pub async fn outbox_entry(&self, id: &str) -> Result<Option<OutboxMessage>> {
+        self.outbox.get(id).await
+    }
+
+    /// Marca um item como enviado (ex: entregue por outro caminho)
+    ///
+    /// Retorna `false` se o ID não existe.
+    pub async fn mark_sent(&self, id: &str) -> Result<bool> {
+        let _guard = self.outbox_lock.lock().await;
+
+        match self.outbox.get(id).await? {
+            Some(mut message) => {
+                message.status = DeliveryStatus::Sent;
+                message.sent_at = Some(Utc::now());
+                self.outbox.save(&message).await?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Remove da outbox os itens já entregues, retornando quantos foram removidos
+    pub async fn clear_delivered(&self) -> Result<usize> {
+        let _guard = self.outbox_lock.lock().await;
+
+        let mut removed = 0;
+        for message in self.outbox.list().await? {
+            if !message.is_pending() && self.outbox.remove(&message.id).await? {
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Envia todos os itens pendentes da outbox
+    ///
+    /// Itens agendados ([`OutboxMessage::not_before`]) ficam para o primeiro
+    /// flush depois do horário. Cada item é enviado individualmente; falhas não interrompem o flush e
+    /// ficam registradas como [`DeliveryStatus::Failed`] para a próxima
+    /// tentativa.
+    ///
+    /// # Erros
+    ///
+    /// Falhas de envio ficam no [`FlushReport`]; apenas erros do
+    /// [`OutboxStore`] são retornados.
+    pub async fn flush(&self) -> Result<FlushReport> {
+        self.flush_matching(|_| true).await
+    }
+
+    /// Como [`flush`](Self::flush), mas envia apenas os itens pendentes aceitos por `filter`
+    ///
+    /// Cada item é reservado antes do envio, então flushes simultâneos (outro
+    /// worker, um `flush()` manual ou outra instância com a mesma outbox)
+    /// não enviam o mesmo item duas vezes.
+    pub(crate) async fn flush_matching<F>(&self, filter: F) -> Result<FlushReport>
+    where
+        F: Fn(&OutboxMessage) -> bool,
+    {
+        let mut report = FlushReport::default();
+        let now = Utc::now();
+        let selected = |m: &OutboxMessage| m.is_due(now) && filter(m);
+
+        for listed in self.pending().await?.into_iter().filter(|m| selected(m)) {
+            let Some(message) = self.claim_outbox_item(&listed.id, &selected).await? else {
+                continue;
+            };
+            let result = self.deliver(&message).await;
+
+            let _guard = self.outbox_lock.lock().await;
+            let entry = self.outbox.get(&message.id).await?;
+            if let Some(mut entry) = entry {
+                entry.attempts += 1;
+                entry.claimed_until = None;
+
+                match result {
+                    // O texto pode ter recebido novas anotações durante o envio
+                    Ok(()) => match entry.text.strip_prefix(message.text.as_str()) {
+                        Some("") => {
+                            entry.status = DeliveryStatus::Sent;
+                            entry.sent_at = Some(Utc::now());
+                            report.sent.push(message.id.clone());
+                        }
+                        rest => {
+                            // O texto enviado já saiu; o restante é um novo
+                            // agrupamento, com janela própria
+                            if let Some(rest) = rest {
+                                entry.text = rest.trim_start_matches('\n').to_string();
+                            }
+                            entry.queued_at = Utc::now();
+                            report.sent.push(message.id.clone());
+                        }
+                    },
+                    Err(e) => {
+                        tracing::warn!("Outbox item {} failed: {}", message.id, e);
+                        entry.status = DeliveryStatus::Failed(e.to_string());
+                        report.failed.push((message.id.clone(), e.to_string()));
+                    }
+                }
+
+                self.outbox.save(&entry).await?;
+            }
+            self.outbox.release(&message.id).await?;
+        }
+
+        if !report.sent.is_empty() || !report.failed.is_empty() {
+            tracing::info!(
+                "Outbox flushed: {} sent, {} failed",
+                report.sent.len(),
+                report.failed.len()
+            );
+        }
+
+        Ok(report)
+    }
+
+    /// Reserva o item para envio, retornando-o como está no armazenamento
+    ///
+    /// Retorna `None` se o item já foi enviado, não é mais aceito por
+    /// `selected` ou está reservado por outro flush.
+    async fn claim_outbox_item<F>(&self, id: &str, selected: F) -> Result<Option<OutboxMessage>>
+    where
+        F: Fn(&OutboxMessage) -> bool,
+    {
+        let _guard = self.outbox_lock.lock().await;
+        let now = Utc::now();
+
+        let Some(mut entry) = self.outbox.get(id).await? else {
+            return Ok(None);
+        };
+        if !entry.is_pending() || entry.is_claimed(now) || !selected(&entry) {
+            return Ok(None);
+        }
+        if !self.outbox.claim(id, OUTBOX_CLAIM_LEASE).await? {
+            tracing::debug!("Outbox item {} claimed by another instance", id);
+            return Ok(None);
+        }
+
+        entry.claimed_until = chrono::Duration::from_std(OUTBOX_CLAIM_LEASE)
+            .ok()
+            .map(|lease| now + lease);
+        self.outbox.save(&entry).await?;
+        Ok(Some(entry))
+    }
+
+    async fn deliver(&self, message: &OutboxMessage) -> Result<()> {
+        let phone_id = message
+            .phone_id
+            .as_deref()
+            .unwrap_or(&self.default_phone_id);
+
+        let (action, text_param) = match message.kind {
+            OutboxKind::Message => ("message_send", "text"),
+            OutboxKind::Annotation => ("note_add", "note_text"),
+        };
+
+        if message.text.is_empty() {
+            return Err(ChatGuruError::ValidationError(
+                "Outbox item has empty text".to_string(),
+            ));
+        }
+
+        let mut request = ChatGuruRequest::new(action)
+            .phone_id(phone_id)
+            .params(&[(text_param, &message.text), ("chat_number", &message.phone)]);
+        if message.not_before.is_some() {
+            // A política já decidiu o horário do envio
+            request = request.bypass_send_policy();
+        }
+        self.execute(&request).await.map(|_| ())
+    }
+}
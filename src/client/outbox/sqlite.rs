@@ -0,0 +1,232 @@
+use super::{OutboxMessage, OutboxStore};
+use crate::error::{ChatGuruError, Result};
+use chrono::Utc;
+use futures_core::future::BoxFuture;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Armazenamento da outbox em um banco SQLite
+///
+/// Cada item é gravado como JSON na tabela `chatguru_outbox`. Processos na
+/// mesma máquina podem compartilhar o arquivo: antes de enviar, o flush
+/// reserva o item na tabela `chatguru_outbox_claims` dentro de uma transação,
+/// então cada item é enviado por um único processo. As consultas rodam em
+/// [`spawn_blocking`](tokio::task::spawn_blocking), fora das tasks assíncronas.
+///
+/// Disponível com a feature `sqlite`.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use std::sync::Arc;
+/// use chatguru::client::SqliteOutboxStore;
+///
+/// let store = SqliteOutboxStore::open("/var/lib/meu-servico/outbox.db")?;
+/// let client = ChatGuruClient::builder()
+///     // ...
+///     .outbox_store(Arc::new(store))
+///     .build()?;
+/// ```
+#[derive(Debug)]
+pub struct SqliteOutboxStore {
+    path: PathBuf,
+    connection: Arc<Mutex<Connection>>,
+    /// Valor gravado em cada reserva feita por esta instância, por item
+    claims: Mutex<HashMap<String, String>>,
+}
+
+impl SqliteOutboxStore {
+    /// Abre (ou cria) o banco da outbox, criando as tabelas se necessário
+    ///
+    /// # Erros
+    ///
+    /// Retorna `InternalError` se o banco não puder ser aberto ou criado.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let connection = Connection::open(&path).map_err(sqlite_error)?;
+        connection
+            .busy_timeout(Duration::from_secs(5))
+            .map_err(sqlite_error)?;
+        connection
+            .execute_batch(
+                "CREATE TABLE IF NOT EXISTS chatguru_outbox (
+                     id TEXT PRIMARY KEY,
+                     message TEXT NOT NULL
+                 );
+                 CREATE TABLE IF NOT EXISTS chatguru_outbox_claims (
+                     id TEXT PRIMARY KEY,
+                     token TEXT NOT NULL,
+                     expires_at INTEGER NOT NULL
+                 );",
+            )
+            .map_err(sqlite_error)?;
+
+        tracing::info!("Outbox opened at {}", path.display());
+
+        Ok(Self {
+            path,
+            connection: Arc::new(Mutex::new(connection)),
+            claims: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Caminho do banco da outbox
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Executa `query` com a conexão, fora do runtime assíncrono
+    async fn with_connection<T, F>(&self, query: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> Result<T> + Send + 'static,
+    {
+        let connection = self.connection.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut connection = connection.lock().unwrap_or_else(|e| e.into_inner());
+            query(&mut connection)
+        })
+        .await
+        .map_err(|e| ChatGuruError::InternalError(format!("SQLite outbox task failed: {}", e)))?
+    }
+
+    fn claims(&self) -> std::sync::MutexGuard<'_, HashMap<String, String>> {
+        self.claims.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn sqlite_error(e: rusqlite::Error) -> ChatGuruError {
+    ChatGuruError::InternalError(format!("SQLite outbox error: {}", e))
+}
+
+impl OutboxStore for SqliteOutboxStore {
+    fn save<'a>(&'a self, message: &'a OutboxMessage) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let id = message.id.clone();
+            let json = serde_json::to_string(message)?;
+            self.with_connection(move |connection| {
+                connection
+                    .execute(
+                        "INSERT INTO chatguru_outbox (id, message) VALUES (?1, ?2)
+                         ON CONFLICT(id) DO UPDATE SET message = excluded.message",
+                        params![id, json],
+                    )
+                    .map_err(sqlite_error)?;
+                Ok(())
+            })
+            .await
+        })
+    }
+
+    fn get<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<OutboxMessage>>> {
+        Box::pin(async move {
+            let id = id.to_string();
+            let json: Option<String> = self
+                .with_connection(move |connection| {
+                    connection
+                        .query_row(
+                            "SELECT message FROM chatguru_outbox WHERE id = ?1",
+                            params![id],
+                            |row| row.get(0),
+                        )
+                        .optional()
+                        .map_err(sqlite_error)
+                })
+                .await?;
+            Ok(json.map(|json| serde_json::from_str(&json)).transpose()?)
+        })
+    }
+
+    fn list(&self) -> BoxFuture<'_, Result<Vec<OutboxMessage>>> {
+        Box::pin(async move {
+            let rows: Vec<String> = self
+                .with_connection(|connection| {
+                    let mut statement = connection
+                        .prepare("SELECT message FROM chatguru_outbox")
+                        .map_err(sqlite_error)?;
+                    let rows = statement
+                        .query_map([], |row| row.get(0))
+                        .map_err(sqlite_error)?;
+                    rows.collect::<rusqlite::Result<_>>().map_err(sqlite_error)
+                })
+                .await?;
+            rows.iter()
+                .map(|json| Ok(serde_json::from_str(json)?))
+                .collect()
+        })
+    }
+
+    fn remove<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool>> {
+        Box::pin(async move {
+            let id = id.to_string();
+            self.with_connection(move |connection| {
+                let removed = connection
+                    .execute("DELETE FROM chatguru_outbox WHERE id = ?1", params![id])
+                    .map_err(sqlite_error)?;
+                Ok(removed > 0)
+            })
+            .await
+        })
+    }
+
+    fn claim<'a>(&'a self, id: &'a str, lease: Duration) -> BoxFuture<'a, Result<bool>> {
+        Box::pin(async move {
+            let now = Utc::now().timestamp_millis();
+            let token = format!("{}-{}", std::process::id(), now);
+            let expires_at =
+                now.saturating_add(i64::try_from(lease.as_millis()).unwrap_or(i64::MAX));
+
+            let (item, claim_token) = (id.to_string(), token.clone());
+            let claimed = self
+                .with_connection(move |connection| {
+                    let transaction = connection
+                        .transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)
+                        .map_err(sqlite_error)?;
+                    // Reservas vencidas (ex: processo que caiu durante o envio) são descartadas
+                    transaction
+                        .execute(
+                            "DELETE FROM chatguru_outbox_claims WHERE id = ?1 AND expires_at <= ?2",
+                            params![item, now],
+                        )
+                        .map_err(sqlite_error)?;
+                    let inserted = transaction
+                        .execute(
+                            "INSERT OR IGNORE INTO chatguru_outbox_claims (id, token, expires_at)
+                             VALUES (?1, ?2, ?3)",
+                            params![item, claim_token, expires_at],
+                        )
+                        .map_err(sqlite_error)?;
+                    transaction.commit().map_err(sqlite_error)?;
+                    Ok(inserted > 0)
+                })
+                .await?;
+
+            if claimed {
+                self.claims().insert(id.to_string(), token);
+            }
+            Ok(claimed)
+        })
+    }
+
+    fn release<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let Some(token) = self.claims().remove(id) else {
+                return Ok(());
+            };
+            let id = id.to_string();
+            self.with_connection(move |connection| {
+                connection
+                    .execute(
+                        "DELETE FROM chatguru_outbox_claims WHERE id = ?1 AND token = ?2",
+                        params![id, token],
+                    )
+                    .map_err(sqlite_error)?;
+                Ok(())
+            })
+            .await
+        })
+    }
+}
@@ -0,0 +1,155 @@
+use super::OutboxMessage;
+use crate::error::Result;
+use crate::json_file::{JsonFileItem, JsonFileMap};
+use futures_core::future::BoxFuture;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Armazenamento dos itens da outbox
+///
+/// A implementação padrão é [`InMemoryOutboxStore`], que perde os itens
+/// quando o processo termina. [`FileOutboxStore`] grava os itens em disco;
+/// implemente este trait para usar outro backend (ex: banco de dados).
+pub trait OutboxStore: Send + Sync {
+    /// Insere ou substitui o item com o mesmo ID
+    fn save<'a>(&'a self, message: &'a OutboxMessage) -> BoxFuture<'a, Result<()>>;
+
+    /// Busca um item pelo ID
+    fn get<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<OutboxMessage>>>;
+
+    /// Lista todos os itens armazenados (em qualquer ordem)
+    fn list(&self) -> BoxFuture<'_, Result<Vec<OutboxMessage>>>;
+
+    /// Remove um item, retornando `true` se ele existia
+    fn remove<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool>>;
+
+    /// Reserva o item para um único flush por até `lease`, retornando `false`
+    /// se outro processo já o reservou
+    ///
+    /// Dentro do processo o cliente já evita envios duplicados; backends
+    /// compartilhados por várias instâncias devem reservar de forma atômica
+    /// (ex: `SET NX PX` no Redis). O padrão sempre aceita.
+    fn claim<'a>(&'a self, id: &'a str, lease: Duration) -> BoxFuture<'a, Result<bool>> {
+        let _ = (id, lease);
+        Box::pin(async { Ok(true) })
+    }
+
+    /// Libera a reserva feita por [`claim`](Self::claim)
+    fn release<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<()>> {
+        let _ = id;
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Armazenamento em memória (padrão do cliente)
+#[derive(Debug, Default)]
+pub struct InMemoryOutboxStore {
+    messages: Mutex<HashMap<String, OutboxMessage>>,
+}
+
+impl InMemoryOutboxStore {
+    /// Cria um armazenamento vazio
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn messages(&self) -> std::sync::MutexGuard<'_, HashMap<String, OutboxMessage>> {
+        self.messages.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl OutboxStore for InMemoryOutboxStore {
+    fn save<'a>(&'a self, message: &'a OutboxMessage) -> BoxFuture<'a, Result<()>> {
+        self.messages().insert(message.id.clone(), message.clone());
+        Box::pin(async { Ok(()) })
+    }
+
+    fn get<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<OutboxMessage>>> {
+        let message = self.messages().get(id).cloned();
+        Box::pin(async move { Ok(message) })
+    }
+
+    fn list(&self) -> BoxFuture<'_, Result<Vec<OutboxMessage>>> {
+        let messages = self.messages().values().cloned().collect();
+        Box::pin(async move { Ok(messages) })
+    }
+
+    fn remove<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool>> {
+        let removed = self.messages().remove(id).is_some();
+        Box::pin(async move { Ok(removed) })
+    }
+}
+
+/// Armazenamento em arquivo JSON, que sobrevive a reinícios do processo
+///
+/// Mantém os itens em memória e regrava o arquivo inteiro a cada alteração
+/// (escrevendo em um arquivo temporário e renomeando, para não corromper o
+/// conteúdo em caso de queda). Adequado para outboxes pequenas de uma única
+/// instância; para várias instâncias use um backend compartilhado.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use std::sync::Arc;
+/// use chatguru::client::FileOutboxStore;
+///
+/// let store = FileOutboxStore::open("/var/lib/meu-servico/outbox.json")?;
+///
+/// let client = ChatGuruClient::builder()
+///     // ...
+///     .outbox_store(Arc::new(store))
+///     .build()?;
+/// ```
+#[derive(Debug)]
+pub struct FileOutboxStore {
+    messages: JsonFileMap<OutboxMessage>,
+}
+
+impl JsonFileItem for OutboxMessage {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        a.queued_at.cmp(&b.queued_at).then_with(|| a.id.cmp(&b.id))
+    }
+}
+
+impl FileOutboxStore {
+    /// Abre (ou cria) o arquivo da outbox, carregando os itens existentes
+    ///
+    /// # Erros
+    ///
+    /// Retorna `InternalError` se o arquivo existir mas não puder ser lido, e
+    /// `SerializationError` se o conteúdo não for uma outbox válida.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self {
+            messages: JsonFileMap::open(path, "outbox")?,
+        })
+    }
+
+    /// Caminho do arquivo da outbox
+    pub fn path(&self) -> &Path {
+        self.messages.path()
+    }
+}
+
+impl OutboxStore for FileOutboxStore {
+    fn save<'a>(&'a self, message: &'a OutboxMessage) -> BoxFuture<'a, Result<()>> {
+        Box::pin(self.messages.insert(message))
+    }
+
+    fn get<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<OutboxMessage>>> {
+        Box::pin(async move { Ok(self.messages.get(id).await) })
+    }
+
+    fn list(&self) -> BoxFuture<'_, Result<Vec<OutboxMessage>>> {
+        Box::pin(async move { Ok(self.messages.values().await) })
+    }
+
+    fn remove<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool>> {
+        Box::pin(self.messages.remove(id))
+    }
+}
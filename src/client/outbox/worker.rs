@@ -0,0 +1,112 @@
+use super::FlushReport;
+use crate::client::{ChatGuruClient, Shutdown};
+use crate::error::Result;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+/// Tarefa em segundo plano que esvazia a outbox periodicamente
+///
+/// Criada por [`ChatGuruClient::start_outbox_worker`]. A tarefa continua
+/// rodando até [`shutdown`](Self::shutdown) (ou
+/// [`ChatGuruClient::shutdown`]) ser chamado; descartar o handle não a
+/// interrompe.
+#[derive(Debug)]
+pub struct OutboxWorker {
+    shutdown: Arc<Notify>,
+    handle: JoinHandle<()>,
+}
+
+impl OutboxWorker {
+    /// Inicia a tarefa que chama `flush` a cada `interval`
+    ///
+    /// `flush` recebe `true` na última chamada, feita no encerramento.
+    /// O worker também para quando `client_shutdown` é disparado.
+    pub(super) fn spawn<F, Fut>(
+        name: &'static str,
+        interval: Duration,
+        client_shutdown: Shutdown,
+        flush: F,
+    ) -> Self
+    where
+        F: Fn(bool) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<FlushReport>> + Send,
+    {
+        let shutdown = Arc::new(Notify::new());
+        let signal = shutdown.clone();
+
+        let handle = tokio::spawn(async move {
+            let _running = client_shutdown.track();
+            tracing::info!("{} started (interval: {}ms)", name, interval.as_millis());
+
+            loop {
+                if let Err(e) = flush(false).await {
+                    tracing::error!("{} flush failed: {}", name, e);
+                }
+
+                tokio::select! {
+                    _ = tokio::time::sleep(interval) => {}
+                    _ = signal.notified() => break,
+                    _ = client_shutdown.triggered() => break,
+                }
+            }
+
+            if let Err(e) = flush(true).await {
+                tracing::error!("{} final flush failed: {}", name, e);
+            }
+            tracing::info!("{} stopped", name);
+        });
+
+        Self { shutdown, handle }
+    }
+
+    /// Interrompe o worker após um último flush e aguarda o término
+    pub async fn shutdown(self) {
+        self.shutdown.notify_one();
+        if let Err(e) = self.handle.await {
+            tracing::error!("Outbox worker terminated abnormally: {}", e);
+        }
+    }
+
+    /// Indica se a tarefa já terminou
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+}
+
+impl ChatGuruClient {
+    /// Inicia uma tarefa que chama [`flush`](Self::flush) a cada `interval`
+    ///
+    /// Itens que falharem são tentados novamente no ciclo seguinte. Com um
+    /// [`OutboxStore`](super::OutboxStore) persistente, itens enfileirados antes de um
+    /// reinício são enviados no primeiro ciclo.
+    ///
+    /// Precisa ser chamado dentro de um runtime Tokio.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// use std::time::Duration;
+    ///
+    /// let worker = client.start_outbox_worker(Duration::from_secs(5));
+    ///
+    /// client.queue_message("5511999999999", None, "Pedido recebido!").await?;
+    ///
+    /// // No encerramento do serviço
+    /// worker.shutdown().await;
+    /// ```
+    pub fn start_outbox_worker(&self, interval: Duration) -> OutboxWorker {
+        let client = self.clone();
+        OutboxWorker::spawn(
+            "Outbox worker",
+            interval,
+            self.shutdown_handle(),
+            move |_| {
+                let client = client.clone();
+                async move { client.flush().await }
+            },
+        )
+    }
+}
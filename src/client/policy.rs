@@ -0,0 +1,475 @@
+//! Política de envio: horário de silêncio, limite diário por contato e blocklist
+//!
+//! Configurada com
+//! [`ChatGuruClientBuilder::send_policy`](super::ChatGuruClientBuilder::send_policy),
+//! a [`SendPolicy`] é consultada antes de toda mensagem enviada ao contato
+//! (`message_send` e `message_file_send`), em qualquer método do cliente.
+
+use super::request::{is_send_action, synthetic_response, ChatGuruRequest};
+use super::response::ApiResponse;
+use super::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::types::PhoneNumber;
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::sync::Mutex;
+use thiserror::Error;
+
+/// Fuso padrão da política: horário de Brasília (UTC-3)
+pub const DEFAULT_POLICY_OFFSET_SECS: i32 = -3 * 3600;
+
+/// Envio recusado pela [`SendPolicy`]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PolicyViolation {
+    /// O número está na blocklist
+    #[error("contact {phone} is blocklisted")]
+    Blocklisted {
+        /// Telefone mascarado
+        phone: String,
+    },
+
+    /// Envio dentro do horário de silêncio do contato
+    #[error("quiet hours for the contact, sending allowed from {allowed_at}")]
+    QuietHours {
+        /// Primeiro momento em que o envio seria permitido
+        allowed_at: DateTime<Utc>,
+    },
+
+    /// O contato já recebeu o máximo de mensagens do dia
+    #[error("daily limit of {limit} messages reached for the contact, sending allowed from {allowed_at}")]
+    DailyLimit {
+        /// Limite configurado
+        limit: u32,
+        /// Primeiro momento em que o envio seria permitido
+        allowed_at: DateTime<Utc>,
+    },
+}
+
+impl PolicyViolation {
+    /// Primeiro momento em que o envio seria permitido (`None` para a blocklist)
+    pub fn allowed_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            PolicyViolation::Blocklisted { .. } => None,
+            PolicyViolation::QuietHours { allowed_at }
+            | PolicyViolation::DailyLimit { allowed_at, .. } => Some(*allowed_at),
+        }
+    }
+}
+
+/// O que fazer com um envio fora da política
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ViolationAction {
+    /// Falha com [`ChatGuruError::PolicyViolation`] (padrão)
+    #[default]
+    Reject,
+    /// Enfileira a mensagem na outbox para o primeiro horário permitido
+    ///
+    /// Só mensagens de texto simples podem ser adiadas; arquivos, botões e
+    /// demais envios com parâmetros extras continuam sendo recusados. Os itens
+    /// adiados são enviados pelo [`flush`](ChatGuruClient::flush) (ou pelo
+    /// [`OutboxWorker`](super::OutboxWorker)) a partir do horário permitido.
+    Defer,
+}
+
+/// Decisão da política para um envio
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyDecision {
+    /// Pode enviar agora
+    Allow,
+    /// Adiar até o momento informado
+    Defer(DateTime<Utc>),
+    /// Não enviar
+    Reject(PolicyViolation),
+}
+
+/// Regras de envio ao contato aplicadas pelo cliente
+///
+/// * **Horário de silêncio** ([`quiet_hours`](Self::quiet_hours)): janela no
+///   horário local do contato em que nada é enviado; pode cruzar a
+///   meia-noite (ex: 21h às 8h). O fuso é o padrão da política
+///   ([`timezone`](Self::timezone), UTC-3 se não informado) ou o do DDD do
+///   contato ([`area_code_timezone`](Self::area_code_timezone)).
+/// * **Limite diário** ([`max_per_contact_per_day`](Self::max_per_contact_per_day)):
+///   máximo de mensagens por contato em cada dia local. A contagem fica em
+///   memória, por instância da política; envios que falham (erro de rede ou
+///   da API) não contam.
+/// * **Blocklist** ([`block`](Self::block)): números que nunca recebem
+///   mensagens, nem adiadas.
+///
+/// Fora da política, o envio falha com [`ChatGuruError::PolicyViolation`] ou
+/// é adiado para a outbox, conforme a [`ViolationAction`].
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use std::sync::Arc;
+/// use chrono::{FixedOffset, NaiveTime};
+/// use chatguru::client::{SendPolicy, ViolationAction};
+///
+/// let policy = SendPolicy::new()
+///     .quiet_hours(
+///         NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+///         NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+///     )
+///     // Acre (UTC-5)
+///     .area_code_timezone("68", FixedOffset::west_opt(5 * 3600).unwrap())
+///     .max_per_contact_per_day(3)
+///     .block("5511999999999")
+///     .on_violation(ViolationAction::Defer);
+///
+/// let client = ChatGuruClient::builder()
+///     // ...
+///     .send_policy(Arc::new(policy))
+///     .build()?;
+/// ```
+pub struct SendPolicy {
+    quiet_hours: Option<(NaiveTime, NaiveTime)>,
+    timezone: FixedOffset,
+    area_code_timezones: HashMap<String, FixedOffset>,
+    max_per_day: Option<u32>,
+    blocklist: HashSet<String>,
+    on_violation: ViolationAction,
+    sent: Mutex<HashMap<String, BTreeMap<NaiveDate, u32>>>,
+}
+
+impl fmt::Debug for SendPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SendPolicy")
+            .field("quiet_hours", &self.quiet_hours)
+            .field("timezone", &self.timezone)
+            .field("area_code_timezones", &self.area_code_timezones)
+            .field("max_per_day", &self.max_per_day)
+            .field("blocklist", &self.blocklist.len())
+            .field("on_violation", &self.on_violation)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for SendPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SendPolicy {
+    /// Política sem restrições
+    pub fn new() -> Self {
+        Self {
+            quiet_hours: None,
+            timezone: FixedOffset::east_opt(DEFAULT_POLICY_OFFSET_SECS)
+                .expect("valid default offset"),
+            area_code_timezones: HashMap::new(),
+            max_per_day: None,
+            blocklist: HashSet::new(),
+            on_violation: ViolationAction::default(),
+            sent: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Não envia entre `start` e `end` (horário local do contato)
+    ///
+    /// Com `start` maior que `end`, a janela cruza a meia-noite; com os dois
+    /// iguais, não há horário de silêncio.
+    pub fn quiet_hours(mut self, start: NaiveTime, end: NaiveTime) -> Self {
+        self.quiet_hours = (start != end).then_some((start, end));
+        self
+    }
+
+    /// Fuso usado para contatos sem fuso por DDD (padrão: UTC-3)
+    pub fn timezone(mut self, offset: FixedOffset) -> Self {
+        self.timezone = offset;
+        self
+    }
+
+    /// Fuso dos contatos com o DDD informado
+    pub fn area_code_timezone(mut self, ddd: impl Into<String>, offset: FixedOffset) -> Self {
+        self.area_code_timezones
+            .insert(ddd.into().trim().to_string(), offset);
+        self
+    }
+
+    /// Máximo de mensagens por contato em cada dia (no horário local do contato)
+    pub fn max_per_contact_per_day(mut self, limit: u32) -> Self {
+        self.max_per_day = Some(limit);
+        self
+    }
+
+    /// Acrescenta um número à blocklist
+    pub fn block(mut self, phone_number: impl Into<PhoneNumber>) -> Self {
+        self.blocklist
+            .insert(phone_number.into().digits().to_string());
+        self
+    }
+
+    /// O que fazer com envios fora da política (padrão: recusar)
+    pub fn on_violation(mut self, action: ViolationAction) -> Self {
+        self.on_violation = action;
+        self
+    }
+
+    /// Indica se o número está na blocklist
+    pub fn is_blocked(&self, phone_number: &PhoneNumber) -> bool {
+        self.blocklist.contains(phone_number.digits())
+    }
+
+    /// Decide o envio de uma mensagem ao número no momento `now`
+    ///
+    /// Envios permitidos ou adiados contam para o limite diário do dia em que
+    /// serão feitos; se um envio permitido falhar, desfaça a contagem com
+    /// [`release`](Self::release).
+    pub fn evaluate(&self, phone_number: &PhoneNumber, now: DateTime<Utc>) -> PolicyDecision {
+        self.decide(phone_number, now, true)
+    }
+
+    /// Como [`evaluate`](Self::evaluate); com `can_defer` falso, recusa em vez de adiar
+    fn decide(
+        &self,
+        phone_number: &PhoneNumber,
+        now: DateTime<Utc>,
+        can_defer: bool,
+    ) -> PolicyDecision {
+        if self.is_blocked(phone_number) {
+            return PolicyDecision::Reject(PolicyViolation::Blocklisted {
+                phone: phone_number.masked(),
+            });
+        }
+
+        let offset = self.offset_for(phone_number);
+        let digits = phone_number.digits();
+        let mut sent = self.sent.lock().unwrap_or_else(|p| p.into_inner());
+        let days = sent.entry(digits.to_string()).or_default();
+
+        let mut at = now.with_timezone(&offset).naive_local();
+        let mut first_violation = None;
+        // Horário de silêncio e limite diário se alternam no máximo algumas vezes
+        for _ in 0..8 {
+            if let Some(end) = self.quiet_end(at) {
+                first_violation.get_or_insert(Violation::QuietHours);
+                at = end;
+            } else if self
+                .max_per_day
+                .is_some_and(|limit| days.get(&at.date()).copied().unwrap_or(0) >= limit)
+            {
+                first_violation.get_or_insert(Violation::DailyLimit);
+                at = (at.date() + Duration::days(1)).and_time(NaiveTime::MIN);
+            } else {
+                break;
+            }
+        }
+
+        let Some(violation) = first_violation else {
+            record(days, at.date());
+            return PolicyDecision::Allow;
+        };
+
+        let allowed_at = local_to_utc(at, offset);
+        match self.on_violation {
+            ViolationAction::Defer if can_defer => {
+                record(days, at.date());
+                PolicyDecision::Defer(allowed_at)
+            }
+            _ => PolicyDecision::Reject(match violation {
+                Violation::QuietHours => PolicyViolation::QuietHours { allowed_at },
+                Violation::DailyLimit => PolicyViolation::DailyLimit {
+                    limit: self.max_per_day.unwrap_or_default(),
+                    allowed_at,
+                },
+            }),
+        }
+    }
+
+    /// Desfaz a contagem de um envio permitido por [`evaluate`](Self::evaluate)
+    /// que não chegou a ser feito
+    ///
+    /// `decided_at` é o mesmo `now` informado a `evaluate`.
+    pub fn release(&self, phone_number: &PhoneNumber, decided_at: DateTime<Utc>) {
+        let date = decided_at
+            .with_timezone(&self.offset_for(phone_number))
+            .date_naive();
+        let digits = phone_number.digits();
+        let mut sent = self.sent.lock().unwrap_or_else(|p| p.into_inner());
+        let Some(days) = sent.get_mut(digits) else {
+            return;
+        };
+        if let Some(count) = days.get_mut(&date) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                days.remove(&date);
+            }
+        }
+        if days.is_empty() {
+            sent.remove(digits);
+        }
+    }
+
+    fn offset_for(&self, phone_number: &PhoneNumber) -> FixedOffset {
+        phone_number
+            .ddd()
+            .and_then(|ddd| self.area_code_timezones.get(ddd))
+            .copied()
+            .unwrap_or(self.timezone)
+    }
+
+    /// Fim do horário de silêncio que contém `at`, se houver
+    fn quiet_end(&self, at: NaiveDateTime) -> Option<NaiveDateTime> {
+        let (start, end) = self.quiet_hours?;
+        let time = at.time();
+        let date = at.date();
+
+        if start < end {
+            (time >= start && time < end).then(|| date.and_time(end))
+        } else if time >= start {
+            Some((date + Duration::days(1)).and_time(end))
+        } else {
+            (time < end).then(|| date.and_time(end))
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Violation {
+    QuietHours,
+    DailyLimit,
+}
+
+/// Conta um envio no dia, descartando os dias que já passaram
+fn record(days: &mut BTreeMap<NaiveDate, u32>, date: NaiveDate) {
+    *days.entry(date).or_default() += 1;
+    if let Some(yesterday) = date.pred_opt() {
+        days.retain(|day, _| *day >= yesterday);
+    }
+}
+
+fn local_to_utc(at: NaiveDateTime, offset: FixedOffset) -> DateTime<Utc> {
+    (at - Duration::seconds(offset.local_minus_utc() as i64)).and_utc()
+}
+
+/// Resultado de [`ChatGuruClient::apply_send_policy`]
+pub(super) enum PolicyCheck {
+    /// A chamada segue sem passar pela política
+    Proceed,
+    /// A chamada segue e já conta para o limite diário; a contagem é desfeita
+    /// por [`ChatGuruClient::settle_send_policy`] se o envio falhar
+    Counted(PhoneNumber, DateTime<Utc>),
+    /// Envio adiado para a outbox, com a resposta sintética
+    Deferred(reqwest::Response),
+}
+
+impl ChatGuruClient {
+    /// Aplica a [`SendPolicy`] configurada a um envio
+    pub(super) async fn apply_send_policy(
+        &self,
+        request: &ChatGuruRequest,
+        request_id: &str,
+    ) -> Result<PolicyCheck> {
+        let Some(ref policy) = self.send_policy else {
+            return Ok(PolicyCheck::Proceed);
+        };
+        if request.bypasses_send_policy() || !is_send_action(request.action()) {
+            return Ok(PolicyCheck::Proceed);
+        }
+        let Some(chat_number) = request.param_value("chat_number") else {
+            return Ok(PolicyCheck::Proceed);
+        };
+        let phone = PhoneNumber::from(chat_number);
+
+        // Só texto simples cabe na outbox
+        let text = match request.action() {
+            "message_send" if request.param_pairs().count() == 2 => request.param_value("text"),
+            _ => None,
+        };
+
+        let now = Utc::now();
+        let allowed_at = match policy.decide(&phone, now, text.is_some()) {
+            PolicyDecision::Allow => return Ok(PolicyCheck::Counted(phone, now)),
+            PolicyDecision::Reject(violation) => {
+                tracing::info!(
+                    "ChatGuru {} to {} rejected by send policy: {}",
+                    request.action(),
+                    phone.masked(),
+                    violation
+                );
+                return Err(ChatGuruError::PolicyViolation(violation));
+            }
+            PolicyDecision::Defer(allowed_at) => allowed_at,
+        };
+
+        let id = self
+            .queue_message_at(
+                phone.clone(),
+                request.phone_id_override(),
+                text.unwrap_or_default(),
+                allowed_at,
+            )
+            .await?;
+        tracing::info!(
+            "ChatGuru message to {} deferred by send policy until {} (outbox item {})",
+            phone.masked(),
+            allowed_at,
+            id
+        );
+
+        let body = serde_json::json!({
+            "code": 200,
+            "result": "success",
+            "description": "Deferred by send policy",
+            "outbox_id": id,
+            "not_before": allowed_at,
+        });
+        Ok(PolicyCheck::Deferred(synthetic_response(
+            200,
+            body.to_string(),
+            request_id,
+        )))
+    }
+
+    /// Desfaz a contagem de um envio permitido pela política se ele falhou
+    ///
+    /// Erros da API que chegam com status 2xx (ex: chat inexistente na
+    /// primeira tentativa de `send_or_register`) também desfazem a contagem;
+    /// para identificá-los o corpo é lido e a resposta, remontada.
+    pub(super) async fn settle_send_policy(
+        &self,
+        result: Result<reqwest::Response>,
+        phone: &PhoneNumber,
+        decided_at: DateTime<Utc>,
+    ) -> Result<reqwest::Response> {
+        let release = || {
+            if let Some(ref policy) = self.send_policy {
+                policy.release(phone, decided_at);
+            }
+        };
+
+        let mut response = match result {
+            Ok(response) if response.status().is_success() => response,
+            other => {
+                release();
+                return other;
+            }
+        };
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let extensions = std::mem::take(response.extensions_mut());
+        let body = match response.text().await {
+            Ok(body) => body,
+            Err(e) => {
+                release();
+                return Err(ChatGuruError::NetworkError(format!(
+                    "Failed to read send response: {}",
+                    e
+                )));
+            }
+        };
+        if ApiResponse::parse(&body).is_some_and(|response| response.is_error()) {
+            release();
+        }
+
+        let mut rebuilt = http::Response::new(body);
+        *rebuilt.status_mut() = status;
+        *rebuilt.headers_mut() = headers;
+        *rebuilt.extensions_mut() = extensions;
+        Ok(reqwest::Response::from(rebuilt))
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Limite de vazão aplicado pelo [`RateLimiter`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// Requisições liberadas por segundo (reposição do bucket)
+    pub per_second: f64,
+    /// Quantidade máxima de requisições em rajada (capacidade do bucket)
+    pub burst: u32,
+}
+
+impl RateLimit {
+    /// Cria um limite com a taxa e a rajada informadas
+    pub fn new(per_second: f64, burst: u32) -> Self {
+        Self {
+            per_second: per_second.max(f64::MIN_POSITIVE),
+            burst: burst.max(1),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// Rate limiter do tipo token bucket, com um bucket por chave
+///
+/// O cliente usa uma chave por `account_id:phone_id`, de modo que cada linha
+/// telefônica respeita o próprio limite. Chamadas que excedem a rajada ficam
+/// aguardando em `acquire()` até haver capacidade, na ordem de chegada.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::client::{RateLimit, RateLimiter};
+///
+/// let limiter = RateLimiter::new(RateLimit::new(5.0, 10));
+/// limiter.acquire("conta:telefone").await;
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    limit: RateLimit,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+    /// Cria um rate limiter com o limite informado
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Limite configurado
+    pub fn limit(&self) -> RateLimit {
+        self.limit
+    }
+
+    /// Aguarda até que haja capacidade para uma requisição na chave informada
+    pub async fn acquire(&self, key: &str) {
+        let wait = self.reserve(key);
+        if !wait.is_zero() {
+            tracing::debug!(
+                "ChatGuru rate limit reached for {}, waiting {}ms",
+                key,
+                wait.as_millis()
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Tenta consumir capacidade sem aguardar
+    ///
+    /// Retorna `false` se a chave não tem capacidade disponível no momento.
+    pub fn try_acquire(&self, key: &str) -> bool {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        let bucket = self.refill(&mut buckets, key);
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Reserva um token e retorna quanto tempo esperar até poder usá-lo
+    ///
+    /// O saldo pode ficar negativo: cada chamada reserva sua vez, garantindo
+    /// que chamadas concorrentes sejam liberadas em sequência.
+    fn reserve(&self, key: &str) -> Duration {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        let per_second = self.limit.per_second;
+        let bucket = self.refill(&mut buckets, key);
+
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / per_second)
+        }
+    }
+
+    fn refill<'a>(&self, buckets: &'a mut HashMap<String, Bucket>, key: &str) -> &'a mut Bucket {
+        let now = Instant::now();
+        let capacity = self.limit.burst as f64;
+        let bucket = buckets.entry(key.to_string()).or_insert_with(|| Bucket {
+            tokens: capacity,
+            updated_at: now,
+        });
+
+        let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.limit.per_second).min(capacity);
+        bucket.updated_at = now;
+        bucket
+    }
+}
@@ -0,0 +1,175 @@
+//! Envio para números sem chat: cadastra o chat (`chat_add`) e repete o envio
+
+use super::chat::ChatStatus;
+use super::request::ChatGuruRequest;
+use super::response::ApiResponse;
+use super::{ChatGuruClient, SentMessage};
+use crate::error::{ChatGuruError, Result};
+use crate::types::PhoneNumber;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Prazo padrão para o ChatGuru concluir o cadastro do chat
+pub const DEFAULT_CHAT_REGISTER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Intervalo entre as consultas à situação do cadastro
+const REGISTER_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Resultado de [`ChatGuruClient::send_or_register`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisteredSend {
+    /// Mensagem enviada
+    pub sent: SentMessage,
+    /// Se o chat precisou ser cadastrado antes do envio
+    pub registered: bool,
+}
+
+/// Indica se a resposta de `chat_add`/`chat_add_status` informa o cadastro concluído
+///
+/// Cadastros recusados (`chat_add_status: "error"`) já chegam como erro da API.
+fn chat_add_done(body: &str) -> bool {
+    ApiResponse::parse(body)
+        .and_then(|response| response.chat_add_status)
+        .is_some_and(|status| matches!(status.to_lowercase().as_str(), "done" | "success"))
+}
+
+impl ChatGuruClient {
+    /// Envia uma mensagem, cadastrando o chat do número antes se ele não existir
+    ///
+    /// Tenta o `message_send`; se a API responder que o chat não existe,
+    /// cadastra o chat com o nome informado (`chat_add`), aguarda o ChatGuru
+    /// concluir o cadastro (até [`DEFAULT_CHAT_REGISTER_TIMEOUT`]) e repete o
+    /// envio uma vez. Usa a linha padrão do cliente.
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - número de telefone inválido ou nome vazio
+    /// * `ApiError` - o ChatGuru recusou o cadastro do chat
+    /// * `Timeout` - o cadastro não foi concluído no prazo
+    /// * `ChatNotFound`, `RateLimited`, `NetworkError`, ... - falha no envio
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let result = client
+    ///     .send_or_register("5511999999999", "Maria Silva", "Olá, Maria! Seu cadastro foi aprovado.")
+    ///     .await?;
+    /// if result.registered {
+    ///     tracing::info!("Chat criado para o novo contato");
+    /// }
+    /// ```
+    pub async fn send_or_register(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        name: &str,
+        message: &str,
+    ) -> Result<RegisteredSend> {
+        let phone_number = phone_number.into();
+        phone_number.validate()?;
+
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ChatGuruError::ValidationError(
+                "Chat name must not be empty".to_string(),
+            ));
+        }
+
+        match self.send_message(phone_number.clone(), None, message).await {
+            Ok(sent) => {
+                return Ok(RegisteredSend {
+                    sent,
+                    registered: false,
+                })
+            }
+            Err(e) if e.is_chat_not_found() => {
+                tracing::info!(
+                    "No chat for {}, registering it before sending",
+                    phone_number.masked()
+                );
+            }
+            Err(e) => return Err(e),
+        }
+
+        self.register_chat(&phone_number, name, DEFAULT_CHAT_REGISTER_TIMEOUT)
+            .await?;
+
+        let sent = self.send_message(phone_number, None, message).await?;
+        Ok(RegisteredSend {
+            sent,
+            registered: true,
+        })
+    }
+
+    /// Cadastra o chat e aguarda a conclusão do cadastro
+    async fn register_chat(
+        &self,
+        phone_number: &PhoneNumber,
+        name: &str,
+        timeout: Duration,
+    ) -> Result<()> {
+        let body = self
+            .call_action(
+                "chat_add",
+                &self.default_phone_id,
+                &[("chat_number", phone_number.digits()), ("name", name)],
+            )
+            .await?;
+        if chat_add_done(&body) {
+            return Ok(());
+        }
+        let chat_add_id = serde_json::from_str::<Value>(&body).ok().and_then(|value| {
+            match value.get("chat_add_id")? {
+                Value::String(id) if !id.is_empty() => Some(id.clone()),
+                Value::Number(id) => Some(id.to_string()),
+                _ => None,
+            }
+        });
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            let done = match chat_add_id {
+                Some(ref id) => self.chat_add_status(id).await?,
+                // Sem ID do cadastro, espera o chat aparecer
+                None => self.fetch_chat_status(phone_number).await?.exists,
+            };
+            if done {
+                tracing::debug!("Chat {} registered", phone_number.masked());
+                return Ok(());
+            }
+
+            if Instant::now() + REGISTER_POLL_INTERVAL > deadline {
+                return Err(ChatGuruError::Timeout(format!(
+                    "Chat for {} was not registered within {}s",
+                    phone_number.masked(),
+                    timeout.as_secs()
+                )));
+            }
+            tokio::time::sleep(REGISTER_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Consulta `chat_add_status`, retornando se o cadastro foi concluído
+    async fn chat_add_status(&self, chat_add_id: &str) -> Result<bool> {
+        let body = self
+            .call_action(
+                "chat_add_status",
+                &self.default_phone_id,
+                &[("chat_add_id", chat_add_id)],
+            )
+            .await?;
+        Ok(chat_add_done(&body))
+    }
+
+    /// Como [`get_chat_status`](Self::get_chat_status), sem passar pelo cache de respostas
+    async fn fetch_chat_status(&self, phone_number: &PhoneNumber) -> Result<ChatStatus> {
+        let request =
+            ChatGuruRequest::new("chat_status").param("chat_number", phone_number.digits());
+
+        match self.execute(&request).await {
+            Ok(body) => ChatStatus::from_body(&body),
+            Err(e) if e.is_chat_not_found() => Ok(ChatStatus::not_found()),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use super::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::types::PhoneNumber;
+
+impl ChatGuruClient {
+    /// Envia uma mensagem de texto como resposta (citação) a uma mensagem do chat
+    ///
+    /// Usa a ação `message_send` com o ID da mensagem citada em
+    /// `reply_message_id`, para o WhatsApp exibir a resposta ligada à
+    /// mensagem do contato (o ID vem em
+    /// [`ChatGuruPayload::message_id`](crate::types::ChatGuruPayload::message_id)).
+    /// Textos acima do limite do builder são divididos; apenas a primeira
+    /// parte cita a mensagem. Envia pelo phone_id padrão do cliente.
+    ///
+    /// Diferente de [`send_confirmation_message`](Self::send_confirmation_message),
+    /// falhas da API são retornadas como erro e o envio para na primeira falha.
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - telefone inválido ou ID da mensagem vazio
+    /// * `NetworkError` - falha de rede
+    /// * `ApiError`, `ChatNotFound`, `RateLimited` ou `Unauthorized` - a API recusou o envio
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// if let (Some(phone), WebhookPayload::ChatGuru(p)) = (payload.get_phone_number(), &payload) {
+    ///     if let Some(ref message_id) = p.message_id {
+    ///         client.send_reply(phone, "Recebemos seu comprovante!", message_id).await?;
+    ///     }
+    /// }
+    /// ```
+    pub async fn send_reply(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+        text: &str,
+        reply_to_message_id: &str,
+    ) -> Result<()> {
+        let phone_number = phone_number.into();
+        phone_number.validate()?;
+
+        let reply_to_message_id = reply_to_message_id.trim();
+        if reply_to_message_id.is_empty() {
+            return Err(ChatGuruError::ValidationError(
+                "Reply requires the ID of the quoted message".to_string(),
+            ));
+        }
+
+        tracing::debug!(
+            "Sending reply to message {} for {}",
+            reply_to_message_id,
+            phone_number.masked()
+        );
+
+        for (index, chunk) in self.message_chunks(text).iter().enumerate() {
+            let mut params = vec![
+                ("text", chunk.as_str()),
+                ("chat_number", phone_number.digits()),
+            ];
+            if index == 0 {
+                params.push(("reply_message_id", reply_to_message_id));
+            }
+
+            if let Err(e) = self
+                .call_action("message_send", &self.default_phone_id, &params)
+                .await
+            {
+                tracing::error!("Failed to send reply: {}", e);
+                return Err(e);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,820 @@
+use super::annotate::SendNote;
+use super::encoding::{encode_component, encode_params, validate_param_name, FORM_CONTENT_TYPE};
+use super::length::SendWarnings;
+use super::metrics::RequestMetrics;
+use super::middleware::{ActionRequest, Next};
+use super::policy::PolicyCheck;
+use super::response::ApiResponse;
+use super::retry::{self, RetryOutcome, RetryPolicy};
+use super::ChatGuruClient;
+use crate::error::{ChatGuruError, Result};
+use crate::secret::{describe_error, redact_url, SecretString};
+use crate::types::PhoneNumber;
+use chrono::Utc;
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tracing::field::Empty;
+use tracing::{Instrument, Span};
+
+/// Header com o ID gerado para cada chamada, para correlacionar com o suporte do ChatGuru
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// Forma de envio dos parâmetros nas requisições à API
+///
+/// Por padrão os parâmetros (incluindo o token `key`) vão no corpo da
+/// requisição como `application/x-www-form-urlencoded`, evitando que o token
+/// e o texto das mensagens apareçam em logs de proxy e contornando limites
+/// de tamanho de URL em anotações longas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RequestMode {
+    /// Parâmetros no corpo `application/x-www-form-urlencoded` (padrão)
+    #[default]
+    FormBody,
+    /// Parâmetros na query string (comportamento das versões anteriores)
+    QueryString,
+}
+
+/// Corpo da resposta sintética retornada no modo dry run
+pub(crate) const DRY_RUN_BODY: &str = r#"{"code": 200, "result": "success", "description": "Dry run: request not sent", "dry_run": true}"#;
+
+/// Ações que entregam conteúdo ao contato
+const SEND_ACTIONS: &[&str] = &["message_send", "message_file_send"];
+
+/// Parâmetros que o cliente preenche em toda chamada e não podem vir da ação
+const RESERVED_PARAMS: &[&str] = &["key", "account_id", "action"];
+
+/// Chamada a uma ação da API: nome da ação, linha (`phone_id`) e parâmetros
+///
+/// Reúne apenas o que muda entre as ações; URL, codificação, autenticação,
+/// retries e leitura da resposta ficam em [`ChatGuruClient::execute`]. Sem
+/// `phone_id`, usa a linha padrão do cliente.
+#[derive(Debug, Clone)]
+pub(crate) struct ChatGuruRequest {
+    action: String,
+    phone_id: Option<String>,
+    params: Vec<(String, String)>,
+    headers: HeaderMap,
+    cacheable: bool,
+    overrides: Overrides,
+    bypass_send_policy: bool,
+    send_note: SendNote,
+}
+
+/// Configurações do cliente substituídas em uma chamada ([`RequestOptions`](super::RequestOptions))
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Overrides {
+    account_id: Option<String>,
+    timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+}
+
+impl ChatGuruRequest {
+    /// Chamada à ação informada, sem parâmetros
+    pub(crate) fn new(action: impl Into<String>) -> Self {
+        Self {
+            action: action.into(),
+            phone_id: None,
+            params: Vec::new(),
+            headers: HeaderMap::new(),
+            cacheable: false,
+            overrides: Overrides::default(),
+            bypass_send_policy: false,
+            send_note: SendNote::default(),
+        }
+    }
+
+    /// Envia pela linha informada em vez da linha padrão do cliente
+    pub(crate) fn phone_id(mut self, phone_id: impl Into<String>) -> Self {
+        self.phone_id = Some(phone_id.into());
+        self
+    }
+
+    /// Acrescenta um parâmetro
+    pub(crate) fn param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.push((name.into(), value.into()));
+        self
+    }
+
+    /// Acrescenta vários parâmetros
+    pub(crate) fn params(mut self, params: &[(&str, &str)]) -> Self {
+        self.params.extend(
+            params
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string())),
+        );
+        self
+    }
+
+    /// Substitui o valor de um parâmetro já acrescentado
+    pub(crate) fn set_param(&mut self, name: &str, value: impl Into<String>) {
+        if let Some((_, current)) = self.params.iter_mut().find(|(param, _)| param == name) {
+            *current = value.into();
+        }
+    }
+
+    /// Marca a chamada como leitura, que pode ser respondida pelo cache de respostas
+    pub(crate) fn cacheable(mut self) -> Self {
+        self.cacheable = true;
+        self
+    }
+
+    /// Dispensa a [`SendPolicy`](super::SendPolicy) (envios já adiados por ela)
+    pub(crate) fn bypass_send_policy(mut self) -> Self {
+        self.bypass_send_policy = true;
+        self
+    }
+
+    pub(crate) fn bypasses_send_policy(&self) -> bool {
+        self.bypass_send_policy
+    }
+
+    /// Contexto do envio para a anotação automática
+    pub(crate) fn send_note(&self) -> &SendNote {
+        &self.send_note
+    }
+
+    pub(crate) fn send_note_mut(&mut self) -> &mut SendNote {
+        &mut self.send_note
+    }
+
+    /// Nova chamada à ação, pela mesma linha e com os mesmos ajustes desta
+    pub(crate) fn follow_up(&self, action: impl Into<String>) -> Self {
+        Self {
+            phone_id: self.phone_id.clone(),
+            overrides: self.overrides.clone(),
+            ..Self::new(action)
+        }
+    }
+
+    /// Nome da ação
+    pub(crate) fn action(&self) -> &str {
+        &self.action
+    }
+
+    /// Parâmetros específicos da ação, na ordem em que foram acrescentados
+    pub(crate) fn param_pairs(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.params
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// Substitui a conta, o timeout e a política de retry do cliente nesta chamada
+    pub(crate) fn with_overrides(
+        mut self,
+        account_id: Option<String>,
+        timeout: Option<Duration>,
+        retry_policy: Option<RetryPolicy>,
+    ) -> Self {
+        self.overrides = Overrides {
+            account_id,
+            timeout,
+            retry_policy,
+        };
+        self
+    }
+
+    /// Chamada como vista pelos middlewares, com a linha padrão já aplicada
+    fn into_action_request(self, default_phone_id: &str, request_id: String) -> ActionRequest {
+        ActionRequest {
+            phone_id: self
+                .phone_id
+                .unwrap_or_else(|| default_phone_id.to_string()),
+            action: self.action,
+            params: self.params,
+            headers: self.headers,
+            request_id,
+            cacheable: self.cacheable,
+            overrides: self.overrides,
+            bypass_send_policy: self.bypass_send_policy,
+            send_note: self.send_note,
+        }
+    }
+
+    fn from_action_request(request: ActionRequest) -> (Self, String) {
+        let chatguru_request = Self {
+            action: request.action,
+            phone_id: Some(request.phone_id),
+            params: request.params,
+            headers: request.headers,
+            cacheable: request.cacheable,
+            overrides: request.overrides,
+            bypass_send_policy: request.bypass_send_policy,
+            send_note: request.send_note,
+        };
+        (chatguru_request, request.request_id)
+    }
+
+    /// Linha escolhida para a chamada, se diferente da padrão do cliente
+    pub(crate) fn phone_id_override(&self) -> Option<&str> {
+        self.phone_id.as_deref()
+    }
+
+    /// Conta da chamada: a substituída nesta chamada ou a do cliente
+    fn account_id<'a>(&'a self, default: &'a str) -> &'a str {
+        self.overrides.account_id.as_deref().unwrap_or(default)
+    }
+
+    pub(crate) fn param_value(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Indica se a ação envia conteúdo ao contato (sujeita a consentimento e
+/// à [`SendPolicy`](super::SendPolicy))
+pub(crate) fn is_send_action(action: &str) -> bool {
+    SEND_ACTIONS.contains(&action)
+}
+
+/// Resposta montada localmente (dry run e reprodução de gravações), sem acessar a rede
+pub(crate) fn synthetic_response(status: u16, body: String, request_id: &str) -> reqwest::Response {
+    http::Response::builder()
+        .status(status)
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .header(REQUEST_ID_HEADER, request_id)
+        .body(body)
+        .map(reqwest::Response::from)
+        .unwrap_or_else(|_| reqwest::Response::from(http::Response::new(String::new())))
+}
+
+/// Chamada de [`ChatGuruClient::execute_raw`], validando a ação e os parâmetros
+pub(crate) fn raw_request(action: &str, params: &[(&str, &str)]) -> Result<ChatGuruRequest> {
+    let action = action.trim();
+    if action.is_empty() {
+        return Err(ChatGuruError::ValidationError(
+            "Action must not be empty".to_string(),
+        ));
+    }
+    if let Some((name, _)) = params
+        .iter()
+        .find(|(name, _)| RESERVED_PARAMS.contains(name))
+    {
+        return Err(ChatGuruError::ValidationError(format!(
+            "Parameter {} is set by the client and cannot be overridden",
+            name
+        )));
+    }
+
+    for (name, _) in params {
+        validate_param_name(name)?;
+    }
+
+    let mut request = ChatGuruRequest::new(action);
+    for (name, value) in params {
+        request = match *name {
+            "phone_id" => request.phone_id(*value),
+            _ => request.param(*name, *value),
+        };
+    }
+    Ok(request)
+}
+
+/// Lê o corpo da resposta, convertendo status de erro (ou `"result": "error"`
+/// com status 2xx) em [`ChatGuruError`]
+pub(crate) async fn read_response(action: &str, response: reqwest::Response) -> Result<String> {
+    let status = response.status();
+    let retry_after = retry::retry_after_header(&response);
+    let response_text = response.text().await.unwrap_or_default();
+
+    let api_error = ApiResponse::parse(&response_text).filter(ApiResponse::is_error);
+
+    if status.is_success() && api_error.is_none() {
+        Ok(response_text)
+    } else {
+        tracing::debug!(
+            "ChatGuru {} failed with status {}: {}",
+            action,
+            status,
+            response_text
+        );
+        Err(match api_error {
+            Some(api_error) => api_error.into_error(status.as_u16(), response_text, retry_after),
+            None => ChatGuruError::from_response(status.as_u16(), response_text, retry_after),
+        })
+    }
+}
+
+/// Gera um ID único para a chamada (`cg-{timestamp}-{sequência}`)
+pub(crate) fn new_request_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    format!(
+        "cg-{:x}-{:x}",
+        Utc::now().timestamp_millis(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Span da chamada, nomeado pela ação (`chatguru.note_add`, `chatguru.message_send`, ...)
+fn action_span(
+    action: &str,
+    account_id: &str,
+    phone_id: &str,
+    phone: &str,
+    request_id: &str,
+) -> Span {
+    macro_rules! span {
+        ($name:literal) => {
+            tracing::info_span!(
+                $name,
+                action,
+                account_id,
+                phone_id,
+                phone,
+                request_id,
+                status = Empty,
+                latency_ms = Empty,
+                attempts = Empty,
+            )
+        };
+    }
+
+    match action {
+        "note_add" => span!("chatguru.note_add"),
+        "message_send" => span!("chatguru.message_send"),
+        "message_file_send" => span!("chatguru.message_file_send"),
+        _ => span!("chatguru.request"),
+    }
+}
+
+impl ChatGuruClient {
+    /// Executa uma ação e trata status HTTP de erro como falha
+    ///
+    /// Diferente dos métodos legados (que apenas logam falhas da API), retorna
+    /// o erro classificado por [`ChatGuruError::from_response`] quando a
+    /// resposta não é 2xx. Em caso de sucesso retorna o corpo da resposta.
+    pub(crate) async fn call_action(
+        &self,
+        action: &str,
+        phone_id: &str,
+        params: &[(&str, &str)],
+    ) -> Result<String> {
+        let request = ChatGuruRequest::new(action)
+            .phone_id(phone_id)
+            .params(params);
+        self.execute(&request).await
+    }
+
+    /// Envia a chamada e lê a resposta, tratando status HTTP de erro como falha
+    ///
+    /// Leituras marcadas com [`ChatGuruRequest::cacheable`] são respondidas
+    /// pelo cache de respostas, quando configurado.
+    pub(crate) async fn execute(&self, request: &ChatGuruRequest) -> Result<String> {
+        let cache = match self.response_cache {
+            Some(ref cache) if request.cacheable => cache,
+            _ => {
+                let response = self.post_action(request).await?;
+                return read_response(request.action(), response).await;
+            }
+        };
+
+        let phone_id = request
+            .phone_id
+            .as_deref()
+            .unwrap_or(&self.default_phone_id);
+        let mut key = format!("{}|{}", phone_id, request.action);
+        for (name, value) in &request.params {
+            key.push_str(&format!("|{}={}", name, value));
+        }
+
+        if let Some(body) = cache.get(&key) {
+            tracing::debug!("ChatGuru {} answered from response cache", request.action);
+            return Ok(body);
+        }
+
+        let response = self.post_action(request).await?;
+        let body = read_response(request.action(), response).await?;
+        cache.insert(key, request.param_value("chat_number"), body.clone());
+        Ok(body)
+    }
+
+    /// Executa uma ação da API que este crate ainda não encapsula
+    ///
+    /// Passa pelo mesmo caminho dos demais métodos: autenticação, modo de
+    /// envio, rate limiter, retries, circuit breaker, métricas e
+    /// classificação de erros. Um parâmetro `phone_id` escolhe a linha
+    /// (padrão: a linha padrão do cliente). Retorna o corpo da resposta.
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - ação vazia, parâmetro reservado (`key`, `account_id`, `action`)
+    ///   ou nome de parâmetro com caracteres fora de `A-Z a-z 0-9 _ - . [ ]`
+    /// * `ApiError`, `ChatNotFound`, `RateLimited`, `Unauthorized`, `NetworkError` ou `CircuitOpen` - falha na chamada
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let body = client
+    ///     .execute_raw(
+    ///         "dialog_execute",
+    ///         &[("chat_number", "5511999999999"), ("dialog_id", "abc123")],
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn execute_raw(&self, action: &str, params: &[(&str, &str)]) -> Result<String> {
+        self.execute(&raw_request(action, params)?).await
+    }
+
+    /// Executa uma ação ainda não encapsulada e interpreta a resposta como JSON
+    ///
+    /// Como [`execute_raw`](Self::execute_raw), com os parâmetros em um
+    /// `HashMap` (enviados em ordem alfabética). Respostas vazias viram
+    /// `Value::Null` e respostas que não são JSON viram `Value::String`.
+    ///
+    /// # Erros
+    ///
+    /// Os mesmos de [`execute_raw`](Self::execute_raw).
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// use std::collections::HashMap;
+    ///
+    /// let response = client
+    ///     .execute_action(
+    ///         "tag_add",
+    ///         HashMap::from([("chat_number", "5511999999999"), ("tag_name", "vip")]),
+    ///     )
+    ///     .await?;
+    /// println!("{}", response["description"]);
+    /// ```
+    pub async fn execute_action(&self, action: &str, params: HashMap<&str, &str>) -> Result<Value> {
+        let mut params: Vec<(&str, &str)> = params.into_iter().collect();
+        params.sort_unstable();
+
+        let body = self.execute_raw(action, &params).await?;
+        if body.trim().is_empty() {
+            return Ok(Value::Null);
+        }
+        Ok(serde_json::from_str(&body).unwrap_or(Value::String(body)))
+    }
+
+    /// Envia uma ação para a API com os parâmetros de autenticação
+    ///
+    /// Adiciona `key`, `account_id`, `phone_id` e `action` aos parâmetros
+    /// específicos da ação, codifica tudo com [`encode_params`](super::encode_params)
+    /// e envia conforme o [`RequestMode`] configurado,
+    /// repetindo falhas transitórias de acordo com a [`RetryPolicy`](super::RetryPolicy).
+    /// Cada tentativa aguarda o [`RateLimiter`](super::RateLimiter) da linha, se configurado.
+    ///
+    /// Com um [`CircuitBreaker`](super::CircuitBreaker) configurado, retorna
+    /// `CircuitOpen` sem fazer a requisição enquanto o circuito estiver aberto.
+    /// Falhas de rede são retornadas como `NetworkError`.
+    ///
+    /// Com um [`ConsentRegistry`](super::ConsentRegistry) configurado, envios a
+    /// contatos descadastrados falham com `OptedOut`; com um
+    /// [`ContentFilter`](super::ContentFilter), o texto é moderado (e pode
+    /// falhar com `Blocked`); com uma [`SendPolicy`](super::SendPolicy), envios
+    /// fora da política são recusados ou adiados. Essas verificações vêm
+    /// antes de tudo isso, nessa ordem; entre o filtro e a política, textos
+    /// acima do limite de tamanho são cortados e o aviso ([`SendWarning`](super::SendWarning))
+    /// é anexado à resposta.
+    ///
+    /// Com [`annotate_sends`](super::ChatGuruClientBuilder::annotate_sends),
+    /// `message_send` aceitos são anotados no chat depois do envio.
+    ///
+    /// Com middlewares registrados, a chamada passa por eles antes do envio.
+    pub(crate) async fn post_action(&self, request: &ChatGuruRequest) -> Result<reqwest::Response> {
+        let request_id = new_request_id();
+        self.check_consent(request).await?;
+        let filtered = self.apply_content_filter(request)?;
+        let request = filtered.as_ref().unwrap_or(request);
+        let Some((limited, warning)) = self.apply_length_limit(request) else {
+            return self.post_checked(request, request_id).await;
+        };
+
+        let mut response = self.post_checked(&limited, request_id).await?;
+        response
+            .extensions_mut()
+            .insert(SendWarnings(vec![warning]));
+        Ok(response)
+    }
+
+    /// Continuação de [`post_action`](Self::post_action) após as verificações do conteúdo
+    async fn post_checked(
+        &self,
+        request: &ChatGuruRequest,
+        request_id: String,
+    ) -> Result<reqwest::Response> {
+        let counted = match self.apply_send_policy(request, &request_id).await? {
+            PolicyCheck::Deferred(response) => return Ok(response),
+            PolicyCheck::Counted(phone, decided_at) => Some((phone, decided_at)),
+            PolicyCheck::Proceed => None,
+        };
+
+        let result = if self.middlewares.is_empty() {
+            self.dispatch(request, &request_id).await
+        } else {
+            let action_request = request
+                .clone()
+                .into_action_request(&self.default_phone_id, request_id.clone());
+            Next::new(self, &self.middlewares).run(action_request).await
+        };
+        let response = match counted {
+            Some((phone, decided_at)) => {
+                self.settle_send_policy(result, &phone, decided_at).await?
+            }
+            None => result?,
+        };
+
+        if self.should_annotate(request) {
+            return self.annotate_sent(request, &request_id, response).await;
+        }
+        Ok(response)
+    }
+
+    /// Fim da cadeia de middlewares: envia a chamada
+    pub(super) async fn dispatch_action(
+        &self,
+        request: ActionRequest,
+    ) -> Result<reqwest::Response> {
+        let (request, request_id) = ChatGuruRequest::from_action_request(request);
+        self.dispatch(&request, &request_id).await
+    }
+
+    async fn dispatch(
+        &self,
+        request: &ChatGuruRequest,
+        request_id: &str,
+    ) -> Result<reqwest::Response> {
+        let action = request.action();
+        let phone_id = request
+            .phone_id
+            .as_deref()
+            .unwrap_or(&self.default_phone_id);
+        let phone = request
+            .param_value("chat_number")
+            .map(|value| PhoneNumber::from(value).masked())
+            .unwrap_or_default();
+
+        if self.dry_run {
+            return Ok(self.dry_run_response(request, phone_id, request_id));
+        }
+
+        #[cfg(feature = "record")]
+        if let Some(ref cassette) = self.cassette {
+            if cassette.mode() == super::CassetteMode::Replay {
+                return cassette.play(request, phone_id, request_id).await;
+            }
+        }
+
+        if let (false, Some(cache), Some(chat)) = (
+            request.cacheable,
+            &self.response_cache,
+            request.param_value("chat_number"),
+        ) {
+            // Escritas no chat tornam as leituras guardadas dele obsoletas
+            cache.invalidate_chat(chat);
+        }
+
+        let _in_flight = self.shutdown.track();
+        let span = action_span(
+            action,
+            request.account_id(&self.account_id),
+            phone_id,
+            &phone,
+            request_id,
+        );
+        let started_at = Instant::now();
+
+        let (result, attempts) = self
+            .post_action_attempts(request, phone_id, request_id)
+            .instrument(span.clone())
+            .await;
+        span.record("attempts", attempts);
+
+        #[cfg(feature = "record")]
+        let result = match (result, &self.cassette) {
+            (Ok(response), Some(cassette)) => {
+                cassette
+                    .record_response(request, phone_id, request_id, response)
+                    .await
+            }
+            (result, _) => result,
+        };
+
+        let latency = started_at.elapsed();
+        let latency_ms = latency.as_millis() as u64;
+        span.record("latency_ms", latency_ms);
+
+        if let Some(ref metrics) = self.metrics {
+            let status = result.as_ref().ok().map(|r| r.status().as_u16());
+            metrics.record_request(&RequestMetrics {
+                action: action.to_string(),
+                status,
+                success: status.is_some_and(|status| (200..300).contains(&status)),
+                latency,
+                attempts,
+            });
+        }
+
+        match result {
+            Ok(ref response) => {
+                span.record("status", response.status().as_u16());
+                tracing::info!(
+                    parent: &span,
+                    "ChatGuru {} finished with status {} in {}ms",
+                    action,
+                    response.status(),
+                    latency_ms
+                );
+            }
+            Err(ref e) => {
+                tracing::warn!(parent: &span, "ChatGuru {} failed in {}ms: {}", action, latency_ms, e);
+            }
+        }
+
+        result
+    }
+
+    /// Laço de tentativas de [`post_action`](Self::post_action), retornando o
+    /// resultado e o número de tentativas feitas
+    async fn post_action_attempts(
+        &self,
+        request: &ChatGuruRequest,
+        phone_id: &str,
+        request_id: &str,
+    ) -> (Result<reqwest::Response>, u32) {
+        let action = request.action();
+        let token = match self.credentials.token().await {
+            Ok(token) => token,
+            Err(e) => {
+                tracing::error!("Failed to obtain ChatGuru API token: {}", e);
+                return (Err(e), 0);
+            }
+        };
+        let retry_policy = request
+            .overrides
+            .retry_policy
+            .as_ref()
+            .unwrap_or(&self.retry_policy);
+        let mut attempt = 1;
+
+        loop {
+            // Descartada sem resultado (future cancelado), libera a requisição de teste
+            let permit = match self.circuit_breaker {
+                Some(ref breaker) => match breaker.permit() {
+                    Some(permit) => Some(permit),
+                    None => {
+                        tracing::warn!("ChatGuru circuit breaker open, skipping {}", action);
+                        return (Err(ChatGuruError::CircuitOpen), attempt - 1);
+                    }
+                },
+                None => None,
+            };
+
+            if let Some(ref limiter) = self.rate_limiter {
+                limiter
+                    .acquire(&format!(
+                        "{}:{}",
+                        request.account_id(&self.account_id),
+                        phone_id
+                    ))
+                    .await;
+            }
+
+            let result = self.send_once(&token, request, phone_id, request_id).await;
+            if let Ok(ref response) = result {
+                // Token recusado: busca um novo na próxima chamada (rotação)
+                if matches!(response.status().as_u16(), 401 | 403) {
+                    self.credentials.invalidate();
+                }
+            }
+            let outcome = RetryOutcome::from_reqwest(&result);
+
+            if let (Some(breaker), Some(permit)) = (&self.circuit_breaker, permit) {
+                let previous = breaker.state();
+                permit.record(&outcome);
+
+                let current = breaker.state();
+                if current != previous {
+                    if let Some(ref metrics) = self.metrics {
+                        metrics.record_circuit_state(current);
+                    }
+                }
+            }
+
+            let retry = if is_send_action(action) {
+                retry_policy.should_retry_send(attempt, &outcome)
+            } else {
+                retry_policy.should_retry(attempt, &outcome)
+            };
+            // Retry-After acima do intervalo máximo: falha agora com RateLimited
+            let delay = retry
+                .then(|| retry_policy.next_delay(attempt, retry::retry_after(&result)))
+                .flatten();
+            let Some(delay) = delay else {
+                let result = result.map_err(|e| {
+                    ChatGuruError::NetworkError(format!(
+                        "{} request failed: {}",
+                        action,
+                        describe_error(e)
+                    ))
+                });
+                return (result, attempt);
+            };
+
+            tracing::warn!(
+                "ChatGuru {} attempt {}/{} failed ({:?}), retrying in {}ms",
+                action,
+                attempt,
+                retry_policy.attempts(),
+                outcome,
+                delay.as_millis()
+            );
+
+            if let Some(ref metrics) = self.metrics {
+                metrics.record_retry(action, attempt);
+            }
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Registra a chamada que seria feita e retorna uma resposta de sucesso
+    /// sintética, sem acessar a rede (modo dry run)
+    ///
+    /// O token vai mascarado e o `chat_number` como em
+    /// [`PhoneNumber::masked`]; os demais parâmetros aparecem como seriam enviados.
+    fn dry_run_response(
+        &self,
+        request: &ChatGuruRequest,
+        phone_id: &str,
+        request_id: &str,
+    ) -> reqwest::Response {
+        let mut params = vec![
+            "key=***".to_string(),
+            format!("account_id={}", request.account_id(&self.account_id)),
+            format!("phone_id={}", phone_id),
+            format!("action={}", request.action()),
+        ];
+        params.extend(
+            request
+                .params
+                .iter()
+                .map(|(name, value)| match name.as_str() {
+                    "chat_number" => {
+                        format!("{}={}", name, PhoneNumber::from(value.as_str()).masked())
+                    }
+                    _ => format!("{}={}", encode_component(name), encode_component(value)),
+                }),
+        );
+
+        tracing::info!(
+            request_id,
+            "ChatGuru dry run, not sent: POST {} ({:?}) {}",
+            redact_url(self.endpoint.url()),
+            self.request_mode,
+            params.join("&")
+        );
+
+        synthetic_response(200, DRY_RUN_BODY.to_string(), request_id)
+    }
+
+    pub(super) async fn send_once(
+        &self,
+        token: &SecretString,
+        request: &ChatGuruRequest,
+        phone_id: &str,
+        request_id: &str,
+    ) -> reqwest::Result<reqwest::Response> {
+        let mut all_params: Vec<(&str, &str)> = vec![
+            ("key", token.expose_secret()),
+            ("account_id", request.account_id(&self.account_id)),
+            ("phone_id", phone_id),
+            ("action", request.action()),
+        ];
+        all_params.extend(
+            request
+                .params
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str())),
+        );
+
+        let base_url = self.endpoint.url();
+
+        let encoded = encode_params(all_params);
+
+        let builder = match self.request_mode {
+            RequestMode::FormBody => self
+                .client
+                .post(base_url)
+                .header(reqwest::header::CONTENT_TYPE, FORM_CONTENT_TYPE)
+                .body(encoded),
+            RequestMode::QueryString => self.client.post(format!("{}?{}", base_url, encoded)),
+        };
+
+        let builder = builder
+            .header(REQUEST_ID_HEADER, request_id)
+            .headers(request.headers.clone());
+        match request.overrides.timeout {
+            Some(timeout) => builder.timeout(timeout).send().await,
+            None => builder.send().await,
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::error::ChatGuruError;
+use crate::types::custom_fields::fold_accent;
+use serde::{Deserialize, Deserializer};
+use std::time::Duration;
+
+/// Valor do campo `result` das respostas da API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ApiResult {
+    /// `"success"`
+    Success,
+    /// `"error"`
+    Error,
+    /// Valor não reconhecido (ou campo ausente)
+    Other(String),
+}
+
+/// Corpo JSON das respostas da API do ChatGuru
+///
+/// Formato conhecido:
+///
+/// ```text
+/// {"code": 200, "result": "success", "description": "..."}
+/// {"code": 400, "result": "error", "description": "Chat não encontrado"}
+/// {"result": "success", "chat_add_id": "...", "chat_add_status": "pending", ...}
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct ApiResponse {
+    /// Código de retorno (número ou texto)
+    #[serde(default, deserialize_with = "code_as_string")]
+    pub code: Option<String>,
+    /// `success` ou `error`
+    #[serde(default)]
+    pub result: Option<String>,
+    /// Descrição do resultado
+    #[serde(default, alias = "message")]
+    pub description: Option<String>,
+    /// Situação de um `chat_add` (`pending`, `done`, `error`, ...)
+    #[serde(default)]
+    pub chat_add_status: Option<String>,
+    /// Descrição da situação do `chat_add`
+    #[serde(default)]
+    pub chat_add_status_description: Option<String>,
+}
+
+fn code_as_string<'de, D>(deserializer: D) -> std::result::Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) if !s.is_empty() => Some(s),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    })
+}
+
+impl ApiResponse {
+    /// Interpreta o corpo da resposta; `None` se não for um objeto JSON
+    pub fn parse(body: &str) -> Option<Self> {
+        match serde_json::from_str::<serde_json::Value>(body).ok()? {
+            value @ serde_json::Value::Object(_) => serde_json::from_value(value).ok(),
+            _ => None,
+        }
+    }
+
+    /// Resultado informado pela API
+    pub fn result(&self) -> ApiResult {
+        match self.result.as_deref().map(str::to_lowercase).as_deref() {
+            Some("success") => ApiResult::Success,
+            Some("error") => ApiResult::Error,
+            other => ApiResult::Other(other.unwrap_or_default().to_string()),
+        }
+    }
+
+    /// Indica se a resposta representa uma falha, mesmo com HTTP 2xx
+    pub fn is_error(&self) -> bool {
+        self.result() == ApiResult::Error
+            || self
+                .chat_add_status
+                .as_deref()
+                .is_some_and(|status| status.eq_ignore_ascii_case("error"))
+    }
+
+    /// Descrição mais específica disponível
+    pub fn description(&self) -> Option<&str> {
+        self.description
+            .as_deref()
+            .filter(|d| !d.is_empty())
+            .or(self.chat_add_status_description.as_deref())
+    }
+
+    /// Indica se a API respondeu que o chat não existe
+    pub fn is_chat_not_found(&self) -> bool {
+        self.description().is_some_and(|description| {
+            let description = fold(description);
+            description.starts_with("chat nao encontrado")
+                || description.starts_with("chat nao existe")
+        })
+    }
+
+    /// Converte a resposta de erro na variante de [`ChatGuruError`] correspondente
+    pub fn into_error(
+        self,
+        status: u16,
+        raw_body: String,
+        retry_after: Option<Duration>,
+    ) -> ChatGuruError {
+        if status == 429 {
+            return ChatGuruError::RateLimited { retry_after };
+        }
+
+        let description = self
+            .description()
+            .map(String::from)
+            .unwrap_or_else(|| raw_body.trim().to_string());
+
+        if status == 401 || status == 403 {
+            return ChatGuruError::Unauthorized(description);
+        }
+        if self.is_chat_not_found() {
+            return ChatGuruError::ChatNotFound(description);
+        }
+
+        ChatGuruError::ApiError {
+            status,
+            code: self.code,
+            description,
+            raw_body,
+        }
+    }
+}
+
+/// Converte para minúsculas e remove acentos (ver [`fold_accent`])
+fn fold(text: &str) -> String {
+    text.trim()
+        .chars()
+        .flat_map(char::to_lowercase)
+        .map(fold_accent)
+        .collect()
+}
@@ -0,0 +1,252 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Resultado de uma tentativa, usado para decidir se ela deve ser repetida
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryOutcome {
+    /// Falha de rede (sem resposta HTTP)
+    Network {
+        /// A requisição excedeu o timeout
+        timeout: bool,
+        /// A conexão não pôde ser estabelecida
+        connect: bool,
+    },
+    /// Resposta HTTP com o status informado
+    Status(u16),
+}
+
+impl RetryOutcome {
+    pub(crate) fn from_reqwest(result: &reqwest::Result<reqwest::Response>) -> Self {
+        match result {
+            Ok(response) => RetryOutcome::Status(response.status().as_u16()),
+            Err(e) => RetryOutcome::Network {
+                timeout: e.is_timeout(),
+                connect: e.is_connect(),
+            },
+        }
+    }
+
+    /// Indica se a tentativa terminou com uma resposta de sucesso (2xx)
+    pub fn is_success(&self) -> bool {
+        matches!(self, RetryOutcome::Status(status) if (200..300).contains(status))
+    }
+}
+
+type RetryPredicate = Arc<dyn Fn(&RetryOutcome) -> bool + Send + Sync>;
+
+/// Política de retry com backoff exponencial e jitter
+///
+/// Por padrão faz até 3 tentativas. Leituras repetem falhas de rede,
+/// respostas `429 Too Many Requests` e erros `5xx` ([`is_transient`](Self::is_transient));
+/// envios (`message_send`, `message_file_send`) só repetem quando a API com
+/// certeza não recebeu a mensagem: falha de conexão, `429` e `503`
+/// ([`is_safe_to_resend`](Self::is_safe_to_resend)). Um timeout de leitura
+/// depois de a API aceitar o envio nunca gera uma segunda mensagem. O
+/// intervalo entre tentativas dobra a cada falha (a partir de `base_delay`,
+/// limitado a `max_delay`) e recebe um jitter aleatório para evitar rajadas
+/// sincronizadas entre instâncias.
+///
+/// Quando a resposta traz o header `Retry-After` (em segundos), ele é usado
+/// como intervalo mínimo; se ele passar de `max_delay`, a chamada não é
+/// repetida e falha com `RateLimited`, informando o `retry_after`.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use chatguru::client::{RetryOutcome, RetryPolicy};
+///
+/// let policy = RetryPolicy::new(5)
+///     .base_delay(Duration::from_millis(500))
+///     .retry_on(|outcome| matches!(outcome, RetryOutcome::Status(503)));
+///
+/// let client = ChatGuruClient::builder()
+///     // ...
+///     .retry_policy(policy)
+///     .build()?;
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    /// Predicado próprio; sem ele, vale o padrão de leituras ou de envios
+    retry_on: Option<RetryPredicate>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RetryPolicy {
+    /// Cria uma política com o número máximo de tentativas (incluindo a primeira)
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            retry_on: None,
+        }
+    }
+
+    /// Política que nunca repete (apenas uma tentativa)
+    pub fn disabled() -> Self {
+        Self::new(1)
+    }
+
+    /// Define o número máximo de tentativas (incluindo a primeira)
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Define o intervalo antes da segunda tentativa (padrão: 200ms)
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Define o intervalo máximo entre tentativas (padrão: 5s)
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Substitui o predicado que decide quais resultados são repetidos
+    ///
+    /// O predicado vale também para os envios: repetir timeouts de
+    /// `message_send` pode entregar a mensagem duas vezes.
+    pub fn retry_on<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&RetryOutcome) -> bool + Send + Sync + 'static,
+    {
+        self.retry_on = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Número máximo de tentativas configurado
+    pub fn attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Predicado padrão das leituras: falhas de rede, 429 e 5xx
+    pub fn is_transient(outcome: &RetryOutcome) -> bool {
+        match outcome {
+            RetryOutcome::Network { .. } => true,
+            RetryOutcome::Status(status) => *status == 429 || (500..600).contains(status),
+        }
+    }
+
+    /// Predicado padrão dos envios: só falhas em que a API não recebeu a chamada
+    ///
+    /// Falha de conexão, `429` e `503`. Timeouts e demais `5xx` ficam de
+    /// fora: a mensagem pode ter sido aceita antes da falha.
+    pub fn is_safe_to_resend(outcome: &RetryOutcome) -> bool {
+        match outcome {
+            RetryOutcome::Network { connect, .. } => *connect,
+            RetryOutcome::Status(status) => matches!(status, 429 | 503),
+        }
+    }
+
+    /// Verifica se a tentativa `attempt` (começando em 1) de uma leitura deve ser repetida
+    pub fn should_retry(&self, attempt: u32, outcome: &RetryOutcome) -> bool {
+        self.retry_with(attempt, outcome, Self::is_transient)
+    }
+
+    /// Como [`should_retry`](Self::should_retry), para envios ao contato
+    pub fn should_retry_send(&self, attempt: u32, outcome: &RetryOutcome) -> bool {
+        self.retry_with(attempt, outcome, Self::is_safe_to_resend)
+    }
+
+    fn retry_with(
+        &self,
+        attempt: u32,
+        outcome: &RetryOutcome,
+        default: fn(&RetryOutcome) -> bool,
+    ) -> bool {
+        let retryable = match self.retry_on {
+            Some(ref predicate) => predicate(outcome),
+            None => default(outcome),
+        };
+        attempt < self.max_attempts && !outcome.is_success() && retryable
+    }
+
+    /// Intervalo antes da próxima tentativa, ou `None` se não vale esperar
+    ///
+    /// Retorna `None` quando o `Retry-After` pedido pela API passa de
+    /// `max_delay`: a chamada deve falhar agora, com `RateLimited`.
+    pub fn next_delay(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if retry_after.is_some_and(|retry_after| retry_after > self.max_delay) {
+            return None;
+        }
+        Some(self.delay_for(attempt, retry_after))
+    }
+
+    /// Calcula o intervalo antes da próxima tentativa, limitado a `max_delay`
+    ///
+    /// `retry_after` é o valor do header `Retry-After`, quando presente.
+    pub fn delay_for(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_delay);
+
+        // Jitter: entre 50% e 100% do backoff calculado
+        let jittered = backoff.mul_f64(0.5 + jitter_fraction() * 0.5);
+
+        match retry_after {
+            Some(retry_after) => jittered.max(retry_after).min(self.max_delay),
+            None => jittered,
+        }
+    }
+}
+
+/// Lê o header `Retry-After` (em segundos) do resultado de uma tentativa
+pub(crate) fn retry_after(result: &reqwest::Result<reqwest::Response>) -> Option<Duration> {
+    retry_after_header(result.as_ref().ok()?)
+}
+
+/// Lê o header `Retry-After` (em segundos) de uma resposta
+pub(crate) fn retry_after_header(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Número pseudoaleatório em [0, 1) para o jitter (xorshift semeado pelo relógio)
+fn jitter_fraction() -> f64 {
+    static STATE: AtomicU64 = AtomicU64::new(0);
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or_default();
+
+    let mut x = STATE.fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed) ^ nanos;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+
+    (x >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,243 @@
+use crate::types::PhoneNumber;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Por quanto tempo uma linha com falha fica fora da rotação por padrão
+pub const DEFAULT_LINE_COOL_DOWN: Duration = Duration::from_secs(300);
+
+/// Como escolher entre as linhas de um grupo do [`PhoneLineRouter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineSelection {
+    /// Alterna entre as linhas disponíveis, distribuindo o volume (padrão)
+    #[default]
+    RoundRobin,
+    /// Usa a primeira linha disponível, na ordem; as demais são reserva
+    Failover,
+}
+
+#[derive(Debug)]
+enum Matcher {
+    AreaCodes(Vec<String>),
+    Campaign(String),
+}
+
+impl Matcher {
+    fn matches(&self, phone: &PhoneNumber, campaign: Option<&str>) -> bool {
+        match self {
+            Matcher::AreaCodes(ddds) => phone
+                .ddd()
+                .is_some_and(|ddd| ddds.iter().any(|candidate| candidate == ddd)),
+            Matcher::Campaign(name) => {
+                campaign.is_some_and(|campaign| campaign.trim().eq_ignore_ascii_case(name))
+            }
+        }
+    }
+}
+
+/// Grupo de linhas de uma regra, com o cursor do round-robin
+#[derive(Debug)]
+struct LinePool {
+    phone_ids: Vec<String>,
+    cursor: AtomicUsize,
+}
+
+impl LinePool {
+    fn new<I, S>(phone_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            phone_ids: phone_ids
+                .into_iter()
+                .map(Into::into)
+                .filter(|phone_id: &String| !phone_id.is_empty())
+                .collect(),
+            cursor: AtomicUsize::new(0),
+        }
+    }
+}
+
+/// Escolhe a linha (`phone_id`) de envio de cada mensagem por regras
+///
+/// As regras são avaliadas na ordem em que foram adicionadas: por DDD do
+/// destinatário ([`area_codes`](Self::area_codes)) ou pela campanha
+/// ([`campaign`](Self::campaign), informada com
+/// [`RequestOptions::campaign`](super::RequestOptions::campaign)). A
+/// primeira regra que casar e tiver linha disponível decide; sem regra
+/// aplicável, usa as [`default_lines`](Self::default_lines) e, se também não
+/// houver, a linha padrão do cliente.
+///
+/// Dentro de cada grupo as linhas são alternadas ou usadas em ordem, conforme
+/// a [`LineSelection`]. Configurado no cliente, o roteador tira da rotação por
+/// [`cool_down`](Self::cool_down) a linha cujo envio falhar com erro
+/// transitório (rede, `5xx`, limite de requisições); use
+/// [`mark_down`](Self::mark_down) para retirar uma linha manualmente (ex:
+/// ao receber o aviso de linha desconectada).
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use std::sync::Arc;
+/// use chatguru::client::{LineSelection, PhoneLineRouter, RequestOptions};
+///
+/// let router = PhoneLineRouter::new()
+///     .area_codes(["11", "12", "13"], ["linha_sp_1", "linha_sp_2"])
+///     .campaign("Black Friday", ["linha_bf"])
+///     .default_lines(["linha_geral_1", "linha_geral_2", "linha_geral_3"])
+///     .selection(LineSelection::RoundRobin);
+///
+/// let client = ChatGuruClient::builder()
+///     // ...
+///     .phone_line_router(Arc::new(router))
+///     .build()?;
+///
+/// // Sem phone_id explícito, a linha vem do roteador
+/// client.send_confirmation_message("5511999999999", None, "Olá!").await?;
+///
+/// let options = RequestOptions::new().campaign("Black Friday");
+/// client
+///     .send_confirmation_message_with_options("5521988887777", "Oferta!", &options)
+///     .await?;
+/// ```
+#[derive(Debug)]
+pub struct PhoneLineRouter {
+    rules: Vec<(Matcher, LinePool)>,
+    default_pool: Option<LinePool>,
+    selection: LineSelection,
+    cool_down: Duration,
+    down_until: Mutex<HashMap<String, Instant>>,
+}
+
+impl Default for PhoneLineRouter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoneLineRouter {
+    /// Roteador sem regras (usa sempre a linha padrão do cliente)
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            default_pool: None,
+            selection: LineSelection::default(),
+            cool_down: DEFAULT_LINE_COOL_DOWN,
+            down_until: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Envia para números com os DDDs informados pelas linhas `phone_ids`
+    pub fn area_codes<D, P, S>(mut self, ddds: D, phone_ids: P) -> Self
+    where
+        D: IntoIterator<Item = S>,
+        P: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let ddds = ddds
+            .into_iter()
+            .map(|ddd| ddd.into().trim().to_string())
+            .collect();
+        self.rules
+            .push((Matcher::AreaCodes(ddds), LinePool::new(phone_ids)));
+        self
+    }
+
+    /// Envia as mensagens da campanha (comparada sem diferenciar maiúsculas)
+    /// pelas linhas `phone_ids`
+    pub fn campaign<P, S>(mut self, campaign: impl Into<String>, phone_ids: P) -> Self
+    where
+        P: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let campaign = campaign.into().trim().to_string();
+        self.rules
+            .push((Matcher::Campaign(campaign), LinePool::new(phone_ids)));
+        self
+    }
+
+    /// Linhas usadas quando nenhuma regra se aplica
+    pub fn default_lines<P, S>(mut self, phone_ids: P) -> Self
+    where
+        P: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.default_pool = Some(LinePool::new(phone_ids));
+        self
+    }
+
+    /// Como escolher entre as linhas de um grupo (padrão: round-robin)
+    pub fn selection(mut self, selection: LineSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Por quanto tempo uma linha com falha fica fora da rotação (padrão: 5 minutos)
+    pub fn cool_down(mut self, cool_down: Duration) -> Self {
+        self.cool_down = cool_down;
+        self
+    }
+
+    /// Linha para enviar ao número, ou `None` para usar a linha padrão do cliente
+    pub fn route(&self, phone: &PhoneNumber, campaign: Option<&str>) -> Option<String> {
+        self.rules
+            .iter()
+            .filter(|(matcher, _)| matcher.matches(phone, campaign))
+            .map(|(_, pool)| pool)
+            .chain(self.default_pool.as_ref())
+            .find_map(|pool| self.pick(pool))
+    }
+
+    /// Retira a linha da rotação pelo `cool_down` configurado
+    pub fn mark_down(&self, phone_id: &str) {
+        tracing::warn!(
+            "Phone line {} taken out of rotation for {}s",
+            phone_id,
+            self.cool_down.as_secs()
+        );
+        self.down_until()
+            .insert(phone_id.to_string(), Instant::now() + self.cool_down);
+    }
+
+    /// Devolve a linha à rotação antes do fim do `cool_down`
+    pub fn mark_up(&self, phone_id: &str) {
+        self.down_until().remove(phone_id);
+    }
+
+    /// Indica se a linha está na rotação
+    pub fn is_available(&self, phone_id: &str) -> bool {
+        let mut down_until = self.down_until();
+        match down_until.get(phone_id) {
+            Some(until) if *until > Instant::now() => false,
+            Some(_) => {
+                down_until.remove(phone_id);
+                true
+            }
+            None => true,
+        }
+    }
+
+    fn pick(&self, pool: &LinePool) -> Option<String> {
+        let count = pool.phone_ids.len();
+        if count == 0 {
+            return None;
+        }
+
+        let start = match self.selection {
+            LineSelection::RoundRobin => pool.cursor.fetch_add(1, Ordering::Relaxed),
+            LineSelection::Failover => 0,
+        };
+        (0..count)
+            .map(|offset| &pool.phone_ids[(start + offset) % count])
+            .find(|phone_id| self.is_available(phone_id))
+            .cloned()
+    }
+
+    fn down_until(&self) -> std::sync::MutexGuard<'_, HashMap<String, Instant>> {
+        self.down_until
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
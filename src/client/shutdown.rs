@@ -0,0 +1,201 @@
+use super::{ChatGuruClient, FlushReport};
+use crate::error::Result;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// Sinal de encerramento compartilhado por um cliente e seus clones
+///
+/// Obtido com [`ChatGuruClient::shutdown_handle`]. Os workers da outbox
+/// param sozinhos quando o sinal é disparado; tarefas próprias podem
+/// aguardar [`triggered`](Self::triggered) para fazer o mesmo. O handle
+/// também conta as requisições em andamento, esperadas por
+/// [`ChatGuruClient::shutdown`].
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// let shutdown = client.shutdown_handle();
+///
+/// tokio::spawn(async move {
+///     loop {
+///         tokio::select! {
+///             _ = shutdown.triggered() => break,
+///             _ = sincronizar(&client) => {}
+///         }
+///     }
+/// });
+/// ```
+#[derive(Clone, Default)]
+pub struct Shutdown {
+    state: Arc<ShutdownState>,
+}
+
+#[derive(Default)]
+struct ShutdownState {
+    triggered: AtomicBool,
+    trigger: Notify,
+    active: AtomicUsize,
+    idle: Notify,
+}
+
+impl fmt::Debug for Shutdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shutdown")
+            .field("triggered", &self.is_triggered())
+            .field("active", &self.active())
+            .finish()
+    }
+}
+
+impl Shutdown {
+    /// Dispara o sinal de encerramento (chamadas repetidas não têm efeito)
+    pub fn trigger(&self) {
+        if !self.state.triggered.swap(true, Ordering::SeqCst) {
+            self.state.trigger.notify_waiters();
+        }
+    }
+
+    /// Indica se o encerramento já foi sinalizado
+    pub fn is_triggered(&self) -> bool {
+        self.state.triggered.load(Ordering::SeqCst)
+    }
+
+    /// Aguarda o sinal de encerramento
+    pub async fn triggered(&self) {
+        let notified = self.state.trigger.notified();
+        if self.is_triggered() {
+            return;
+        }
+        notified.await;
+    }
+
+    /// Quantidade de requisições e workers em andamento
+    pub fn active(&self) -> usize {
+        self.state.active.load(Ordering::SeqCst)
+    }
+
+    /// Registra uma atividade em andamento até o guard ser descartado
+    pub(crate) fn track(&self) -> ActivityGuard {
+        self.state.active.fetch_add(1, Ordering::SeqCst);
+        ActivityGuard {
+            state: self.state.clone(),
+        }
+    }
+
+    /// Aguarda não haver atividades em andamento, por até `timeout`
+    ///
+    /// Retorna `false` se o prazo acabou antes.
+    async fn wait_idle(&self, timeout: Duration) -> bool {
+        let idle = async {
+            loop {
+                let notified = self.state.idle.notified();
+                if self.active() == 0 {
+                    return;
+                }
+                notified.await;
+            }
+        };
+        tokio::time::timeout(timeout, idle).await.is_ok()
+    }
+}
+
+/// Mantém uma atividade contada em [`Shutdown::active`] enquanto existir
+pub(crate) struct ActivityGuard {
+    state: Arc<ShutdownState>,
+}
+
+impl Drop for ActivityGuard {
+    fn drop(&mut self) {
+        if self.state.active.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.state.idle.notify_waiters();
+        }
+    }
+}
+
+/// Resultado de [`ChatGuruClient::shutdown`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// Itens enviados (ou que falharam) no flush final da outbox; `None` se
+    /// o prazo acabou antes do flush terminar
+    pub flushed: Option<FlushReport>,
+    /// Requisições e workers ainda em andamento quando o prazo acabou
+    pub abandoned: usize,
+}
+
+impl ShutdownReport {
+    /// Indica se tudo terminou dentro do prazo e a outbox foi esvaziada
+    pub fn is_clean(&self) -> bool {
+        self.abandoned == 0 && self.flushed.as_ref().is_some_and(FlushReport::is_complete)
+    }
+}
+
+impl ChatGuruClient {
+    /// Handle do sinal de encerramento compartilhado por este cliente e seus clones
+    pub fn shutdown_handle(&self) -> Shutdown {
+        self.shutdown.clone()
+    }
+
+    /// Encerra o cliente sem perder mensagens, respeitando o prazo informado
+    ///
+    /// Sinaliza o encerramento aos workers iniciados por
+    /// [`start_outbox_worker`](Self::start_outbox_worker) e
+    /// [`AnnotationBuffer::start`](super::AnnotationBuffer::start), que fazem
+    /// um último flush e param; aguarda as requisições em andamento (de
+    /// todos os clones) e envia o que ainda restar na outbox. O que não
+    /// terminar até `deadline` é abandonado e contado no relatório; com um
+    /// [`OutboxStore`](super::OutboxStore) persistente, itens não enviados
+    /// continuam guardados para o próximo processo.
+    ///
+    /// O cliente continua utilizável depois, mas novos workers param logo
+    /// após o primeiro flush.
+    ///
+    /// # Erros
+    ///
+    /// * Os erros de [`flush`](Self::flush) ao ler ou atualizar a outbox
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// use std::time::Duration;
+    ///
+    /// // Cloud Run envia SIGTERM e espera até 10s
+    /// tokio::signal::ctrl_c().await?;
+    /// let report = client.shutdown(Duration::from_secs(8)).await?;
+    /// if !report.is_clean() {
+    ///     tracing::warn!("Encerramento incompleto: {:?}", report);
+    /// }
+    /// ```
+    pub async fn shutdown(&self, deadline: Duration) -> Result<ShutdownReport> {
+        let started_at = Instant::now();
+        tracing::info!(
+            "ChatGuru client shutting down ({}ms deadline)",
+            deadline.as_millis()
+        );
+
+        self.shutdown.trigger();
+        let drained = self.shutdown.wait_idle(deadline).await;
+
+        let remaining = deadline.saturating_sub(started_at.elapsed());
+        let flushed = match tokio::time::timeout(remaining, self.flush()).await {
+            Ok(report) => Some(report?),
+            Err(_) => None,
+        };
+
+        let report = ShutdownReport {
+            flushed,
+            abandoned: if drained { 0 } else { self.shutdown.active() },
+        };
+        if report.is_clean() {
+            tracing::info!(
+                "ChatGuru client shut down in {}ms",
+                started_at.elapsed().as_millis()
+            );
+        } else {
+            tracing::warn!("ChatGuru client shut down with pending work: {:?}", report);
+        }
+        Ok(report)
+    }
+}
@@ -1,3 +1,5 @@
+use crate::client::response::ApiResponse;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Erros específicos do cliente ChatGuru
@@ -8,8 +10,32 @@ pub enum ChatGuruError {
     NetworkError(String),
 
     /// Erro retornado pela API do ChatGuru
-    #[error("ChatGuru API error: {0}")]
-    ApiError(String),
+    #[error("ChatGuru API error (status {status}): {description}")]
+    ApiError {
+        /// Status HTTP da resposta
+        status: u16,
+        /// Código de erro informado pela API, quando presente
+        code: Option<String>,
+        /// Descrição do erro
+        description: String,
+        /// Corpo completo da resposta
+        raw_body: String,
+    },
+
+    /// A API recusou a requisição por excesso de chamadas (HTTP 429)
+    #[error("ChatGuru rate limit exceeded")]
+    RateLimited {
+        /// Intervalo sugerido pelo header `Retry-After`, quando presente
+        retry_after: Option<Duration>,
+    },
+
+    /// O chat não existe para o número informado
+    #[error("ChatGuru chat not found: {0}")]
+    ChatNotFound(String),
+
+    /// Token ou conta inválidos (HTTP 401/403)
+    #[error("ChatGuru unauthorized: {0}")]
+    Unauthorized(String),
 
     /// Erro de serialização/deserialização
     #[error("Serialization error: {0}")]
@@ -22,6 +48,93 @@ pub enum ChatGuruError {
     /// Erro interno do cliente
     #[error("Internal error: {0}")]
     InternalError(String),
+
+    /// Prazo esgotado aguardando um evento (ex: confirmação de entrega)
+    #[error("Timed out: {0}")]
+    Timeout(String),
+
+    /// Circuit breaker aberto: a API está indisponível e a chamada não foi feita
+    #[error("Circuit breaker open: ChatGuru API calls are suspended")]
+    CircuitOpen,
+
+    /// Envio recusado pela [`SendPolicy`](crate::client::SendPolicy) do cliente
+    #[error("Send policy violation: {0}")]
+    PolicyViolation(crate::client::PolicyViolation),
+
+    /// O contato pediu para não receber mensagens ([`ConsentRegistry`](crate::client::ConsentRegistry))
+    #[error("Contact {0} opted out of messages")]
+    OptedOut(String),
+
+    /// Mensagem recusada pelo [`ContentFilter`](crate::client::ContentFilter)
+    #[error("Message blocked by content filter: {reason}")]
+    Blocked {
+        /// Motivo informado pelo filtro
+        reason: String,
+    },
+}
+
+impl ChatGuruError {
+    /// Classifica uma resposta de erro da API
+    ///
+    /// Interpreta o corpo no formato `{code, result, description}` da API
+    /// (quando for JSON) e usa o status HTTP para escolher a variante mais
+    /// específica.
+    pub fn from_response(
+        status: u16,
+        body: impl Into<String>,
+        retry_after: Option<Duration>,
+    ) -> Self {
+        let raw_body = body.into();
+
+        match ApiResponse::parse(&raw_body) {
+            Some(response) => response.into_error(status, raw_body, retry_after),
+            // Corpo não-JSON (ex: páginas de erro de proxy)
+            None if is_chat_not_found_message(&raw_body) => {
+                ChatGuruError::ChatNotFound(raw_body.trim().to_string())
+            }
+            None => ApiResponse::default().into_error(status, raw_body, retry_after),
+        }
+    }
+
+    /// Indica se vale a pena repetir a operação que gerou o erro
+    ///
+    /// Falhas de rede, limite de requisições, circuit breaker aberto e erros
+    /// `5xx` da API são transitórios; os demais não mudam com uma nova tentativa.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ChatGuruError::NetworkError(_)
+            | ChatGuruError::RateLimited { .. }
+            | ChatGuruError::CircuitOpen => true,
+            ChatGuruError::ApiError { status, .. } => (500..600).contains(status),
+            _ => false,
+        }
+    }
+
+    /// Indica se o erro é de chat inexistente (comum para contatos inativos)
+    pub fn is_chat_not_found(&self) -> bool {
+        matches!(self, ChatGuruError::ChatNotFound(_))
+    }
+
+    /// Status HTTP da resposta que gerou o erro, quando houver
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ChatGuruError::ApiError { status, .. } => Some(*status),
+            ChatGuruError::RateLimited { .. } => Some(429),
+            _ => None,
+        }
+    }
+}
+
+/// Mensagens usadas pela API para chats inexistentes, em corpos que não são JSON
+///
+/// Aceita "Chat não encontrado"/"Chat não existe" mesmo com o "não" sem
+/// acento ou com encoding quebrado.
+fn is_chat_not_found_message(text: &str) -> bool {
+    let text = text.to_lowercase();
+    text.match_indices("chat n").any(|(index, _)| {
+        let rest: String = text[index..].chars().take(24).collect();
+        rest.contains("encontrad") || rest.contains("exist")
+    })
 }
 
 /// Result type para operações do ChatGuru
@@ -0,0 +1,234 @@
+//! Formatação de texto do WhatsApp
+//!
+//! Funções para o markdown do WhatsApp (`*negrito*`, `_itálico_`,
+//! `~tachado~`, ```` ```monoespaçado``` ````) e um [`MessageBuilder`] para
+//! compor mensagens de confirmação em seções, com o texto dinâmico escapado
+//! para não quebrar a formatação.
+//!
+//! O WhatsApp não tem caractere de escape: [`escape`] insere um espaço de
+//! largura zero (U+200B) após cada marcador, o que impede que ele forme um
+//! par sem alterar o texto exibido.
+//!
+//! # Exemplo
+//!
+//! ```rust,ignore
+//! use chatguru::format::{bold, MessageBuilder};
+//!
+//! let message = MessageBuilder::new()
+//!     .heading("✅ Pedido confirmado")
+//!     .field("Cliente", &payload.nome)
+//!     .field("Tarefa", &task_id)
+//!     .blank_line()
+//!     .bullet_list(["Prazo: 2 dias úteis", "Acompanhe pelo link abaixo"])
+//!     .text(&link)
+//!     .build();
+//!
+//! client.send_confirmation_message(&phone, None, &message).await?;
+//! ```
+
+use std::fmt;
+
+/// Caracteres que iniciam ou terminam formatação no WhatsApp
+const MARKERS: &[char] = &['*', '_', '~', '`'];
+
+/// Espaço de largura zero, usado para neutralizar marcadores
+const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// Neutraliza os marcadores de formatação (`*`, `_`, `~`, `` ` ``) do texto
+///
+/// Use em textos vindos do usuário ou de sistemas externos antes de
+/// combiná-los com formatação.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        escaped.push(c);
+        if MARKERS.contains(&c) {
+            escaped.push(ZERO_WIDTH_SPACE);
+        }
+    }
+    escaped
+}
+
+/// Aplica um marcador ao texto escapado
+///
+/// O WhatsApp só reconhece a formatação sem espaços junto aos marcadores,
+/// então os espaços das pontas ficam de fora. Texto vazio não é marcado.
+fn wrap(text: &str, marker: &str) -> String {
+    let start = text.len() - text.trim_start().len();
+    let end = text.trim_end().len();
+    if start >= end {
+        return text.to_string();
+    }
+
+    format!(
+        "{}{}{}{}{}",
+        &text[..start],
+        marker,
+        escape(&text[start..end]),
+        marker,
+        &text[end..]
+    )
+}
+
+/// `*negrito*`
+pub fn bold(text: &str) -> String {
+    wrap(text, "*")
+}
+
+/// `_itálico_`
+pub fn italic(text: &str) -> String {
+    wrap(text, "_")
+}
+
+/// `~tachado~`
+pub fn strikethrough(text: &str) -> String {
+    wrap(text, "~")
+}
+
+/// ```` ```monoespaçado``` ````
+///
+/// O conteúdo não é escapado (a formatação não se aplica dentro do bloco);
+/// crases triplas no texto são separadas para não fechar o bloco.
+pub fn monospace(text: &str) -> String {
+    format!("```{}```", text.replace("```", "`\u{200B}``"))
+}
+
+/// Lista com marcadores (`• item`), um item por linha
+pub fn bullet_list<I, S>(items: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    items
+        .into_iter()
+        .map(|item| format!("• {}", escape(item.as_ref())))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Lista numerada (`1. item`), um item por linha
+pub fn numbered_list<I, S>(items: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(i, item)| format!("{}. {}", i + 1, escape(item.as_ref())))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Citação (`> texto`) em cada linha do texto
+pub fn quote(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("> {}", escape(line)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Compõe uma mensagem formatada, linha a linha
+///
+/// Os métodos que recebem texto dinâmico ([`text`](Self::text),
+/// [`field`](Self::field), listas) escapam os marcadores; use
+/// [`raw`](Self::raw) para incluir texto já formatado. Linhas em branco
+/// consecutivas são reduzidas a uma e as das pontas são removidas.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageBuilder {
+    lines: Vec<String>,
+}
+
+impl MessageBuilder {
+    /// Cria uma mensagem vazia
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Título em negrito
+    pub fn heading(self, text: &str) -> Self {
+        self.raw(bold(text))
+    }
+
+    /// Parágrafo de texto, escapado
+    pub fn text(self, text: &str) -> Self {
+        self.raw(escape(text))
+    }
+
+    /// Texto já formatado, incluído sem escape
+    pub fn raw(mut self, text: impl Into<String>) -> Self {
+        self.lines.push(text.into());
+        self
+    }
+
+    /// Linha `*Rótulo:* valor`
+    pub fn field(self, label: &str, value: &str) -> Self {
+        let line = format!("{} {}", bold(&format!("{}:", label)), escape(value));
+        self.raw(line)
+    }
+
+    /// Lista com marcadores
+    pub fn bullet_list<I, S>(self, items: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.raw(bullet_list(items))
+    }
+
+    /// Lista numerada
+    pub fn numbered_list<I, S>(self, items: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.raw(numbered_list(items))
+    }
+
+    /// Citação
+    pub fn quote(self, text: &str) -> Self {
+        self.raw(quote(text))
+    }
+
+    /// Bloco monoespaçado
+    pub fn monospace(self, text: &str) -> Self {
+        self.raw(monospace(text))
+    }
+
+    /// Seção precedida de uma linha em branco, com título em negrito e corpo escapado
+    pub fn section(self, title: &str, body: &str) -> Self {
+        self.blank_line().heading(title).text(body)
+    }
+
+    /// Linha em branco entre seções
+    pub fn blank_line(self) -> Self {
+        self.raw(String::new())
+    }
+
+    /// Indica se nenhuma linha com conteúdo foi adicionada
+    pub fn is_empty(&self) -> bool {
+        self.lines.iter().all(|line| line.trim().is_empty())
+    }
+
+    /// Monta o texto final da mensagem
+    pub fn build(&self) -> String {
+        let mut output: Vec<&str> = Vec::with_capacity(self.lines.len());
+        for line in &self.lines {
+            let blank = line.trim().is_empty();
+            if blank && matches!(output.last(), None | Some(&"")) {
+                continue;
+            }
+            output.push(if blank { "" } else { line.as_str() });
+        }
+        while output.last().is_some_and(|last| last.is_empty()) {
+            output.pop();
+        }
+        output.join("\n")
+    }
+}
+
+impl fmt::Display for MessageBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.build())
+    }
+}
@@ -0,0 +1,53 @@
+//! Autenticação no Google Cloud pelo servidor de metadados
+//!
+//! Usado pelo publisher do Pub/Sub e pelo provider de credenciais do Secret
+//! Manager quando rodando no Cloud Run, GCE ou GKE.
+
+use crate::error::{ChatGuruError, Result};
+use crate::secret::{describe_error, SecretString};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Servidor de metadados do Google Cloud (Cloud Run, GCE, GKE)
+const METADATA_TOKEN_URL: &str =
+    "http://metadata.google.internal/computeMetadata/v1/instance/service-accounts/default/token";
+
+/// Margem antes da expiração em que o token do metadata é renovado
+pub(crate) const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+#[derive(Deserialize)]
+struct MetadataToken {
+    access_token: String,
+    expires_in: u64,
+}
+
+/// Busca o token OAuth da conta de serviço, com sua validade
+///
+/// # Erros
+///
+/// * `NetworkError` - o servidor de metadados não pôde ser acessado
+/// * `Unauthorized` - o servidor de metadados recusou o pedido
+/// * `SerializationError` - resposta em formato inesperado
+pub(crate) async fn metadata_token(client: &reqwest::Client) -> Result<(SecretString, Duration)> {
+    let response = client
+        .get(METADATA_TOKEN_URL)
+        .header("Metadata-Flavor", "Google")
+        .send()
+        .await
+        .map_err(|e| ChatGuruError::NetworkError(describe_error(e)))?;
+
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        return Err(ChatGuruError::Unauthorized(format!(
+            "Metadata server token request failed (status {})",
+            status.as_u16()
+        )));
+    }
+
+    let token: MetadataToken = serde_json::from_str(&body)?;
+    Ok((
+        SecretString::new(token.access_token),
+        Duration::from_secs(token.expires_in),
+    ))
+}
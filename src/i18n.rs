@@ -0,0 +1,302 @@
+//! Mensagens em vários idiomas (feature `i18n`)
+//!
+//! Um [`Localizer`] guarda o texto de cada mensagem por idioma (com a
+//! sintaxe de placeholders de [`templates`](crate::templates)).
+//! [`detect_locale`] descobre o idioma do contato pelo campo personalizado
+//! de idioma ou, sem ele, pelo texto da mensagem recebida. Com o localizer
+//! configurado em
+//! [`ChatGuruClientBuilder::localizer`](crate::ChatGuruClientBuilder::localizer),
+//! [`ChatGuruClient::send_localized`](crate::ChatGuruClient::send_localized)
+//! envia a mensagem no idioma do contexto.
+//!
+//! # Exemplo
+//!
+//! ```rust,ignore
+//! use chatguru::i18n::{detect_locale, Localizer, DEFAULT_LOCALE};
+//! use chatguru::templates::TemplateContext;
+//!
+//! let localizer = Localizer::new()
+//!     .message("pedido_confirmado", "pt-BR", "Olá {nome|cliente}! Seu pedido {pedido} foi confirmado ✅")
+//!     .message("pedido_confirmado", "es", "¡Hola {nome|cliente}! Tu pedido {pedido} fue confirmado ✅");
+//!
+//! let client = ChatGuruClient::builder()
+//!     // ...
+//!     .localizer(Arc::new(localizer))
+//!     .build()?;
+//!
+//! let context = TemplateContext::from_payload(&chatguru_payload)
+//!     .with("pedido", "1234")
+//!     .locale(detect_locale(&payload).unwrap_or_else(|| DEFAULT_LOCALE.to_string()));
+//! client.send_localized("5511999999999", "pedido_confirmado", &context).await?;
+//! ```
+
+use crate::error::{ChatGuruError, Result};
+use crate::templates::{MessageTemplate, TemplateContext};
+use crate::types::custom_fields::normalize_key;
+use crate::types::WebhookPayload;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Idioma padrão das mensagens
+pub const DEFAULT_LOCALE: &str = "pt-BR";
+
+/// Nomes do campo personalizado com o idioma do contato
+pub const LOCALE_FIELDS: &[&str] = &["idioma", "lingua", "language", "locale"];
+
+/// Nomes de idiomas aceitos no campo personalizado, já normalizados
+const LANGUAGE_NAMES: &[(&str, &[&str])] = &[
+    (
+        "pt-BR",
+        &[
+            "portugues",
+            "portuguese",
+            "portuguesbrasil",
+            "brasil",
+            "ptbr",
+            "pt",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "espanol",
+            "espanhol",
+            "spanish",
+            "castellano",
+            "castelhano",
+            "es",
+        ],
+    ),
+    ("en", &["english", "ingles", "en"]),
+];
+
+/// Palavras frequentes só em português
+const PORTUGUESE_WORDS: &[&str] = &[
+    "olá", "oi", "obrigado", "obrigada", "você", "vocês", "não", "quero", "preciso", "tenho",
+    "estou", "muito", "bom", "boa", "meu", "minha", "ajuda", "onde", "quanto", "também", "com",
+    "um", "uma", "isso", "eu", "os", "mas",
+];
+
+/// Palavras frequentes só em espanhol
+const SPANISH_WORDS: &[&str] = &[
+    "hola", "gracias", "usted", "ustedes", "quiero", "necesito", "tengo", "estoy", "muy", "mucho",
+    "bueno", "buenos", "buenas", "mi", "ayuda", "dónde", "cuánto", "también", "con", "un", "una",
+    "eso", "yo", "el", "los", "pero",
+];
+
+/// Textos das mensagens por chave e idioma
+///
+/// Cada mensagem é um [`MessageTemplate`] com uma variante por idioma. Na
+/// renderização, usa o idioma do [`TemplateContext`] (exato ou idioma base,
+/// `es-AR` → `es`); sem texto nesse idioma, usa o idioma padrão e, por fim,
+/// o primeiro texto cadastrado para a chave.
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::i18n::Localizer;
+/// use chatguru::templates::TemplateContext;
+///
+/// let localizer = Localizer::new()
+///     .message("boas_vindas", "pt-BR", "Olá, {nome}!")
+///     .message("boas_vindas", "es", "¡Hola, {nome}!");
+///
+/// let context = TemplateContext::new().with("nome", "Lucía").locale("es-AR");
+/// assert_eq!(localizer.render("boas_vindas", &context).unwrap(), "¡Hola, Lucía!");
+///
+/// let context = TemplateContext::new().with("nome", "John").locale("en");
+/// assert_eq!(localizer.render("boas_vindas", &context).unwrap(), "Olá, John!");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Localizer {
+    default_locale: String,
+    messages: HashMap<String, MessageTemplate>,
+}
+
+impl Default for Localizer {
+    fn default() -> Self {
+        Self {
+            default_locale: DEFAULT_LOCALE.to_string(),
+            messages: HashMap::new(),
+        }
+    }
+}
+
+impl Localizer {
+    /// Localizer vazio com o idioma padrão [`DEFAULT_LOCALE`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Define o idioma usado quando a mensagem não tem texto no idioma pedido
+    pub fn default_locale(mut self, locale: impl Into<String>) -> Self {
+        self.default_locale = locale.into();
+        self
+    }
+
+    /// Idioma padrão configurado
+    pub fn default_locale_tag(&self) -> &str {
+        &self.default_locale
+    }
+
+    /// Cadastra o texto de uma mensagem em um idioma (substitui o anterior)
+    pub fn message(
+        mut self,
+        key: impl Into<String>,
+        locale: impl Into<String>,
+        body: impl Into<String>,
+    ) -> Self {
+        let key = key.into();
+        let body = body.into();
+        let template = self
+            .messages
+            .remove(&key)
+            .unwrap_or_else(|| MessageTemplate::named(key.clone(), body.clone()));
+        self.messages
+            .insert(key, template.with_locale(locale, body));
+        self
+    }
+
+    /// Indica se a mensagem está cadastrada
+    pub fn contains(&self, key: &str) -> bool {
+        self.messages.contains_key(key)
+    }
+
+    /// Template da mensagem, com as variantes por idioma
+    pub fn template(&self, key: &str) -> Option<&MessageTemplate> {
+        self.messages.get(key)
+    }
+
+    /// Renderiza a mensagem no idioma do contexto
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se a mensagem não estiver cadastrada ou se
+    /// faltar valor para algum placeholder.
+    pub fn render(&self, key: &str, context: &TemplateContext) -> Result<String> {
+        let template = self.messages.get(key).ok_or_else(|| {
+            ChatGuruError::ValidationError(format!("Unknown localized message '{}'", key))
+        })?;
+
+        match context.locale_tag() {
+            Some(locale) if template.has_locale(locale) => template.render(context),
+            _ => template.render(&context.clone().locale(self.default_locale.clone())),
+        }
+    }
+}
+
+/// Detecta o idioma do contato a partir do webhook
+///
+/// Usa o campo personalizado de idioma ([`LOCALE_FIELDS`]), que aceita
+/// códigos (`es`, `pt_BR`) ou nomes (`Español`, `Português`). Sem ele,
+/// compara palavras e sinais típicos do português e do espanhol no texto da
+/// mensagem.
+///
+/// # Retorno
+///
+/// O código do idioma (`pt-BR`, `es`, ...), ou `None` se não for possível
+/// decidir.
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::i18n::detect_locale;
+/// use chatguru::types::WebhookPayload;
+///
+/// let payload = WebhookPayload::parse(
+///     r#"{"campanha_id": "1", "nome": "Lucía", "texto_mensagem": "Hola, ¿dónde está mi pedido?"}"#,
+/// )
+/// .unwrap();
+/// assert_eq!(detect_locale(&payload).as_deref(), Some("es"));
+///
+/// let payload = WebhookPayload::parse(
+///     r#"{"campanha_id": "1", "nome": "Ana", "texto_mensagem": "Oi, não recebi meu pedido",
+///         "campos_personalizados": {"Idioma": "Español"}}"#,
+/// )
+/// .unwrap();
+/// assert_eq!(detect_locale(&payload).as_deref(), Some("es"));
+/// ```
+pub fn detect_locale(payload: &WebhookPayload) -> Option<String> {
+    locale_field(payload)
+        .and_then(|value| parse_locale(&value))
+        .or_else(|| {
+            payload
+                .get_message_text()
+                .and_then(|text| locale_from_text(&text))
+                .map(String::from)
+        })
+}
+
+/// Valor do campo personalizado de idioma
+fn locale_field(payload: &WebhookPayload) -> Option<String> {
+    let from_map = |map: &HashMap<String, Value>| {
+        let wanted: Vec<String> = LOCALE_FIELDS.iter().map(|f| normalize_key(f)).collect();
+        map.iter()
+            .find(|(name, _)| wanted.contains(&normalize_key(name)))
+            .and_then(|(_, value)| value.as_str().map(String::from))
+    };
+
+    match payload {
+        WebhookPayload::ChatGuru(p) => LOCALE_FIELDS
+            .iter()
+            .find_map(|field| p.get_custom_str(field)),
+        WebhookPayload::EventType(p) => {
+            from_map(&p.data.custom_data).or_else(|| from_map(&p.data.extra))
+        }
+        WebhookPayload::Generic(p) => from_map(&p.extra),
+    }
+}
+
+/// Interpreta o código ou nome de um idioma
+fn parse_locale(value: &str) -> Option<String> {
+    let key = normalize_key(value);
+    if let Some((locale, _)) = LANGUAGE_NAMES
+        .iter()
+        .find(|(_, names)| names.contains(&key.as_str()))
+    {
+        return Some(locale.to_string());
+    }
+
+    // Outros códigos no formato `ll` ou `ll-RR`
+    let value = value.trim().replace('_', "-");
+    let mut parts = value.split('-');
+    let language = parts.next().unwrap_or_default();
+    let region = parts.next();
+    let valid = (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_alphabetic())
+        && region.is_none_or(|r| r.len() == 2 && r.chars().all(|c| c.is_ascii_alphabetic()))
+        && parts.next().is_none();
+    valid.then_some(value)
+}
+
+/// Decide entre português e espanhol pelas palavras e sinais do texto
+fn locale_from_text(text: &str) -> Option<&'static str> {
+    let text = text.to_lowercase();
+    let mut portuguese = 0;
+    let mut spanish = 0;
+
+    for c in text.chars() {
+        match c {
+            'ã' | 'õ' | 'ç' => portuguese += 2,
+            'ñ' | '¿' | '¡' => spanish += 2,
+            _ => {}
+        }
+    }
+    for word in text
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|w| !w.is_empty())
+    {
+        if PORTUGUESE_WORDS.contains(&word) {
+            portuguese += 1;
+        }
+        if SPANISH_WORDS.contains(&word) {
+            spanish += 1;
+        }
+    }
+
+    match portuguese.cmp(&spanish) {
+        std::cmp::Ordering::Greater => Some(DEFAULT_LOCALE),
+        std::cmp::Ordering::Less => Some("es"),
+        std::cmp::Ordering::Equal => None,
+    }
+}
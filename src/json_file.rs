@@ -0,0 +1,134 @@
+//! Itens persistidos em um arquivo JSON
+//!
+//! Base do [`FileOutboxStore`](crate::client::FileOutboxStore) e da
+//! [`FileDeadLetterQueue`](crate::webhook::FileDeadLetterQueue): os itens
+//! ficam em memória, indexados pelo ID, e o arquivo é regravado inteiro a
+//! cada alteração (em um arquivo temporário renomeado em seguida, para não
+//! corromper o conteúdo em caso de queda).
+
+use crate::error::{ChatGuruError, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Item guardado em um [`JsonFileMap`]
+pub(crate) trait JsonFileItem: Serialize + DeserializeOwned + Clone {
+    /// ID do item (chave do mapa)
+    fn id(&self) -> &str;
+
+    /// Ordem dos itens no arquivo e em [`JsonFileMap::values`]
+    fn order(a: &Self, b: &Self) -> Ordering;
+}
+
+/// Mapa `ID → item` gravado em um arquivo JSON (uma lista ordenada)
+#[derive(Debug)]
+pub(crate) struct JsonFileMap<T> {
+    path: PathBuf,
+    /// Nome do conteúdo nos logs e erros (ex: `outbox`)
+    label: &'static str,
+    items: tokio::sync::Mutex<HashMap<String, T>>,
+}
+
+impl<T: JsonFileItem> JsonFileMap<T> {
+    /// Abre (ou cria) o arquivo, carregando os itens existentes
+    ///
+    /// # Erros
+    ///
+    /// Retorna `InternalError` se o arquivo existir mas não puder ser lido, e
+    /// `SerializationError` se o conteúdo não for uma lista de itens válida.
+    pub(crate) fn open(path: impl AsRef<Path>, label: &'static str) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+
+        let items = match std::fs::read(&path) {
+            Ok(bytes) if bytes.is_empty() => HashMap::new(),
+            Ok(bytes) => {
+                let list: Vec<T> = serde_json::from_slice(&bytes)?;
+                list.into_iter()
+                    .map(|item| (item.id().to_string(), item))
+                    .collect()
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => {
+                return Err(ChatGuruError::InternalError(format!(
+                    "Failed to read {} file {}: {}",
+                    label,
+                    path.display(),
+                    e
+                )))
+            }
+        };
+
+        tracing::info!(
+            "Loaded {} from {} ({} items)",
+            label,
+            path.display(),
+            items.len()
+        );
+
+        Ok(Self {
+            path,
+            label,
+            items: tokio::sync::Mutex::new(items),
+        })
+    }
+
+    /// Caminho do arquivo
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Insere ou substitui o item com o mesmo ID e regrava o arquivo
+    pub(crate) async fn insert(&self, item: &T) -> Result<()> {
+        let mut items = self.items.lock().await;
+        items.insert(item.id().to_string(), item.clone());
+        self.persist(&items).await
+    }
+
+    /// Busca um item pelo ID
+    pub(crate) async fn get(&self, id: &str) -> Option<T> {
+        self.items.lock().await.get(id).cloned()
+    }
+
+    /// Todos os itens, na ordem de [`JsonFileItem::order`]
+    pub(crate) async fn values(&self) -> Vec<T> {
+        sorted(self.items.lock().await.values().cloned().collect())
+    }
+
+    /// Remove um item, regravando o arquivo; retorna `true` se ele existia
+    pub(crate) async fn remove(&self, id: &str) -> Result<bool> {
+        let mut items = self.items.lock().await;
+        if items.remove(id).is_none() {
+            return Ok(false);
+        }
+        self.persist(&items).await?;
+        Ok(true)
+    }
+
+    async fn persist(&self, items: &HashMap<String, T>) -> Result<()> {
+        let mut list: Vec<&T> = items.values().collect();
+        list.sort_by(|a, b| T::order(a, b));
+        let bytes = serde_json::to_vec_pretty(&list)?;
+
+        let tmp_path = self.path.with_extension("tmp");
+        let write = async {
+            tokio::fs::write(&tmp_path, &bytes).await?;
+            tokio::fs::rename(&tmp_path, &self.path).await
+        };
+
+        write.await.map_err(|e| {
+            ChatGuruError::InternalError(format!(
+                "Failed to write {} file {}: {}",
+                self.label,
+                self.path.display(),
+                e
+            ))
+        })
+    }
+}
+
+fn sorted<T: JsonFileItem>(mut items: Vec<T>) -> Vec<T> {
+    items.sort_by(T::order);
+    items
+}
@@ -7,7 +7,41 @@
 //! - Cliente HTTP para enviar mensagens de confirmação via WhatsApp
 //! - Tipos de webhook flexíveis (ChatGuru, EventType, Generic)
 //! - Normalização automática de campos de mídia
+//! - Download de mídias com limite de tamanho e detecção de tipo
+//! - Miniaturas e remoção de EXIF das imagens recebidas (feature `image`)
+//! - Templates de mensagens com placeholders e variantes por idioma
+//! - Mensagens por idioma com detecção do idioma do contato e `send_localized` (feature `i18n`)
+//! - Formatação de texto do WhatsApp (negrito, itálico, listas) com escape
+//! - Limite de tamanho de mensagens e anotações contado e cortado por grafemas, sem quebrar emojis (`chatguru::text`)
+//! - Registro de várias contas e linhas (`AccountManager`) com pool de conexões compartilhado
+//! - Escolha da linha de envio por DDD, campanha, round-robin e failover (`PhoneLineRouter`)
+//! - Política de envio com horário de silêncio, limite diário por contato e blocklist (`SendPolicy`)
+//! - Campanha de origem (`Campaign`) lida de qualquer formato de webhook
+//! - Atendente responsável (`Agent`) lido de qualquer formato de webhook
+//! - Tags do chat em qualquer formato e diferença entre webhooks (`TagDiff`)
+//! - Links para o chat no painel (`ChatLink`) em Markdown ou no formato do Slack
+//! - Cópia dos webhooks sem dados pessoais para logs e exportação (`types::redact`)
+//! - Registro de descadastro (LGPD) com detecção de "parar"/"sair" nas respostas (`ConsentRegistry`)
+//! - Anotação automática no chat das mensagens enviadas pelo bot (`annotate_sends`)
+//! - Envio para números novos com cadastro automático do chat (`send_or_register`)
+//! - Moderação do conteúdo enviado e recebido por regras ou expressões regulares (`ContentFilter`)
+//! - Estado de conversa por chat (`SessionManager`) com armazenamento plugável
+//! - Outbox com armazenamento plugável (memória, arquivo, SQLite ou Redis) e worker de envio
+//! - Extractor `ChatGuruWebhook` para handlers axum e actix-web com validação de tamanho, Content-Type e assinatura (features `axum` e `actix`)
+//! - Criação de tarefas no ClickUp a partir de webhooks (feature `clickup`)
+//! - Envelope JSON versionado dos eventos (`EventEnvelope`) para sistemas externos
+//! - Publicação dos eventos no Google Pub/Sub com chave de ordenação por chat (feature `publisher`), Kafka (`kafka`, ou `kafka-rest` pelo REST Proxy) e NATS (`nats`)
+//! - Pipeline webhook → CRM → anotação com integrações plugáveis (`CrmSink`)
+//! - Middlewares que interceptam as chamadas à API (headers, auditoria, assinatura, testes de caos)
 //! - Tratamento de erros específico para ChatGuru
+//! - Verificação de assinatura (HMAC-SHA256) dos webhooks recebidos
+//! - Roteamento declarativo dos webhooks por campanha, tag, mídia e campos (`webhook::Filter`)
+//! - Gravação de webhooks recebidos e reprocessamento após quedas
+//! - Binário `chatguru-cli` para enviar mensagens, anotar chats e inspecionar webhooks pelo terminal (feature `cli`)
+//! - Gravação e reprodução das chamadas à API para testes de integração sem credenciais (feature `record`)
+//! - Simulador de tráfego de webhooks para testes de carga (feature `simulator`)
+//! - Token e URLs mascarados em logs e na saída `Debug`
+//! - Rotação do token sem recriar o cliente (`CredentialsProvider`), lido do Google Secret Manager (feature `gcp-secret-manager`) ou do Vault (`vault`)
 //!
 //! # Arquitetura da API ChatGuru
 //!
@@ -17,16 +51,20 @@
 //!
 //! ## Endpoints Implementados
 //!
+//! Os parâmetros são enviados no corpo `application/x-www-form-urlencoded`
+//! (use `RequestMode::QueryString` no builder para o modo legado via query string),
+//! sempre com o percent-encoding estrito de `client::encode_params`.
+//!
 //! ### Adicionar Anotação
 //! ```text
-//! POST {api_endpoint}?key={token}&account_id={id}&phone_id={phone_id}
-//!      &action=note_add&note_text={text}&chat_number={number}
+//! POST {api_endpoint}
+//! key={token}&account_id={id}&phone_id={phone_id}&action=note_add&note_text={text}&chat_number={number}
 //! ```
 //!
 //! ### Enviar Mensagem
 //! ```text
-//! POST {api_endpoint}?key={token}&account_id={id}&phone_id={phone_id}
-//!      &action=message_send&text={text}&chat_number={number}
+//! POST {api_endpoint}
+//! key={token}&account_id={id}&phone_id={phone_id}&action=message_send&text={text}&chat_number={number}
 //! ```
 //!
 //! # Exemplo Básico
@@ -107,11 +145,13 @@
 //!
 //! # Configuração
 //!
-//! Configure através de variáveis de ambiente:
+//! Configure através de variáveis de ambiente e use `ChatGuruClient::from_env()`
+//! (ou `try_from_env()` para tratar o erro):
 //!
 //! - `CHATGURU_API_TOKEN`: Token de autenticação da API
 //! - `CHATGURU_API_ENDPOINT`: URL base da API (padrão: `https://api.chatguru.app/api/v1`)
 //! - `CHATGURU_ACCOUNT_ID`: ID da conta ChatGuru
+//! - `CHATGURU_PHONE_ID`: phone_id padrão (opcional)
 //!
 //! # Tratamento de Erros
 //!
@@ -119,21 +159,54 @@
 //!
 //! Os erros são categorizados em:
 //! - `NetworkError`: Falhas de rede/HTTP
-//! - `ApiError`: Erros retornados pela API
+//! - `ApiError`: Erros retornados pela API (com status, código e corpo da resposta)
+//! - `RateLimited`: Limite de requisições da API excedido (HTTP 429)
+//! - `ChatNotFound`: Chat inexistente para o número informado
+//! - `Unauthorized`: Token ou conta inválidos (HTTP 401/403)
 //! - `SerializationError`: Erros de serialização/deserialização JSON
 //! - `ValidationError`: Dados inválidos
 //! - `InternalError`: Erros internos do cliente
+//! - `Timeout`: Prazo esgotado aguardando um evento (ex: confirmação de entrega)
+//! - `CircuitOpen`: Chamada bloqueada pelo circuit breaker
+//!
+//! Use `ChatGuruError::is_retryable()` e `ChatGuruError::is_chat_not_found()`
+//! em vez de comparar o texto das mensagens de erro.
 
 // Módulos públicos
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "clickup")]
+pub mod clickup;
 pub mod client;
 pub mod error;
+pub mod format;
+#[cfg(any(feature = "publisher", feature = "gcp-secret-manager"))]
+pub(crate) mod gcp;
+#[cfg(feature = "i18n")]
+pub mod i18n;
+pub(crate) mod json_file;
+pub mod media;
+pub mod pipeline;
+#[cfg(feature = "publisher")]
+pub mod publisher;
+pub mod secret;
+pub mod session;
+#[cfg(feature = "simulator")]
+pub mod simulator;
+pub mod templates;
+#[cfg(feature = "test-util")]
+pub mod test_fixtures;
+pub mod text;
 pub mod types;
+pub mod webhook;
 
 // Re-exports principais
-pub use client::ChatGuruClient;
+pub use client::{ChatGuruApi, ChatGuruClient, ChatGuruClientBuilder, RequestMode, RetryPolicy};
 pub use error::{ChatGuruError, Result};
+pub use secret::{redact_url, SecretString};
 
 // Re-exports de types para conveniência
 pub use types::{
-    BotContext, ChatGuruPayload, EventData, EventTypePayload, GenericPayload, WebhookPayload,
+    BotContext, ChatEvent, ChatGuruPayload, EventData, EventEnvelope, EventTypePayload,
+    GenericPayload, PhoneNumber, WebhookPayload,
 };
@@ -0,0 +1,232 @@
+use super::sniff::sniff_mime_with_name;
+use crate::error::{ChatGuruError, Result};
+use base64::Engine;
+
+/// MIME types aceitos pelo WhatsApp para envio de mídia
+pub const SUPPORTED_MIME_TYPES: &[&str] = &[
+    "image/jpeg",
+    "image/png",
+    "image/webp",
+    "audio/ogg",
+    "audio/mpeg",
+    "audio/mp4",
+    "audio/aac",
+    "audio/amr",
+    "video/mp4",
+    "video/3gpp",
+    "application/pdf",
+    "application/msword",
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    "application/vnd.ms-excel",
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    "application/vnd.ms-powerpoint",
+    "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+    "application/zip",
+    "text/plain",
+    "text/csv",
+];
+
+/// Limite de tamanho para imagens, áudios e vídeos (16 MiB)
+const MAX_MEDIA_BYTES: usize = 16 * 1024 * 1024;
+
+/// Limite de tamanho para documentos (100 MiB)
+const MAX_DOCUMENT_BYTES: usize = 100 * 1024 * 1024;
+
+/// Origem do arquivo de uma [`MediaAttachment`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MediaSource {
+    /// Arquivo publicado em uma URL acessível pelo ChatGuru
+    Url(String),
+    /// Conteúdo enviado junto com a requisição
+    Bytes(Vec<u8>),
+}
+
+/// Mídia a ser enviada com `send_media_message()`
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::media::MediaAttachment;
+///
+/// let boleto = MediaAttachment::from_url("https://exemplo.com/boleto.pdf")
+///     .caption("Segue o boleto 📄");
+///
+/// let foto = MediaAttachment::from_bytes(std::fs::read("foto.png")?)
+///     .filename("foto.png");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaAttachment {
+    source: MediaSource,
+    mime: Option<String>,
+    filename: Option<String>,
+    caption: Option<String>,
+}
+
+impl MediaAttachment {
+    /// Mídia publicada em uma URL
+    pub fn from_url(url: impl Into<String>) -> Self {
+        Self {
+            source: MediaSource::Url(url.into()),
+            mime: None,
+            filename: None,
+            caption: None,
+        }
+    }
+
+    /// Mídia a partir dos bytes do arquivo
+    pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Self {
+        Self {
+            source: MediaSource::Bytes(bytes.into()),
+            mime: None,
+            filename: None,
+            caption: None,
+        }
+    }
+
+    /// Define a legenda enviada junto com a mídia
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Define o nome do arquivo exibido ao contato
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    /// Define o MIME type explicitamente (sem detecção automática)
+    pub fn mime(mut self, mime: impl Into<String>) -> Self {
+        self.mime = Some(mime.into());
+        self
+    }
+
+    /// Origem do arquivo
+    pub fn source(&self) -> &MediaSource {
+        &self.source
+    }
+
+    /// Legenda configurada
+    pub fn caption_text(&self) -> Option<&str> {
+        self.caption.as_deref()
+    }
+
+    /// Nome do arquivo (explícito ou derivado da URL)
+    pub fn file_name(&self) -> Option<String> {
+        self.filename.clone().or_else(|| match &self.source {
+            MediaSource::Url(url) => url
+                .split(['?', '#'])
+                .next()
+                .and_then(|path| path.rsplit('/').next())
+                .filter(|name| name.contains('.'))
+                .map(String::from),
+            MediaSource::Bytes(_) => None,
+        })
+    }
+
+    /// MIME type da mídia: explícito, detectado pelos bytes ou pela extensão
+    pub fn resolved_mime(&self) -> Option<String> {
+        if let Some(ref mime) = self.mime {
+            return Some(mime.to_ascii_lowercase());
+        }
+        if let MediaSource::Bytes(ref bytes) = self.source {
+            return sniff_mime_with_name(bytes, self.file_name().as_deref()).map(String::from);
+        }
+        self.file_name()
+            .and_then(|name| mime_from_extension(&name))
+            .map(String::from)
+    }
+
+    /// Valida formato e tamanho da mídia
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se o tipo não puder ser determinado, não for
+    /// suportado pelo WhatsApp ou exceder o limite de tamanho.
+    pub fn validate(&self) -> Result<String> {
+        if let MediaSource::Url(ref url) = self.source {
+            if !(url.starts_with("https://") || url.starts_with("http://")) {
+                return Err(ChatGuruError::ValidationError(format!(
+                    "Media URL must be http(s): {}",
+                    url
+                )));
+            }
+        }
+
+        let mime = self.resolved_mime().ok_or_else(|| {
+            ChatGuruError::ValidationError(
+                "Could not determine media type; set it with .mime()".to_string(),
+            )
+        })?;
+
+        if !SUPPORTED_MIME_TYPES.contains(&mime.as_str()) {
+            return Err(ChatGuruError::ValidationError(format!(
+                "Unsupported media type for WhatsApp: {}",
+                mime
+            )));
+        }
+
+        if let MediaSource::Bytes(ref bytes) = self.source {
+            let limit = if mime.starts_with("application/") || mime.starts_with("text/") {
+                MAX_DOCUMENT_BYTES
+            } else {
+                MAX_MEDIA_BYTES
+            };
+            if bytes.is_empty() || bytes.len() > limit {
+                return Err(ChatGuruError::ValidationError(format!(
+                    "Invalid media size: {} bytes (limit {})",
+                    bytes.len(),
+                    limit
+                )));
+            }
+        }
+
+        Ok(mime)
+    }
+
+    /// Valor enviado no parâmetro `file_url`
+    ///
+    /// URLs são enviadas como estão; bytes viram uma URI `data:` em base64.
+    pub(crate) fn file_url(&self, mime: &str) -> String {
+        match &self.source {
+            MediaSource::Url(url) => url.clone(),
+            MediaSource::Bytes(bytes) => format!(
+                "data:{};base64,{}",
+                mime,
+                base64::engine::general_purpose::STANDARD.encode(bytes)
+            ),
+        }
+    }
+}
+
+/// MIME type correspondente à extensão de um arquivo
+pub fn mime_from_extension(filename: &str) -> Option<&'static str> {
+    let extension = filename.rsplit_once('.')?.1.to_ascii_lowercase();
+    Some(match extension.as_str() {
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "webp" => "image/webp",
+        "gif" => "image/gif",
+        "ogg" | "opus" => "audio/ogg",
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "aac" => "audio/aac",
+        "amr" => "audio/amr",
+        "mp4" => "video/mp4",
+        "3gp" => "video/3gpp",
+        "pdf" => "application/pdf",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "ppt" => "application/vnd.ms-powerpoint",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "odt" => "application/vnd.oasis.opendocument.text",
+        "ods" => "application/vnd.oasis.opendocument.spreadsheet",
+        "odp" => "application/vnd.oasis.opendocument.presentation",
+        "zip" => "application/zip",
+        "txt" => "text/plain",
+        "csv" => "text/csv",
+        _ => return None,
+    })
+}
@@ -0,0 +1,231 @@
+use super::download::MediaFile;
+use crate::error::{ChatGuruError, Result};
+use std::time::Duration;
+
+/// MIME type completo dos áudios `ptt` (aceito pelas APIs de transcrição)
+pub const OPUS_MIME: &str = "audio/ogg; codecs=opus";
+
+/// Taxa de amostragem do granule position do Opus (sempre 48 kHz)
+const OPUS_GRANULE_RATE: u64 = 48_000;
+
+/// Tamanho do cabeçalho fixo de uma página OGG (sem a tabela de segmentos)
+const OGG_PAGE_HEADER: usize = 27;
+
+/// Metadados de um áudio OGG/Opus (formato dos áudios `ptt` do WhatsApp)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpusInfo {
+    /// Quantidade de canais
+    pub channels: u8,
+    /// Taxa de amostragem original, em Hz (informativa; o Opus decodifica a 48 kHz)
+    pub input_sample_rate: u32,
+    /// Amostras descartadas no início da decodificação
+    pub pre_skip: u16,
+    /// Duração do áudio
+    pub duration: Duration,
+    /// Encoder que gerou o arquivo (`OpusTags`)
+    pub vendor: Option<String>,
+    /// Comentários do arquivo (`TITLE=...`), com a chave em maiúsculas
+    pub tags: Vec<(String, String)>,
+}
+
+impl OpusInfo {
+    /// Valor do comentário informado (chave sem diferenciar maiúsculas)
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Indica se os bytes são um áudio OGG com stream Opus
+pub fn is_ogg_opus(bytes: &[u8]) -> bool {
+    OggPages::new(bytes)
+        .next()
+        .is_some_and(|page| page.body.starts_with(b"OpusHead"))
+}
+
+/// Lê os metadados e a duração de um áudio OGG/Opus
+///
+/// A duração vem do granule position da última página completa, então
+/// arquivos truncados retornam a duração do trecho disponível.
+///
+/// # Erros
+///
+/// Retorna `ValidationError` se os bytes não forem OGG/Opus.
+pub fn probe_opus(bytes: &[u8]) -> Result<OpusInfo> {
+    let invalid = |reason: &str| {
+        ChatGuruError::ValidationError(format!("Invalid OGG/Opus audio: {}", reason))
+    };
+
+    let mut pages = OggPages::new(bytes);
+    let first = pages.next().ok_or_else(|| invalid("missing OGG page"))?;
+    let head = first.body;
+    if !head.starts_with(b"OpusHead") || head.len() < 19 {
+        return Err(invalid("missing OpusHead"));
+    }
+    let serial = first.serial;
+    let pre_skip = u16::from_le_bytes([head[10], head[11]]);
+
+    // O pacote OpusTags pode ocupar várias páginas
+    let mut tags_packet = Vec::new();
+    let mut tags_done = false;
+    let mut last_granule = None;
+    for page in pages.filter(|page| page.serial == serial) {
+        if !tags_done {
+            for (segment, complete) in page.segments() {
+                tags_packet.extend_from_slice(segment);
+                if complete {
+                    tags_done = true;
+                    break;
+                }
+            }
+        }
+        // -1 indica página sem fim de pacote
+        if page.granule != u64::MAX {
+            last_granule = Some(page.granule);
+        }
+    }
+
+    let samples = last_granule
+        .unwrap_or(0)
+        .saturating_sub(u64::from(pre_skip));
+
+    let mut info = OpusInfo {
+        channels: head[9],
+        input_sample_rate: u32::from_le_bytes([head[12], head[13], head[14], head[15]]),
+        pre_skip,
+        duration: Duration::from_micros(samples.saturating_mul(1_000_000) / OPUS_GRANULE_RATE),
+        vendor: None,
+        tags: Vec::new(),
+    };
+    // Comentários malformados não invalidam o áudio
+    parse_opus_tags(&tags_packet, &mut info);
+    Ok(info)
+}
+
+/// Valida um áudio `ptt` antes de processá-lo (ex: transcrição)
+///
+/// # Erros
+///
+/// Retorna `ValidationError` se os bytes não forem OGG/Opus ou se o áudio
+/// for mais longo que `max_duration`.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use chatguru::media::validate_voice_note;
+///
+/// let info = validate_voice_note(&file.bytes, Duration::from_secs(5 * 60))?;
+/// transcrever(&file.bytes, OPUS_MIME).await?;
+/// ```
+pub fn validate_voice_note(bytes: &[u8], max_duration: Duration) -> Result<OpusInfo> {
+    let info = probe_opus(bytes)?;
+    if info.duration > max_duration {
+        return Err(ChatGuruError::ValidationError(format!(
+            "Audio too long: {:.1}s (limit {:.1}s)",
+            info.duration.as_secs_f64(),
+            max_duration.as_secs_f64()
+        )));
+    }
+    Ok(info)
+}
+
+impl MediaFile {
+    /// Metadados do arquivo, se ele for OGG/Opus
+    pub fn opus_info(&self) -> Option<OpusInfo> {
+        probe_opus(&self.bytes).ok()
+    }
+}
+
+/// Página OGG
+struct OggPage<'a> {
+    granule: u64,
+    serial: u32,
+    lacing: &'a [u8],
+    body: &'a [u8],
+}
+
+impl<'a> OggPage<'a> {
+    /// Segmentos da página, indicando se cada um fecha um pacote (< 255 bytes)
+    fn segments(&self) -> impl Iterator<Item = (&'a [u8], bool)> + 'a {
+        let body = self.body;
+        let mut offset = 0;
+        self.lacing.iter().map(move |&len| {
+            let segment = &body[offset..offset + len as usize];
+            offset += len as usize;
+            (segment, len < 255)
+        })
+    }
+}
+
+/// Itera as páginas completas de um arquivo OGG
+struct OggPages<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> OggPages<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+}
+
+impl<'a> Iterator for OggPages<'a> {
+    type Item = OggPage<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.bytes;
+        if bytes.len() < OGG_PAGE_HEADER || !bytes.starts_with(b"OggS") {
+            return None;
+        }
+
+        let segments = bytes[26] as usize;
+        let lacing = bytes.get(OGG_PAGE_HEADER..OGG_PAGE_HEADER + segments)?;
+        let body_start = OGG_PAGE_HEADER + segments;
+        let body_len: usize = lacing.iter().map(|&len| len as usize).sum();
+        let body = bytes.get(body_start..body_start + body_len)?;
+
+        let mut granule = [0; 8];
+        granule.copy_from_slice(&bytes[6..14]);
+        let mut serial = [0; 4];
+        serial.copy_from_slice(&bytes[14..18]);
+
+        self.bytes = &bytes[body_start + body_len..];
+        Some(OggPage {
+            granule: u64::from_le_bytes(granule),
+            serial: u32::from_le_bytes(serial),
+            lacing,
+            body,
+        })
+    }
+}
+
+/// Lê o pacote `OpusTags` (vendor e comentários no formato Vorbis) para `info`
+fn parse_opus_tags(packet: &[u8], info: &mut OpusInfo) -> Option<()> {
+    let mut rest = packet.strip_prefix(b"OpusTags")?;
+
+    info.vendor = read_string(&mut rest).filter(|v| !v.is_empty());
+    let count = read_u32(&mut rest)?;
+    info.tags = (0..count)
+        .map_while(|_| read_string(&mut rest))
+        .filter_map(|comment| {
+            let (key, value) = comment.split_once('=')?;
+            Some((key.to_ascii_uppercase(), value.to_string()))
+        })
+        .collect();
+    Some(())
+}
+
+fn read_u32(rest: &mut &[u8]) -> Option<usize> {
+    let value = u32::from_le_bytes(rest.get(..4)?.try_into().ok()?);
+    *rest = &rest[4..];
+    Some(value as usize)
+}
+
+fn read_string(rest: &mut &[u8]) -> Option<String> {
+    let len = read_u32(rest)?;
+    let value = rest.get(..len)?;
+    *rest = &rest[len..];
+    Some(String::from_utf8_lossy(value).into_owned())
+}
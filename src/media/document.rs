@@ -0,0 +1,125 @@
+use super::download::MediaFile;
+use super::sniff::sniff_mime_with_name;
+
+/// Metadados de um documento recebido (`tipo_mensagem = "document"`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentInfo {
+    /// MIME type detectado pelo conteúdo (e pela extensão, em formatos genéricos)
+    pub mime: String,
+    /// Quantidade de páginas (apenas PDF)
+    pub pages: Option<u32>,
+    /// Tamanho em bytes
+    pub size: usize,
+    /// Nome do arquivo, quando conhecido
+    pub filename: Option<String>,
+}
+
+impl DocumentInfo {
+    /// Extrai os metadados dos bytes do documento
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// use chatguru::media::DocumentInfo;
+    ///
+    /// let file = downloader.download(&media_url).await?;
+    /// let info = DocumentInfo::from_bytes(&file.bytes, file.filename.as_deref());
+    /// if info.is_pdf() && info.pages.unwrap_or(0) > 50 {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn from_bytes(bytes: &[u8], filename: Option<&str>) -> Self {
+        let mime = sniff_mime_with_name(bytes, filename).unwrap_or("application/octet-stream");
+
+        Self {
+            mime: mime.to_string(),
+            pages: (mime == "application/pdf")
+                .then(|| pdf_page_count(bytes))
+                .flatten(),
+            size: bytes.len(),
+            filename: filename.map(String::from),
+        }
+    }
+
+    /// Indica se o documento é um PDF
+    pub fn is_pdf(&self) -> bool {
+        self.mime == "application/pdf"
+    }
+
+    /// Extensão usual do tipo detectado (ex: `docx`), sem o ponto
+    pub fn extension(&self) -> Option<&'static str> {
+        Some(match self.mime.as_str() {
+            "application/pdf" => "pdf",
+            "application/msword" => "doc",
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document" => "docx",
+            "application/vnd.ms-excel" => "xls",
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet" => "xlsx",
+            "application/vnd.ms-powerpoint" => "ppt",
+            "application/vnd.openxmlformats-officedocument.presentationml.presentation" => "pptx",
+            "application/vnd.oasis.opendocument.text" => "odt",
+            "application/vnd.oasis.opendocument.spreadsheet" => "ods",
+            "application/vnd.oasis.opendocument.presentation" => "odp",
+            "application/zip" => "zip",
+            _ => return None,
+        })
+    }
+}
+
+impl MediaFile {
+    /// Metadados do arquivo como documento
+    pub fn document_info(&self) -> DocumentInfo {
+        DocumentInfo::from_bytes(&self.bytes, self.filename.as_deref())
+    }
+}
+
+/// Quantidade de páginas de um PDF
+///
+/// Conta os objetos `/Type /Page`; quando eles estão em object streams
+/// compactados (PDF 1.5+), usa o maior `/Count` da árvore de páginas.
+/// `None` se nenhuma das duas informações for encontrada.
+pub fn pdf_page_count(bytes: &[u8]) -> Option<u32> {
+    let mut pages = 0u32;
+    let mut max_count = None;
+
+    let mut offset = 0;
+    while let Some(position) = find(&bytes[offset..], b"/Type") {
+        let rest = skip_whitespace(&bytes[offset + position + 5..]);
+        if rest.starts_with(b"/Page") && !rest[5..].first().is_some_and(u8::is_ascii_alphanumeric) {
+            pages += 1;
+        }
+        offset += position + 5;
+    }
+
+    offset = 0;
+    while let Some(position) = find(&bytes[offset..], b"/Count") {
+        let rest = skip_whitespace(&bytes[offset + position + 6..]);
+        let digits = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+        if let Some(count) = std::str::from_utf8(&rest[..digits])
+            .ok()
+            .and_then(|n| n.parse::<u32>().ok())
+        {
+            max_count = Some(max_count.map_or(count, |max: u32| max.max(count)));
+        }
+        offset += position + 6;
+    }
+
+    if pages > 0 {
+        Some(pages)
+    } else {
+        max_count
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn skip_whitespace(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+    &bytes[start..]
+}
@@ -0,0 +1,278 @@
+use super::sniff::sniff_mime_with_name;
+use crate::client::retry;
+use crate::client::{RetryOutcome, RetryPolicy};
+use crate::error::{ChatGuruError, Result};
+use crate::secret::{describe_error, redact_url};
+use reqwest::Client;
+use std::time::Duration;
+
+/// Tamanho máximo padrão de uma mídia baixada (16 MiB, limite do WhatsApp)
+pub const DEFAULT_MAX_MEDIA_SIZE: usize = 16 * 1024 * 1024;
+
+/// Arquivo de mídia baixado
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaFile {
+    /// Conteúdo do arquivo
+    pub bytes: Vec<u8>,
+    /// MIME type detectado (bytes > header `Content-Type` > `application/octet-stream`)
+    ///
+    /// Para ZIPs genéricos e documentos OLE (DOC, XLS, PPT), a extensão do
+    /// nome do arquivo define o tipo.
+    pub mime: String,
+    /// Nome do arquivo (header `Content-Disposition` ou último segmento da URL)
+    pub filename: Option<String>,
+}
+
+impl MediaFile {
+    /// Tamanho do arquivo em bytes
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Indica se o arquivo está vazio
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Parte principal do MIME type (`image`, `audio`, `video`, `application`)
+    pub fn mime_family(&self) -> &str {
+        self.mime.split('/').next().unwrap_or_default()
+    }
+}
+
+/// Baixa mídias com limite de tamanho e detecção de tipo
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::media::MediaDownloader;
+///
+/// let downloader = MediaDownloader::new()
+///     .max_size(5 * 1024 * 1024)
+///     .allowed_types(["audio/ogg", "audio/mpeg"]);
+///
+/// let file = downloader.download(&media_url).await?;
+/// transcrever(&file.bytes, &file.mime).await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct MediaDownloader {
+    client: Client,
+    max_size: usize,
+    allowed_types: Vec<String>,
+    retry_policy: RetryPolicy,
+}
+
+impl Default for MediaDownloader {
+    fn default() -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(30))
+            .connect_timeout(Duration::from_secs(5))
+            .build()
+            .unwrap_or_else(|_| Client::new());
+
+        Self::with_client(client)
+    }
+}
+
+impl MediaDownloader {
+    /// Cria um downloader com cliente HTTP próprio (timeout de 30s)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cria um downloader reutilizando um cliente HTTP existente
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client,
+            max_size: DEFAULT_MAX_MEDIA_SIZE,
+            allowed_types: Vec::new(),
+            retry_policy: RetryPolicy::disabled(),
+        }
+    }
+
+    /// Define o tamanho máximo aceito, em bytes (padrão: 16 MiB)
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Restringe os MIME types aceitos
+    ///
+    /// Aceita tipos completos (`image/png`) ou famílias (`image/*`). Lista
+    /// vazia (padrão) aceita qualquer tipo.
+    pub fn allowed_types<I, S>(mut self, types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_types = types.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Repete downloads que falharem por rede, 429 ou 5xx (padrão: sem retry)
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Baixa a mídia da URL informada
+    ///
+    /// # Erros
+    ///
+    /// * `NetworkError` - falha de rede ou status HTTP de erro
+    /// * `ValidationError` - arquivo maior que o limite ou tipo não permitido
+    pub async fn download(&self, url: &str) -> Result<MediaFile> {
+        let mut attempt = 1;
+        let result = loop {
+            let result = self.client.get(url).send().await;
+            let outcome = RetryOutcome::from_reqwest(&result);
+            if !self.retry_policy.should_retry(attempt, &outcome) {
+                break result;
+            }
+            let Some(delay) = self
+                .retry_policy
+                .next_delay(attempt, retry::retry_after(&result))
+            else {
+                break result;
+            };
+            tracing::warn!(
+                "Media download attempt {}/{} failed ({:?}), retrying in {}ms",
+                attempt,
+                self.retry_policy.attempts(),
+                outcome,
+                delay.as_millis()
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        };
+
+        let mut response = result.map_err(|e| {
+            ChatGuruError::NetworkError(format!("Failed to download media: {}", describe_error(e)))
+        })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ChatGuruError::NetworkError(format!(
+                "Failed to download media: HTTP {}",
+                status
+            )));
+        }
+
+        if let Some(length) = response.content_length() {
+            if length as usize > self.max_size {
+                return Err(self.too_large(length as usize));
+            }
+        }
+
+        let header_mime = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.split(';').next())
+            .map(|v| v.trim().to_ascii_lowercase())
+            .filter(|v| !v.is_empty());
+
+        let filename = response
+            .headers()
+            .get(reqwest::header::CONTENT_DISPOSITION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(filename_from_disposition)
+            .or_else(|| filename_from_url(url));
+
+        // Ler em partes para abortar assim que o limite for excedido
+        let mut bytes = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(|e| {
+            ChatGuruError::NetworkError(format!("Failed to download media: {}", describe_error(e)))
+        })? {
+            if bytes.len() + chunk.len() > self.max_size {
+                return Err(self.too_large(bytes.len() + chunk.len()));
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+
+        let sniffed = sniff_mime_with_name(&bytes, filename.as_deref());
+        if let (Some(sniffed), Some(ref declared)) = (sniffed, &header_mime) {
+            if !same_family(sniffed, declared) {
+                tracing::warn!(
+                    "Media content type mismatch for {}: declared {}, detected {}",
+                    redact_url(url),
+                    declared,
+                    sniffed
+                );
+            }
+        }
+
+        let mime = sniffed
+            .map(String::from)
+            .or(header_mime)
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+
+        if !self.is_allowed(&mime) {
+            return Err(ChatGuruError::ValidationError(format!(
+                "Media type not allowed: {}",
+                mime
+            )));
+        }
+
+        tracing::debug!(
+            "Downloaded media {} ({} bytes, {})",
+            redact_url(url),
+            bytes.len(),
+            mime
+        );
+
+        Ok(MediaFile {
+            bytes,
+            mime,
+            filename,
+        })
+    }
+
+    fn is_allowed(&self, mime: &str) -> bool {
+        self.allowed_types.is_empty()
+            || self.allowed_types.iter().any(|allowed| {
+                allowed == mime
+                    || allowed
+                        .strip_suffix("/*")
+                        .is_some_and(|family| mime.starts_with(&format!("{}/", family)))
+            })
+    }
+
+    fn too_large(&self, size: usize) -> ChatGuruError {
+        ChatGuruError::ValidationError(format!(
+            "Media too large: {} bytes (limit {})",
+            size, self.max_size
+        ))
+    }
+}
+
+/// Baixa uma mídia com as configurações padrão de [`MediaDownloader`]
+pub async fn download(url: &str) -> Result<MediaFile> {
+    MediaDownloader::new().download(url).await
+}
+
+fn same_family(a: &str, b: &str) -> bool {
+    a.split('/').next() == b.split('/').next()
+}
+
+fn filename_from_disposition(value: &str) -> Option<String> {
+    value.split(';').find_map(|part| {
+        let part = part.trim();
+        let name = part
+            .strip_prefix("filename*=UTF-8''")
+            .or_else(|| part.strip_prefix("filename="))?;
+        let name = urlencoding::decode(name.trim_matches('"'))
+            .ok()?
+            .into_owned();
+        (!name.is_empty()).then_some(name)
+    })
+}
+
+fn filename_from_url(url: &str) -> Option<String> {
+    let path = url.split(['?', '#']).next()?;
+    let name = path.rsplit('/').next()?;
+    if name.is_empty() || !name.contains('.') {
+        return None;
+    }
+    urlencoding::decode(name).ok().map(|n| n.into_owned())
+}
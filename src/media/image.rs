@@ -0,0 +1,322 @@
+use super::sniff::sniff_mime;
+use crate::error::{ChatGuruError, Result};
+use ::image::codecs::jpeg::JpegEncoder;
+use ::image::codecs::png::PngEncoder;
+use ::image::metadata::Orientation;
+use ::image::{DynamicImage, ImageDecoder, ImageError, ImageReader};
+use std::io::Cursor;
+
+/// Qualidade dos JPEGs gerados por [`make_thumbnail`]
+const THUMBNAIL_JPEG_QUALITY: u8 = 85;
+
+/// Miniatura gerada por [`make_thumbnail`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Thumbnail {
+    /// Conteúdo da imagem, sem metadados
+    pub bytes: Vec<u8>,
+    /// MIME type da miniatura
+    pub mime: &'static str,
+    /// Largura em pixels
+    pub width: u32,
+    /// Altura em pixels
+    pub height: u32,
+}
+
+/// Largura e altura da imagem, lidas do cabeçalho (JPEG, PNG, GIF ou WebP)
+pub fn image_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    match sniff_mime(bytes)? {
+        "image/jpeg" => jpeg_dimensions(bytes),
+        "image/png" => {
+            let width = u32::from_be_bytes(bytes.get(16..20)?.try_into().ok()?);
+            let height = u32::from_be_bytes(bytes.get(20..24)?.try_into().ok()?);
+            Some((width, height))
+        }
+        "image/gif" => {
+            let width = u16::from_le_bytes(bytes.get(6..8)?.try_into().ok()?);
+            let height = u16::from_le_bytes(bytes.get(8..10)?.try_into().ok()?);
+            Some((width.into(), height.into()))
+        }
+        "image/webp" => webp_dimensions(bytes),
+        _ => None,
+    }
+}
+
+/// Miniatura da imagem com no máximo `max_px` pixels no maior lado
+///
+/// Imagens que já cabem em `max_px` e não precisam ser giradas são
+/// retornadas sem metadados e sem recodificar. As demais são decodificadas,
+/// giradas conforme a orientação do EXIF e, se necessário, reduzidas
+/// mantendo a proporção: JPEGs são recodificados como
+/// JPEG e PNG, GIF (primeiro quadro) e WebP como PNG, preservando a
+/// transparência. A imagem recodificada não carrega metadados.
+///
+/// # Erros
+///
+/// Retorna `ValidationError` se `max_px` for zero, se o formato não for
+/// reconhecido ou se a imagem não puder ser decodificada.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::media::make_thumbnail;
+///
+/// let file = downloader.download(&media_url).await?;
+/// let thumbnail = make_thumbnail(&file.bytes, 256)?;
+/// storage.put("preview.jpg", thumbnail.bytes).await?;
+/// ```
+pub fn make_thumbnail(bytes: &[u8], max_px: u32) -> Result<Thumbnail> {
+    if max_px == 0 {
+        return Err(ChatGuruError::ValidationError(
+            "Thumbnail size must be greater than zero".to_string(),
+        ));
+    }
+    let mime = sniff_mime(bytes)
+        .filter(|mime| mime.starts_with("image/"))
+        .ok_or_else(|| ChatGuruError::ValidationError("Unsupported image format".to_string()))?;
+    let (width, height) = image_dimensions(bytes).ok_or_else(|| {
+        ChatGuruError::ValidationError(format!("Could not read {} dimensions", mime))
+    })?;
+
+    if width.max(height) <= max_px && exif_orientation(bytes) == Orientation::NoTransforms {
+        return Ok(Thumbnail {
+            bytes: strip_exif(bytes)?,
+            mime,
+            width,
+            height,
+        });
+    }
+
+    resize(bytes, mime, max_px)
+}
+
+/// Orientação do EXIF da imagem (`NoTransforms` se ausente ou ilegível)
+fn exif_orientation(bytes: &[u8]) -> Orientation {
+    ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .ok()
+        .and_then(|reader| reader.into_decoder().ok())
+        .and_then(|mut decoder| decoder.orientation().ok())
+        .unwrap_or(Orientation::NoTransforms)
+}
+
+/// Decodifica, gira, reduz e recodifica a imagem para caber em `max_px`
+fn resize(bytes: &[u8], mime: &'static str, max_px: u32) -> Result<Thumbnail> {
+    let invalid = |e: ImageError| {
+        ChatGuruError::ValidationError(format!("Could not decode {} image: {}", mime, e))
+    };
+
+    let mut decoder = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|e| invalid(ImageError::IoError(e)))?
+        .into_decoder()
+        .map_err(invalid)?;
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+    let mut image = DynamicImage::from_decoder(decoder).map_err(invalid)?;
+    image.apply_orientation(orientation);
+    if image.width().max(image.height()) > max_px {
+        image = image.thumbnail(max_px, max_px);
+    }
+
+    let mut output = Vec::new();
+    let encoded = if mime == "image/jpeg" {
+        let encoder = JpegEncoder::new_with_quality(&mut output, THUMBNAIL_JPEG_QUALITY);
+        DynamicImage::ImageRgb8(image.to_rgb8())
+            .write_with_encoder(encoder)
+            .map(|()| "image/jpeg")
+    } else {
+        image
+            .write_with_encoder(PngEncoder::new(&mut output))
+            .map(|()| "image/png")
+    };
+    let mime = encoded.map_err(|e| {
+        ChatGuruError::ValidationError(format!("Could not encode {} thumbnail: {}", mime, e))
+    })?;
+
+    Ok(Thumbnail {
+        bytes: output,
+        mime,
+        width: image.width(),
+        height: image.height(),
+    })
+}
+
+/// Remove os metadados (EXIF, XMP, IPTC, textos) da imagem
+///
+/// Suporta JPEG, PNG e WebP; GIFs são retornados sem alteração. Os pixels
+/// não são recodificados. A orientação do EXIF também é removida, então
+/// fotos tiradas com o celular na vertical podem aparecer rotacionadas.
+///
+/// # Erros
+///
+/// Retorna `ValidationError` se o formato não for suportado ou o arquivo
+/// estiver corrompido.
+pub fn strip_exif(bytes: &[u8]) -> Result<Vec<u8>> {
+    let corrupted = |mime: &str| {
+        ChatGuruError::ValidationError(format!("Corrupted {} image, metadata not removed", mime))
+    };
+
+    match sniff_mime(bytes) {
+        Some("image/jpeg") => strip_jpeg(bytes).ok_or_else(|| corrupted("JPEG")),
+        Some("image/png") => strip_png(bytes).ok_or_else(|| corrupted("PNG")),
+        Some("image/webp") => strip_webp(bytes).ok_or_else(|| corrupted("WebP")),
+        Some("image/gif") => Ok(bytes.to_vec()),
+        other => Err(ChatGuruError::ValidationError(format!(
+            "Unsupported image format for metadata removal: {}",
+            other.unwrap_or("unknown")
+        ))),
+    }
+}
+
+/// Segmento de um JPEG: marcador e conteúdo (sem o campo de tamanho)
+struct JpegSegment<'a> {
+    marker: u8,
+    data: &'a [u8],
+    raw: &'a [u8],
+}
+
+/// Segmentos do JPEG até o início dos dados da imagem (SOS, exclusive)
+///
+/// Retorna os segmentos e o offset do SOS, ou `None` se o arquivo estiver corrompido.
+fn jpeg_segments(bytes: &[u8]) -> Option<(Vec<JpegSegment<'_>>, usize)> {
+    let mut segments = Vec::new();
+    let mut offset = 2;
+    loop {
+        // Bytes 0xFF extras são preenchimento
+        while bytes.get(offset + 1) == Some(&0xFF) {
+            offset += 1;
+        }
+        if *bytes.get(offset)? != 0xFF {
+            return None;
+        }
+        let marker = *bytes.get(offset + 1)?;
+        if marker == 0xDA {
+            return Some((segments, offset));
+        }
+        let length = u16::from_be_bytes(bytes.get(offset + 2..offset + 4)?.try_into().ok()?);
+        let end = offset + 2 + length as usize;
+        segments.push(JpegSegment {
+            marker,
+            data: bytes.get(offset + 4..end)?,
+            raw: &bytes[offset..end],
+        });
+        offset = end;
+    }
+}
+
+fn strip_jpeg(bytes: &[u8]) -> Option<Vec<u8>> {
+    let (segments, sos) = jpeg_segments(bytes)?;
+
+    let mut output = Vec::with_capacity(bytes.len());
+    output.extend_from_slice(&bytes[..2]);
+    for segment in segments {
+        let metadata = match segment.marker {
+            // APP1: EXIF ou XMP
+            0xE1 => {
+                segment.data.starts_with(b"Exif\0")
+                    || segment.data.starts_with(b"http://ns.adobe.com/")
+            }
+            // APP13: IPTC (Photoshop)
+            0xED => true,
+            // COM: comentário
+            0xFE => true,
+            _ => false,
+        };
+        if !metadata {
+            output.extend_from_slice(segment.raw);
+        }
+    }
+    output.extend_from_slice(&bytes[sos..]);
+    Some(output)
+}
+
+fn jpeg_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    let (segments, _) = jpeg_segments(bytes)?;
+    segments
+        .iter()
+        .find(|s| matches!(s.marker, 0xC0..=0xCF) && !matches!(s.marker, 0xC4 | 0xC8 | 0xCC))
+        .and_then(|sof| {
+            let height = u16::from_be_bytes(sof.data.get(1..3)?.try_into().ok()?);
+            let width = u16::from_be_bytes(sof.data.get(3..5)?.try_into().ok()?);
+            Some((width.into(), height.into()))
+        })
+}
+
+fn strip_png(bytes: &[u8]) -> Option<Vec<u8>> {
+    let mut output = bytes.get(..8)?.to_vec();
+    let mut offset = 8;
+    while offset < bytes.len() {
+        let length = u32::from_be_bytes(bytes.get(offset..offset + 4)?.try_into().ok()?);
+        let kind = bytes.get(offset + 4..offset + 8)?;
+        // Tamanho + tipo + dados + CRC
+        let end = offset + 12 + length as usize;
+        let chunk = bytes.get(offset..end)?;
+
+        if !matches!(kind, b"eXIf" | b"tEXt" | b"zTXt" | b"iTXt" | b"tIME") {
+            output.extend_from_slice(chunk);
+        }
+        offset = end;
+    }
+    Some(output)
+}
+
+fn strip_webp(bytes: &[u8]) -> Option<Vec<u8>> {
+    let mut chunks = Vec::new();
+    let mut offset = 12;
+    while offset < bytes.len() {
+        let kind = bytes.get(offset..offset + 4)?;
+        let length = u32::from_le_bytes(bytes.get(offset + 4..offset + 8)?.try_into().ok()?);
+        // Chunks têm tamanho par (byte de preenchimento quando ímpar)
+        let end = (offset + 8 + length as usize + (length as usize & 1)).min(bytes.len());
+        let chunk = bytes.get(offset..end)?;
+
+        match kind {
+            b"EXIF" | b"XMP " => {}
+            b"VP8X" => {
+                let mut chunk = chunk.to_vec();
+                // Flags de EXIF (0x08) e XMP (0x04)
+                *chunk.get_mut(8)? &= !0x0C;
+                chunks.push(chunk);
+            }
+            _ => chunks.push(chunk.to_vec()),
+        }
+        offset = end;
+    }
+
+    let size: usize = 4 + chunks.iter().map(Vec::len).sum::<usize>();
+    let mut output = Vec::with_capacity(size + 8);
+    output.extend_from_slice(b"RIFF");
+    output.extend_from_slice(&u32::try_from(size).ok()?.to_le_bytes());
+    output.extend_from_slice(b"WEBP");
+    for chunk in chunks {
+        output.extend_from_slice(&chunk);
+    }
+    Some(output)
+}
+
+fn webp_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    let u24 = |offset: usize| -> Option<u32> {
+        let raw = bytes.get(offset..offset + 3)?;
+        Some(u32::from_le_bytes([raw[0], raw[1], raw[2], 0]))
+    };
+
+    match bytes.get(12..16)? {
+        b"VP8X" => Some((u24(24)? + 1, u24(27)? + 1)),
+        b"VP8 " => {
+            // Frame tag (3 bytes) e start code 9d 01 2a antes das dimensões
+            if bytes.get(23..26)? != [0x9D, 0x01, 0x2A] {
+                return None;
+            }
+            let width = u16::from_le_bytes(bytes.get(26..28)?.try_into().ok()?) & 0x3FFF;
+            let height = u16::from_le_bytes(bytes.get(28..30)?.try_into().ok()?) & 0x3FFF;
+            Some((width.into(), height.into()))
+        }
+        b"VP8L" => {
+            if *bytes.get(20)? != 0x2F {
+                return None;
+            }
+            let bits = u32::from_le_bytes(bytes.get(21..25)?.try_into().ok()?);
+            Some(((bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,27 @@
+//! Download e identificação de mídias recebidas nos webhooks
+//!
+//! - [`MediaDownloader`]: baixa a mídia com limite de tamanho e detecta o tipo
+//! - [`sniff_mime`]: identifica o MIME type pelos primeiros bytes do arquivo
+//! - [`MediaAttachment`]: mídia a ser enviada com `send_media_message()`
+//! - [`DocumentInfo`]: tipo real, páginas e tamanho dos documentos recebidos
+//! - [`probe_opus`]: valida áudios `ptt` (OGG/Opus) e lê a duração
+//! - `make_thumbnail` / `strip_exif`: prévias de imagens sem metadados (feature `image`)
+//! - [`MediaProcessor`]: processamento das mídias recebidas (transcrição, OCR)
+
+mod attachment;
+mod audio;
+mod document;
+mod download;
+#[cfg(feature = "image")]
+mod image;
+mod processor;
+mod sniff;
+
+pub use attachment::{mime_from_extension, MediaAttachment, MediaSource, SUPPORTED_MIME_TYPES};
+pub use audio::{is_ogg_opus, probe_opus, validate_voice_note, OpusInfo, OPUS_MIME};
+pub use document::{pdf_page_count, DocumentInfo};
+pub use download::{download, MediaDownloader, MediaFile, DEFAULT_MAX_MEDIA_SIZE};
+#[cfg(feature = "image")]
+pub use image::{image_dimensions, make_thumbnail, strip_exif, Thumbnail};
+pub use processor::{MediaProcessor, ProcessedMedia};
+pub use sniff::sniff_mime;
@@ -0,0 +1,88 @@
+use super::download::MediaFile;
+use crate::error::Result;
+use futures_core::future::BoxFuture;
+
+/// Resultado de um [`MediaProcessor`] (transcrição, OCR, descrição...)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProcessedMedia {
+    /// Nome do processador que gerou o resultado (preenchido pelo pipeline)
+    pub processor: String,
+    /// Título da anotação (ex: `Transcrição do áudio`); padrão: nome do processador
+    pub label: Option<String>,
+    /// Texto extraído da mídia
+    pub text: String,
+    /// URL da mídia processada (preenchida pelo pipeline)
+    pub media_url: Option<String>,
+}
+
+impl ProcessedMedia {
+    /// Resultado com o texto extraído
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Define o título da anotação
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Texto anotado no chat: `{título}:` seguido do texto
+    ///
+    /// `None` quando o texto está vazio (ex: áudio sem fala).
+    pub fn annotation_text(&self) -> Option<String> {
+        let text = self.text.trim();
+        if text.is_empty() {
+            return None;
+        }
+        let label = self.label.as_deref().unwrap_or(&self.processor);
+        Some(format!("{}:\n{}", label, text))
+    }
+}
+
+/// Processamento das mídias recebidas (ex: transcrição com Whisper, OCR)
+///
+/// O [`Pipeline`](crate::pipeline::Pipeline) baixa a mídia (com limite de
+/// tamanho e retry) e entrega o arquivo a cada processador que o aceitar.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::media::{MediaFile, MediaProcessor, ProcessedMedia};
+///
+/// struct Whisper { /* ... */ }
+///
+/// impl MediaProcessor for Whisper {
+///     fn name(&self) -> &str {
+///         "Whisper"
+///     }
+///
+///     fn accepts(&self, file: &MediaFile) -> bool {
+///         file.mime_family() == "audio"
+///     }
+///
+///     fn process<'a>(&'a self, file: &'a MediaFile) -> BoxFuture<'a, chatguru::Result<ProcessedMedia>> {
+///         Box::pin(async move {
+///             let text = self.transcribe(&file.bytes, &file.mime).await?;
+///             Ok(ProcessedMedia::new(text).with_label("Transcrição do áudio"))
+///         })
+///     }
+/// }
+/// ```
+pub trait MediaProcessor: Send + Sync {
+    /// Nome usado em logs e como título padrão da anotação
+    fn name(&self) -> &str {
+        "MediaProcessor"
+    }
+
+    /// Indica se o processador trata o arquivo (padrão: todos)
+    fn accepts(&self, _file: &MediaFile) -> bool {
+        true
+    }
+
+    /// Processa o arquivo baixado
+    fn process<'a>(&'a self, file: &'a MediaFile) -> BoxFuture<'a, Result<ProcessedMedia>>;
+}
@@ -0,0 +1,120 @@
+use super::attachment::mime_from_extension;
+
+/// Identifica o MIME type pelos bytes iniciais ("magic numbers") do arquivo
+///
+/// Cobre os formatos que o WhatsApp encaminha: imagens, áudios (incluindo
+/// OGG/Opus dos áudios `ptt`), vídeos e documentos comuns. Arquivos ZIP são
+/// inspecionados para distinguir documentos do Office (DOCX, XLSX, PPTX) e
+/// do OpenDocument; documentos do Office antigos (DOC, XLS, PPT) são
+/// identificados apenas como `application/x-ole-storage`.
+///
+/// # Retorno
+///
+/// `Some(mime)` quando o formato é reconhecido, `None` caso contrário.
+pub fn sniff_mime(bytes: &[u8]) -> Option<&'static str> {
+    let starts = |magic: &[u8]| bytes.starts_with(magic);
+
+    if starts(&[0xFF, 0xD8, 0xFF]) {
+        return Some("image/jpeg");
+    }
+    if starts(b"\x89PNG\r\n\x1a\n") {
+        return Some("image/png");
+    }
+    if starts(b"GIF87a") || starts(b"GIF89a") {
+        return Some("image/gif");
+    }
+    if starts(b"RIFF") && bytes.len() >= 12 {
+        match &bytes[8..12] {
+            b"WEBP" => return Some("image/webp"),
+            b"WAVE" => return Some("audio/wav"),
+            _ => {}
+        }
+    }
+    if starts(b"%PDF-") {
+        return Some("application/pdf");
+    }
+    if starts(b"OggS") {
+        return Some("audio/ogg");
+    }
+    if starts(b"#!AMR") {
+        return Some("audio/amr");
+    }
+    if starts(b"ID3") || (bytes.len() >= 2 && bytes[0] == 0xFF && bytes[1] & 0xE0 == 0xE0) {
+        return Some("audio/mpeg");
+    }
+    if bytes.len() >= 12 && &bytes[4..8] == b"ftyp" {
+        return Some(match &bytes[8..12] {
+            b"M4A " | b"M4B " => "audio/mp4",
+            b"qt  " => "video/quicktime",
+            b"3gp4" | b"3gp5" | b"3gp6" | b"3g2a" => "video/3gpp",
+            _ => "video/mp4",
+        });
+    }
+    if starts(b"PK\x03\x04") {
+        return Some(sniff_zip(bytes));
+    }
+    if starts(&[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1]) {
+        return Some("application/x-ole-storage");
+    }
+
+    None
+}
+
+/// MIME type pelos bytes, usando a extensão do arquivo para formatos genéricos
+///
+/// ZIPs não reconhecidos e documentos OLE (DOC, XLS, PPT) só são
+/// distinguidos pela extensão; nos demais casos prevalece o conteúdo.
+pub(crate) fn sniff_mime_with_name(bytes: &[u8], filename: Option<&str>) -> Option<&'static str> {
+    let from_name = filename.and_then(mime_from_extension);
+    match sniff_mime(bytes) {
+        Some("application/zip" | "application/x-ole-storage") if from_name.is_some() => from_name,
+        Some(sniffed) => Some(sniffed),
+        None => from_name,
+    }
+}
+
+/// Formatos baseados em ZIP, identificados pelo conteúdo do arquivo
+const ZIP_FORMATS: &[(&[u8], &str)] = &[
+    (
+        b"word/document",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    ),
+    (
+        b"xl/workbook",
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    ),
+    (
+        b"ppt/presentation",
+        "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+    ),
+];
+
+/// Documentos OpenDocument (entrada `mimetype` no início do ZIP)
+const OPENDOCUMENT_FORMATS: &[&str] = &[
+    "application/vnd.oasis.opendocument.text",
+    "application/vnd.oasis.opendocument.spreadsheet",
+    "application/vnd.oasis.opendocument.presentation",
+];
+
+/// Identifica documentos do Office/OpenDocument dentro de um ZIP
+///
+/// Os nomes das entradas aparecem nos cabeçalhos locais e no diretório
+/// central (no fim do arquivo), então a busca funciona mesmo com a ordem
+/// das entradas variando entre editores.
+fn sniff_zip(bytes: &[u8]) -> &'static str {
+    // Entrada `mimetype` sem compressão, obrigatória como primeira no OpenDocument
+    if bytes.get(30..38) == Some(b"mimetype") {
+        if let Some(mime) = OPENDOCUMENT_FORMATS
+            .iter()
+            .find(|mime| bytes.get(38..38 + mime.len()) == Some(mime.as_bytes()))
+        {
+            return mime;
+        }
+    }
+
+    ZIP_FORMATS
+        .iter()
+        .find(|(entry, _)| bytes.windows(entry.len()).any(|window| window == *entry))
+        .map(|(_, mime)| *mime)
+        .unwrap_or("application/zip")
+}
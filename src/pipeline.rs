@@ -0,0 +1,367 @@
+//! Pipeline de integração com sistemas externos (CRMs)
+//!
+//! Cada integração implementa [`CrmSink`], recebendo o [`ChatEvent`] já
+//! normalizado. O [`Pipeline`] faz o restante do fluxo comum a todas elas:
+//! normaliza o webhook, converte em evento, entrega a cada sink e anota no
+//! chat o resultado.
+//!
+//! Webhooks com mídia também podem passar por [`MediaProcessor`]s
+//! (transcrição, OCR): o pipeline baixa o arquivo uma vez e anota no chat o
+//! texto extraído por cada processador.
+//!
+//! # Exemplo
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//! use chatguru::pipeline::{CrmSink, Pipeline, SinkReceipt};
+//!
+//! struct Pipedrive { /* ... */ }
+//!
+//! impl CrmSink for Pipedrive {
+//!     fn name(&self) -> &str {
+//!         "Pipedrive"
+//!     }
+//!
+//!     fn push(&self, event: ChatEvent) -> BoxFuture<'_, chatguru::Result<SinkReceipt>> {
+//!         Box::pin(async move {
+//!             let deal_id = self.create_deal(&event).await?;
+//!             Ok(SinkReceipt::new(deal_id))
+//!         })
+//!     }
+//! }
+//!
+//! let pipeline = Pipeline::new(Arc::new(client)).sink(Arc::new(Pipedrive { /* ... */ }));
+//! let outcome = pipeline.process(payload).await?;
+//! ```
+
+use crate::client::ChatGuruApi;
+use crate::client::RetryPolicy;
+use crate::error::Result;
+use crate::media::{MediaDownloader, MediaProcessor, ProcessedMedia};
+use crate::types::{ChatEvent, MediaTypeMap, PhoneNumber, WebhookPayload};
+use futures_core::future::BoxFuture;
+use std::fmt;
+use std::sync::Arc;
+
+/// Resultado da entrega de um evento a um [`CrmSink`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SinkReceipt {
+    /// Nome do sink que gerou o recibo (preenchido pelo [`Pipeline`])
+    pub sink: String,
+    /// ID do registro criado ou atualizado no sistema externo
+    pub external_id: Option<String>,
+    /// Link para o registro no sistema externo
+    pub url: Option<String>,
+    /// Texto da anotação no chat (substitui o texto padrão do pipeline)
+    pub annotation: Option<String>,
+}
+
+impl SinkReceipt {
+    /// Recibo com o ID do registro externo
+    pub fn new(external_id: impl Into<String>) -> Self {
+        Self {
+            external_id: Some(external_id.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Recibo de um evento ignorado pelo sink (sem anotação)
+    pub fn skipped() -> Self {
+        Self::default()
+    }
+
+    /// Define o link do registro
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Define o texto da anotação
+    pub fn with_annotation(mut self, annotation: impl Into<String>) -> Self {
+        self.annotation = Some(annotation.into());
+        self
+    }
+
+    /// Indica se o sink registrou o evento
+    pub fn is_recorded(&self) -> bool {
+        self.external_id.is_some() || self.annotation.is_some()
+    }
+
+    /// Texto anotado no chat para este recibo
+    ///
+    /// Usa [`annotation`](Self::annotation) quando definido; senão,
+    /// `Registrado em {sink}: {id}` seguido do link. `None` para recibos de
+    /// eventos ignorados.
+    pub fn annotation_text(&self) -> Option<String> {
+        if let Some(ref annotation) = self.annotation {
+            return Some(annotation.clone());
+        }
+        let external_id = self.external_id.as_ref()?;
+        let mut text = format!("Registrado em {}: {}", self.sink, external_id);
+        if let Some(ref url) = self.url {
+            text.push('\n');
+            text.push_str(url);
+        }
+        Some(text)
+    }
+}
+
+/// Integração que recebe os eventos do ChatGuru (CRM, helpdesk, planilha...)
+pub trait CrmSink: Send + Sync {
+    /// Nome usado em logs e na anotação padrão
+    fn name(&self) -> &str {
+        "CRM"
+    }
+
+    /// Indica se o sink trata o evento (padrão: todos exceto `Unknown`)
+    fn accepts(&self, event: &ChatEvent) -> bool {
+        !matches!(event, ChatEvent::Unknown(_))
+    }
+
+    /// Entrega o evento ao sistema externo
+    fn push(&self, event: ChatEvent) -> BoxFuture<'_, Result<SinkReceipt>>;
+}
+
+/// Resultado de [`Pipeline::process`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PipelineOutcome {
+    /// Tipo do evento ([`ChatEvent::kind`])
+    pub event_kind: &'static str,
+    /// Recibos dos sinks que aceitaram o evento, na ordem de registro
+    pub receipts: Vec<SinkReceipt>,
+    /// Resultados dos processadores de mídia
+    pub media: Vec<ProcessedMedia>,
+    /// Sinks, processadores de mídia e anotação que falharam, com o erro
+    pub failures: Vec<(String, String)>,
+    /// Quantidade de anotações feitas no chat
+    pub annotations: usize,
+}
+
+impl PipelineOutcome {
+    /// Indica se nenhum sink, processador de mídia ou anotação falhou
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Webhook → normalização → sinks → anotação no chat
+///
+/// Uma falha em um sink ou processador de mídia não impede os demais: ela
+/// é registrada em [`PipelineOutcome::failures`] (o download da mídia
+/// aparece como `media` e a anotação como `annotation`). A anotação só é feita quando o webhook traz
+/// `chat_id` e telefone, e pode ser desligada com
+/// [`annotate`](Self::annotate).
+#[derive(Clone)]
+pub struct Pipeline {
+    client: Arc<dyn ChatGuruApi>,
+    sinks: Vec<Arc<dyn CrmSink>>,
+    processors: Vec<Arc<dyn MediaProcessor>>,
+    downloader: MediaDownloader,
+    media_types: MediaTypeMap,
+    annotate: bool,
+}
+
+impl fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pipeline")
+            .field(
+                "sinks",
+                &self.sinks.iter().map(|s| s.name()).collect::<Vec<_>>(),
+            )
+            .field(
+                "processors",
+                &self.processors.iter().map(|p| p.name()).collect::<Vec<_>>(),
+            )
+            .field("annotate", &self.annotate)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Pipeline {
+    /// Cria um pipeline sem sinks, que anota pelo cliente informado
+    pub fn new(client: Arc<dyn ChatGuruApi>) -> Self {
+        Self {
+            client,
+            sinks: Vec::new(),
+            processors: Vec::new(),
+            downloader: MediaDownloader::new().retry_policy(RetryPolicy::default()),
+            media_types: MediaTypeMap::default(),
+            annotate: true,
+        }
+    }
+
+    /// Adiciona um sink (executados na ordem em que foram adicionados)
+    pub fn sink(mut self, sink: Arc<dyn CrmSink>) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+
+    /// Adiciona um processador de mídia (executados na ordem em que foram adicionados)
+    pub fn media_processor(mut self, processor: Arc<dyn MediaProcessor>) -> Self {
+        self.processors.push(processor);
+        self
+    }
+
+    /// Define o downloader das mídias (padrão: 16 MiB e até 3 tentativas)
+    pub fn media_downloader(mut self, downloader: MediaDownloader) -> Self {
+        self.downloader = downloader;
+        self
+    }
+
+    /// Define o mapeamento `tipo_mensagem → MIME type` usado na normalização
+    pub fn media_types(mut self, media_types: MediaTypeMap) -> Self {
+        self.media_types = media_types;
+        self
+    }
+
+    /// Liga/desliga a anotação dos recibos no chat (padrão: ligada)
+    pub fn annotate(mut self, enabled: bool) -> Self {
+        self.annotate = enabled;
+        self
+    }
+
+    /// Processa um webhook
+    ///
+    /// # Erros
+    ///
+    /// Retorna erro apenas se o payload não puder ser convertido em
+    /// [`ChatEvent`]. Falhas de sinks, mídia e anotação ficam em
+    /// [`PipelineOutcome::failures`].
+    pub async fn process(&self, mut payload: WebhookPayload) -> Result<PipelineOutcome> {
+        payload.normalize_media_fields_with(&self.media_types);
+        let event = ChatEvent::try_from(&payload)?;
+
+        let mut outcome = PipelineOutcome {
+            event_kind: event.kind(),
+            ..PipelineOutcome::default()
+        };
+
+        self.process_media(&payload, &mut outcome).await;
+
+        for sink in &self.sinks {
+            if !sink.accepts(&event) {
+                continue;
+            }
+            match sink.push(event.clone()).await {
+                Ok(mut receipt) => {
+                    receipt.sink = sink.name().to_string();
+                    outcome.receipts.push(receipt);
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "Sink {} failed for {} event: {}",
+                        sink.name(),
+                        event.kind(),
+                        e
+                    );
+                    outcome
+                        .failures
+                        .push((sink.name().to_string(), e.to_string()));
+                }
+            }
+        }
+
+        if self.annotate {
+            let texts: Vec<String> = outcome
+                .media
+                .iter()
+                .filter_map(ProcessedMedia::annotation_text)
+                .chain(
+                    outcome
+                        .receipts
+                        .iter()
+                        .filter_map(SinkReceipt::annotation_text),
+                )
+                .collect();
+            self.annotate_chat(&event, &texts, &mut outcome).await;
+        }
+
+        Ok(outcome)
+    }
+
+    /// Baixa a mídia do webhook e a entrega aos processadores que a aceitarem
+    async fn process_media(&self, payload: &WebhookPayload, outcome: &mut PipelineOutcome) {
+        if self.processors.is_empty() || !payload.has_media() {
+            return;
+        }
+        let Some(url) = payload.get_media_url() else {
+            return;
+        };
+
+        let file = match self.downloader.download(&url).await {
+            Ok(file) => file,
+            Err(e) => {
+                tracing::error!("Media download failed for pipeline: {}", e);
+                outcome.failures.push(("media".to_string(), e.to_string()));
+                return;
+            }
+        };
+
+        for processor in &self.processors {
+            if !processor.accepts(&file) {
+                continue;
+            }
+            match processor.process(&file).await {
+                Ok(mut processed) => {
+                    processed.processor = processor.name().to_string();
+                    processed.media_url = Some(url.clone());
+                    outcome.media.push(processed);
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "Media processor {} failed for {}: {}",
+                        processor.name(),
+                        file.mime,
+                        e
+                    );
+                    outcome
+                        .failures
+                        .push((processor.name().to_string(), e.to_string()));
+                }
+            }
+        }
+    }
+
+    /// Anota os textos no chat do evento, somando as anotações feitas em `outcome`
+    ///
+    /// Para na primeira falha, que é registrada como `annotation`.
+    async fn annotate_chat(
+        &self,
+        event: &ChatEvent,
+        texts: &[String],
+        outcome: &mut PipelineOutcome,
+    ) {
+        if texts.is_empty() {
+            return;
+        }
+
+        let chat = event.chat();
+        let chat_id = chat.and_then(|c| c.chat_id.as_deref());
+        let phone = chat.and_then(|c| c.phone.as_deref());
+        let (Some(chat_id), Some(phone)) = (chat_id, phone) else {
+            tracing::debug!("Event without chat_id or phone, pipeline annotation skipped");
+            return;
+        };
+
+        for text in texts {
+            match self
+                .client
+                .add_annotation(chat_id, PhoneNumber::from(phone), text)
+                .await
+            {
+                Ok(_) => outcome.annotations += 1,
+                Err(e) => {
+                    tracing::error!(
+                        "Pipeline annotation failed for {} event: {}",
+                        event.kind(),
+                        e
+                    );
+                    outcome
+                        .failures
+                        .push(("annotation".to_string(), e.to_string()));
+                    return;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use super::{EventMessage, EventPublisher, MessageFormat};
+use crate::error::{ChatGuruError, Result};
+use futures_core::future::BoxFuture;
+use rdkafka::error::{KafkaError, RDKafkaErrorCode};
+use rdkafka::message::{Header, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use rdkafka::ClientConfig;
+use std::fmt;
+use std::time::Duration;
+
+/// Tempo padrão de espera por espaço na fila local do produtor (5 segundos)
+const DEFAULT_QUEUE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Publica eventos em um tópico Kafka com o produtor da librdkafka
+///
+/// A chave do registro é a chave de ordenação do chat, então todos os
+/// eventos de um chat vão para a mesma partição. Os atributos da mensagem
+/// vão nos headers do registro.
+///
+/// O ID retornado por [`publish`](EventPublisher::publish) é
+/// `{tópico}:{partição}:{offset}`.
+///
+/// Disponível com a feature `kafka` (compila a librdkafka; sem ela, use o
+/// `KafkaRestPublisher` da feature `kafka-rest`).
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::publisher::{EventPublisher, KafkaPublisher, MessageFormat};
+///
+/// let publisher = KafkaPublisher::new("kafka-1:9092,kafka-2:9092", "chatguru.events")?
+///     .format(MessageFormat::Envelope);
+///
+/// let id = publisher.publish_payload(&payload).await?;
+/// ```
+#[derive(Clone)]
+pub struct KafkaPublisher {
+    producer: FutureProducer,
+    topic: String,
+    format: MessageFormat,
+    queue_timeout: Duration,
+}
+
+impl fmt::Debug for KafkaPublisher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KafkaPublisher")
+            .field("topic", &self.topic)
+            .field("format", &self.format)
+            .field("queue_timeout", &self.queue_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl KafkaPublisher {
+    /// Cria o publisher para o tópico, conectando aos brokers informados
+    /// (`host:porta` separados por vírgula)
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se a configuração do produtor for recusada.
+    pub fn new(brokers: &str, topic: impl Into<String>) -> Result<Self> {
+        let mut config = ClientConfig::new();
+        config.set("bootstrap.servers", brokers);
+        Self::with_config(&config, topic)
+    }
+
+    /// Cria o publisher com uma configuração completa da librdkafka
+    ///
+    /// Use para autenticação e TLS (ex: `security.protocol`,
+    /// `sasl.mechanisms`, `sasl.username`, `sasl.password`).
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se a configuração do produtor for recusada.
+    pub fn with_config(config: &ClientConfig, topic: impl Into<String>) -> Result<Self> {
+        let producer: FutureProducer = config.create().map_err(|e| {
+            ChatGuruError::ValidationError(format!("Invalid Kafka producer config: {}", e))
+        })?;
+        Ok(Self {
+            producer,
+            topic: topic.into(),
+            format: MessageFormat::default(),
+            queue_timeout: DEFAULT_QUEUE_TIMEOUT,
+        })
+    }
+
+    /// Define o formato do valor dos registros (padrão: [`MessageFormat::Json`])
+    pub fn format(mut self, format: MessageFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Define quanto esperar por espaço na fila local do produtor (padrão: 5s)
+    ///
+    /// O tempo até a confirmação do broker é limitado por
+    /// `message.timeout.ms` na configuração da librdkafka.
+    pub fn queue_timeout(mut self, timeout: Duration) -> Self {
+        self.queue_timeout = timeout;
+        self
+    }
+}
+
+impl EventPublisher for KafkaPublisher {
+    /// Publica a mensagem como um registro do tópico
+    ///
+    /// # Erros
+    ///
+    /// * `Timeout` - o registro não foi confirmado dentro do prazo
+    /// * `NetworkError` - nenhum broker acessível
+    /// * `InternalError` - o Kafka recusou o registro
+    /// * `SerializationError` - `data` não é JSON no formato [`MessageFormat::Envelope`]
+    fn publish<'a>(&'a self, message: &'a EventMessage) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let value = message.encode(self.format)?;
+            let headers =
+                message
+                    .attributes
+                    .iter()
+                    .fold(OwnedHeaders::new(), |headers, (name, value)| {
+                        headers.insert(Header {
+                            key: name,
+                            value: Some(value),
+                        })
+                    });
+
+            let mut record = FutureRecord::to(&self.topic)
+                .payload(&value)
+                .headers(headers);
+            if let Some(ref key) = message.ordering_key {
+                record = record.key(key);
+            }
+
+            let (partition, offset) = self
+                .producer
+                .send(record, self.queue_timeout)
+                .await
+                .map_err(|(e, _)| kafka_error(&self.topic, e))?;
+
+            let id = format!("{}:{}:{}", self.topic, partition, offset);
+            tracing::debug!("Event published to Kafka as {}", id);
+            Ok(id)
+        })
+    }
+}
+
+fn kafka_error(topic: &str, e: KafkaError) -> ChatGuruError {
+    match e.rdkafka_error_code() {
+        Some(RDKafkaErrorCode::MessageTimedOut | RDKafkaErrorCode::QueueFull) => {
+            ChatGuruError::Timeout(format!("Kafka delivery to {} timed out: {}", topic, e))
+        }
+        Some(RDKafkaErrorCode::AllBrokersDown | RDKafkaErrorCode::BrokerTransportFailure) => {
+            ChatGuruError::NetworkError(format!("Kafka brokers unreachable: {}", e))
+        }
+        _ => ChatGuruError::InternalError(format!("Kafka rejected record for {}: {}", topic, e)),
+    }
+}
@@ -0,0 +1,187 @@
+use super::{EventMessage, EventPublisher, MessageFormat};
+use crate::client::encode_component;
+use crate::error::{ChatGuruError, Result};
+use crate::secret::{describe_error, SecretString};
+use base64::Engine;
+use futures_core::future::BoxFuture;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::fmt;
+
+/// Content-Type do formato binário da API v2 do REST Proxy
+const KAFKA_BINARY_CONTENT_TYPE: &str = "application/vnd.kafka.binary.v2+json";
+
+#[derive(Deserialize)]
+struct ProduceResponse {
+    #[serde(default)]
+    offsets: Vec<RecordOffset>,
+}
+
+#[derive(Deserialize)]
+struct RecordOffset {
+    partition: Option<i64>,
+    offset: Option<i64>,
+    error: Option<String>,
+}
+
+/// Publica eventos em um tópico Kafka pelo REST Proxy (Confluent / Redpanda)
+///
+/// Alternativa ao `KafkaPublisher` (feature `kafka`) para ambientes sem
+/// acesso direto aos brokers ou sem a librdkafka. A chave do registro é a chave de ordenação do chat, então todos os
+/// eventos de um chat vão para a mesma partição. O REST Proxy v2 não envia
+/// headers: use [`MessageFormat::Envelope`] quando os consumidores precisarem
+/// dos atributos.
+///
+/// O ID retornado por [`publish`](EventPublisher::publish) é
+/// `{tópico}:{partição}:{offset}`.
+///
+/// Disponível com a feature `kafka-rest`.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::publisher::{EventPublisher, KafkaRestPublisher, MessageFormat};
+///
+/// let publisher = KafkaRestPublisher::new("http://kafka-rest:8082", "chatguru.events")
+///     .basic_auth(api_key, api_secret)
+///     .format(MessageFormat::Envelope);
+///
+/// let id = publisher.publish_payload(&payload).await?;
+/// ```
+#[derive(Clone)]
+pub struct KafkaRestPublisher {
+    rest_url: String,
+    topic: String,
+    format: MessageFormat,
+    credentials: Option<(String, SecretString)>,
+    http_client: reqwest::Client,
+}
+
+impl fmt::Debug for KafkaRestPublisher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KafkaRestPublisher")
+            .field("rest_url", &self.rest_url)
+            .field("topic", &self.topic)
+            .field("format", &self.format)
+            .field("credentials", &self.credentials)
+            .finish_non_exhaustive()
+    }
+}
+
+impl KafkaRestPublisher {
+    /// Cria o publisher para o tópico, usando o REST Proxy em `rest_url`
+    pub fn new(rest_url: impl Into<String>, topic: impl Into<String>) -> Self {
+        Self {
+            rest_url: rest_url.into().trim_end_matches('/').to_string(),
+            topic: topic.into(),
+            format: MessageFormat::default(),
+            credentials: None,
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Define o formato do valor dos registros (padrão: [`MessageFormat::Json`])
+    pub fn format(mut self, format: MessageFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Autentica com usuário e senha (ex: API key e secret do Confluent Cloud)
+    pub fn basic_auth(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<SecretString>,
+    ) -> Self {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+
+    /// Reutiliza um cliente HTTP existente
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = client;
+        self
+    }
+
+    /// Corpo de `POST /topics/{tópico}` para a mensagem
+    fn request_body(&self, message: &EventMessage) -> Result<Value> {
+        let engine = base64::engine::general_purpose::STANDARD;
+        let value = engine.encode(message.encode(self.format)?);
+
+        let record = match message.ordering_key {
+            Some(ref key) => json!({ "key": engine.encode(key), "value": value }),
+            None => json!({ "value": value }),
+        };
+        Ok(json!({ "records": [record] }))
+    }
+}
+
+impl EventPublisher for KafkaRestPublisher {
+    /// Publica a mensagem como um registro do tópico
+    ///
+    /// # Erros
+    ///
+    /// * `NetworkError` - falha de conexão com o REST Proxy
+    /// * `InternalError` - o REST Proxy ou o Kafka recusou o registro
+    /// * `SerializationError` - resposta do REST Proxy em formato inesperado
+    fn publish<'a>(&'a self, message: &'a EventMessage) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let url = format!("{}/topics/{}", self.rest_url, encode_component(&self.topic));
+
+            let mut request = self
+                .http_client
+                .post(&url)
+                .header("Content-Type", KAFKA_BINARY_CONTENT_TYPE)
+                .header("Accept", "application/vnd.kafka.v2+json")
+                .body(serde_json::to_vec(&self.request_body(message)?)?);
+            if let Some((ref username, ref password)) = self.credentials {
+                request = request.basic_auth(username, Some(password.expose_secret()));
+            }
+
+            let response = request
+                .send()
+                .await
+                .map_err(|e| ChatGuruError::NetworkError(describe_error(e)))?;
+
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            if !status.is_success() {
+                return Err(ChatGuruError::InternalError(format!(
+                    "Kafka REST Proxy error (status {}): {}",
+                    status.as_u16(),
+                    kafka_error(&body)
+                )));
+            }
+
+            let produced: ProduceResponse = serde_json::from_str(&body)?;
+            let record = produced.offsets.into_iter().next().ok_or_else(|| {
+                ChatGuruError::InternalError(format!(
+                    "Kafka REST Proxy response without offsets: {}",
+                    body
+                ))
+            })?;
+            if let Some(error) = record.error {
+                return Err(ChatGuruError::InternalError(format!(
+                    "Kafka rejected record for {}: {}",
+                    self.topic, error
+                )));
+            }
+
+            let id = format!(
+                "{}:{}:{}",
+                self.topic,
+                record.partition.unwrap_or_default(),
+                record.offset.unwrap_or_default()
+            );
+            tracing::debug!("Event published to Kafka as {}", id);
+            Ok(id)
+        })
+    }
+}
+
+/// Mensagem de erro do corpo de resposta do REST Proxy (`{"error_code": ..., "message": "..."}`)
+fn kafka_error(body: &str) -> String {
+    serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|value| Some(value.get("message")?.as_str()?.to_string()))
+        .unwrap_or_else(|| body.trim().to_string())
+}
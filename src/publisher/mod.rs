@@ -0,0 +1,227 @@
+//! Publicação dos webhooks recebidos em filas/tópicos
+//!
+//! Serviços que recebem os webhooks em um único ponto (ex: Cloud Run) e os
+//! distribuem para outros consumidores publicam cada evento com
+//! [`EventPublisher`]. [`EventMessage`] serializa o evento e preenche os
+//! atributos usados para filtrar e rotear (`event_kind`, `account_id`,
+//! `phone_id`, `chat_id`), além da chave de ordenação por chat.
+//!
+//! - Google Cloud Pub/Sub (API REST): [`PubSubPublisher`]
+//! - Kafka com a librdkafka (feature `kafka`): `KafkaPublisher`
+//! - Kafka via REST Proxy (feature `kafka-rest`): `KafkaRestPublisher`
+//! - NATS (feature `nats`): `NatsPublisher`
+//!
+//! Em destinos sem atributos por mensagem (ex: Kafka REST Proxy), use
+//! [`MessageFormat::Envelope`] para levar os atributos e a chave no corpo.
+//!
+//! Disponível com a feature `publisher`.
+//!
+//! # Exemplo
+//!
+//! ```rust,ignore
+//! use chatguru::publisher::{EventMessage, EventPublisher, PubSubPublisher};
+//!
+//! let publisher = PubSubPublisher::new("meu-projeto", "chatguru-webhooks")
+//!     .attribute("account_id", client.account_id());
+//!
+//! let message = EventMessage::from_payload(&payload)?;
+//! let id = publisher.publish(&message).await?;
+//! ```
+
+#[cfg(feature = "kafka")]
+mod kafka;
+#[cfg(feature = "kafka-rest")]
+mod kafka_rest;
+#[cfg(feature = "nats")]
+mod nats;
+mod pubsub;
+
+#[cfg(feature = "kafka")]
+pub use kafka::KafkaPublisher;
+#[cfg(feature = "kafka-rest")]
+pub use kafka_rest::KafkaRestPublisher;
+#[cfg(feature = "nats")]
+pub use nats::NatsPublisher;
+pub use pubsub::{PubSubAuth, PubSubPublisher, PUBSUB_API_URL};
+
+use crate::error::Result;
+use crate::types::{ChatEvent, EventEnvelope, PhoneNumber, WebhookPayload};
+use futures_core::future::BoxFuture;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Formato do corpo publicado
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// [`EventMessage::data`] como está (JSON)
+    #[default]
+    Json,
+    /// `{"key", "attributes", "data"}`, com esquema fixo
+    ///
+    /// `key` é a chave de ordenação (ou `null`), `attributes` um mapa de
+    /// strings e `data` o JSON do evento. O esquema fixo permite registrar o
+    /// formato em um schema registry (ex: Avro com `data` como string).
+    Envelope,
+}
+
+/// Corpo de [`MessageFormat::Envelope`]
+#[derive(Serialize)]
+struct Envelope<'a> {
+    key: Option<&'a str>,
+    attributes: &'a BTreeMap<String, String>,
+    data: Value,
+}
+
+/// Evento serializado para publicação
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventMessage {
+    /// Corpo da mensagem (JSON)
+    pub data: Vec<u8>,
+    /// Atributos/headers da mensagem
+    pub attributes: BTreeMap<String, String>,
+    /// Chave que mantém a ordem dos eventos de um mesmo chat
+    pub ordering_key: Option<String>,
+}
+
+/// Corpo publicado por [`EventMessage::from_payload`]
+#[derive(Serialize)]
+struct PayloadBody<'a> {
+    event: &'a ChatEvent,
+    payload: &'a WebhookPayload,
+}
+
+impl EventMessage {
+    /// Mensagem com o [`ChatEvent`] serializado como corpo
+    pub fn from_event(event: &ChatEvent) -> Result<Self> {
+        let data = serde_json::to_vec(event)?;
+        Ok(Self::new(data, event))
+    }
+
+    /// Mensagem com o evento e o payload original
+    ///
+    /// O corpo é `{"event": ChatEvent, "payload": WebhookPayload}`, para que
+    /// consumidores possam usar o evento tipado sem perder campos do payload.
+    pub fn from_payload(payload: &WebhookPayload) -> Result<Self> {
+        let event = ChatEvent::try_from(payload)?;
+        let data = serde_json::to_vec(&PayloadBody {
+            event: &event,
+            payload,
+        })?;
+
+        Ok(Self::new(data, &event))
+    }
+
+    /// Mensagem com o [`EventEnvelope`] serializado como corpo
+    ///
+    /// Além dos atributos do evento, inclui `account_id` (quando definido no
+    /// envelope), `event_id` e `envelope_version`.
+    pub fn from_envelope(envelope: &EventEnvelope) -> Result<Self> {
+        let data = serde_json::to_vec(envelope)?;
+        let mut message = Self::new(data, &envelope.event()?);
+
+        if let Some(ref account_id) = envelope.account_id {
+            message
+                .attributes
+                .insert("account_id".to_string(), account_id.clone());
+        }
+        if let Some(ref phone_id) = envelope.phone_id {
+            message
+                .attributes
+                .insert("phone_id".to_string(), phone_id.clone());
+        }
+        message
+            .attributes
+            .insert("event_id".to_string(), envelope.id.clone());
+        message
+            .attributes
+            .insert("envelope_version".to_string(), envelope.version.to_string());
+        Ok(message)
+    }
+
+    fn new(data: Vec<u8>, event: &ChatEvent) -> Self {
+        let mut attributes = BTreeMap::new();
+        attributes.insert("event_kind".to_string(), event.kind().to_string());
+
+        let chat = event.chat();
+        if let Some(phone_id) = chat.and_then(|c| c.phone_id.clone()) {
+            attributes.insert("phone_id".to_string(), phone_id);
+        }
+        if let Some(chat_id) = chat.and_then(|c| c.chat_id.clone()) {
+            attributes.insert("chat_id".to_string(), chat_id);
+        }
+
+        Self {
+            data,
+            attributes,
+            ordering_key: chat_key(event),
+        }
+    }
+
+    /// Adiciona (ou substitui) um atributo
+    pub fn with_attribute(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.insert(name.into(), value.into());
+        self
+    }
+
+    /// Define o `account_id` da conta que recebeu o webhook
+    pub fn with_account_id(self, account_id: impl Into<String>) -> Self {
+        self.with_attribute("account_id", account_id)
+    }
+
+    /// Substitui a chave de ordenação (`None` publica sem ordenação)
+    pub fn with_ordering_key(mut self, key: Option<String>) -> Self {
+        self.ordering_key = key;
+        self
+    }
+
+    /// Tipo do evento (atributo `event_kind`)
+    pub fn kind(&self) -> Option<&str> {
+        self.attributes.get("event_kind").map(String::as_str)
+    }
+
+    /// Corpo da mensagem no formato informado
+    ///
+    /// # Erros
+    ///
+    /// Retorna `SerializationError` se, no formato
+    /// [`Envelope`](MessageFormat::Envelope), `data` não for JSON.
+    pub fn encode(&self, format: MessageFormat) -> Result<Vec<u8>> {
+        match format {
+            MessageFormat::Json => Ok(self.data.clone()),
+            MessageFormat::Envelope => {
+                let data: Value = serde_json::from_slice(&self.data)?;
+                Ok(serde_json::to_vec(&Envelope {
+                    key: self.ordering_key.as_deref(),
+                    attributes: &self.attributes,
+                    data,
+                })?)
+            }
+        }
+    }
+}
+
+/// Chave de ordenação por chat: ID do chat ou, na falta dele, dígitos do telefone
+fn chat_key(event: &ChatEvent) -> Option<String> {
+    let chat = event.chat()?;
+    chat.chat_id.clone().or_else(|| {
+        chat.phone
+            .as_deref()
+            .map(|phone| PhoneNumber::from(phone).digits().to_string())
+            .filter(|digits| !digits.is_empty())
+    })
+}
+
+/// Destino de publicação dos eventos (Pub/Sub, Kafka, NATS...)
+pub trait EventPublisher: Send + Sync {
+    /// Publica uma mensagem, retornando o ID atribuído pelo destino
+    fn publish<'a>(&'a self, message: &'a EventMessage) -> BoxFuture<'a, Result<String>>;
+
+    /// Converte o payload com [`EventMessage::from_payload`] e publica
+    fn publish_payload<'a>(&'a self, payload: &'a WebhookPayload) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let message = EventMessage::from_payload(payload)?;
+            self.publish(&message).await
+        })
+    }
+}
@@ -0,0 +1,240 @@
+use super::{EventMessage, EventPublisher};
+use crate::error::{ChatGuruError, Result};
+use crate::gcp::{self, TOKEN_REFRESH_MARGIN};
+use crate::secret::{describe_error, SecretString};
+use base64::Engine;
+use futures_core::future::BoxFuture;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::Mutex;
+
+/// URL padrão da API do Pub/Sub
+pub const PUBSUB_API_URL: &str = "https://pubsub.googleapis.com/v1";
+
+/// Autenticação das chamadas ao Pub/Sub
+#[derive(Clone)]
+pub enum PubSubAuth {
+    /// Token da conta de serviço obtido no servidor de metadados (padrão)
+    Metadata,
+    /// Token OAuth fixo (ex: `gcloud auth print-access-token`)
+    Bearer(SecretString),
+    /// Sem autenticação (emulador do Pub/Sub)
+    None,
+}
+
+impl fmt::Debug for PubSubAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PubSubAuth::Metadata => f.write_str("Metadata"),
+            PubSubAuth::Bearer(token) => f.debug_tuple("Bearer").field(token).finish(),
+            PubSubAuth::None => f.write_str("None"),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct PublishResponse {
+    #[serde(rename = "messageIds", default)]
+    message_ids: Vec<String>,
+}
+
+/// Publica eventos em um tópico do Google Cloud Pub/Sub
+///
+/// Usa a API REST (`topics.publish`). Por padrão autentica com o token da
+/// conta de serviço do servidor de metadados, mantido em cache até perto
+/// da expiração. As mensagens são publicadas com a chave de ordenação do
+/// chat; o tópico/assinatura precisa ter a ordenação habilitada para que
+/// ela tenha efeito (ou desligue com [`ordering`](Self::ordering)).
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::publisher::{EventPublisher, PubSubPublisher};
+///
+/// let publisher = PubSubPublisher::new("meu-projeto", "chatguru-webhooks")
+///     .attribute("account_id", "conta-principal");
+///
+/// let message_id = publisher.publish_payload(&payload).await?;
+/// ```
+#[derive(Clone)]
+pub struct PubSubPublisher {
+    project: String,
+    topic: String,
+    endpoint: String,
+    auth: PubSubAuth,
+    ordering: bool,
+    attributes: BTreeMap<String, String>,
+    http_client: reqwest::Client,
+    token: Arc<Mutex<Option<(SecretString, Instant)>>>,
+}
+
+impl fmt::Debug for PubSubPublisher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PubSubPublisher")
+            .field("project", &self.project)
+            .field("topic", &self.topic)
+            .field("endpoint", &self.endpoint)
+            .field("auth", &self.auth)
+            .field("ordering", &self.ordering)
+            .field("attributes", &self.attributes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PubSubPublisher {
+    /// Cria o publisher para o tópico `projects/{project}/topics/{topic}`
+    pub fn new(project: impl Into<String>, topic: impl Into<String>) -> Self {
+        Self {
+            project: project.into(),
+            topic: topic.into(),
+            endpoint: PUBSUB_API_URL.to_string(),
+            auth: PubSubAuth::Metadata,
+            ordering: true,
+            attributes: BTreeMap::new(),
+            http_client: reqwest::Client::new(),
+            token: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Usa outro endpoint (ex: regional ou o emulador `http://localhost:8085/v1`)
+    pub fn endpoint(mut self, url: impl Into<String>) -> Self {
+        self.endpoint = url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Define a autenticação (padrão: [`PubSubAuth::Metadata`])
+    pub fn auth(mut self, auth: PubSubAuth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Liga/desliga o envio da chave de ordenação (padrão: ligado)
+    pub fn ordering(mut self, enabled: bool) -> Self {
+        self.ordering = enabled;
+        self
+    }
+
+    /// Atributo incluído em todas as mensagens (ex: `account_id`)
+    ///
+    /// Atributos da própria mensagem têm precedência.
+    pub fn attribute(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.insert(name.into(), value.into());
+        self
+    }
+
+    /// Reutiliza um cliente HTTP existente
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = client;
+        self
+    }
+
+    /// Nome completo do tópico
+    pub fn topic_name(&self) -> String {
+        format!("projects/{}/topics/{}", self.project, self.topic)
+    }
+
+    /// Corpo de `topics.publish` para a mensagem
+    fn request_body(&self, message: &EventMessage) -> Value {
+        let mut attributes = self.attributes.clone();
+        attributes.extend(message.attributes.clone());
+
+        let mut entry = Map::new();
+        entry.insert(
+            "data".to_string(),
+            Value::String(base64::engine::general_purpose::STANDARD.encode(&message.data)),
+        );
+        if !attributes.is_empty() {
+            entry.insert("attributes".to_string(), json!(attributes));
+        }
+        if self.ordering {
+            if let Some(ref key) = message.ordering_key {
+                entry.insert("orderingKey".to_string(), Value::String(key.clone()));
+            }
+        }
+
+        json!({ "messages": [entry] })
+    }
+
+    /// Token de acesso atual, renovado no servidor de metadados quando necessário
+    async fn access_token(&self) -> Result<Option<SecretString>> {
+        match self.auth {
+            PubSubAuth::None => Ok(None),
+            PubSubAuth::Bearer(ref token) => Ok(Some(token.clone())),
+            PubSubAuth::Metadata => {
+                let mut cached = self.token.lock().await;
+                if let Some((ref token, expires_at)) = *cached {
+                    if Instant::now() + TOKEN_REFRESH_MARGIN < expires_at {
+                        return Ok(Some(token.clone()));
+                    }
+                }
+
+                let (secret, expires_in) = gcp::metadata_token(&self.http_client).await?;
+                let expires_at = Instant::now() + expires_in;
+                *cached = Some((secret.clone(), expires_at));
+                tracing::debug!("Pub/Sub access token refreshed from metadata server");
+                Ok(Some(secret))
+            }
+        }
+    }
+}
+
+impl EventPublisher for PubSubPublisher {
+    /// Publica a mensagem, retornando o ID atribuído pelo Pub/Sub
+    ///
+    /// # Erros
+    ///
+    /// * `NetworkError` - falha de conexão com o Pub/Sub ou o servidor de metadados
+    /// * `Unauthorized` - token não obtido no servidor de metadados
+    /// * `InternalError` - o Pub/Sub recusou a mensagem (status diferente de 2xx)
+    /// * `SerializationError` - resposta do Pub/Sub em formato inesperado
+    fn publish<'a>(&'a self, message: &'a EventMessage) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let url = format!("{}/{}:publish", self.endpoint, self.topic_name());
+
+            let mut request = self
+                .http_client
+                .post(&url)
+                .json(&self.request_body(message));
+            if let Some(token) = self.access_token().await? {
+                request = request.bearer_auth(token.expose_secret());
+            }
+
+            let response = request
+                .send()
+                .await
+                .map_err(|e| ChatGuruError::NetworkError(describe_error(e)))?;
+
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            if !status.is_success() {
+                return Err(ChatGuruError::InternalError(format!(
+                    "Pub/Sub API error (status {}): {}",
+                    status.as_u16(),
+                    pubsub_error(&body)
+                )));
+            }
+
+            let published: PublishResponse = serde_json::from_str(&body)?;
+            let id = published.message_ids.into_iter().next().ok_or_else(|| {
+                ChatGuruError::InternalError(format!(
+                    "Pub/Sub response without message id: {}",
+                    body
+                ))
+            })?;
+            tracing::debug!("Event published to {} as {}", self.topic_name(), id);
+            Ok(id)
+        })
+    }
+}
+
+/// Mensagem de erro do corpo de resposta do Google (`{"error": {"message": "..."}}`)
+fn pubsub_error(body: &str) -> String {
+    serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|value| Some(value.get("error")?.get("message")?.as_str()?.to_string()))
+        .unwrap_or_else(|| body.trim().to_string())
+}
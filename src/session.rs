@@ -0,0 +1,388 @@
+//! Estado de conversa por chat
+//!
+//! Todo bot acaba guardando o mesmo estado por chat: a última mensagem do
+//! contato, as tarefas abertas a partir da conversa e dados próprios da
+//! aplicação (etapa de um fluxo, respostas já coletadas...). O
+//! [`SessionManager`] mantém esse estado, alimentado pelos webhooks e
+//! consultado antes de enviar mensagens, em um [`SessionStore`] plugável.
+//!
+//! # Exemplo
+//!
+//! ```rust,ignore
+//! use std::time::Duration;
+//! use chatguru::session::SessionManager;
+//!
+//! let sessions = SessionManager::new().ttl(Duration::from_secs(6 * 3600));
+//!
+//! // No handler de webhooks:
+//! let session = sessions.observe(&payload).await?;
+//! if session.open_tasks.is_empty() {
+//!     let task_id = criar_tarefa(&payload).await?;
+//!     sessions
+//!         .update(&session.key, |s| {
+//!             s.add_task(&task_id);
+//!             s.set_with_ttl("etapa", "aguardando_prazo", Duration::from_secs(600));
+//!         })
+//!         .await?;
+//! }
+//! ```
+
+use crate::error::Result;
+use crate::types::{PhoneNumber, WebhookPayload};
+use crate::webhook::ordering::KeyedLocks;
+use chrono::{DateTime, Utc};
+use futures_core::future::BoxFuture;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Tempo padrão de inatividade após o qual a sessão é descartada (24 horas)
+pub const DEFAULT_SESSION_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Valor guardado na sessão, com expiração opcional
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionValue {
+    /// Valor
+    pub value: Value,
+    /// Momento a partir do qual o valor é ignorado
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl SessionValue {
+    /// Indica se o valor já expirou
+    pub fn is_expired(&self) -> bool {
+        matches!(self.expires_at, Some(expires_at) if expires_at <= Utc::now())
+    }
+}
+
+/// Estado de um chat
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    /// Chave da sessão (dígitos do telefone ou ID do chat)
+    pub key: String,
+    /// ID do chat no ChatGuru, quando conhecido
+    pub chat_id: Option<String>,
+    /// Linha (phone_id) usada na conversa, quando conhecida
+    pub phone_id: Option<String>,
+    /// Último texto recebido do contato
+    pub last_message: Option<String>,
+    /// Momento em que o último texto foi recebido
+    pub last_message_at: Option<DateTime<Utc>>,
+    /// Momento da última atualização da sessão
+    pub updated_at: DateTime<Utc>,
+    /// IDs de tarefas abertas a partir da conversa
+    #[serde(default)]
+    pub open_tasks: Vec<String>,
+    /// Dados da aplicação
+    #[serde(default)]
+    pub data: HashMap<String, SessionValue>,
+}
+
+impl Session {
+    /// Cria uma sessão vazia
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            chat_id: None,
+            phone_id: None,
+            last_message: None,
+            last_message_at: None,
+            updated_at: Utc::now(),
+            open_tasks: Vec::new(),
+            data: HashMap::new(),
+        }
+    }
+
+    /// Valor guardado (ignorando valores expirados)
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.data
+            .get(name)
+            .filter(|entry| !entry.is_expired())
+            .map(|entry| &entry.value)
+    }
+
+    /// Valor guardado, desserializado para o tipo pedido
+    pub fn get_as<T: DeserializeOwned>(&self, name: &str) -> Option<T> {
+        self.get(name)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    /// Guarda um valor sem expiração
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<Value>) {
+        self.data.insert(
+            name.into(),
+            SessionValue {
+                value: value.into(),
+                expires_at: None,
+            },
+        );
+    }
+
+    /// Guarda um valor que expira após `ttl`
+    pub fn set_with_ttl(
+        &mut self,
+        name: impl Into<String>,
+        value: impl Into<Value>,
+        ttl: Duration,
+    ) {
+        let expires_at = chrono::Duration::from_std(ttl)
+            .ok()
+            .and_then(|ttl| Utc::now().checked_add_signed(ttl));
+        self.data.insert(
+            name.into(),
+            SessionValue {
+                value: value.into(),
+                expires_at,
+            },
+        );
+    }
+
+    /// Remove um valor, retornando-o se existia (e não tinha expirado)
+    pub fn remove(&mut self, name: &str) -> Option<Value> {
+        self.data
+            .remove(name)
+            .filter(|entry| !entry.is_expired())
+            .map(|entry| entry.value)
+    }
+
+    /// Registra uma tarefa aberta (sem duplicar)
+    pub fn add_task(&mut self, task_id: impl Into<String>) {
+        let task_id = task_id.into();
+        if !self.open_tasks.contains(&task_id) {
+            self.open_tasks.push(task_id);
+        }
+    }
+
+    /// Remove uma tarefa da lista de abertas, retornando `true` se ela estava aberta
+    pub fn close_task(&mut self, task_id: &str) -> bool {
+        let before = self.open_tasks.len();
+        self.open_tasks.retain(|id| id != task_id);
+        self.open_tasks.len() != before
+    }
+
+    /// Descarta os valores expirados
+    pub fn purge_expired(&mut self) {
+        self.data.retain(|_, entry| !entry.is_expired());
+    }
+}
+
+/// Armazenamento das sessões
+///
+/// A implementação padrão é [`InMemorySessionStore`]; implemente este trait
+/// para compartilhar as sessões entre instâncias (ex: Redis com
+/// `SET key json EX ttl`).
+///
+/// [`SessionManager::update`] faz `load`, altera a sessão e chama `save`,
+/// serializando apenas as chamadas do próprio processo. Armazenamentos
+/// compartilhados por várias instâncias devem tornar esse ciclo atômico por
+/// chave (ex: `WATCH`/`MULTI` no Redis, ou gravação condicionada a
+/// [`Session::updated_at`] não ter mudado desde o `load`); caso contrário,
+/// atualizações simultâneas do mesmo chat em instâncias diferentes podem se
+/// sobrescrever.
+pub trait SessionStore: Send + Sync {
+    /// Busca a sessão (sessões expiradas não são retornadas)
+    fn load<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<Session>>>;
+
+    /// Grava a sessão, que expira após `ttl` sem novas gravações
+    fn save<'a>(&'a self, session: &'a Session, ttl: Duration) -> BoxFuture<'a, Result<()>>;
+
+    /// Remove a sessão, retornando `true` se ela existia
+    fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<bool>>;
+}
+
+/// Armazenamento em memória (padrão do [`SessionManager`])
+///
+/// Sessões expiradas são descartadas quando acessadas e a cada gravação.
+#[derive(Debug, Default)]
+pub struct InMemorySessionStore {
+    sessions: Mutex<HashMap<String, (Session, Option<Instant>)>>,
+}
+
+impl InMemorySessionStore {
+    /// Cria um armazenamento vazio
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Quantidade de sessões armazenadas (incluindo expiradas ainda não descartadas)
+    pub fn len(&self) -> usize {
+        self.sessions().len()
+    }
+
+    /// Indica se não há sessões armazenadas
+    pub fn is_empty(&self) -> bool {
+        self.sessions().is_empty()
+    }
+
+    fn sessions(&self) -> std::sync::MutexGuard<'_, HashMap<String, (Session, Option<Instant>)>> {
+        self.sessions.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl SessionStore for InMemorySessionStore {
+    fn load<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<Session>>> {
+        let mut sessions = self.sessions();
+        let session = match sessions.get(key) {
+            Some((_, Some(expires_at))) if *expires_at <= Instant::now() => {
+                sessions.remove(key);
+                None
+            }
+            Some((session, _)) => Some(session.clone()),
+            None => None,
+        };
+        Box::pin(async move { Ok(session) })
+    }
+
+    fn save<'a>(&'a self, session: &'a Session, ttl: Duration) -> BoxFuture<'a, Result<()>> {
+        let now = Instant::now();
+        let mut sessions = self.sessions();
+        sessions.retain(|_, (_, expires_at)| !matches!(expires_at, Some(at) if *at <= now));
+        sessions.insert(session.key.clone(), (session.clone(), now.checked_add(ttl)));
+        Box::pin(async { Ok(()) })
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<bool>> {
+        let removed = self.sessions().remove(key).is_some();
+        Box::pin(async move { Ok(removed) })
+    }
+}
+
+/// Mantém o estado de conversa por chat
+///
+/// A chave da sessão são os dígitos do telefone do contato (ou o ID do chat,
+/// quando o webhook não traz telefone), para que a mesma sessão seja
+/// encontrada a partir do webhook e na hora de enviar uma mensagem.
+///
+/// [`update`](Self::update) serializa as atualizações de cada chat dentro do
+/// processo (clones do gerenciador compartilham as travas). Entre
+/// instâncias, a atomicidade depende do [`SessionStore`].
+#[derive(Clone)]
+pub struct SessionManager {
+    store: Arc<dyn SessionStore>,
+    ttl: Duration,
+    locks: KeyedLocks,
+}
+
+impl Default for SessionManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for SessionManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SessionManager")
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SessionManager {
+    /// Cria um gerenciador com armazenamento em memória
+    pub fn new() -> Self {
+        Self::with_store(Arc::new(InMemorySessionStore::new()))
+    }
+
+    /// Cria um gerenciador com o armazenamento informado
+    pub fn with_store(store: Arc<dyn SessionStore>) -> Self {
+        Self {
+            store,
+            ttl: DEFAULT_SESSION_TTL,
+            locks: KeyedLocks::default(),
+        }
+    }
+
+    /// Define o tempo de inatividade após o qual a sessão é descartada (padrão: 24h)
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Chave da sessão para um telefone
+    pub fn key_for_phone(phone_number: impl Into<PhoneNumber>) -> String {
+        phone_number.into().digits().to_string()
+    }
+
+    /// Chave da sessão de um webhook (telefone ou, na falta dele, ID do chat)
+    pub fn key_for(payload: &WebhookPayload) -> Option<String> {
+        payload
+            .get_phone_number()
+            .map(Self::key_for_phone)
+            .filter(|key| !key.is_empty())
+            .or_else(|| payload.get_chat_id())
+    }
+
+    /// Busca a sessão pela chave
+    pub async fn get(&self, key: &str) -> Result<Option<Session>> {
+        let mut session = self.store.load(key).await?;
+        if let Some(ref mut session) = session {
+            session.purge_expired();
+        }
+        Ok(session)
+    }
+
+    /// Busca a sessão do contato antes de enviar uma mensagem
+    pub async fn get_for_phone(
+        &self,
+        phone_number: impl Into<PhoneNumber>,
+    ) -> Result<Option<Session>> {
+        self.get(&Self::key_for_phone(phone_number)).await
+    }
+
+    /// Atualiza a sessão do chat com os dados do webhook
+    ///
+    /// Cria a sessão se ela não existir e registra chat, linha e último
+    /// texto recebido. Webhooks sem telefone nem ID de chat retornam uma
+    /// sessão vazia sem gravá-la.
+    pub async fn observe(&self, payload: &WebhookPayload) -> Result<Session> {
+        let Some(key) = Self::key_for(payload) else {
+            tracing::debug!("Webhook without phone or chat id, session not tracked");
+            return Ok(Session::new(String::new()));
+        };
+
+        let phone_id = match payload {
+            WebhookPayload::ChatGuru(p) => p.phone_id.clone(),
+            _ => None,
+        };
+
+        self.update(&key, |session| {
+            if let Some(chat_id) = payload.get_chat_id() {
+                session.chat_id = Some(chat_id);
+            }
+            if phone_id.is_some() {
+                session.phone_id = phone_id;
+            }
+            if let Some(text) = payload.get_message_text().filter(|t| !t.is_empty()) {
+                session.last_message = Some(text);
+                session.last_message_at = Some(Utc::now());
+            }
+        })
+        .await
+    }
+
+    /// Carrega (ou cria) a sessão, aplica a alteração e grava o resultado
+    ///
+    /// Atualizações da mesma chave feitas por este gerenciador (ou seus
+    /// clones) rodam uma de cada vez, então nenhuma alteração se perde.
+    pub async fn update<F>(&self, key: &str, change: F) -> Result<Session>
+    where
+        F: FnOnce(&mut Session),
+    {
+        let _turn = self.locks.lock(key.to_string()).await;
+        let mut session = self.get(key).await?.unwrap_or_else(|| Session::new(key));
+        change(&mut session);
+        session.purge_expired();
+        session.updated_at = Utc::now();
+        self.store.save(&session, self.ttl).await?;
+        Ok(session)
+    }
+
+    /// Encerra a sessão, retornando `true` se ela existia
+    pub async fn remove(&self, key: &str) -> Result<bool> {
+        self.store.delete(key).await
+    }
+}
@@ -0,0 +1,534 @@
+//! Simulador de tráfego de webhooks do ChatGuru
+//!
+//! Gera webhooks sintéticos realistas (texto, mídia e formato legado com
+//! `event_type`, na proporção de [`TrafficMix`]) e os envia por POST para uma
+//! URL, na taxa configurada. Serve para testes de carga dos handlers antes de
+//! ir para produção.
+//!
+//! O envio segue um modelo aberto: cada webhook sai no seu horário, sem
+//! esperar as respostas anteriores, até o limite de
+//! [`max_in_flight`](WebhookSimulator::max_in_flight) requisições pendentes.
+//! Os eventos são determinísticos para uma mesma [`seed`](WebhookSimulator::seed).
+//!
+//! Disponível com a feature `simulator`.
+//!
+//! # Exemplo
+//!
+//! ```rust,ignore
+//! use chatguru::simulator::{TrafficMix, WebhookSimulator};
+//! use std::time::Duration;
+//!
+//! let report = WebhookSimulator::new("http://localhost:8080/webhook")
+//!     .rate(200.0)
+//!     .mix(TrafficMix { text: 60, media: 30, legacy: 10 })
+//!     .secret("segredo-do-webhook")
+//!     .run_for(Duration::from_secs(60))
+//!     .await?;
+//!
+//! println!(
+//!     "{} enviados, {} ok, p95 {:?}",
+//!     report.sent,
+//!     report.succeeded,
+//!     report.latency_percentile(0.95)
+//! );
+//! ```
+
+use crate::error::{ChatGuruError, Result};
+use crate::secret::{describe_error, SecretString};
+use crate::types::{ChatGuruPayload, EventData, EventTypePayload, WebhookPayload};
+use crate::webhook::verify::{sign, SIGNATURE_HEADER};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// Taxa padrão de envio (webhooks por segundo)
+pub const DEFAULT_RATE: f64 = 10.0;
+
+/// Número padrão de contatos simulados
+pub const DEFAULT_CHATS: usize = 100;
+
+/// Limite padrão de requisições pendentes
+pub const DEFAULT_MAX_IN_FLIGHT: usize = 256;
+
+const TEXT_MESSAGES: &[&str] = &[
+    "Olá, gostaria de saber o status do pedido {n}",
+    "Bom dia! Vocês entregam no meu bairro? (protocolo {n})",
+    "Preciso de um orçamento para {n} unidades",
+    "Quero cancelar a assinatura {n}",
+    "Obrigado pelo atendimento! Nota {n} de 10",
+    "Qual o horário de funcionamento? Sou o cliente {n}",
+];
+
+/// Tipo de mídia (`tipo_mensagem`) e extensão do arquivo de cada anexo
+const MEDIA: &[(&str, &str)] = &[
+    ("image", "jpg"),
+    ("ptt", "ogg"),
+    ("video", "mp4"),
+    ("document", "pdf"),
+];
+
+const LEGACY_EVENTS: &[&str] = &["new_lead", "annotation", "status_change", "task_created"];
+
+const NAMES: &[&str] = &[
+    "João Silva",
+    "Maria Souza",
+    "Ana Oliveira",
+    "Pedro Santos",
+    "Carla Lima",
+    "Lucas Pereira",
+];
+
+/// Formato de um webhook simulado
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SimulatedKind {
+    /// Mensagem de texto no formato ChatGuru
+    Text,
+    /// Mídia (imagem, áudio, vídeo ou documento) no formato ChatGuru
+    Media,
+    /// Evento no formato legado (`event_type`)
+    Legacy,
+}
+
+/// Proporção de cada formato no tráfego simulado (pesos relativos)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrafficMix {
+    /// Peso das mensagens de texto
+    pub text: u32,
+    /// Peso das mídias
+    pub media: u32,
+    /// Peso dos eventos no formato legado
+    pub legacy: u32,
+}
+
+impl Default for TrafficMix {
+    /// 70% texto, 20% mídia e 10% legado
+    fn default() -> Self {
+        Self {
+            text: 70,
+            media: 20,
+            legacy: 10,
+        }
+    }
+}
+
+impl TrafficMix {
+    fn pick(&self, roll: u64) -> SimulatedKind {
+        let total = u64::from(self.text) + u64::from(self.media) + u64::from(self.legacy);
+        let roll = roll % total.max(1);
+        if roll < u64::from(self.text) {
+            SimulatedKind::Text
+        } else if roll < u64::from(self.text) + u64::from(self.media) {
+            SimulatedKind::Media
+        } else {
+            SimulatedKind::Legacy
+        }
+    }
+}
+
+/// Webhook gerado pelo simulador
+#[derive(Debug, Clone)]
+pub struct SimulatedEvent {
+    /// Número de sequência do evento
+    pub sequence: u64,
+    /// Formato do webhook
+    pub kind: SimulatedKind,
+    /// Payload gerado
+    pub payload: WebhookPayload,
+}
+
+impl SimulatedEvent {
+    /// Corpo JSON enviado no POST
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.payload).unwrap_or_default()
+    }
+}
+
+/// Resultado de [`WebhookSimulator::run`]
+#[derive(Clone, Default)]
+pub struct SimulationReport {
+    /// Webhooks enviados
+    pub sent: usize,
+    /// Respostas 2xx
+    pub succeeded: usize,
+    /// Respostas fora de 2xx
+    pub rejected: usize,
+    /// Falhas de rede ou timeout
+    pub failed: usize,
+    /// Quantidade de respostas por status HTTP
+    pub by_status: BTreeMap<u16, usize>,
+    /// Quantidade de webhooks enviados por formato
+    pub by_kind: BTreeMap<SimulatedKind, usize>,
+    /// Duração total da simulação
+    pub elapsed: Duration,
+    latencies: Vec<Duration>,
+}
+
+impl std::fmt::Debug for SimulationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SimulationReport")
+            .field("sent", &self.sent)
+            .field("succeeded", &self.succeeded)
+            .field("rejected", &self.rejected)
+            .field("failed", &self.failed)
+            .field("by_status", &self.by_status)
+            .field("by_kind", &self.by_kind)
+            .field("elapsed", &self.elapsed)
+            .field("p50", &self.latency_percentile(0.5))
+            .field("p95", &self.latency_percentile(0.95))
+            .finish()
+    }
+}
+
+impl SimulationReport {
+    /// Latência no percentil `p` (de 0.0 a 1.0), considerando as respostas recebidas
+    pub fn latency_percentile(&self, p: f64) -> Option<Duration> {
+        let last = self.latencies.len().checked_sub(1)?;
+        let index = (p.clamp(0.0, 1.0) * last as f64).round() as usize;
+        self.latencies.get(index).copied()
+    }
+
+    /// Taxa efetivamente atingida (webhooks por segundo)
+    pub fn achieved_rate(&self) -> f64 {
+        match self.elapsed.as_secs_f64() {
+            secs if secs > 0.0 => self.sent as f64 / secs,
+            _ => 0.0,
+        }
+    }
+
+    /// Indica se todos os webhooks receberam resposta 2xx
+    pub fn is_clean(&self) -> bool {
+        self.succeeded == self.sent
+    }
+
+    fn record(&mut self, (result, latency): (reqwest::Result<u16>, Duration)) {
+        match result {
+            Ok(status) => {
+                *self.by_status.entry(status).or_default() += 1;
+                if (200..300).contains(&status) {
+                    self.succeeded += 1;
+                } else {
+                    self.rejected += 1;
+                }
+                self.latencies.push(latency);
+            }
+            Err(e) => {
+                self.failed += 1;
+                tracing::debug!("Simulated webhook failed: {}", describe_error(e));
+            }
+        }
+    }
+}
+
+/// Gerador e emissor de webhooks sintéticos
+///
+/// Veja o [módulo](self) para um exemplo.
+#[derive(Clone)]
+pub struct WebhookSimulator {
+    url: String,
+    rate: f64,
+    mix: TrafficMix,
+    chats: usize,
+    seed: u64,
+    max_in_flight: usize,
+    secret: Option<SecretString>,
+    signature_header: String,
+    timeout: Duration,
+    http_client: reqwest::Client,
+}
+
+impl std::fmt::Debug for WebhookSimulator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookSimulator")
+            .field("url", &crate::secret::redact_url(&self.url))
+            .field("rate", &self.rate)
+            .field("mix", &self.mix)
+            .field("chats", &self.chats)
+            .field("seed", &self.seed)
+            .field("max_in_flight", &self.max_in_flight)
+            .field("signs_requests", &self.secret.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebhookSimulator {
+    /// Simulador que envia os webhooks para `url`
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            rate: DEFAULT_RATE,
+            mix: TrafficMix::default(),
+            chats: DEFAULT_CHATS,
+            seed: 0x00C4_A76B_0000_0001,
+            max_in_flight: DEFAULT_MAX_IN_FLIGHT,
+            secret: None,
+            signature_header: SIGNATURE_HEADER.to_string(),
+            timeout: Duration::from_secs(30),
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Webhooks por segundo (padrão: 10)
+    pub fn rate(mut self, events_per_second: f64) -> Self {
+        self.rate = events_per_second;
+        self
+    }
+
+    /// Proporção de cada formato (padrão: 70% texto, 20% mídia, 10% legado)
+    pub fn mix(mut self, mix: TrafficMix) -> Self {
+        self.mix = mix;
+        self
+    }
+
+    /// Número de contatos distintos (padrão: 100)
+    ///
+    /// Os webhooks são distribuídos entre os contatos, o que exercita o
+    /// processamento em série por chat dos handlers.
+    pub fn chats(mut self, chats: usize) -> Self {
+        self.chats = chats.max(1);
+        self
+    }
+
+    /// Semente dos eventos gerados; a mesma semente gera a mesma sequência
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Limite de requisições pendentes (padrão: 256)
+    ///
+    /// Ao atingir o limite, o envio espera uma resposta, e a taxa efetiva
+    /// fica abaixo da configurada (veja [`SimulationReport::achieved_rate`]).
+    pub fn max_in_flight(mut self, max: usize) -> Self {
+        self.max_in_flight = max.max(1);
+        self
+    }
+
+    /// Assina cada corpo com HMAC-SHA256 (hex), como o ChatGuru
+    pub fn secret(mut self, secret: impl Into<SecretString>) -> Self {
+        self.secret = Some(secret.into());
+        self
+    }
+
+    /// Header da assinatura (padrão: [`SIGNATURE_HEADER`])
+    pub fn signature_header(mut self, name: impl Into<String>) -> Self {
+        self.signature_header = name.into();
+        self
+    }
+
+    /// Timeout de cada requisição (padrão: 30 segundos)
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Reutiliza um cliente HTTP existente
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = client;
+        self
+    }
+
+    /// Gera o evento de número `sequence`, sem enviá-lo
+    pub fn generate(&self, sequence: u64) -> SimulatedEvent {
+        let mut rng = Rng::new(self.seed ^ sequence.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        let kind = self.mix.pick(rng.next());
+        let chat = (rng.next() % self.chats as u64) as usize;
+
+        let payload = match kind {
+            SimulatedKind::Text => {
+                WebhookPayload::ChatGuru(self.chat_payload(chat, sequence, &mut rng))
+            }
+            SimulatedKind::Media => {
+                let (tipo, extension) = MEDIA[rng.index(MEDIA.len())];
+                let url = format!(
+                    "https://cdn.chatguru.example/media/{}/{}.{}",
+                    chat, sequence, extension
+                );
+                let mut payload = self.chat_payload(chat, sequence, &mut rng);
+                payload.texto_mensagem = String::new();
+                payload.tipo_mensagem = Some(tipo.to_string());
+                payload.url_arquivo = Some(url);
+                WebhookPayload::ChatGuru(payload)
+            }
+            SimulatedKind::Legacy => {
+                WebhookPayload::EventType(self.legacy_payload(chat, sequence, &mut rng))
+            }
+        };
+
+        SimulatedEvent {
+            sequence,
+            kind,
+            payload,
+        }
+    }
+
+    fn chat_payload(&self, chat: usize, sequence: u64, rng: &mut Rng) -> ChatGuruPayload {
+        let message = TEXT_MESSAGES[rng.index(TEXT_MESSAGES.len())];
+        ChatGuruPayload::builder()
+            .campanha("1001", "Atendimento")
+            .nome(NAMES[chat % NAMES.len()])
+            .email(format!("contato{}@example.com", chat))
+            .celular(phone(chat))
+            .chat_id(format!("chat_sim_{}", chat))
+            .link_chat(format!("https://s15.chatguru.app/chats#chat_sim_{}", chat))
+            .chat_created("2024-01-15 10:30:00")
+            .message(message.replace("{n}", &sequence.to_string()))
+            .build()
+    }
+
+    fn legacy_payload(&self, chat: usize, sequence: u64, rng: &mut Rng) -> EventTypePayload {
+        EventTypePayload {
+            id: format!("evt_sim_{}", sequence),
+            event_type: LEGACY_EVENTS[rng.index(LEGACY_EVENTS.len())].to_string(),
+            timestamp: (base_time() + chrono::Duration::seconds(sequence as i64)).to_rfc3339(),
+            data: EventData {
+                lead_name: Some(NAMES[chat % NAMES.len()].to_string()),
+                phone: Some(phone(chat)),
+                email: Some(format!("contato{}@example.com", chat)),
+                project_name: None,
+                task_title: None,
+                annotation: Some(format!("Evento simulado {}", sequence)),
+                amount: None,
+                status: None,
+                custom_data: HashMap::new(),
+                extra: HashMap::new(),
+            },
+        }
+    }
+
+    /// Envia `total` webhooks na taxa configurada e aguarda as respostas
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se a URL não for `http(s)://` ou a taxa não
+    /// for positiva. Falhas de envio não interrompem a simulação: são
+    /// contadas no [`SimulationReport`].
+    pub async fn run(&self, total: usize) -> Result<SimulationReport> {
+        self.validate()?;
+
+        let interval = Duration::from_secs_f64(1.0 / self.rate);
+        let slots = Arc::new(Semaphore::new(self.max_in_flight));
+        let mut ticker = tokio::time::interval(interval);
+        let mut requests = JoinSet::new();
+        let mut report = SimulationReport::default();
+        let started_at = Instant::now();
+
+        for sequence in 0..total as u64 {
+            ticker.tick().await;
+            let Ok(slot) = slots.clone().acquire_owned().await else {
+                break;
+            };
+
+            let event = self.generate(sequence);
+            *report.by_kind.entry(event.kind).or_default() += 1;
+            report.sent += 1;
+
+            let request = self.request(&event.to_json());
+            requests.spawn(async move {
+                let sent_at = Instant::now();
+                let result = request.send().await;
+                drop(slot);
+                (
+                    result.map(|response| response.status().as_u16()),
+                    sent_at.elapsed(),
+                )
+            });
+
+            // Recolhe as respostas já recebidas para não acumular resultados
+            while let Some(Ok(result)) = requests.try_join_next() {
+                report.record(result);
+            }
+        }
+
+        while let Some(joined) = requests.join_next().await {
+            if let Ok(result) = joined {
+                report.record(result);
+            }
+        }
+
+        report.elapsed = started_at.elapsed();
+        report.latencies.sort();
+        tracing::info!(
+            "Webhook simulation finished: {} sent, {} succeeded, {} rejected, {} failed in {:?}",
+            report.sent,
+            report.succeeded,
+            report.rejected,
+            report.failed,
+            report.elapsed
+        );
+        Ok(report)
+    }
+
+    /// Envia webhooks na taxa configurada durante `duration`
+    ///
+    /// # Erros
+    ///
+    /// Os de [`run`](Self::run).
+    pub async fn run_for(&self, duration: Duration) -> Result<SimulationReport> {
+        let total = (duration.as_secs_f64() * self.rate).round().max(0.0) as usize;
+        self.run(total).await
+    }
+
+    fn request(&self, body: &str) -> reqwest::RequestBuilder {
+        let mut request = self
+            .http_client
+            .post(&self.url)
+            .timeout(self.timeout)
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+        if let Some(ref secret) = self.secret {
+            request = request.header(
+                self.signature_header.as_str(),
+                sign(body.as_bytes(), secret.expose_secret()),
+            );
+        }
+        request.body(body.to_string())
+    }
+
+    fn validate(&self) -> Result<()> {
+        if !(self.url.starts_with("http://") || self.url.starts_with("https://")) {
+            return Err(ChatGuruError::ValidationError(format!(
+                "Invalid simulator URL {:?}: expected an http:// or https:// URL",
+                crate::secret::redact_url(&self.url)
+            )));
+        }
+        if !(self.rate.is_finite() && self.rate > 0.0) {
+            return Err(ChatGuruError::ValidationError(format!(
+                "Invalid simulator rate {}: must be a positive number of events per second",
+                self.rate
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Horário do primeiro evento legado; os seguintes avançam um segundo cada
+fn base_time() -> chrono::DateTime<chrono::Utc> {
+    chrono::DateTime::from_timestamp(1_705_314_600, 0).unwrap_or_default()
+}
+
+/// Celular do contato simulado de índice `chat`
+fn phone(chat: usize) -> String {
+    format!("55119{:08}", chat)
+}
+
+/// Gerador pseudoaleatório (splitmix64) para os eventos simulados
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn index(&mut self, len: usize) -> usize {
+        (self.next() % len as u64) as usize
+    }
+}
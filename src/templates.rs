@@ -0,0 +1,290 @@
+//! Templates de mensagens com placeholders
+//!
+//! Substitui a formatação ad hoc de mensagens de confirmação por templates
+//! declarados uma vez, com placeholders nomeados, valores padrão e variantes
+//! por idioma.
+//!
+//! # Sintaxe
+//!
+//! - `{nome}`: substituído pelo valor de `nome` no contexto
+//! - `{nome|Cliente}`: usa `Cliente` quando `nome` não está no contexto
+//! - `{{` e `}}`: chaves literais
+//!
+//! # Exemplo
+//!
+//! ```rust,ignore
+//! use chatguru::templates::{MessageTemplate, TemplateContext};
+//!
+//! let template = MessageTemplate::new("Olá {nome|cliente}! Sua tarefa {task_id} foi criada ✅")
+//!     .with_locale("es", "¡Hola {nome|cliente}! Tu tarea {task_id} fue creada ✅");
+//!
+//! let context = TemplateContext::from_payload(&payload)
+//!     .with("task_id", "TASK-123");
+//!
+//! client.send_templated("5511999999999", &template, &context).await?;
+//! ```
+
+use crate::error::{ChatGuruError, Result};
+use crate::types::ChatGuruPayload;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Valores disponíveis para os placeholders de um template
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplateContext {
+    values: HashMap<String, String>,
+    locale: Option<String>,
+}
+
+impl TemplateContext {
+    /// Cria um contexto vazio
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cria um contexto com os dados de um webhook
+    ///
+    /// Preenche `nome`, `email`, `celular`, `campanha_id`, `campanha_nome`,
+    /// `origem`, `responsavel_nome`, `link_chat` e cada campo personalizado
+    /// pelo próprio nome (valores vazios são ignorados).
+    pub fn from_payload(payload: &ChatGuruPayload) -> Self {
+        let mut context = Self::new();
+
+        for (key, value) in [
+            ("nome", payload.nome.as_str()),
+            ("email", payload.email.as_str()),
+            ("celular", payload.celular.as_str()),
+            ("campanha_id", payload.campanha_id.as_str()),
+            ("campanha_nome", payload.campanha_nome.as_str()),
+            ("origem", payload.origem.as_str()),
+            ("link_chat", payload.link_chat.as_str()),
+            (
+                "responsavel_nome",
+                payload.responsavel_nome.as_deref().unwrap_or_default(),
+            ),
+        ] {
+            if !value.is_empty() {
+                context.insert(key, value);
+            }
+        }
+
+        for (key, value) in &payload.campos_personalizados {
+            let text = match value {
+                Value::Null => continue,
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            if !text.is_empty() {
+                context.values.entry(key.clone()).or_insert(text);
+            }
+        }
+
+        context
+    }
+
+    /// Define um valor (substitui o anterior)
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.values.insert(key.into(), value.into());
+    }
+
+    /// Define um valor no estilo builder
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    /// Define o idioma usado para escolher a variante do template (ex: `pt-BR`, `es`)
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Idioma configurado
+    pub fn locale_tag(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// Valor de um placeholder
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+}
+
+/// Template de mensagem com variantes opcionais por idioma
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageTemplate {
+    name: Option<String>,
+    body: String,
+    variants: HashMap<String, String>,
+}
+
+impl MessageTemplate {
+    /// Cria um template com o texto padrão
+    pub fn new(body: impl Into<String>) -> Self {
+        Self {
+            name: None,
+            body: body.into(),
+            variants: HashMap::new(),
+        }
+    }
+
+    /// Cria um template nomeado (o nome aparece em logs e erros)
+    pub fn named(name: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Self::new(body)
+        }
+    }
+
+    /// Adiciona uma variante para um idioma (`pt-BR`, `es`, `en`...)
+    pub fn with_locale(mut self, locale: impl Into<String>, body: impl Into<String>) -> Self {
+        self.variants
+            .insert(normalize_locale(&locale.into()), body.into());
+        self
+    }
+
+    /// Nome do template, se definido
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Texto do template para o idioma informado
+    ///
+    /// Procura o idioma exato (`pt-br`), depois o idioma base (`pt`) e por
+    /// fim usa o texto padrão.
+    pub fn body_for(&self, locale: Option<&str>) -> &str {
+        let Some(locale) = locale.map(normalize_locale) else {
+            return &self.body;
+        };
+
+        self.variants
+            .get(&locale)
+            .or_else(|| {
+                let language = locale.split('-').next().unwrap_or_default();
+                self.variants.get(language)
+            })
+            .map(String::as_str)
+            .unwrap_or(&self.body)
+    }
+
+    /// Indica se há variante para o idioma (exato ou idioma base)
+    pub fn has_locale(&self, locale: &str) -> bool {
+        let locale = normalize_locale(locale);
+        let language = locale.split('-').next().unwrap_or_default();
+        self.variants.contains_key(&locale) || self.variants.contains_key(language)
+    }
+
+    /// Nomes dos placeholders usados no texto padrão
+    pub fn placeholders(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        let _ = render_body(&self.body, &mut |name| {
+            names.push(name.to_string());
+            Some(String::new())
+        });
+        names
+    }
+
+    /// Renderiza o template com o contexto
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` listando os placeholders sem valor no
+    /// contexto e sem valor padrão, ou se houver chaves não fechadas.
+    pub fn render(&self, context: &TemplateContext) -> Result<String> {
+        let body = self.body_for(context.locale_tag());
+
+        let rendered = render_body(body, &mut |name| context.get(name).map(String::from))
+            .map_err(|e| self.error(e))?;
+
+        match rendered.filled {
+            Some(text) => Ok(text),
+            None => Err(self.error(format!(
+                "missing values for {}",
+                rendered.missing.join(", ")
+            ))),
+        }
+    }
+
+    fn error(&self, message: String) -> ChatGuruError {
+        match self.name {
+            Some(ref name) => {
+                ChatGuruError::ValidationError(format!("Template '{}': {}", name, message))
+            }
+            None => ChatGuruError::ValidationError(format!("Template: {}", message)),
+        }
+    }
+}
+
+impl From<&str> for MessageTemplate {
+    fn from(body: &str) -> Self {
+        MessageTemplate::new(body)
+    }
+}
+
+impl From<String> for MessageTemplate {
+    fn from(body: String) -> Self {
+        MessageTemplate::new(body)
+    }
+}
+
+struct Rendered {
+    filled: Option<String>,
+    missing: Vec<String>,
+}
+
+/// Substitui os placeholders usando `lookup`; `None` indica valor ausente
+fn render_body(
+    body: &str,
+    lookup: &mut dyn FnMut(&str) -> Option<String>,
+) -> std::result::Result<Rendered, String> {
+    let mut output = String::with_capacity(body.len());
+    let mut missing = Vec::new();
+    let mut chars = body.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().map(|(_, next)| *next) == Some('{') => {
+                chars.next();
+                output.push('{');
+            }
+            '}' if chars.peek().map(|(_, next)| *next) == Some('}') => {
+                chars.next();
+                output.push('}');
+            }
+            '{' => {
+                let rest = &body[index + 1..];
+                let end = rest
+                    .find('}')
+                    .ok_or_else(|| format!("unclosed placeholder at position {}", index))?;
+                let inner = &rest[..end];
+                let (name, default) = match inner.split_once('|') {
+                    Some((name, default)) => (name.trim(), Some(default)),
+                    None => (inner.trim(), None),
+                };
+
+                match lookup(name).or_else(|| default.map(String::from)) {
+                    Some(value) => output.push_str(&value),
+                    None => missing.push(name.to_string()),
+                }
+
+                // Avançar até depois do '}'
+                let close = index + 1 + end;
+                for (i, _) in chars.by_ref() {
+                    if i == close {
+                        break;
+                    }
+                }
+            }
+            other => output.push(other),
+        }
+    }
+
+    Ok(Rendered {
+        filled: missing.is_empty().then_some(output),
+        missing,
+    })
+}
+
+fn normalize_locale(locale: &str) -> String {
+    locale.trim().replace('_', "-").to_ascii_lowercase()
+}
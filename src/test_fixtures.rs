@@ -0,0 +1,450 @@
+//! Payloads de webhook prontos para testes
+//!
+//! Geradores no estilo builder para os três formatos de webhook
+//! ([`ChatGuruPayload`], [`EventTypePayload`] e [`GenericPayload`]) e
+//! amostras de JSON cru, para testar handlers sem copiar payloads de
+//! produção.
+//!
+//! Disponível com a feature `test-util`.
+//!
+//! # Exemplo
+//!
+//! ```rust,ignore
+//! use chatguru::test_fixtures::{self, ChatGuruFixture};
+//!
+//! let payload = ChatGuruFixture::text("Quero um orçamento")
+//!     .nome("Maria")
+//!     .celular("5511988887777")
+//!     .custom_field("Tarefa", "Orçamento")
+//!     .into_webhook();
+//!
+//! router.dispatch(payload).await?;
+//!
+//! let audio: WebhookPayload = serde_json::from_str(test_fixtures::PTT_AUDIO_JSON)?;
+//! ```
+
+use crate::types::{
+    ButtonReply, ChatGuruPayload, EventData, EventTypePayload, GenericPayload, GeoPoint, VCard,
+    WebhookPayload,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Telefone usado por padrão nos fixtures
+pub const DEFAULT_PHONE: &str = "5511999999999";
+
+/// Chat usado por padrão nos fixtures
+pub const DEFAULT_CHAT_ID: &str = "chat_fixture_123";
+
+/// Mensagem de texto no formato ChatGuru
+pub const TEXT_MESSAGE_JSON: &str = r#"{
+    "campanha_id": "1001",
+    "campanha_nome": "Atendimento",
+    "origem": "whatsapp",
+    "email": "joao@example.com",
+    "nome": "João Silva",
+    "tags": ["cliente"],
+    "texto_mensagem": "Olá, preciso de ajuda com meu pedido",
+    "campos_personalizados": {"Tarefa": "Suporte"},
+    "bot_context": {"ChatGuru": false},
+    "responsavel_nome": "Ana",
+    "responsavel_email": "ana@example.com",
+    "link_chat": "https://s15.chatguru.app/chats#chat_fixture_123",
+    "celular": "5511999999999",
+    "phone_id": "62558780e2923cc4705beee1",
+    "chat_id": "chat_fixture_123",
+    "chat_created": "2024-01-15 10:30:00"
+}"#;
+
+/// Imagem (formato novo: `tipo_mensagem` + `url_arquivo`)
+pub const IMAGE_MESSAGE_JSON: &str = r#"{
+    "campanha_id": "1001",
+    "nome": "João Silva",
+    "texto_mensagem": "Segue a foto",
+    "tipo_mensagem": "image",
+    "url_arquivo": "https://files.chatguru.app/fixtures/foto.jpg",
+    "celular": "5511999999999",
+    "chat_id": "chat_fixture_123",
+    "chat_created": "2024-01-15 10:31:00"
+}"#;
+
+/// Áudio gravado no WhatsApp (`ptt`)
+pub const PTT_AUDIO_JSON: &str = r#"{
+    "campanha_id": "1001",
+    "nome": "João Silva",
+    "texto_mensagem": "",
+    "tipo_mensagem": "ptt",
+    "url_arquivo": "https://files.chatguru.app/fixtures/audio.ogg",
+    "celular": "5511999999999",
+    "chat_id": "chat_fixture_123",
+    "chat_created": "2024-01-15 10:32:00"
+}"#;
+
+/// Documento (formato antigo: `media_url` + `media_type`)
+pub const DOCUMENT_JSON: &str = r#"{
+    "campanha_id": "1001",
+    "nome": "João Silva",
+    "texto_mensagem": "Contrato assinado",
+    "media_url": "https://files.chatguru.app/fixtures/contrato.pdf",
+    "media_type": "application/pdf",
+    "celular": "5511999999999",
+    "chat_id": "chat_fixture_123",
+    "chat_created": "2024-01-15 10:33:00"
+}"#;
+
+/// Evento no formato legado com `event_type`
+///
+/// Como todos os campos de [`ChatGuruPayload`] têm valor padrão, desserializar
+/// este JSON com `serde_json` como [`WebhookPayload`] resulta na variante
+/// `ChatGuru`; use [`WebhookPayload::parse`] (que retorna `EventType`) ou
+/// [`EventTypeFixture`] para obter o formato legado.
+pub const EVENT_TYPE_JSON: &str = r#"{
+    "id": "evt_fixture_1",
+    "event_type": "annotation.added",
+    "timestamp": "2024-01-15T10:30:00Z",
+    "data": {
+        "lead_name": "João Silva",
+        "phone": "5511999999999",
+        "email": "joao@example.com",
+        "annotation": "Cliente pediu retorno",
+        "custom_data": {}
+    }
+}"#;
+
+/// Payload genérico/mínimo
+///
+/// Assim como [`EVENT_TYPE_JSON`], é desserializado como a variante
+/// `ChatGuru` de [`WebhookPayload`]; use [`GenericFixture`] para obter a
+/// variante `Generic`.
+pub const GENERIC_JSON: &str = r#"{
+    "nome": "João Silva",
+    "celular": "5511999999999",
+    "mensagem": "Olá"
+}"#;
+
+/// Gerador de [`ChatGuruPayload`] com valores padrão realistas
+#[derive(Debug, Clone)]
+pub struct ChatGuruFixture {
+    payload: ChatGuruPayload,
+}
+
+impl ChatGuruFixture {
+    fn base() -> Self {
+        Self {
+            payload: ChatGuruPayload::builder()
+                .campanha("1001", "Atendimento")
+                .email("joao@example.com")
+                .nome("João Silva")
+                .link_chat(format!(
+                    "https://s15.chatguru.app/chats#{}",
+                    DEFAULT_CHAT_ID
+                ))
+                .celular(DEFAULT_PHONE)
+                .phone_id(crate::client::DEFAULT_PHONE_ID)
+                .chat_id(DEFAULT_CHAT_ID)
+                .chat_created("2024-01-15 10:30:00")
+                .build(),
+        }
+    }
+
+    /// Mensagem de texto
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::base().message(text)
+    }
+
+    /// Imagem enviada pelo contato
+    pub fn image(url: impl Into<String>) -> Self {
+        Self::base().attachment("image", url)
+    }
+
+    /// Áudio gravado no WhatsApp (`ptt`)
+    pub fn ptt_audio(url: impl Into<String>) -> Self {
+        Self::base().attachment("ptt", url)
+    }
+
+    /// Documento enviado pelo contato
+    pub fn document(url: impl Into<String>) -> Self {
+        Self::base().attachment("document", url)
+    }
+
+    /// Localização compartilhada pelo contato
+    pub fn location(latitude: f64, longitude: f64) -> Self {
+        let mut fixture = Self::base();
+        fixture.payload.tipo_mensagem = Some("location".to_string());
+        fixture.payload.location = GeoPoint::new(latitude, longitude);
+        fixture
+    }
+
+    /// Cartão de contato (vCard) compartilhado pelo contato
+    pub fn contact_card(vcard: &str) -> Self {
+        let mut fixture = Self::base();
+        fixture.payload.tipo_mensagem = Some("contact".to_string());
+        fixture.payload.contact_card = VCard::parse(vcard);
+        fixture
+    }
+
+    /// Resposta a um botão de mensagem interativa
+    pub fn button_reply(id: impl Into<String>, title: impl Into<String>) -> Self {
+        let title = title.into();
+        let mut fixture = Self::base().message(title.clone());
+        fixture.payload.tipo_mensagem = Some("button_reply".to_string());
+        fixture.payload.button_reply = Some(ButtonReply {
+            id: id.into(),
+            title,
+            ..ButtonReply::default()
+        });
+        fixture
+    }
+
+    /// Status de entrega de uma mensagem enviada pela API
+    pub fn message_status(message_id: impl Into<String>, status: impl Into<String>) -> Self {
+        let mut fixture = Self::base();
+        fixture.payload.message_id = Some(message_id.into());
+        fixture.payload.message_status = Some(status.into());
+        fixture
+    }
+
+    /// Marca a mensagem como resposta (citação) à mensagem informada
+    pub fn quoting(mut self, message_id: impl Into<String>) -> Self {
+        self.payload.quoted_message_id = Some(message_id.into());
+        self
+    }
+
+    /// Define o texto da mensagem
+    pub fn message(mut self, text: impl Into<String>) -> Self {
+        self.payload.texto_mensagem = text.into();
+        self
+    }
+
+    /// Define o nome do contato
+    pub fn nome(mut self, nome: impl Into<String>) -> Self {
+        self.payload.nome = nome.into();
+        self
+    }
+
+    /// Define o celular do contato
+    pub fn celular(mut self, celular: impl Into<String>) -> Self {
+        self.payload.celular = celular.into();
+        self
+    }
+
+    /// Define o email do contato
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.payload.email = email.into();
+        self
+    }
+
+    /// Define o chat_id
+    pub fn chat_id(mut self, chat_id: impl Into<String>) -> Self {
+        self.payload.chat_id = Some(chat_id.into());
+        self
+    }
+
+    /// Define o phone_id da linha que recebeu a mensagem
+    pub fn phone_id(mut self, phone_id: impl Into<String>) -> Self {
+        self.payload.phone_id = Some(phone_id.into());
+        self
+    }
+
+    /// Define a campanha
+    pub fn campanha(mut self, id: impl Into<String>, nome: impl Into<String>) -> Self {
+        self.payload.campanha_id = id.into();
+        self.payload.campanha_nome = nome.into();
+        self
+    }
+
+    /// Adiciona uma tag
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.payload.tags.push(tag.into());
+        self
+    }
+
+    /// Define um campo personalizado
+    pub fn custom_field(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.payload
+            .campos_personalizados
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// Define o atendente responsável
+    pub fn responsavel(mut self, nome: impl Into<String>, email: impl Into<String>) -> Self {
+        self.payload.responsavel_nome = Some(nome.into());
+        self.payload.responsavel_email = Some(email.into());
+        self
+    }
+
+    /// Define a data de criação do chat (usada na deduplicação)
+    pub fn chat_created(mut self, chat_created: impl Into<String>) -> Self {
+        self.payload.chat_created = Some(chat_created.into());
+        self
+    }
+
+    /// Troca `tipo_mensagem`/`url_arquivo` pelo formato antigo (`media_type`/`media_url`)
+    pub fn legacy_media_fields(mut self) -> Self {
+        self.payload.normalize_media_fields();
+        self.payload.tipo_mensagem = None;
+        self.payload.url_arquivo = None;
+        self
+    }
+
+    fn attachment(mut self, tipo: &str, url: impl Into<String>) -> Self {
+        self.payload.tipo_mensagem = Some(tipo.to_string());
+        self.payload.url_arquivo = Some(url.into());
+        self
+    }
+
+    /// Retorna o payload construído
+    pub fn build(self) -> ChatGuruPayload {
+        self.payload
+    }
+
+    /// Retorna o payload como [`WebhookPayload`]
+    pub fn into_webhook(self) -> WebhookPayload {
+        WebhookPayload::ChatGuru(self.payload)
+    }
+
+    /// Serializa o payload como o corpo JSON enviado pelo ChatGuru
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.payload).unwrap_or_default()
+    }
+}
+
+/// Gerador de [`EventTypePayload`] (formato legado)
+#[derive(Debug, Clone)]
+pub struct EventTypeFixture {
+    payload: EventTypePayload,
+}
+
+impl EventTypeFixture {
+    /// Evento do tipo informado com dados padrão
+    pub fn new(event_type: impl Into<String>) -> Self {
+        Self {
+            payload: EventTypePayload {
+                id: "evt_fixture_1".to_string(),
+                event_type: event_type.into(),
+                timestamp: "2024-01-15T10:30:00Z".to_string(),
+                data: EventData {
+                    lead_name: Some("João Silva".to_string()),
+                    phone: Some(DEFAULT_PHONE.to_string()),
+                    email: Some("joao@example.com".to_string()),
+                    project_name: None,
+                    task_title: None,
+                    annotation: None,
+                    amount: None,
+                    status: None,
+                    custom_data: HashMap::new(),
+                    extra: HashMap::new(),
+                },
+            },
+        }
+    }
+
+    /// Define o ID do evento
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.payload.id = id.into();
+        self
+    }
+
+    /// Define o timestamp do evento
+    pub fn timestamp(mut self, timestamp: impl Into<String>) -> Self {
+        self.payload.timestamp = timestamp.into();
+        self
+    }
+
+    /// Define o nome do lead
+    pub fn lead_name(mut self, lead_name: impl Into<String>) -> Self {
+        self.payload.data.lead_name = Some(lead_name.into());
+        self
+    }
+
+    /// Define o telefone do lead
+    pub fn phone(mut self, phone: impl Into<String>) -> Self {
+        self.payload.data.phone = Some(phone.into());
+        self
+    }
+
+    /// Define a anotação do evento
+    pub fn annotation(mut self, annotation: impl Into<String>) -> Self {
+        self.payload.data.annotation = Some(annotation.into());
+        self
+    }
+
+    /// Define um valor em `custom_data`
+    pub fn custom_data(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.payload
+            .data
+            .custom_data
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// Retorna o payload construído
+    pub fn build(self) -> EventTypePayload {
+        self.payload
+    }
+
+    /// Retorna o payload como [`WebhookPayload`]
+    pub fn into_webhook(self) -> WebhookPayload {
+        WebhookPayload::EventType(self.payload)
+    }
+
+    /// Serializa o payload como JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.payload).unwrap_or_default()
+    }
+}
+
+/// Gerador de [`GenericPayload`]
+#[derive(Debug, Clone)]
+pub struct GenericFixture {
+    payload: GenericPayload,
+}
+
+impl GenericFixture {
+    /// Payload genérico com nome, celular e a mensagem informada
+    pub fn new(mensagem: impl Into<String>) -> Self {
+        Self {
+            payload: GenericPayload {
+                nome: Some("João Silva".to_string()),
+                celular: Some(DEFAULT_PHONE.to_string()),
+                email: None,
+                mensagem: Some(mensagem.into()),
+                extra: HashMap::new(),
+            },
+        }
+    }
+
+    /// Define o nome do contato
+    pub fn nome(mut self, nome: impl Into<String>) -> Self {
+        self.payload.nome = Some(nome.into());
+        self
+    }
+
+    /// Define o celular do contato
+    pub fn celular(mut self, celular: impl Into<String>) -> Self {
+        self.payload.celular = Some(celular.into());
+        self
+    }
+
+    /// Define um campo extra
+    pub fn extra(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.payload.extra.insert(name.into(), value.into());
+        self
+    }
+
+    /// Retorna o payload construído
+    pub fn build(self) -> GenericPayload {
+        self.payload
+    }
+
+    /// Retorna o payload como [`WebhookPayload`]
+    pub fn into_webhook(self) -> WebhookPayload {
+        WebhookPayload::Generic(self.payload)
+    }
+
+    /// Serializa o payload como JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.payload).unwrap_or_default()
+    }
+}
@@ -0,0 +1,60 @@
+//! Tamanho e truncamento de textos por grafemas
+//!
+//! Emojis compostos (famílias, bandeiras, tons de pele) ocupam vários
+//! `char`s; cortar um texto por `char`s pode separar essas sequências e
+//! deixar símbolos quebrados no painel do ChatGuru e no WhatsApp. As funções
+//! deste módulo contam e cortam por grafemas (o que o usuário vê como um
+//! caractere).
+//!
+//! # Exemplo
+//!
+//! ```rust
+//! use chatguru::text::{grapheme_len, truncate_graphemes};
+//!
+//! let text = "Pedido entregue 👨‍👩‍👧 🇧🇷";
+//! assert_eq!(grapheme_len(text), 19);
+//! assert_eq!(truncate_graphemes(text, 17), "Pedido entregue 👨‍👩‍👧");
+//! ```
+
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Reticências acrescentadas por [`truncate_with_ellipsis`]
+pub const ELLIPSIS: &str = "…";
+
+/// Quantidade de grafemas do texto
+pub fn grapheme_len(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+
+/// Primeiros `max` grafemas do texto, sem cortar emojis compostos
+///
+/// Textos dentro do limite são retornados inteiros.
+pub fn truncate_graphemes(text: &str, max: usize) -> &str {
+    match text.grapheme_indices(true).nth(max) {
+        Some((end, _)) => &text[..end],
+        None => text,
+    }
+}
+
+/// Como [`truncate_graphemes`], terminando com [`ELLIPSIS`] quando o texto é cortado
+///
+/// As reticências contam no limite e os espaços antes delas são removidos.
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::text::truncate_with_ellipsis;
+///
+/// assert_eq!(truncate_with_ellipsis("Olá, tudo bem? 😀", 20), "Olá, tudo bem? 😀");
+/// assert_eq!(truncate_with_ellipsis("Olá, tudo bem? 😀", 11), "Olá, tudo…");
+/// ```
+pub fn truncate_with_ellipsis(text: &str, max: usize) -> Cow<'_, str> {
+    let truncated = truncate_graphemes(text, max);
+    if truncated.len() == text.len() {
+        return Cow::Borrowed(text);
+    }
+
+    let kept = truncate_graphemes(truncated, max.saturating_sub(1)).trim_end();
+    Cow::Owned(format!("{}{}", kept, ELLIPSIS))
+}
@@ -0,0 +1,347 @@
+//! Desserialização de webhooks sem cópia dos textos
+//!
+//! [`WebhookPayloadRef`] tem a mesma estrutura de [`WebhookPayload`], mas os
+//! campos de texto são `Cow<'a, str>` que apontam para o corpo recebido. Só
+//! textos com escapes JSON (`\n`, `\u00e9`, ...) são copiados. Útil para
+//! filtrar ou rotear webhooks em alto volume antes de decidir se o evento
+//! precisa da versão owned ([`WebhookPayloadRef::to_owned`]).
+//!
+//! Campos estruturados pouco frequentes (localização, vCard, botões, reação,
+//! campos personalizados e extras) continuam owned.
+
+use super::button_reply::ButtonReply;
+use super::de;
+use super::location::GeoPoint;
+use super::payload::{
+    lenient, BotContext, ChatGuruPayload, EventData, EventTypePayload, GenericPayload,
+};
+use super::reaction::Reaction;
+use super::vcard::VCard;
+use super::webhook::WebhookPayload;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Como [`WebhookPayload`], com os textos emprestados do corpo do webhook
+///
+/// O corpo precisa viver enquanto o payload for usado; para guardar o
+/// evento ou enviá-lo a outra tarefa, converta com [`to_owned`](Self::to_owned).
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::types::{WebhookPayload, WebhookPayloadRef};
+///
+/// let body = r#"{"campanha_id": "123", "nome": "João", "celular": "5511999999999"}"#;
+/// let payload: WebhookPayloadRef = serde_json::from_str(body).unwrap();
+/// assert_eq!(payload.phone_number(), Some("5511999999999"));
+///
+/// let owned: WebhookPayload = payload.to_owned();
+/// assert_eq!(owned.get_contact_name(), "João");
+/// ```
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)] // mesmo motivo de `WebhookPayload`
+pub enum WebhookPayloadRef<'a> {
+    /// Formato ChatGuru (campanha_id, nome, etc)
+    #[serde(borrow)]
+    ChatGuru(ChatGuruPayloadRef<'a>),
+    /// Formato com event_type (antigo)
+    #[serde(borrow)]
+    EventType(EventTypePayloadRef<'a>),
+    /// Formato genérico/mínimo
+    #[serde(borrow)]
+    Generic(GenericPayloadRef<'a>),
+}
+
+impl WebhookPayloadRef<'_> {
+    /// Converte para o [`WebhookPayload`] equivalente, copiando os textos
+    pub fn to_owned(&self) -> WebhookPayload {
+        match self {
+            WebhookPayloadRef::ChatGuru(p) => WebhookPayload::ChatGuru(p.to_owned()),
+            WebhookPayloadRef::EventType(p) => WebhookPayload::EventType(p.to_owned()),
+            WebhookPayloadRef::Generic(p) => WebhookPayload::Generic(p.to_owned()),
+        }
+    }
+
+    /// Nome do contato, como em [`WebhookPayload::get_contact_name`]
+    pub fn contact_name(&self) -> &str {
+        match self {
+            WebhookPayloadRef::ChatGuru(p) => &p.nome,
+            WebhookPayloadRef::EventType(p) => p.data.lead_name.as_deref().unwrap_or("Contato"),
+            WebhookPayloadRef::Generic(p) => p.nome.as_deref().unwrap_or("Contato"),
+        }
+    }
+
+    /// Telefone do contato, como em [`WebhookPayload::get_phone_number`]
+    pub fn phone_number(&self) -> Option<&str> {
+        match self {
+            WebhookPayloadRef::ChatGuru(p) => Some(&*p.celular).filter(|c| !c.is_empty()),
+            WebhookPayloadRef::EventType(p) => p.data.phone.as_deref(),
+            WebhookPayloadRef::Generic(p) => p.celular.as_deref(),
+        }
+    }
+
+    /// Texto da mensagem, como em [`WebhookPayload::get_message_text`]
+    pub fn message_text(&self) -> Option<&str> {
+        match self {
+            WebhookPayloadRef::ChatGuru(p) => Some(&*p.texto_mensagem).filter(|t| !t.is_empty()),
+            WebhookPayloadRef::EventType(p) => p.data.annotation.as_deref(),
+            WebhookPayloadRef::Generic(p) => p.mensagem.as_deref(),
+        }
+    }
+
+    /// ID do chat, como em [`WebhookPayload::get_chat_id`]
+    pub fn chat_id(&self) -> Option<&str> {
+        match self {
+            WebhookPayloadRef::ChatGuru(p) => p.chat_id.as_deref(),
+            WebhookPayloadRef::EventType(p) => Some(&p.id),
+            WebhookPayloadRef::Generic(_) => None,
+        }
+    }
+}
+
+impl From<WebhookPayloadRef<'_>> for WebhookPayload {
+    fn from(payload: WebhookPayloadRef<'_>) -> Self {
+        payload.to_owned()
+    }
+}
+
+/// Como [`ChatGuruPayload`], com os textos emprestados do corpo do webhook
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ChatGuruPayloadRef<'a> {
+    #[serde(default, borrow, deserialize_with = "de::cow_or_number")]
+    pub campanha_id: Cow<'a, str>,
+    #[serde(default, borrow)]
+    pub campanha_nome: Cow<'a, str>,
+    #[serde(default, borrow)]
+    pub origem: Cow<'a, str>,
+    #[serde(default, borrow)]
+    pub email: Cow<'a, str>,
+    #[serde(default, borrow)]
+    pub nome: Cow<'a, str>,
+    #[serde(default, borrow, deserialize_with = "de::cow_list")]
+    pub tags: Vec<Cow<'a, str>>,
+    #[serde(default, borrow, alias = "mensagem", alias = "message", alias = "text")]
+    pub texto_mensagem: Cow<'a, str>,
+
+    #[serde(default, borrow, deserialize_with = "borrowed_opt")]
+    pub media_url: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_opt")]
+    pub media_type: Option<Cow<'a, str>>,
+
+    #[serde(default, borrow, deserialize_with = "borrowed_opt")]
+    pub tipo_mensagem: Option<Cow<'a, str>>,
+    #[serde(
+        default,
+        borrow,
+        alias = "url_midia",
+        deserialize_with = "borrowed_opt"
+    )]
+    pub url_arquivo: Option<Cow<'a, str>>,
+
+    #[serde(default, alias = "localizacao", deserialize_with = "lenient")]
+    pub location: Option<GeoPoint>,
+    #[serde(default, alias = "vcard", deserialize_with = "lenient")]
+    pub contact_card: Option<VCard>,
+
+    #[serde(default, alias = "resposta_botao", deserialize_with = "lenient")]
+    pub button_reply: Option<ButtonReply>,
+
+    #[serde(
+        default,
+        borrow,
+        alias = "id_mensagem_citada",
+        alias = "reply_to_message_id",
+        deserialize_with = "de::opt_cow_or_number"
+    )]
+    pub quoted_message_id: Option<Cow<'a, str>>,
+    #[serde(default, alias = "reacao", deserialize_with = "lenient")]
+    pub reaction: Option<Reaction>,
+
+    #[serde(default)]
+    pub campos_personalizados: HashMap<String, Value>,
+    #[serde(default)]
+    pub bot_context: Option<BotContext>,
+    #[serde(default, borrow, deserialize_with = "borrowed_opt")]
+    pub responsavel_nome: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_opt")]
+    pub responsavel_email: Option<Cow<'a, str>>,
+    #[serde(default, borrow)]
+    pub link_chat: Cow<'a, str>,
+    #[serde(default, borrow, deserialize_with = "de::cow_or_number")]
+    pub celular: Cow<'a, str>,
+    #[serde(default, borrow, deserialize_with = "de::opt_cow_or_number")]
+    pub phone_id: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "de::opt_cow_or_number")]
+    pub chat_id: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "de::opt_cow_or_number")]
+    pub chat_created: Option<Cow<'a, str>>,
+
+    #[serde(
+        default,
+        borrow,
+        alias = "id_mensagem",
+        deserialize_with = "de::opt_cow_or_number"
+    )]
+    pub message_id: Option<Cow<'a, str>>,
+    #[serde(
+        default,
+        borrow,
+        alias = "status_mensagem",
+        deserialize_with = "borrowed_opt"
+    )]
+    pub message_status: Option<Cow<'a, str>>,
+}
+
+impl ChatGuruPayloadRef<'_> {
+    /// Converte para o [`ChatGuruPayload`] equivalente, copiando os textos
+    pub fn to_owned(&self) -> ChatGuruPayload {
+        ChatGuruPayload {
+            campanha_id: self.campanha_id.to_string(),
+            campanha_nome: self.campanha_nome.to_string(),
+            origem: self.origem.to_string(),
+            email: self.email.to_string(),
+            nome: self.nome.to_string(),
+            tags: self.tags.iter().map(|tag| tag.to_string()).collect(),
+            texto_mensagem: self.texto_mensagem.to_string(),
+            media_url: owned_opt(&self.media_url),
+            media_type: owned_opt(&self.media_type),
+            tipo_mensagem: owned_opt(&self.tipo_mensagem),
+            url_arquivo: owned_opt(&self.url_arquivo),
+            location: self.location.clone(),
+            contact_card: self.contact_card.clone(),
+            button_reply: self.button_reply.clone(),
+            quoted_message_id: owned_opt(&self.quoted_message_id),
+            reaction: self.reaction.clone(),
+            campos_personalizados: self.campos_personalizados.clone(),
+            bot_context: self.bot_context.clone(),
+            responsavel_nome: owned_opt(&self.responsavel_nome),
+            responsavel_email: owned_opt(&self.responsavel_email),
+            link_chat: self.link_chat.to_string(),
+            celular: self.celular.to_string(),
+            phone_id: owned_opt(&self.phone_id),
+            chat_id: owned_opt(&self.chat_id),
+            chat_created: owned_opt(&self.chat_created),
+            message_id: owned_opt(&self.message_id),
+            message_status: owned_opt(&self.message_status),
+        }
+    }
+}
+
+/// Como [`EventTypePayload`], com os textos emprestados do corpo do webhook
+#[derive(Debug, Deserialize, Clone)]
+pub struct EventTypePayloadRef<'a> {
+    #[serde(borrow, deserialize_with = "de::cow_or_number")]
+    pub id: Cow<'a, str>,
+    #[serde(borrow)]
+    pub event_type: Cow<'a, str>,
+    #[serde(borrow, deserialize_with = "de::cow_or_number")]
+    pub timestamp: Cow<'a, str>,
+    #[serde(borrow)]
+    pub data: EventDataRef<'a>,
+}
+
+impl EventTypePayloadRef<'_> {
+    /// Converte para o [`EventTypePayload`] equivalente, copiando os textos
+    pub fn to_owned(&self) -> EventTypePayload {
+        EventTypePayload {
+            id: self.id.to_string(),
+            event_type: self.event_type.to_string(),
+            timestamp: self.timestamp.to_string(),
+            data: self.data.to_owned(),
+        }
+    }
+}
+
+/// Como [`EventData`], com os textos emprestados do corpo do webhook
+#[derive(Debug, Deserialize, Clone)]
+pub struct EventDataRef<'a> {
+    #[serde(default, borrow, deserialize_with = "borrowed_opt")]
+    pub lead_name: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "de::opt_cow_or_number")]
+    pub phone: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_opt")]
+    pub email: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_opt")]
+    pub project_name: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_opt")]
+    pub task_title: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_opt")]
+    pub annotation: Option<Cow<'a, str>>,
+    pub amount: Option<f64>,
+    #[serde(default, borrow, deserialize_with = "borrowed_opt")]
+    pub status: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub custom_data: HashMap<String, Value>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl EventDataRef<'_> {
+    /// Converte para o [`EventData`] equivalente, copiando os textos
+    pub fn to_owned(&self) -> EventData {
+        EventData {
+            lead_name: owned_opt(&self.lead_name),
+            phone: owned_opt(&self.phone),
+            email: owned_opt(&self.email),
+            project_name: owned_opt(&self.project_name),
+            task_title: owned_opt(&self.task_title),
+            annotation: owned_opt(&self.annotation),
+            amount: self.amount,
+            status: owned_opt(&self.status),
+            custom_data: self.custom_data.clone(),
+            extra: self.extra.clone(),
+        }
+    }
+}
+
+/// Como [`GenericPayload`], com os textos emprestados do corpo do webhook
+#[derive(Debug, Deserialize, Clone)]
+pub struct GenericPayloadRef<'a> {
+    #[serde(default, borrow, deserialize_with = "borrowed_opt")]
+    pub nome: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "de::opt_cow_or_number")]
+    pub celular: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_opt")]
+    pub email: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_opt")]
+    pub mensagem: Option<Cow<'a, str>>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl GenericPayloadRef<'_> {
+    /// Converte para o [`GenericPayload`] equivalente, copiando os textos
+    pub fn to_owned(&self) -> GenericPayload {
+        GenericPayload {
+            nome: owned_opt(&self.nome),
+            celular: owned_opt(&self.celular),
+            email: owned_opt(&self.email),
+            mensagem: owned_opt(&self.mensagem),
+            extra: self.extra.clone(),
+        }
+    }
+}
+
+/// Texto que o serde empresta do corpo sempre que não há escapes
+///
+/// O serde só empresta `Cow<str>` quando ele é o tipo do campo; dentro de
+/// `Option`, o `Cow` sempre é copiado. Campos que também aceitam número
+/// usam os helpers de [`de`](super::de).
+#[derive(Deserialize)]
+pub(super) struct BorrowedStr<'a>(#[serde(borrow)] pub(super) Cow<'a, str>);
+
+fn borrowed_opt<'de: 'a, 'a, D>(deserializer: D) -> Result<Option<Cow<'a, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<BorrowedStr<'de>>::deserialize(deserializer)?.map(|s| s.0))
+}
+
+fn owned_opt(value: &Option<Cow<'_, str>>) -> Option<String> {
+    value.as_deref().map(str::to_string)
+}
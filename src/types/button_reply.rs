@@ -0,0 +1,82 @@
+//! Respostas a mensagens interativas (botões e listas)
+//!
+//! Quando o contato toca em um botão ou escolhe um item de uma
+//! [`InteractiveMessage`](crate::client::InteractiveMessage), o ChatGuru
+//! envia um webhook com `tipo_mensagem` `button_reply` (ou `list_reply`),
+//! o título escolhido no texto da mensagem e o ID da opção em
+//! `resposta_botao`. [`ButtonReply`] aceita tanto o objeto quanto apenas o
+//! ID como texto.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+/// Origem da resposta: botão de resposta rápida ou item de lista
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplyKind {
+    #[default]
+    Button,
+    ListRow,
+}
+
+/// Opção escolhida pelo contato em uma mensagem interativa
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ButtonReply {
+    /// ID da opção, como definido no envio
+    pub id: String,
+    /// Texto da opção escolhida
+    pub title: String,
+    pub kind: ReplyKind,
+    /// ID da mensagem interativa respondida, quando informado
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+}
+
+impl ButtonReply {
+    fn from_value(value: &Value) -> Option<Self> {
+        let object = match value {
+            Value::String(id) if !id.trim().is_empty() => {
+                return Some(ButtonReply {
+                    id: id.trim().to_string(),
+                    ..ButtonReply::default()
+                })
+            }
+            Value::Object(object) => object,
+            _ => return None,
+        };
+
+        let text = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|name| object.get(*name))
+                .and_then(|value| match value {
+                    Value::String(s) => Some(s.trim().to_string()),
+                    Value::Number(n) => Some(n.to_string()),
+                    _ => None,
+                })
+                .filter(|s| !s.is_empty())
+        };
+
+        let row_id = text(&["row_id", "id_item"]);
+        let kind = match text(&["type", "tipo"]).as_deref() {
+            Some("list_reply" | "list_row" | "list") => ReplyKind::ListRow,
+            _ if row_id.is_some() => ReplyKind::ListRow,
+            _ => ReplyKind::Button,
+        };
+
+        Some(ButtonReply {
+            id: text(&["id", "button_id", "id_botao"]).or(row_id)?,
+            title: text(&["title", "titulo", "text"]).unwrap_or_default(),
+            kind,
+            message_id: text(&["message_id", "id_mensagem", "context_message_id"]),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ButtonReply {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        Self::from_value(&value)
+            .ok_or_else(|| serde::de::Error::custom("invalid button reply: expected an option id"))
+    }
+}
@@ -0,0 +1,186 @@
+//! Links para abrir um chat no painel do ChatGuru
+//!
+//! O campo `link_chat` dos webhooks traz a URL do chat no painel da conta
+//! (`https://s15.chatguru.app/chats#5f1a...`). [`ChatLink`] separa a conta e
+//! o ID do chat, monta o link a partir deles e o formata para notificações
+//! em Markdown (ClickUp) ou no formato de links do Slack.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Domínio dos painéis do ChatGuru
+pub const CHATGURU_APP_DOMAIN: &str = "chatguru.app";
+
+/// Caminho da lista de chats no painel
+const CHATS_PATH: &str = "/chats";
+
+/// Link de um chat no painel do ChatGuru
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::types::ChatLink;
+///
+/// let link = ChatLink::parse("https://s15.chatguru.app/chats#5f1a2b3c").unwrap();
+/// assert_eq!(link.account(), Some("s15"));
+/// assert_eq!(link.chat_id(), "5f1a2b3c");
+/// assert_eq!(link, ChatLink::new("s15", "5f1a2b3c"));
+///
+/// assert_eq!(
+///     link.as_markdown("Abrir chat"),
+///     "[Abrir chat](https://s15.chatguru.app/chats#5f1a2b3c)"
+/// );
+/// assert_eq!(
+///     link.as_slack("Abrir chat"),
+///     "<https://s15.chatguru.app/chats#5f1a2b3c|Abrir chat>"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChatLink {
+    /// Esquema e host do painel, sem barra final (`https://s15.chatguru.app`)
+    origin: String,
+    chat_id: String,
+}
+
+impl ChatLink {
+    /// Monta o link do chat a partir da conta e do ID do chat
+    ///
+    /// `account` é o subdomínio da conta (`s15`), o host do painel
+    /// (`s15.chatguru.app`) ou a URL do painel (`https://painel.exemplo.com`).
+    pub fn new(account: &str, chat_id: impl Into<String>) -> Self {
+        let account = account.trim().trim_end_matches('/');
+        let origin = if account.contains("://") {
+            account.to_string()
+        } else if account.contains('.') {
+            format!("https://{}", account)
+        } else {
+            format!("https://{}.{}", account, CHATGURU_APP_DOMAIN)
+        };
+
+        Self {
+            origin,
+            chat_id: chat_id.into().trim().to_string(),
+        }
+    }
+
+    /// Lê o link de um chat (campo `link_chat` dos webhooks)
+    ///
+    /// Aceita o ID do chat no fragmento (`/chats#ID`, formato atual) ou no
+    /// caminho (`/chats/ID`); sem esquema, assume `https://`.
+    ///
+    /// # Retorno
+    ///
+    /// `None` se o texto não for uma URL com o ID de um chat.
+    pub fn parse(link: &str) -> Option<Self> {
+        let link = link.trim();
+        let (scheme, rest) = match link.split_once("://") {
+            Some((scheme, rest)) if scheme == "http" || scheme == "https" => (scheme, rest),
+            Some(_) => return None,
+            None => ("https", link),
+        };
+
+        let (address, fragment) = match rest.split_once('#') {
+            Some((address, fragment)) => (address, Some(fragment)),
+            None => (rest, None),
+        };
+        let address = address.split('?').next().unwrap_or_default();
+        let (host, path) = match address.find('/') {
+            Some(index) => address.split_at(index),
+            None => (address, ""),
+        };
+        if host.is_empty() || host.contains(char::is_whitespace) {
+            return None;
+        }
+
+        let chat_id = match fragment {
+            Some(fragment) => fragment.trim_start_matches('/'),
+            None => path
+                .trim_end_matches('/')
+                .strip_prefix(CHATS_PATH)?
+                .strip_prefix('/')?,
+        };
+        if chat_id.is_empty()
+            || chat_id.contains(['/', '?', '#'])
+            || chat_id.contains(char::is_whitespace)
+        {
+            return None;
+        }
+
+        Some(Self {
+            origin: format!("{}://{}", scheme, host),
+            chat_id: chat_id.to_string(),
+        })
+    }
+
+    /// ID do chat
+    pub fn chat_id(&self) -> &str {
+        &self.chat_id
+    }
+
+    /// Esquema e host do painel (`https://s15.chatguru.app`)
+    pub fn origin(&self) -> &str {
+        &self.origin
+    }
+
+    /// Subdomínio da conta (`s15`), para painéis em `chatguru.app`
+    pub fn account(&self) -> Option<&str> {
+        let host = self
+            .origin
+            .split_once("://")
+            .map_or(&*self.origin, |(_, host)| host);
+        host.strip_suffix(CHATGURU_APP_DOMAIN)?
+            .strip_suffix('.')
+            .filter(|account| !account.is_empty())
+    }
+
+    /// URL do chat no painel
+    pub fn url(&self) -> String {
+        format!("{}{}#{}", self.origin, CHATS_PATH, self.chat_id)
+    }
+
+    /// Link em Markdown (`[label](url)`), com o texto escapado
+    pub fn as_markdown(&self, label: &str) -> String {
+        let mut escaped = String::with_capacity(label.len());
+        for c in label.chars() {
+            if matches!(c, '\\' | '[' | ']') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        format!(
+            "[{}]({})",
+            escaped,
+            self.url().replace('(', "%28").replace(')', "%29")
+        )
+    }
+
+    /// Link no formato do Slack (`<url|label>`), com o texto escapado
+    pub fn as_slack(&self, label: &str) -> String {
+        let escaped = label
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('|', "&#124;");
+        format!("<{}|{}>", self.url(), escaped)
+    }
+}
+
+impl fmt::Display for ChatLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.url())
+    }
+}
+
+impl Serialize for ChatLink {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.url())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChatLink {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        ChatLink::parse(&raw)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid ChatGuru chat link: {}", raw)))
+    }
+}
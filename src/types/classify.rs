@@ -0,0 +1,112 @@
+//! Classificação rápida do formato de um webhook
+//!
+//! O enum `untagged` [`WebhookPayload`] desserializa o corpo inteiro em cada
+//! variante até uma aceitar. [`WebhookPayload::classify`] olha apenas os nomes
+//! dos campos de primeiro nível (os valores são pulados como [`RawValue`],
+//! sem alocação) e escolhe o formato com as mesmas regras de
+//! [`WebhookPayload::parse`]:
+//!
+//! 1. `ChatGuru` se houver `campanha_id` ou outro campo específico desse
+//!    formato (`chat_id`, `phone_id`, `texto_mensagem`...); `nome`, `email`,
+//!    `celular` e `mensagem` sozinhos não bastam;
+//! 2. `EventType` se houver `event_type`;
+//! 3. `Generic` nos demais casos.
+
+use super::borrowed::BorrowedStr;
+use super::parse::CHATGURU_FIELDS;
+use super::schema::SchemaVersion;
+use super::webhook::WebhookPayload;
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use serde_json::value::RawValue;
+use std::fmt;
+
+impl WebhookPayload {
+    /// Identifica o formato do webhook só pelos nomes dos campos, sem desserializá-lo
+    ///
+    /// Retorna `None` se o corpo não for um objeto JSON válido. O formato
+    /// indicado é o que [`parse`](Self::parse) escolheria, a menos que algum
+    /// campo tenha tipo inválido (nesse caso `parse` tenta o próximo formato).
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust
+    /// use chatguru::types::{SchemaVersion, WebhookPayload};
+    ///
+    /// let body = br#"{"campanha_id": "123", "campos_personalizados": {"Tarefa": "Suporte"}}"#;
+    /// assert_eq!(WebhookPayload::classify(body), Some(SchemaVersion::ChatGuru));
+    ///
+    /// let legacy = br#"{"id": "1", "event_type": "new_lead", "timestamp": "", "data": {}}"#;
+    /// assert_eq!(WebhookPayload::classify(legacy), Some(SchemaVersion::EventType));
+    ///
+    /// let form = br#"{"nome": "Ana", "celular": "5511999999999", "mensagem": "Oi"}"#;
+    /// assert_eq!(WebhookPayload::classify(form), Some(SchemaVersion::Generic));
+    /// ```
+    pub fn classify(body: &[u8]) -> Option<SchemaVersion> {
+        let body = std::str::from_utf8(body).ok()?;
+        serde_json::from_str::<Markers>(body)
+            .ok()
+            .map(|markers| markers.schema())
+    }
+}
+
+/// Formato indicado pelos nomes dos campos de primeiro nível
+pub(super) fn classify_keys<'k>(keys: impl IntoIterator<Item = &'k str>) -> SchemaVersion {
+    let mut markers = Markers::default();
+    for key in keys {
+        markers.record(key);
+    }
+    markers.schema()
+}
+
+/// Marcadores de formato encontrados nos campos de primeiro nível
+#[derive(Default)]
+struct Markers {
+    chatguru: bool,
+    event_type: bool,
+}
+
+impl Markers {
+    fn record(&mut self, key: &str) {
+        match key {
+            "campanha_id" => self.chatguru = true,
+            "event_type" => self.event_type = true,
+            _ if !self.chatguru && CHATGURU_FIELDS.contains(&key) => self.chatguru = true,
+            _ => {}
+        }
+    }
+
+    fn schema(&self) -> SchemaVersion {
+        if self.chatguru {
+            SchemaVersion::ChatGuru
+        } else if self.event_type {
+            SchemaVersion::EventType
+        } else {
+            SchemaVersion::Generic
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Markers {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(MarkersVisitor)
+    }
+}
+
+struct MarkersVisitor;
+
+impl<'de> Visitor<'de> for MarkersVisitor {
+    type Value = Markers;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a webhook JSON object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Markers, A::Error> {
+        let mut markers = Markers::default();
+        while let Some(BorrowedStr(key)) = map.next_key()? {
+            map.next_value::<&'de RawValue>()?;
+            markers.record(&key);
+        }
+        Ok(markers)
+    }
+}
@@ -0,0 +1,213 @@
+//! Acesso tipado aos campos personalizados (`campos_personalizados`)
+//!
+//! Os nomes dos campos são definidos por cada conta no painel do ChatGuru e
+//! variam em caixa, acentos e separadores (`Data_Nascimento`, `data nascimento`,
+//! `dataNascimento`). As chaves são comparadas pela forma normalizada de
+//! [`normalize_key`]: minúsculas, sem acentos e sem `_`, `-`, `.` ou espaços.
+
+use super::parse::from_pretty_json;
+use super::payload::ChatGuruPayload;
+use crate::error::{ChatGuruError, Result};
+use chrono::{DateTime, NaiveDate};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// Formatos de data aceitos por [`ChatGuruPayload::get_custom_date`]
+const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%d/%m/%Y", "%d-%m-%Y", "%d.%m.%Y", "%Y/%m/%d"];
+
+/// Normaliza o nome de um campo personalizado para comparação
+///
+/// ```rust,ignore
+/// use chatguru::types::custom_fields::normalize_key;
+///
+/// assert_eq!(normalize_key("Data_Nascimento"), "datanascimento");
+/// assert_eq!(normalize_key("  Região de Atuação "), "regiaodeatuacao");
+/// ```
+pub fn normalize_key(key: &str) -> String {
+    key.chars()
+        .flat_map(char::to_lowercase)
+        .map(fold_accent)
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+/// Remove o acento das letras latinas usadas em português e espanhol
+pub(crate) fn fold_accent(c: char) -> char {
+    match c {
+        'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        'ç' => 'c',
+        'ñ' => 'n',
+        other => other,
+    }
+}
+
+/// Interpreta números no formato brasileiro (`1.234,56`), internacional
+/// (`1234.56`) e com prefixo de moeda (`R$ 10,00`)
+fn parse_number(text: &str) -> Option<f64> {
+    let text = text.trim().trim_start_matches("R$").trim().replace(' ', "");
+    if text.is_empty() {
+        return None;
+    }
+
+    let normalized = match (text.contains(','), text.contains('.')) {
+        (true, true) if text.rfind(',') > text.rfind('.') => {
+            text.replace('.', "").replace(',', ".")
+        }
+        (true, true) => text.replace(',', ""),
+        (true, false) => text.replace(',', "."),
+        _ => text,
+    };
+    normalized.parse().ok()
+}
+
+fn parse_bool(text: &str) -> Option<bool> {
+    match normalize_key(text).as_str() {
+        "true" | "1" | "sim" | "s" | "yes" | "y" | "verdadeiro" => Some(true),
+        "false" | "0" | "nao" | "n" | "no" | "falso" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_date(text: &str) -> Option<NaiveDate> {
+    let text = text.trim();
+    DATE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(text, format).ok())
+        .or_else(|| {
+            DateTime::parse_from_rfc3339(text)
+                .ok()
+                .map(|dt| dt.date_naive())
+        })
+        .or_else(|| {
+            // `AAAA-MM-DD HH:MM:SS` e `DD/MM/AAAA HH:MM`: usa só a data
+            text.split_whitespace()
+                .next()
+                .filter(|date| date.len() < text.len())
+                .and_then(parse_date)
+        })
+}
+
+impl ChatGuruPayload {
+    /// Valor bruto de um campo personalizado, com comparação aproximada do nome
+    ///
+    /// Tenta primeiro a chave exata e depois a forma normalizada
+    /// (veja [`normalize_key`]).
+    pub fn get_custom(&self, key: &str) -> Option<&Value> {
+        if let Some(value) = self.campos_personalizados.get(key) {
+            return Some(value);
+        }
+
+        let wanted = normalize_key(key);
+        self.campos_personalizados
+            .iter()
+            .find(|(name, _)| normalize_key(name) == wanted)
+            .map(|(_, value)| value)
+    }
+
+    /// Campo personalizado como texto
+    ///
+    /// Números e booleanos são convertidos para texto; valores vazios,
+    /// `null`, listas e objetos retornam `None`.
+    pub fn get_custom_str(&self, key: &str) -> Option<String> {
+        match self.get_custom(key)? {
+            Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+            Value::Number(n) => Some(n.to_string()),
+            Value::Bool(b) => Some(b.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Campo personalizado como número
+    ///
+    /// Aceita números JSON e textos como `"1.234,56"`, `"1234.56"` e `"R$ 10,00"`.
+    pub fn get_custom_number(&self, key: &str) -> Option<f64> {
+        match self.get_custom(key)? {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => parse_number(s),
+            _ => None,
+        }
+    }
+
+    /// Campo personalizado como booleano
+    ///
+    /// Aceita `true`/`false`, `1`/`0` e textos como `"sim"`, `"não"`, `"S"` e `"N"`.
+    pub fn get_custom_bool(&self, key: &str) -> Option<bool> {
+        match self.get_custom(key)? {
+            Value::Bool(b) => Some(*b),
+            Value::Number(n) => n.as_i64().map(|n| n != 0),
+            Value::String(s) => parse_bool(s),
+            _ => None,
+        }
+    }
+
+    /// Campo personalizado como data
+    ///
+    /// Aceita `AAAA-MM-DD`, `DD/MM/AAAA`, `DD-MM-AAAA`, RFC 3339 e datas
+    /// seguidas de horário (o horário é descartado).
+    pub fn get_custom_date(&self, key: &str) -> Option<NaiveDate> {
+        match self.get_custom(key)? {
+            Value::String(s) => parse_date(s),
+            _ => None,
+        }
+    }
+
+    /// Campos personalizados com as chaves normalizadas por [`normalize_key`]
+    ///
+    /// Se duas chaves colidirem após a normalização, prevalece a que vier
+    /// primeiro em ordem alfabética do nome original.
+    pub fn custom_fields_normalized(&self) -> HashMap<String, Value> {
+        let mut names: Vec<&String> = self.campos_personalizados.keys().collect();
+        names.sort();
+
+        let mut normalized = HashMap::with_capacity(names.len());
+        for name in names {
+            normalized
+                .entry(normalize_key(name))
+                .or_insert_with(|| self.campos_personalizados[name].clone());
+        }
+        normalized
+    }
+
+    /// Converte `campos_personalizados` em uma struct do usuário
+    ///
+    /// As chaves são usadas como vieram no webhook; use `#[serde(rename = "...")]`
+    /// ou `#[serde(alias = "...")]` para mapear os nomes do painel.
+    ///
+    /// # Erros
+    ///
+    /// Retorna `SerializationError` com o nome do campo ausente ou com tipo
+    /// inválido (ex: ``Invalid custom field `valor_proposta`: invalid type: string "abc", expected f64``).
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// #[derive(serde::Deserialize)]
+    /// struct Lead {
+    ///     #[serde(rename = "Empresa")]
+    ///     empresa: String,
+    ///     #[serde(rename = "Valor_Proposta", default)]
+    ///     valor_proposta: Option<f64>,
+    /// }
+    ///
+    /// let lead: Lead = payload.parse_custom_fields()?;
+    /// ```
+    pub fn parse_custom_fields<T: DeserializeOwned>(&self) -> Result<T> {
+        // Uma chave por linha, para identificar o campo pela linha do erro
+        let fields: BTreeMap<&String, &Value> = self.campos_personalizados.iter().collect();
+        let json = serde_json::to_string_pretty(&fields)?;
+
+        from_pretty_json(&json).map_err(|(field, message)| {
+            ChatGuruError::SerializationError(match field {
+                Some(field) if !message.starts_with("missing field") => {
+                    format!("Invalid custom field `{}`: {}", field, message)
+                }
+                _ => format!("Invalid custom fields: {}", message),
+            })
+        })
+    }
+}
@@ -0,0 +1,185 @@
+//! Datas dos webhooks (`chat_created`, `timestamp`)
+//!
+//! O ChatGuru envia as datas como texto, em formatos diferentes conforme a
+//! versão e a configuração da conta: ISO 8601 com ou sem fuso
+//! (`2024-01-15T10:30:00-03:00`, `2024-01-15 10:30:00`), epoch em segundos
+//! ou milissegundos (`1705325400`) e formato brasileiro (`15/01/2024 10:30`).
+//! [`parse_timestamp`] tenta todos e, se nenhum servir, lista os formatos
+//! tentados no erro.
+
+use super::payload::{ChatGuruPayload, EventTypePayload};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc};
+use thiserror::Error;
+
+/// Formatos com fuso horário explícito
+const ZONED_FORMATS: &[&str] = &["%Y-%m-%dT%H:%M:%S%.f%z", "%Y-%m-%d %H:%M:%S%.f%:z"];
+
+/// Formatos sem fuso horário, interpretados no fuso informado
+const NAIVE_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+    "%d/%m/%Y %H:%M:%S",
+    "%d/%m/%Y %H:%M",
+    "%d/%m/%Y, %H:%M:%S",
+];
+
+/// Formatos só com a data, interpretados como meia-noite
+const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%d/%m/%Y"];
+
+/// Epoch com 12 dígitos ou mais é tratado como milissegundos
+const EPOCH_MILLIS_DIGITS: usize = 12;
+
+/// Data em formato não reconhecido
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Unrecognized timestamp {input:?} (tried: {})", .tried.join("; "))]
+pub struct TimestampParseError {
+    /// Texto recebido
+    pub input: String,
+    /// Formatos tentados, na ordem
+    pub tried: Vec<&'static str>,
+}
+
+/// Interpreta uma data enviada pelo ChatGuru, tratando datas sem fuso como UTC
+///
+/// Aceita, nesta ordem: epoch em segundos ou milissegundos, RFC 3339,
+/// ISO 8601 com fuso, ISO 8601 sem fuso, formato brasileiro
+/// (`DD/MM/AAAA HH:MM[:SS]`) e apenas a data.
+///
+/// # Erros
+///
+/// Retorna [`TimestampParseError`] com os formatos tentados se nenhum servir.
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::types::parse_timestamp;
+///
+/// let a = parse_timestamp("2024-01-15T10:30:00-03:00").unwrap();
+/// let b = parse_timestamp("2024-01-15 13:30:00").unwrap();
+/// let c = parse_timestamp("1705325400").unwrap();
+/// assert_eq!(a, b);
+/// assert_eq!(a, c);
+///
+/// let err = parse_timestamp("ontem").unwrap_err();
+/// assert!(err.tried.contains(&"RFC 3339"));
+/// ```
+pub fn parse_timestamp(text: &str) -> Result<DateTime<Utc>, TimestampParseError> {
+    parse_timestamp_in(text, Utc.fix())
+}
+
+/// Como [`parse_timestamp`], interpretando datas sem fuso no fuso `offset`
+///
+/// Útil quando a conta do ChatGuru envia horários locais, como
+/// `FixedOffset::west_opt(3 * 3600)` para o horário de Brasília.
+///
+/// # Erros
+///
+/// Os de [`parse_timestamp`].
+pub fn parse_timestamp_in(
+    text: &str,
+    offset: FixedOffset,
+) -> Result<DateTime<Utc>, TimestampParseError> {
+    let text = text.trim();
+    let mut tried = Vec::new();
+
+    tried.push("epoch seconds/milliseconds");
+    if let Some(timestamp) = parse_epoch(text) {
+        return Ok(timestamp);
+    }
+
+    tried.push("RFC 3339");
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(text) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+
+    for format in ZONED_FORMATS {
+        tried.push(*format);
+        if let Ok(timestamp) = DateTime::parse_from_str(text, format) {
+            return Ok(timestamp.with_timezone(&Utc));
+        }
+    }
+
+    for format in NAIVE_FORMATS {
+        tried.push(*format);
+        if let Ok(naive) = NaiveDateTime::parse_from_str(text, format) {
+            if let Some(timestamp) = in_offset(naive, offset) {
+                return Ok(timestamp);
+            }
+        }
+    }
+
+    for format in DATE_FORMATS {
+        tried.push(*format);
+        if let Some(naive) = NaiveDate::parse_from_str(text, format)
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+        {
+            if let Some(timestamp) = in_offset(naive, offset) {
+                return Ok(timestamp);
+            }
+        }
+    }
+
+    Err(TimestampParseError {
+        input: text.to_string(),
+        tried,
+    })
+}
+
+fn parse_epoch(text: &str) -> Option<DateTime<Utc>> {
+    let (integer, fraction) = text.split_once('.').unwrap_or((text, ""));
+    if integer.is_empty()
+        || !integer.bytes().all(|b| b.is_ascii_digit())
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let value: f64 = text.parse().ok()?;
+    let millis = if integer.len() >= EPOCH_MILLIS_DIGITS {
+        value
+    } else {
+        value * 1000.0
+    };
+    Utc.timestamp_millis_opt(millis as i64).single()
+}
+
+fn in_offset(naive: NaiveDateTime, offset: FixedOffset) -> Option<DateTime<Utc>> {
+    offset
+        .from_local_datetime(&naive)
+        .single()
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+}
+
+impl ChatGuruPayload {
+    /// Data de criação do chat (`chat_created`), interpretada
+    ///
+    /// Retorna `Ok(None)` quando o campo não veio (ou veio vazio). Datas sem
+    /// fuso são tratadas como UTC; para outro fuso, use
+    /// [`parse_timestamp_in`] com o campo `chat_created`.
+    ///
+    /// # Erros
+    ///
+    /// Os de [`parse_timestamp`].
+    pub fn chat_created_at(&self) -> Result<Option<DateTime<Utc>>, TimestampParseError> {
+        match self.chat_created.as_deref().map(str::trim) {
+            Some(text) if !text.is_empty() => parse_timestamp(text).map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl EventTypePayload {
+    /// Momento do evento (`timestamp`), interpretado
+    ///
+    /// Datas sem fuso são tratadas como UTC.
+    ///
+    /// # Erros
+    ///
+    /// Os de [`parse_timestamp`].
+    pub fn occurred_at(&self) -> Result<DateTime<Utc>, TimestampParseError> {
+        parse_timestamp(&self.timestamp)
+    }
+}
@@ -0,0 +1,174 @@
+//! Desserializadores tolerantes a variações de tipo dos webhooks
+//!
+//! O ChatGuru não é consistente nos tipos de alguns campos: IDs e telefones
+//! às vezes chegam como número (`"campanha_id": 1001`) e `tags` às vezes
+//! chega como texto separado por vírgulas (`"tags": "cliente, vip"`). Sem
+//! estes helpers, o payload inteiro falharia no formato certo e cairia em
+//! `Generic`.
+//!
+//! Os helpers `cow_*` emprestam o texto do corpo quando possível e são usados
+//! pelos payloads de [`borrowed`](super::borrowed).
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use std::borrow::Cow;
+use std::fmt;
+
+/// Texto ou número, convertido para texto (`null` vira texto vazio)
+///
+/// Empresta o texto do corpo quando não há escapes.
+struct Text<'a>(Cow<'a, str>);
+
+impl<'de> Deserialize<'de> for Text<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(TextVisitor).map(Text)
+    }
+}
+
+struct TextVisitor;
+
+impl<'de> Visitor<'de> for TextVisitor {
+    type Value = Cow<'de, str>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string or a number")
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, value: &'de str) -> Result<Self::Value, E> {
+        Ok(Cow::Borrowed(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(value.to_string()))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(value.to_string()))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(value.to_string()))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Cow::Borrowed(""))
+    }
+}
+
+/// Lista de textos: array (de textos ou números), texto separado por
+/// vírgulas ou `null`
+struct TextList<'a>(Vec<Cow<'a, str>>);
+
+impl<'de> Deserialize<'de> for TextList<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(TextListVisitor).map(TextList)
+    }
+}
+
+struct TextListVisitor;
+
+/// Itens de um texto separado por vírgulas, sem espaços e sem itens vazios
+fn split_items(text: &str) -> impl Iterator<Item = &str> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
+impl<'de> Visitor<'de> for TextListVisitor {
+    type Value = Vec<Cow<'de, str>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an array of strings or a comma-separated string")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(Text(item)) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, value: &'de str) -> Result<Self::Value, E> {
+        Ok(split_items(value).map(Cow::Borrowed).collect())
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(split_items(value)
+            .map(|item| Cow::Owned(item.to_string()))
+            .collect())
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(vec![Cow::Owned(value.to_string())])
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(vec![Cow::Owned(value.to_string())])
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Vec::new())
+    }
+}
+
+/// `String` que também aceita número (`1001` → `"1001"`)
+pub(super) fn string_or_number<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Text::deserialize(deserializer)?.0.into_owned())
+}
+
+/// `Option<String>` que também aceita número
+pub(super) fn opt_string_or_number<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<Text>::deserialize(deserializer)?.map(|text| text.0.into_owned()))
+}
+
+/// `Vec<String>` que também aceita texto separado por vírgulas
+pub(super) fn string_list<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(TextList::deserialize(deserializer)?
+        .0
+        .into_iter()
+        .map(Cow::into_owned)
+        .collect())
+}
+
+/// Como [`string_or_number`], emprestando o texto do corpo
+pub(super) fn cow_or_number<'de: 'a, 'a, D>(deserializer: D) -> Result<Cow<'a, str>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Text::deserialize(deserializer)?.0)
+}
+
+/// Como [`opt_string_or_number`], emprestando o texto do corpo
+pub(super) fn opt_cow_or_number<'de: 'a, 'a, D>(
+    deserializer: D,
+) -> Result<Option<Cow<'a, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<Text<'de>>::deserialize(deserializer)?.map(|text| text.0))
+}
+
+/// Como [`string_list`], emprestando os textos do corpo
+pub(super) fn cow_list<'de: 'a, 'a, D>(deserializer: D) -> Result<Vec<Cow<'a, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(TextList::deserialize(deserializer)?.0)
+}
@@ -0,0 +1,131 @@
+//! Envelope JSON estável dos eventos para sistemas externos
+//!
+//! Serviços que repassam os webhooks adiante (Pub/Sub, Kafka, webhooks de
+//! saída) publicam [`EventEnvelope`] em vez de cada um definir seu próprio
+//! formato. O esquema é versionado por [`ENVELOPE_VERSION`]: campos só são
+//! adicionados dentro de uma versão; mudanças incompatíveis geram uma nova.
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "id": "evt-18f3a2b4c5d-0",
+//!   "occurred_at": "2024-05-10T14:32:00Z",
+//!   "account_id": "conta-principal",
+//!   "phone_id": "5511999999999",
+//!   "kind": "message_received",
+//!   "data": { "chat": { "chat_id": "...", ... }, "text": "Olá" }
+//! }
+//! ```
+
+use super::event::ChatEvent;
+use super::webhook::WebhookPayload;
+use crate::error::{ChatGuruError, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Versão atual do esquema de [`EventEnvelope`]
+pub const ENVELOPE_VERSION: u32 = 1;
+
+static ENVELOPE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Evento versionado, no formato compartilhado entre os consumidores
+///
+/// `kind` e `data` são a serialização de [`ChatEvent`] (`data` é o conteúdo
+/// da variante); use [`event`](Self::event) para obter o evento tipado.
+/// Todos os campos são sempre serializados (`null` quando ausentes).
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::types::EventEnvelope;
+///
+/// let envelope = EventEnvelope::from_payload(&payload)?.with_account_id("conta-principal");
+/// let body = serde_json::to_vec(&envelope)?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventEnvelope {
+    /// Versão do esquema ([`ENVELOPE_VERSION`] ao criar)
+    pub version: u32,
+    /// ID único do envelope
+    pub id: String,
+    /// Momento do evento (do webhook, quando informado; senão, da criação do envelope)
+    pub occurred_at: DateTime<Utc>,
+    /// Conta do ChatGuru que recebeu o webhook
+    pub account_id: Option<String>,
+    /// Linha (phone_id) que recebeu o evento
+    pub phone_id: Option<String>,
+    /// Tipo do evento ([`ChatEvent::kind`])
+    pub kind: String,
+    /// Dados do evento
+    pub data: Value,
+}
+
+impl EventEnvelope {
+    /// Cria o envelope de um [`ChatEvent`], com `occurred_at` no momento atual
+    pub fn from_event(event: &ChatEvent) -> Result<Self> {
+        let mut value = serde_json::to_value(event)?;
+        let data = value
+            .get_mut("data")
+            .map(Value::take)
+            .unwrap_or(Value::Null);
+
+        Ok(Self {
+            version: ENVELOPE_VERSION,
+            id: format!(
+                "evt-{:x}-{:x}",
+                Utc::now().timestamp_millis(),
+                ENVELOPE_COUNTER.fetch_add(1, Ordering::Relaxed)
+            ),
+            occurred_at: Utc::now(),
+            account_id: None,
+            phone_id: event.chat().and_then(|c| c.phone_id.clone()),
+            kind: event.kind().to_string(),
+            data,
+        })
+    }
+
+    /// Cria o envelope de um webhook
+    ///
+    /// No formato legado (`event_type`), `occurred_at` vem do `timestamp`
+    /// do webhook quando ele é reconhecido (veja [`EventTypePayload::occurred_at`](super::payload::EventTypePayload::occurred_at)).
+    ///
+    /// # Erros
+    ///
+    /// Os de [`ChatEvent::try_from`].
+    pub fn from_payload(payload: &WebhookPayload) -> Result<Self> {
+        let mut envelope = Self::from_event(&ChatEvent::try_from(payload)?)?;
+
+        if let WebhookPayload::EventType(ref p) = payload {
+            if let Ok(timestamp) = p.occurred_at() {
+                envelope.occurred_at = timestamp;
+            }
+        }
+        Ok(envelope)
+    }
+
+    /// Define a conta que recebeu o webhook
+    pub fn with_account_id(mut self, account_id: impl Into<String>) -> Self {
+        self.account_id = Some(account_id.into());
+        self
+    }
+
+    /// Evento tipado do envelope
+    ///
+    /// # Erros
+    ///
+    /// * `ValidationError` - envelope de uma versão mais nova que [`ENVELOPE_VERSION`]
+    /// * `SerializationError` - `kind`/`data` não correspondem a um [`ChatEvent`]
+    pub fn event(&self) -> Result<ChatEvent> {
+        if self.version > ENVELOPE_VERSION {
+            return Err(ChatGuruError::ValidationError(format!(
+                "Unsupported event envelope version {} (latest known is {})",
+                self.version, ENVELOPE_VERSION
+            )));
+        }
+        Ok(serde_json::from_value(
+            json!({ "kind": self.kind, "data": self.data }),
+        )?)
+    }
+}
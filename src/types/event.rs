@@ -0,0 +1,511 @@
+use super::button_reply::ButtonReply;
+use super::payload::{ChatGuruPayload, EventTypePayload, GenericPayload};
+use super::reaction::Reaction;
+use super::webhook::WebhookPayload;
+use crate::error::{ChatGuruError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Identificação do chat/contato de origem de um evento
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatInfo {
+    /// ID do chat no ChatGuru
+    pub chat_id: Option<String>,
+    /// Telefone do contato (como recebido)
+    pub phone: Option<String>,
+    /// Linha (phone_id) que recebeu o evento
+    pub phone_id: Option<String>,
+    /// Nome do contato
+    pub name: Option<String>,
+    /// E-mail do contato
+    pub email: Option<String>,
+}
+
+/// Mídia anexada a uma mensagem
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MediaInfo {
+    /// URL do arquivo
+    pub url: String,
+    /// MIME type (ex: `image/jpeg`), quando conhecido
+    pub mime_type: Option<String>,
+    /// Tipo bruto informado pelo ChatGuru (`image`, `ptt`, `document`...)
+    pub tipo_mensagem: Option<String>,
+    /// Legenda/texto enviado junto com a mídia
+    pub caption: Option<String>,
+}
+
+/// Campanha/origem associada a um evento
+///
+/// Lida de qualquer formato com [`WebhookPayload::campaign`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Campaign {
+    /// ID da campanha
+    pub id: String,
+    /// Nome da campanha
+    pub name: Option<String>,
+    /// Origem do lead (ex: formulário, anúncio)
+    pub origin: Option<String>,
+}
+
+/// Atendente responsável pelo chat
+///
+/// Lido de qualquer formato com [`WebhookPayload::get_agent`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Agent {
+    /// Nome do atendente
+    pub name: Option<String>,
+    /// E-mail do atendente
+    pub email: Option<String>,
+}
+
+/// Chaves dos campos de campanha nos mapas `extra` dos formatos legados
+const CAMPAIGN_ID_KEYS: &[&str] = &["campanha_id", "campaign_id"];
+const CAMPAIGN_NAME_KEYS: &[&str] = &["campanha_nome", "campaign_name"];
+const CAMPAIGN_ORIGIN_KEYS: &[&str] = &["origem", "origin"];
+
+/// Chaves dos campos do atendente nos mapas `extra` dos formatos legados
+const AGENT_NAME_KEYS: &[&str] = &["responsavel_nome", "agent_name", "atendente_nome"];
+const AGENT_EMAIL_KEYS: &[&str] = &["responsavel_email", "agent_email", "atendente_email"];
+/// Chaves com o atendente como objeto (`{"nome": ..., "email": ...}`) ou só o nome
+const AGENT_OBJECT_KEYS: &[&str] = &["responsavel", "agent", "atendente"];
+
+impl Campaign {
+    /// Campanha com ID ou nome; `None` se os dois estiverem vazios
+    fn build(id: Option<String>, name: Option<String>, origin: Option<String>) -> Option<Self> {
+        if id.is_none() && name.is_none() {
+            return None;
+        }
+        Some(Self {
+            id: id.unwrap_or_default(),
+            name,
+            origin,
+        })
+    }
+
+    /// Campanha lida dos campos avulsos de um mapa (`extra` dos formatos legados)
+    fn from_map(map: &HashMap<String, Value>, fallback_name: Option<&str>) -> Option<Self> {
+        Self::build(
+            map_text(map, CAMPAIGN_ID_KEYS),
+            map_text(map, CAMPAIGN_NAME_KEYS)
+                .or_else(|| fallback_name.and_then(|name| non_empty(name.trim()))),
+            map_text(map, CAMPAIGN_ORIGIN_KEYS),
+        )
+    }
+}
+
+impl Agent {
+    /// Atendente com nome ou e-mail; `None` se os dois estiverem vazios
+    fn build(name: Option<&str>, email: Option<&str>) -> Option<Self> {
+        let name = name.and_then(|name| non_empty(name.trim()));
+        let email = email.and_then(|email| non_empty(email.trim()));
+        if name.is_none() && email.is_none() {
+            return None;
+        }
+        Some(Self { name, email })
+    }
+
+    /// Atendente lido dos campos avulsos de um mapa (`extra` dos formatos legados)
+    fn from_map(map: &HashMap<String, Value>) -> Option<Self> {
+        let agent = Self::build(
+            map_text(map, AGENT_NAME_KEYS).as_deref(),
+            map_text(map, AGENT_EMAIL_KEYS).as_deref(),
+        );
+        if agent.is_some() {
+            return agent;
+        }
+
+        AGENT_OBJECT_KEYS
+            .iter()
+            .find_map(|key| match map.get(*key)? {
+                Value::String(name) => Self::build(Some(name), None),
+                Value::Object(object) => {
+                    let text = |keys: &[&str]| {
+                        keys.iter()
+                            .find_map(|key| object.get(*key).and_then(Value::as_str))
+                    };
+                    Self::build(text(&["nome", "name"]), text(&["email"]))
+                }
+                _ => None,
+            })
+    }
+}
+
+/// Primeiro valor de texto (ou número) não vazio entre as chaves
+fn map_text(map: &HashMap<String, Value>, keys: &[&str]) -> Option<String> {
+    keys.iter().find_map(|key| match map.get(*key)? {
+        Value::String(s) => non_empty(s.trim()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    })
+}
+
+impl ChatGuruPayload {
+    /// Campanha do payload (`campanha_id`, `campanha_nome`, `origem`)
+    pub fn campaign(&self) -> Option<Campaign> {
+        Campaign::build(
+            non_empty(self.campanha_id.trim()),
+            non_empty(self.campanha_nome.trim()),
+            non_empty(self.origem.trim()),
+        )
+    }
+
+    /// Atendente responsável (`responsavel_nome`, `responsavel_email`)
+    pub fn agent(&self) -> Option<Agent> {
+        Agent::build(
+            self.responsavel_nome.as_deref(),
+            self.responsavel_email.as_deref(),
+        )
+    }
+}
+
+impl EventTypePayload {
+    /// Campanha do evento, lida dos campos extras de `data`
+    ///
+    /// Sem `campanha_nome`, usa o nome do projeto (`project_name`).
+    pub fn campaign(&self) -> Option<Campaign> {
+        Campaign::from_map(&self.data.extra, self.data.project_name.as_deref())
+    }
+
+    /// Atendente responsável, lido dos campos extras de `data`
+    pub fn agent(&self) -> Option<Agent> {
+        Agent::from_map(&self.data.extra)
+    }
+}
+
+impl GenericPayload {
+    /// Campanha do payload, lida dos campos extras
+    pub fn campaign(&self) -> Option<Campaign> {
+        Campaign::from_map(&self.extra, None)
+    }
+
+    /// Atendente responsável, lido dos campos extras
+    pub fn agent(&self) -> Option<Agent> {
+        Agent::from_map(&self.extra)
+    }
+}
+
+/// Evento de negócio derivado de um [`WebhookPayload`]
+///
+/// Enquanto `WebhookPayload` reflete os formatos crus enviados pelo ChatGuru,
+/// `ChatEvent` expõe apenas o que interessa para a lógica de negócio, com
+/// campos nomeados e tipados.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::types::ChatEvent;
+///
+/// match ChatEvent::try_from(payload)? {
+///     ChatEvent::MessageReceived { chat, text } => println!("{:?}: {}", chat.name, text),
+///     ChatEvent::MediaReceived { media, .. } => println!("Mídia: {}", media.url),
+///     ChatEvent::CampaignTriggered { campaign, .. } => println!("Campanha {}", campaign.id),
+///     ChatEvent::ChatCreated { chat, .. } => println!("Novo chat {:?}", chat.chat_id),
+///     ChatEvent::ButtonReplied { reply, .. } => println!("Opção {}", reply.id),
+///     ChatEvent::ReactionReceived { reaction, .. } => println!("Reação {}", reaction.emoji),
+///     ChatEvent::MessageStatusChanged { message_id, status, .. } => {
+///         println!("Mensagem {}: {:?}", message_id, status)
+///     }
+///     ChatEvent::Unknown(raw) => println!("Evento desconhecido: {}", raw),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+pub enum ChatEvent {
+    /// Mensagem de texto recebida do contato
+    MessageReceived { chat: ChatInfo, text: String },
+    /// Mensagem com mídia recebida do contato
+    MediaReceived { chat: ChatInfo, media: MediaInfo },
+    /// Campanha, funil ou evento legado disparado para o contato
+    CampaignTriggered {
+        chat: ChatInfo,
+        campaign: Campaign,
+        /// Tipo do evento no formato legado (`event_type`)
+        event_type: Option<String>,
+    },
+    /// Chat criado no ChatGuru
+    ChatCreated {
+        chat: ChatInfo,
+        /// Data de criação como enviada pelo ChatGuru
+        created_at: Option<String>,
+    },
+    /// Opção escolhida pelo contato em botões ou lista
+    ButtonReplied { chat: ChatInfo, reply: ButtonReply },
+    /// Reação (emoji) do contato a uma mensagem
+    ReactionReceived { chat: ChatInfo, reaction: Reaction },
+    /// Status de entrega/leitura de uma mensagem enviada pela API
+    MessageStatusChanged {
+        chat: ChatInfo,
+        /// ID da mensagem, como retornado no envio
+        message_id: String,
+        status: MessageStatus,
+    },
+    /// Payload que não corresponde a nenhum evento conhecido
+    Unknown(Value),
+}
+
+/// Situação de entrega de uma mensagem enviada
+///
+/// As variantes seguem a ordem de progresso da entrega (`Pending` <
+/// `Sent` < `Delivered` < `Read`); `Failed` é final.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageStatus {
+    /// Na fila de envio do ChatGuru
+    Pending,
+    /// Enviada ao WhatsApp
+    Sent,
+    /// Entregue no aparelho do contato
+    Delivered,
+    /// Lida (ou áudio reproduzido) pelo contato
+    Read,
+    /// O envio falhou
+    Failed,
+}
+
+impl MessageStatus {
+    /// Interpreta o status informado pelo ChatGuru (em inglês ou português)
+    pub fn parse(status: &str) -> Option<Self> {
+        let status = status.trim().to_lowercase();
+        let status = status
+            .strip_prefix("message.")
+            .or_else(|| status.strip_prefix("message_"))
+            .unwrap_or(&status);
+
+        match status {
+            "pending" | "queued" | "pendente" | "na_fila" => Some(MessageStatus::Pending),
+            "sent" | "server_ack" | "enviada" | "enviado" => Some(MessageStatus::Sent),
+            "delivered" | "delivery_ack" | "entregue" => Some(MessageStatus::Delivered),
+            "read" | "read_ack" | "played" | "lida" | "lido" => Some(MessageStatus::Read),
+            "failed" | "error" | "falha" | "erro" => Some(MessageStatus::Failed),
+            _ => None,
+        }
+    }
+
+    /// Indica se a mensagem chegou ao contato (`Delivered` ou `Read`)
+    pub fn is_delivered(&self) -> bool {
+        matches!(self, MessageStatus::Delivered | MessageStatus::Read)
+    }
+
+    /// Indica se o status não muda mais (`Read` ou `Failed`)
+    pub fn is_final(&self) -> bool {
+        matches!(self, MessageStatus::Read | MessageStatus::Failed)
+    }
+}
+
+/// Valores de `event_type` (formato legado) que indicam status de mensagem
+///
+/// O status vem em `data.status` ou no próprio `event_type` (ex: `message.read`).
+const MESSAGE_STATUS_EVENTS: &[&str] = &["message_status", "message.status", "message_ack"];
+
+/// Valores de `event_type` (formato legado) que indicam criação de chat
+const CHAT_CREATED_EVENTS: &[&str] = &["chat_created", "chat.created", "new_chat", "novo_chat"];
+
+impl ChatEvent {
+    /// Nome curto da variante, útil para logs e métricas
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ChatEvent::MessageReceived { .. } => "message_received",
+            ChatEvent::MediaReceived { .. } => "media_received",
+            ChatEvent::CampaignTriggered { .. } => "campaign_triggered",
+            ChatEvent::ChatCreated { .. } => "chat_created",
+            ChatEvent::ButtonReplied { .. } => "button_replied",
+            ChatEvent::ReactionReceived { .. } => "reaction_received",
+            ChatEvent::MessageStatusChanged { .. } => "message_status_changed",
+            ChatEvent::Unknown(_) => "unknown",
+        }
+    }
+
+    /// Chat de origem do evento (`None` para `Unknown`)
+    pub fn chat(&self) -> Option<&ChatInfo> {
+        match self {
+            ChatEvent::MessageReceived { chat, .. }
+            | ChatEvent::MediaReceived { chat, .. }
+            | ChatEvent::CampaignTriggered { chat, .. }
+            | ChatEvent::ChatCreated { chat, .. }
+            | ChatEvent::ButtonReplied { chat, .. }
+            | ChatEvent::ReactionReceived { chat, .. }
+            | ChatEvent::MessageStatusChanged { chat, .. } => Some(chat),
+            ChatEvent::Unknown(_) => None,
+        }
+    }
+
+    fn from_chatguru(p: &ChatGuruPayload, payload: &WebhookPayload) -> Option<Self> {
+        let chat = ChatInfo {
+            chat_id: p.chat_id.clone(),
+            phone: non_empty(&p.celular),
+            phone_id: p.phone_id.clone(),
+            name: non_empty(&p.nome),
+            email: non_empty(&p.email),
+        };
+
+        if let (Some(message_id), Some(status)) = (&p.message_id, &p.message_status) {
+            if let Some(status) = MessageStatus::parse(status) {
+                return Some(ChatEvent::MessageStatusChanged {
+                    chat,
+                    message_id: message_id.clone(),
+                    status,
+                });
+            }
+        }
+
+        if let Some(ref reaction) = p.reaction {
+            return Some(ChatEvent::ReactionReceived {
+                chat,
+                reaction: reaction.clone(),
+            });
+        }
+
+        if let Some(reply) = p.button_reply() {
+            return Some(ChatEvent::ButtonReplied { chat, reply });
+        }
+
+        if let Some(url) = payload.get_media_url() {
+            return Some(ChatEvent::MediaReceived {
+                chat,
+                media: MediaInfo {
+                    url,
+                    mime_type: payload.get_media_type(),
+                    tipo_mensagem: p.tipo_mensagem.clone(),
+                    caption: non_empty(&p.texto_mensagem),
+                },
+            });
+        }
+
+        if !p.texto_mensagem.is_empty() {
+            return Some(ChatEvent::MessageReceived {
+                chat,
+                text: p.texto_mensagem.clone(),
+            });
+        }
+
+        if let Some(campaign) = p.campaign().filter(|campaign| !campaign.id.is_empty()) {
+            return Some(ChatEvent::CampaignTriggered {
+                chat,
+                campaign,
+                event_type: None,
+            });
+        }
+
+        p.chat_created
+            .as_ref()
+            .map(|created_at| ChatEvent::ChatCreated {
+                chat,
+                created_at: Some(created_at.clone()),
+            })
+    }
+
+    fn from_event_type(p: &EventTypePayload) -> Self {
+        let chat = ChatInfo {
+            chat_id: Some(p.id.clone()),
+            phone: p.data.phone.clone(),
+            phone_id: None,
+            name: p.data.lead_name.clone(),
+            email: p.data.email.clone(),
+        };
+
+        let event_type = p.event_type.to_lowercase();
+        if CHAT_CREATED_EVENTS.contains(&event_type.as_str()) {
+            return ChatEvent::ChatCreated {
+                chat,
+                created_at: Some(p.timestamp.clone()),
+            };
+        }
+
+        let extra_str = |key: &str| {
+            p.data
+                .extra
+                .get(key)
+                .and_then(Value::as_str)
+                .map(String::from)
+        };
+
+        if let Some(message_id) = extra_str("message_id") {
+            let status = if MESSAGE_STATUS_EVENTS.contains(&event_type.as_str()) {
+                p.data.status.as_deref().and_then(MessageStatus::parse)
+            } else {
+                MessageStatus::parse(&event_type)
+            };
+            if let Some(status) = status {
+                return ChatEvent::MessageStatusChanged {
+                    chat,
+                    message_id,
+                    status,
+                };
+            }
+        }
+
+        ChatEvent::CampaignTriggered {
+            chat,
+            campaign: p.campaign().unwrap_or_default(),
+            event_type: Some(p.event_type.clone()),
+        }
+    }
+}
+
+impl TryFrom<WebhookPayload> for ChatEvent {
+    type Error = ChatGuruError;
+
+    fn try_from(payload: WebhookPayload) -> Result<Self> {
+        ChatEvent::try_from(&payload)
+    }
+}
+
+impl TryFrom<&WebhookPayload> for ChatEvent {
+    type Error = ChatGuruError;
+
+    fn try_from(payload: &WebhookPayload) -> Result<Self> {
+        let event = match payload {
+            WebhookPayload::ChatGuru(p) => ChatEvent::from_chatguru(p, payload),
+            WebhookPayload::EventType(p) => Some(ChatEvent::from_event_type(p)),
+            WebhookPayload::Generic(p) => {
+                let chat = ChatInfo {
+                    chat_id: None,
+                    phone: p.celular.clone(),
+                    phone_id: None,
+                    name: p.nome.clone(),
+                    email: p.email.clone(),
+                };
+                let extra_str = |key: &str| p.extra.get(key).and_then(Value::as_str);
+                let status = extra_str("message_id").zip(
+                    extra_str("message_status")
+                        .or_else(|| extra_str("status"))
+                        .and_then(MessageStatus::parse),
+                );
+
+                match status {
+                    Some((message_id, status)) => Some(ChatEvent::MessageStatusChanged {
+                        chat,
+                        message_id: message_id.to_string(),
+                        status,
+                    }),
+                    None => match (p.mensagem.as_ref(), p.campaign()) {
+                        (Some(text), _) => Some(ChatEvent::MessageReceived {
+                            chat,
+                            text: text.clone(),
+                        }),
+                        (None, Some(campaign)) => Some(ChatEvent::CampaignTriggered {
+                            chat,
+                            campaign,
+                            event_type: None,
+                        }),
+                        (None, None) => None,
+                    },
+                }
+            }
+        };
+
+        match event {
+            Some(event) => Ok(event),
+            None => Ok(ChatEvent::Unknown(serde_json::to_value(payload)?)),
+        }
+    }
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
@@ -0,0 +1,125 @@
+//! Localizações compartilhadas pelo WhatsApp
+//!
+//! O ChatGuru encaminha a localização como objeto (`latitude`/`longitude`,
+//! às vezes como texto) ou, em mensagens `location` sem o objeto, como
+//! `"lat,lng"` no texto da mensagem. [`GeoPoint`] aceita todas as formas.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+/// Coordenadas de uma localização compartilhada
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GeoPoint {
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Nome do local, quando o contato escolheu um estabelecimento
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Endereço do local, quando informado
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+}
+
+impl GeoPoint {
+    /// Cria um ponto sem nome nem endereço
+    ///
+    /// # Retorno
+    ///
+    /// `None` se as coordenadas estiverem fora dos limites válidos.
+    pub fn new(latitude: f64, longitude: f64) -> Option<Self> {
+        let valid = (-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude);
+        valid.then_some(Self {
+            latitude,
+            longitude,
+            name: None,
+            address: None,
+        })
+    }
+
+    /// Lê coordenadas no formato `"lat,lng"`
+    ///
+    /// Também aceita links do Google Maps com `q=lat,lng` ou `@lat,lng`,
+    /// como os gerados pelo WhatsApp Web.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust
+    /// use chatguru::types::GeoPoint;
+    ///
+    /// let point = GeoPoint::parse("-23.5505, -46.6333").unwrap();
+    /// assert_eq!(point.latitude, -23.5505);
+    ///
+    /// let link = GeoPoint::parse("https://maps.google.com/maps?q=-23.5505%2C-46.6333&z=17");
+    /// assert_eq!(link, Some(point));
+    /// ```
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().replace("%2C", ",").replace("%2c", ",");
+        let coordinates = match text.find("q=").or_else(|| text.find('@')) {
+            Some(start) if text.contains("://") => {
+                let rest = &text[start..];
+                let rest = rest.trim_start_matches("q=").trim_start_matches('@');
+                rest.split(['&', '/']).next().unwrap_or_default()
+            }
+            _ => text.as_str(),
+        };
+
+        let (latitude, longitude) = coordinates.split_once(',')?;
+        let longitude = longitude.split(',').next().unwrap_or_default();
+        Self::new(
+            latitude.trim().parse().ok()?,
+            longitude.trim().parse().ok()?,
+        )
+    }
+
+    /// Link do Google Maps para a localização
+    pub fn maps_url(&self) -> String {
+        format!(
+            "https://www.google.com/maps/search/?api=1&query={},{}",
+            self.latitude, self.longitude
+        )
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        let object = match value {
+            Value::String(text) => return Self::parse(text),
+            Value::Object(object) => object,
+            _ => return None,
+        };
+
+        let coordinate = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|name| object.get(*name))
+                .and_then(|value| match value {
+                    Value::Number(n) => n.as_f64(),
+                    Value::String(s) => s.trim().parse().ok(),
+                    _ => None,
+                })
+        };
+        let text = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|name| object.get(*name))
+                .and_then(Value::as_str)
+                .filter(|s| !s.trim().is_empty())
+                .map(|s| s.trim().to_string())
+        };
+
+        let mut point = Self::new(
+            coordinate(&["latitude", "lat"])?,
+            coordinate(&["longitude", "lng", "lon"])?,
+        )?;
+        point.name = text(&["name", "nome"]);
+        point.address = text(&["address", "endereco"]);
+        Some(point)
+    }
+}
+
+impl<'de> Deserialize<'de> for GeoPoint {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        Self::from_value(&value).ok_or_else(|| {
+            serde::de::Error::custom("invalid location: expected latitude/longitude")
+        })
+    }
+}
@@ -0,0 +1,164 @@
+//! Tipos de mensagem do ChatGuru e seus MIME types
+//!
+//! [`MediaKind`] concentra o mapeamento `tipo_mensagem → MIME type` usado na
+//! normalização dos payloads. Tipos que o ChatGuru passe a enviar (ou que
+//! uma conta use de forma diferente) podem ser mapeados com um
+//! [`MediaTypeMap`], configurado no
+//! [`WebhookExtractor`](crate::webhook::WebhookExtractor) ou no
+//! [`Pipeline`](crate::pipeline::Pipeline).
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// Tipo de mensagem informado no campo `tipo_mensagem`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MediaKind {
+    /// Mensagem de texto (`chat`, `text`), sem arquivo
+    Text,
+    /// Imagem (`image`)
+    Image,
+    /// Áudio gravado (`ptt`) ou enviado como arquivo (`audio`)
+    Audio,
+    /// Vídeo (`video`)
+    Video,
+    /// Documento (`document`); o MIME type vem da extensão da URL
+    Document,
+    /// Figurinha (`sticker`), sempre WebP no WhatsApp
+    Sticker,
+    /// Localização (`location`), sem arquivo
+    Location,
+    /// Contato (`contact`, `vcard`), enviado como vCard
+    Contact,
+    /// Resposta a botões ou lista (`button_reply`, `list_reply`), sem arquivo
+    ButtonReply,
+    /// Tipo não reconhecido, mantido como recebido
+    Other(String),
+}
+
+impl MediaKind {
+    /// Identifica o tipo a partir do valor de `tipo_mensagem`
+    ///
+    /// A comparação ignora maiúsculas e espaços nas pontas.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust
+    /// use chatguru::types::MediaKind;
+    ///
+    /// assert_eq!(MediaKind::from_tipo_mensagem("ptt"), MediaKind::Audio);
+    /// assert_eq!(MediaKind::from_tipo_mensagem("Sticker"), MediaKind::Sticker);
+    /// ```
+    pub fn from_tipo_mensagem(tipo: &str) -> Self {
+        let tipo = tipo.trim().to_lowercase();
+        match tipo.as_str() {
+            "chat" | "text" => MediaKind::Text,
+            "image" => MediaKind::Image,
+            "ptt" | "audio" => MediaKind::Audio,
+            "video" => MediaKind::Video,
+            "document" => MediaKind::Document,
+            "sticker" => MediaKind::Sticker,
+            "location" => MediaKind::Location,
+            "contact" | "vcard" => MediaKind::Contact,
+            "button_reply" | "buttons_response" | "list_reply" | "list_response" => {
+                MediaKind::ButtonReply
+            }
+            _ => MediaKind::Other(tipo),
+        }
+    }
+
+    /// MIME type correspondente ao tipo
+    ///
+    /// `url` é usada apenas para documentos, cujo tipo depende da extensão
+    /// do arquivo (padrão: PDF). Tipos não reconhecidos viram
+    /// `application/{tipo}`.
+    ///
+    /// # Retorno
+    ///
+    /// `None` para tipos sem arquivo (texto, localização e respostas a botões).
+    pub fn to_mime(&self, url: Option<&str>) -> Option<String> {
+        let mime = match self {
+            MediaKind::Text | MediaKind::Location | MediaKind::ButtonReply => return None,
+            MediaKind::Image => "image/jpeg",
+            MediaKind::Audio => "audio/ogg", // ptt = push-to-talk (OGG/Opus)
+            MediaKind::Video => "video/mp4",
+            MediaKind::Document => return Some(super::payload::document_mime(url)),
+            MediaKind::Sticker => "image/webp",
+            MediaKind::Contact => "text/vcard",
+            MediaKind::Other(tipo) => return Some(format!("application/{}", tipo)),
+        };
+        Some(mime.to_string())
+    }
+
+    /// Nome do tipo como enviado pelo ChatGuru
+    pub fn as_str(&self) -> &str {
+        match self {
+            MediaKind::Text => "chat",
+            MediaKind::Image => "image",
+            MediaKind::Audio => "ptt",
+            MediaKind::Video => "video",
+            MediaKind::Document => "document",
+            MediaKind::Sticker => "sticker",
+            MediaKind::Location => "location",
+            MediaKind::Contact => "contact",
+            MediaKind::ButtonReply => "button_reply",
+            MediaKind::Other(tipo) => tipo,
+        }
+    }
+}
+
+impl fmt::Display for MediaKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Mapeamento `tipo_mensagem → MIME type` com entradas personalizadas
+///
+/// As entradas registradas têm prioridade; os demais tipos seguem
+/// [`MediaKind::to_mime`].
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::types::MediaTypeMap;
+///
+/// let media_types = MediaTypeMap::new()
+///     .register("sticker", "image/png")
+///     .register("gif", "video/mp4");
+///
+/// assert_eq!(media_types.mime_for("gif", None).as_deref(), Some("video/mp4"));
+/// assert_eq!(media_types.mime_for("ptt", None).as_deref(), Some("audio/ogg"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MediaTypeMap {
+    custom: HashMap<String, String>,
+}
+
+impl MediaTypeMap {
+    /// Cria um mapeamento sem entradas personalizadas
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registra o MIME type de um `tipo_mensagem` (substitui o padrão)
+    pub fn register(mut self, tipo: impl AsRef<str>, mime: impl Into<String>) -> Self {
+        self.custom
+            .insert(tipo.as_ref().trim().to_lowercase(), mime.into());
+        self
+    }
+
+    /// MIME type de um `tipo_mensagem`
+    ///
+    /// `url` é usada para deduzir o tipo dos documentos pela extensão.
+    pub fn mime_for(&self, tipo: &str, url: Option<&str>) -> Option<String> {
+        match self.custom.get(&tipo.trim().to_lowercase()) {
+            Some(mime) => Some(mime.clone()),
+            None => MediaKind::from_tipo_mensagem(tipo).to_mime(url),
+        }
+    }
+
+    /// Indica se há entradas personalizadas
+    pub fn is_empty(&self) -> bool {
+        self.custom.is_empty()
+    }
+}
@@ -1,7 +1,43 @@
+pub mod borrowed;
+pub mod button_reply;
+pub mod chat_link;
+mod classify;
+pub mod custom_fields;
+pub mod datetime;
+mod de;
+pub mod envelope;
+pub mod event;
+pub mod location;
+pub mod media_kind;
+pub mod parse;
 pub mod payload;
+pub mod payload_builder;
+pub mod phone;
+pub mod reaction;
+pub mod redact;
+pub mod schema;
+pub mod tags;
+pub mod vcard;
 pub mod webhook;
 
 // Re-export dos tipos principais para conveniência
+pub use borrowed::{
+    ChatGuruPayloadRef, EventDataRef, EventTypePayloadRef, GenericPayloadRef, WebhookPayloadRef,
+};
+pub use button_reply::{ButtonReply, ReplyKind};
+pub use chat_link::ChatLink;
+pub use datetime::{parse_timestamp, parse_timestamp_in, TimestampParseError};
+pub use envelope::{EventEnvelope, ENVELOPE_VERSION};
+pub use event::{Agent, Campaign, ChatEvent, ChatInfo, MediaInfo, MessageStatus};
+pub use location::GeoPoint;
+pub use media_kind::{MediaKind, MediaTypeMap};
+pub use parse::{FormatMismatch, ParsedWebhook, WebhookParseError};
 pub use payload::{BotContext, ChatGuruPayload, EventData, EventTypePayload, GenericPayload};
+pub use payload_builder::ChatGuruPayloadBuilder;
+pub use phone::PhoneNumber;
+pub use reaction::Reaction;
+pub use schema::{MigratedPayload, SchemaVersion};
+pub use tags::TagDiff;
+pub use vcard::VCard;
 
 pub use webhook::WebhookPayload;
@@ -0,0 +1,338 @@
+//! Desserialização de webhooks com diagnóstico
+//!
+//! Quando nenhuma variante do enum `untagged` [`WebhookPayload`] aceita o
+//! JSON, o serde informa apenas "data did not match any variant". Além
+//! disso, como todos os campos de [`ChatGuruPayload`] têm valor padrão,
+//! qualquer objeto é aceito como `ChatGuru`. [`WebhookPayload::parse`] tenta
+//! cada formato explicitamente e registra por que cada um foi descartado.
+//! Quando os campos indicam o formato (veja [`WebhookPayload::classify`]),
+//! ele é desserializado direto; os demais só são tentados se ele falhar.
+
+use super::classify::classify_keys;
+use super::payload::{ChatGuruPayload, EventTypePayload, GenericPayload};
+use super::schema::SchemaVersion;
+use super::webhook::WebhookPayload;
+use serde::de::{Deserialize, DeserializeOwned};
+use serde_json::Value;
+use std::fmt;
+use thiserror::Error;
+
+/// Campos (e aliases) específicos do formato ChatGuru; o objeto precisa ter ao menos um
+///
+/// Campos comuns a formulários genéricos (`nome`, `email`, `celular`,
+/// `mensagem` e os aliases `message`/`text`) ficam de fora: sozinhos, eles
+/// indicam um [`GenericPayload`].
+pub(super) const CHATGURU_FIELDS: &[&str] = &[
+    "campanha_id",
+    "campanha_nome",
+    "origem",
+    "tags",
+    "texto_mensagem",
+    "media_url",
+    "media_type",
+    "tipo_mensagem",
+    "url_arquivo",
+    "url_midia",
+    "location",
+    "localizacao",
+    "contact_card",
+    "vcard",
+    "button_reply",
+    "resposta_botao",
+    "quoted_message_id",
+    "id_mensagem_citada",
+    "reply_to_message_id",
+    "reaction",
+    "reacao",
+    "campos_personalizados",
+    "bot_context",
+    "responsavel_nome",
+    "responsavel_email",
+    "link_chat",
+    "phone_id",
+    "chat_id",
+    "chat_created",
+    "message_status",
+    "status_mensagem",
+];
+
+/// Motivo pelo qual um formato de webhook foi descartado
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatMismatch {
+    /// Formato tentado
+    pub schema: SchemaVersion,
+    /// Campo de primeiro nível que causou a falha, quando identificado
+    pub field: Option<String>,
+    /// Descrição da falha
+    pub reason: String,
+}
+
+impl fmt::Display for FormatMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.field {
+            Some(ref field) => write!(f, "{:?}: field `{}`: {}", self.schema, field, self.reason),
+            None => write!(f, "{:?}: {}", self.schema, self.reason),
+        }
+    }
+}
+
+/// Erro de [`WebhookPayload::parse`]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum WebhookParseError {
+    /// Corpo não é JSON válido
+    #[error("Invalid webhook JSON: {0}")]
+    InvalidJson(String),
+
+    /// JSON válido, mas não é um objeto
+    #[error("Webhook payload must be a JSON object, got {0}")]
+    NotAnObject(&'static str),
+
+    /// Nenhum formato aceitou o objeto
+    #[error("Webhook payload did not match any format: {}", join_mismatches(.0))]
+    NoMatchingFormat(Vec<FormatMismatch>),
+}
+
+impl WebhookParseError {
+    /// Motivos de descarte de cada formato (vazio para JSON inválido)
+    pub fn mismatches(&self) -> &[FormatMismatch] {
+        match self {
+            WebhookParseError::NoMatchingFormat(mismatches) => mismatches,
+            _ => &[],
+        }
+    }
+}
+
+fn join_mismatches(mismatches: &[FormatMismatch]) -> String {
+    mismatches
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Resultado de [`WebhookPayload::parse_report`] e [`WebhookPayload::parse_with_raw`]
+#[derive(Debug, Clone)]
+pub struct ParsedWebhook {
+    /// Payload desserializado
+    pub payload: WebhookPayload,
+    /// JSON original, como recebido (antes de normalizações)
+    pub raw: Value,
+    /// Formatos tentados antes do aceito, com o motivo do descarte
+    pub rejected: Vec<FormatMismatch>,
+}
+
+impl ParsedWebhook {
+    /// Consome o resultado e retorna o payload
+    pub fn into_payload(self) -> WebhookPayload {
+        self.payload
+    }
+}
+
+impl WebhookPayload {
+    /// Desserializa um webhook tentando cada formato, com diagnóstico em caso de falha
+    ///
+    /// Os formatos são tentados do mais recente ao mais antigo. Diferente de
+    /// `serde_json::from_str::<WebhookPayload>`, o formato `ChatGuru` só é
+    /// aceito se o objeto tiver ao menos um campo desse formato; assim
+    /// eventos legados com `event_type` resultam na variante `EventType`.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// match WebhookPayload::parse(&body) {
+    ///     Ok(payload) => handle(payload).await,
+    ///     Err(e) => {
+    ///         for mismatch in e.mismatches() {
+    ///             tracing::warn!("Webhook rejected as {:?}: {}", mismatch.schema, mismatch.reason);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn parse(body: &str) -> Result<Self, WebhookParseError> {
+        Self::parse_slice(body.as_bytes())
+    }
+
+    /// Como [`parse`](Self::parse), a partir dos bytes do corpo
+    ///
+    /// Quando [`classify`](Self::classify) indica `ChatGuru` ou `EventType`, o
+    /// corpo é desserializado direto nesse formato; os demais formatos só
+    /// são tentados se ele falhar.
+    pub fn parse_slice(body: &[u8]) -> Result<Self, WebhookParseError> {
+        // `from_str` valida o UTF-8 uma única vez, e não a cada texto do corpo
+        let fast = std::str::from_utf8(body)
+            .ok()
+            .and_then(|text| match Self::classify(body)? {
+                SchemaVersion::ChatGuru => serde_json::from_str(text)
+                    .ok()
+                    .map(WebhookPayload::ChatGuru),
+                SchemaVersion::EventType => serde_json::from_str(text)
+                    .ok()
+                    .map(WebhookPayload::EventType),
+                SchemaVersion::Generic => None,
+            });
+        if let Some(payload) = fast {
+            return Ok(payload);
+        }
+
+        let parsed = Self::parse_report(body)?;
+        if !parsed.rejected.is_empty() {
+            tracing::debug!(
+                "Webhook parsed as {:?} after rejecting: {}",
+                parsed.payload.schema_version(),
+                join_mismatches(&parsed.rejected)
+            );
+        }
+        Ok(parsed.payload)
+    }
+
+    /// Como [`parse`](Self::parse), mantendo também o JSON original
+    ///
+    /// Permite persistir o webhook exatamente como recebido (auditoria,
+    /// reprocessamento) sem desserializar o corpo duas vezes.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let parsed = WebhookPayload::parse_with_raw(&body)?;
+    /// audit_log.insert(&parsed.raw).await?;
+    /// handle(parsed.payload).await;
+    /// ```
+    pub fn parse_with_raw(body: &str) -> Result<ParsedWebhook, WebhookParseError> {
+        Self::parse_report(body.as_bytes())
+    }
+
+    /// Como [`parse_slice`](Self::parse_slice), informando também os formatos
+    /// descartados antes do aceito (ex: por que o webhook caiu em `Generic`)
+    pub fn parse_report(body: &[u8]) -> Result<ParsedWebhook, WebhookParseError> {
+        let value: Value = serde_json::from_slice(body)
+            .map_err(|e| WebhookParseError::InvalidJson(e.to_string()))?;
+
+        let Value::Object(ref fields) = value else {
+            return Err(WebhookParseError::NotAnObject(json_type(&value)));
+        };
+        let schema = classify_keys(fields.keys().map(String::as_str));
+        let has_chatguru_fields = schema == SchemaVersion::ChatGuru;
+
+        // Formato indicado pelos campos: desserializa direto do `Value`, sem
+        // gerar o JSON formatado usado apenas no diagnóstico
+        let fast = match schema {
+            SchemaVersion::ChatGuru => ChatGuruPayload::deserialize(&value)
+                .ok()
+                .map(WebhookPayload::ChatGuru),
+            SchemaVersion::EventType => EventTypePayload::deserialize(&value)
+                .ok()
+                .map(WebhookPayload::EventType),
+            SchemaVersion::Generic => None,
+        };
+        if let Some(payload) = fast {
+            let rejected = match schema {
+                SchemaVersion::ChatGuru => Vec::new(),
+                _ => vec![no_chatguru_fields()],
+            };
+            return Ok(ParsedWebhook {
+                payload,
+                raw: value,
+                rejected,
+            });
+        }
+
+        let json = serde_json::to_string_pretty(&value)
+            .map_err(|e| WebhookParseError::InvalidJson(e.to_string()))?;
+        let mut rejected = Vec::new();
+
+        let accepted = |payload, rejected| ParsedWebhook {
+            payload,
+            raw: value,
+            rejected,
+        };
+
+        if has_chatguru_fields {
+            match from_pretty_json::<ChatGuruPayload>(&json) {
+                Ok(p) => return Ok(accepted(WebhookPayload::ChatGuru(p), rejected)),
+                Err((field, reason)) => rejected.push(FormatMismatch {
+                    schema: SchemaVersion::ChatGuru,
+                    field,
+                    reason,
+                }),
+            }
+        } else {
+            rejected.push(no_chatguru_fields());
+        }
+
+        match from_pretty_json::<EventTypePayload>(&json) {
+            Ok(p) => return Ok(accepted(WebhookPayload::EventType(p), rejected)),
+            Err((field, reason)) => rejected.push(FormatMismatch {
+                schema: SchemaVersion::EventType,
+                field,
+                reason,
+            }),
+        }
+
+        match from_pretty_json::<GenericPayload>(&json) {
+            Ok(p) => Ok(accepted(WebhookPayload::Generic(p), rejected)),
+            Err((field, reason)) => {
+                rejected.push(FormatMismatch {
+                    schema: SchemaVersion::Generic,
+                    field,
+                    reason,
+                });
+                Err(WebhookParseError::NoMatchingFormat(rejected))
+            }
+        }
+    }
+}
+
+fn no_chatguru_fields() -> FormatMismatch {
+    FormatMismatch {
+        schema: SchemaVersion::ChatGuru,
+        field: None,
+        reason: "no ChatGuru fields present".to_string(),
+    }
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Desserializa JSON gerado por `to_string_pretty`, identificando o campo de
+/// primeiro nível da falha
+///
+/// Retorna o campo (quando identificado) e a mensagem do serde sem a posição.
+pub(crate) fn from_pretty_json<T: DeserializeOwned>(
+    json: &str,
+) -> Result<T, (Option<String>, String)> {
+    serde_json::from_str(json).map_err(|e| {
+        let message = e.to_string();
+        let message = message
+            .split(" at line ")
+            .next()
+            .unwrap_or(&message)
+            .to_string();
+
+        let field = match message.strip_prefix("missing field `") {
+            Some(rest) => rest.split('`').next().map(String::from),
+            None => field_at_line(json, e.line()),
+        };
+        (field, message)
+    })
+}
+
+/// Nome do campo de primeiro nível que contém a linha `line` (1-based) do JSON
+/// gerado por `to_string_pretty`
+fn field_at_line(json: &str, line: usize) -> Option<String> {
+    json.lines()
+        .take(line)
+        .filter(|l| l.starts_with("  \"") && !l.starts_with("   "))
+        .last()
+        .and_then(|l| {
+            let key = l.trim_start().split("\": ").next()?;
+            serde_json::from_str::<String>(&format!("{}\"", key)).ok()
+        })
+}
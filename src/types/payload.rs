@@ -2,13 +2,21 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 
+use super::button_reply::{ButtonReply, ReplyKind};
+use super::chat_link::ChatLink;
+use super::de;
+use super::location::GeoPoint;
+use super::media_kind::{MediaKind, MediaTypeMap};
+use super::reaction::Reaction;
+use super::vcard::VCard;
+
 /// Payload do ChatGuru atual
 ///
 /// Estrutura completa do payload recebido nos webhooks do ChatGuru,
 /// incluindo campos personalizados, mídia anexada e contexto do bot.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct ChatGuruPayload {
-    #[serde(default)]
+    #[serde(default, deserialize_with = "de::string_or_number")]
     pub campanha_id: String,
     #[serde(default)]
     pub campanha_nome: String,
@@ -18,7 +26,7 @@ pub struct ChatGuruPayload {
     pub email: String,
     #[serde(default)]
     pub nome: String,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "de::string_list")]
     pub tags: Vec<String>,
     #[serde(default, alias = "mensagem", alias = "message", alias = "text")]
     pub texto_mensagem: String,
@@ -35,6 +43,27 @@ pub struct ChatGuruPayload {
     #[serde(default, alias = "url_midia")]
     pub url_arquivo: Option<String>, // URL do arquivo de mídia
 
+    // Localização e contato compartilhados (ignorados quando inválidos)
+    #[serde(default, alias = "localizacao", deserialize_with = "lenient")]
+    pub location: Option<GeoPoint>,
+    #[serde(default, alias = "vcard", deserialize_with = "lenient")]
+    pub contact_card: Option<VCard>,
+
+    // Resposta a mensagens interativas (botões e listas)
+    #[serde(default, alias = "resposta_botao", deserialize_with = "lenient")]
+    pub button_reply: Option<ButtonReply>,
+
+    // Mensagem citada (resposta) e reação a uma mensagem
+    #[serde(
+        default,
+        alias = "id_mensagem_citada",
+        alias = "reply_to_message_id",
+        deserialize_with = "de::opt_string_or_number"
+    )]
+    pub quoted_message_id: Option<String>,
+    #[serde(default, alias = "reacao", deserialize_with = "lenient")]
+    pub reaction: Option<Reaction>,
+
     #[serde(default)]
     pub campos_personalizados: HashMap<String, Value>,
     #[serde(default)]
@@ -45,14 +74,24 @@ pub struct ChatGuruPayload {
     pub responsavel_email: Option<String>,
     #[serde(default)]
     pub link_chat: String,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "de::string_or_number")]
     pub celular: String,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "de::opt_string_or_number")]
     pub phone_id: Option<String>,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "de::opt_string_or_number")]
     pub chat_id: Option<String>,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "de::opt_string_or_number")]
     pub chat_created: Option<String>,
+
+    // Webhooks de status de entrega (mensagem enviada pela API)
+    #[serde(
+        default,
+        alias = "id_mensagem",
+        deserialize_with = "de::opt_string_or_number"
+    )]
+    pub message_id: Option<String>, // ID retornado no envio da mensagem
+    #[serde(default, alias = "status_mensagem")]
+    pub message_status: Option<String>, // "sent", "delivered", "read", "failed"
 }
 
 impl ChatGuruPayload {
@@ -70,6 +109,12 @@ impl ChatGuruPayload {
     /// assert!(payload.media_type.is_some());
     /// ```
     pub fn normalize_media_fields(&mut self) {
+        self.normalize_media_fields_with(&MediaTypeMap::default());
+    }
+
+    /// Como [`normalize_media_fields`](Self::normalize_media_fields), com
+    /// mapeamentos `tipo_mensagem → MIME type` personalizados
+    pub fn normalize_media_fields_with(&mut self, media_types: &MediaTypeMap) {
         // Se já tem media_url e media_type, não faz nada
         if self.media_url.is_some() && self.media_type.is_some() {
             return;
@@ -83,21 +128,87 @@ impl ChatGuruPayload {
         // Mapear tipo_mensagem → media_type
         if let Some(ref tipo) = self.tipo_mensagem {
             if self.media_type.is_none() {
-                self.media_type = Some(match tipo.as_str() {
-                    "image" => "image/jpeg".to_string(),
-                    "ptt" => "audio/ogg".to_string(), // ptt = push-to-talk (áudio)
-                    "audio" => "audio/ogg".to_string(),
-                    "video" => "video/mp4".to_string(),
-                    "document" => "application/pdf".to_string(),
-                    other => format!("application/{}", other),
-                });
+                self.media_type = media_types.mime_for(tipo, self.media_url.as_deref());
             }
         }
     }
+
+    /// Tipo da mensagem, a partir de `tipo_mensagem`
+    pub fn media_kind(&self) -> Option<MediaKind> {
+        self.tipo_mensagem
+            .as_deref()
+            .map(MediaKind::from_tipo_mensagem)
+    }
+
+    /// Localização compartilhada
+    ///
+    /// Usa o campo `location`; em mensagens `location` sem o campo, lê as
+    /// coordenadas do texto da mensagem.
+    pub fn location(&self) -> Option<GeoPoint> {
+        self.location.clone().or_else(|| match self.media_kind() {
+            Some(MediaKind::Location) => GeoPoint::parse(&self.texto_mensagem),
+            _ => None,
+        })
+    }
+
+    /// Cartão de contato compartilhado
+    ///
+    /// Usa o campo `vcard`; caso contrário, lê o vCard do texto da mensagem.
+    pub fn contact_card(&self) -> Option<VCard> {
+        self.contact_card
+            .clone()
+            .or_else(|| VCard::parse(&self.texto_mensagem))
+    }
+
+    /// Link do chat no painel, lido de `link_chat`
+    pub fn chat_link(&self) -> Option<ChatLink> {
+        ChatLink::parse(&self.link_chat)
+    }
+
+    /// Opção escolhida em uma mensagem interativa
+    ///
+    /// Usa o campo `resposta_botao`; o título, quando não vem no campo, é o
+    /// texto da mensagem.
+    pub fn button_reply(&self) -> Option<ButtonReply> {
+        let mut reply = self.button_reply.clone()?;
+        if reply.title.is_empty() {
+            reply.title = self.texto_mensagem.trim().to_string();
+        }
+        if let Some(tipo) = self.tipo_mensagem.as_deref() {
+            if tipo.trim().to_lowercase().starts_with("list_") {
+                reply.kind = ReplyKind::ListRow;
+            }
+        }
+        Some(reply)
+    }
+}
+
+/// Desserializa campos opcionais descartando valores inválidos
+///
+/// Uma localização ou vCard malformado não deve invalidar o webhook inteiro.
+pub(super) fn lenient<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    let value = Value::deserialize(deserializer)?;
+    Ok(serde_json::from_value(value).ok())
+}
+
+/// MIME type de um documento pela extensão da URL (padrão: PDF)
+///
+/// O tipo real só é conhecido após o download; veja
+/// [`DocumentInfo`](crate::media::DocumentInfo).
+pub(crate) fn document_mime(url: Option<&str>) -> String {
+    url.and_then(|url| url.split(['?', '#']).next())
+        .and_then(|path| path.rsplit('/').next())
+        .and_then(crate::media::mime_from_extension)
+        .unwrap_or("application/pdf")
+        .to_string()
 }
 
 /// Contexto do bot ChatGuru
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct BotContext {
     #[serde(rename = "ChatGuru")]
     pub chat_guru: Option<bool>,
@@ -108,8 +219,10 @@ pub struct BotContext {
 /// Estrutura usada em versões antigas do webhook, mantida para compatibilidade.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct EventTypePayload {
+    #[serde(deserialize_with = "de::string_or_number")]
     pub id: String,
     pub event_type: String,
+    #[serde(deserialize_with = "de::string_or_number")]
     pub timestamp: String,
     pub data: EventData,
 }
@@ -118,6 +231,7 @@ pub struct EventTypePayload {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct EventData {
     pub lead_name: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_string_or_number")]
     pub phone: Option<String>,
     pub email: Option<String>,
     pub project_name: Option<String>,
@@ -139,6 +253,7 @@ pub struct EventData {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct GenericPayload {
     pub nome: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_string_or_number")]
     pub celular: Option<String>,
     pub email: Option<String>,
     pub mensagem: Option<String>,
@@ -0,0 +1,213 @@
+//! Construção de [`ChatGuruPayload`] campo a campo
+//!
+//! Útil em testes de integração e simuladores de webhook, em que montar a
+//! struct literal exige preencher todos os campos.
+
+use super::button_reply::ButtonReply;
+use super::location::GeoPoint;
+use super::payload::{BotContext, ChatGuruPayload};
+use super::reaction::Reaction;
+use super::vcard::VCard;
+use super::webhook::WebhookPayload;
+use serde_json::Value;
+
+/// Builder de [`ChatGuruPayload`], criado por [`ChatGuruPayload::builder`]
+///
+/// Parte de um payload com origem `whatsapp`; os demais campos ficam vazios
+/// até serem definidos.
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::types::ChatGuruPayload;
+///
+/// let payload = ChatGuruPayload::builder()
+///     .campanha("1001", "Atendimento")
+///     .nome("Maria")
+///     .celular("5511988887777")
+///     .chat_id("chat_123")
+///     .with_media("image", "https://cdn.exemplo.com/foto.jpg")
+///     .with_custom_field("Tarefa", "Orçamento")
+///     .build();
+///
+/// assert_eq!(payload.tipo_mensagem.as_deref(), Some("image"));
+/// assert_eq!(payload.get_custom_str("Tarefa").as_deref(), Some("Orçamento"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChatGuruPayloadBuilder {
+    payload: ChatGuruPayload,
+}
+
+impl Default for ChatGuruPayloadBuilder {
+    fn default() -> Self {
+        Self {
+            payload: ChatGuruPayload {
+                origem: "whatsapp".to_string(),
+                ..ChatGuruPayload::default()
+            },
+        }
+    }
+}
+
+impl ChatGuruPayload {
+    /// Cria um builder para montar o payload campo a campo
+    pub fn builder() -> ChatGuruPayloadBuilder {
+        ChatGuruPayloadBuilder::default()
+    }
+}
+
+impl ChatGuruPayloadBuilder {
+    /// Define a campanha
+    pub fn campanha(mut self, id: impl Into<String>, nome: impl Into<String>) -> Self {
+        self.payload.campanha_id = id.into();
+        self.payload.campanha_nome = nome.into();
+        self
+    }
+
+    /// Define a origem do contato (padrão: `whatsapp`)
+    pub fn origem(mut self, origem: impl Into<String>) -> Self {
+        self.payload.origem = origem.into();
+        self
+    }
+
+    /// Define o nome do contato
+    pub fn nome(mut self, nome: impl Into<String>) -> Self {
+        self.payload.nome = nome.into();
+        self
+    }
+
+    /// Define o email do contato
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.payload.email = email.into();
+        self
+    }
+
+    /// Define o celular do contato
+    pub fn celular(mut self, celular: impl Into<String>) -> Self {
+        self.payload.celular = celular.into();
+        self
+    }
+
+    /// Define o texto da mensagem
+    pub fn message(mut self, text: impl Into<String>) -> Self {
+        self.payload.texto_mensagem = text.into();
+        self
+    }
+
+    /// Adiciona uma tag
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.payload.tags.push(tag.into());
+        self
+    }
+
+    /// Anexa uma mídia (`tipo_mensagem` + `url_arquivo`)
+    ///
+    /// `tipo` usa os valores do ChatGuru: `image`, `ptt`, `audio`, `video`,
+    /// `document`, `sticker`...
+    pub fn with_media(mut self, tipo: impl Into<String>, url: impl Into<String>) -> Self {
+        self.payload.tipo_mensagem = Some(tipo.into());
+        self.payload.url_arquivo = Some(url.into());
+        self
+    }
+
+    /// Define um campo personalizado
+    pub fn with_custom_field(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.payload
+            .campos_personalizados
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// Define a localização compartilhada (ignorada se as coordenadas forem inválidas)
+    pub fn location(mut self, latitude: f64, longitude: f64) -> Self {
+        self.payload.tipo_mensagem = Some("location".to_string());
+        self.payload.location = GeoPoint::new(latitude, longitude);
+        self
+    }
+
+    /// Define o cartão de contato compartilhado
+    pub fn contact_card(mut self, card: VCard) -> Self {
+        self.payload.tipo_mensagem = Some("contact".to_string());
+        self.payload.contact_card = Some(card);
+        self
+    }
+
+    /// Define a opção escolhida em uma mensagem interativa
+    pub fn button_reply(mut self, reply: ButtonReply) -> Self {
+        self.payload.tipo_mensagem = Some("button_reply".to_string());
+        self.payload.button_reply = Some(reply);
+        self
+    }
+
+    /// Define a reação do contato
+    pub fn reaction(mut self, reaction: Reaction) -> Self {
+        self.payload.reaction = Some(reaction);
+        self
+    }
+
+    /// Marca a mensagem como resposta (citação) à mensagem informada
+    pub fn quoting(mut self, message_id: impl Into<String>) -> Self {
+        self.payload.quoted_message_id = Some(message_id.into());
+        self
+    }
+
+    /// Define o status de entrega de uma mensagem enviada pela API
+    pub fn message_status(
+        mut self,
+        message_id: impl Into<String>,
+        status: impl Into<String>,
+    ) -> Self {
+        self.payload.message_id = Some(message_id.into());
+        self.payload.message_status = Some(status.into());
+        self
+    }
+
+    /// Define o atendente responsável
+    pub fn responsavel(mut self, nome: impl Into<String>, email: impl Into<String>) -> Self {
+        self.payload.responsavel_nome = Some(nome.into());
+        self.payload.responsavel_email = Some(email.into());
+        self
+    }
+
+    /// Indica se o chat está sendo atendido pelo bot do ChatGuru
+    pub fn bot_active(mut self, active: bool) -> Self {
+        self.payload.bot_context = Some(BotContext {
+            chat_guru: Some(active),
+        });
+        self
+    }
+
+    /// Define o link do chat no painel
+    pub fn link_chat(mut self, link: impl Into<String>) -> Self {
+        self.payload.link_chat = link.into();
+        self
+    }
+
+    /// Define o chat_id
+    pub fn chat_id(mut self, chat_id: impl Into<String>) -> Self {
+        self.payload.chat_id = Some(chat_id.into());
+        self
+    }
+
+    /// Define o phone_id da linha que recebeu a mensagem
+    pub fn phone_id(mut self, phone_id: impl Into<String>) -> Self {
+        self.payload.phone_id = Some(phone_id.into());
+        self
+    }
+
+    /// Define a data de criação do chat (usada na deduplicação)
+    pub fn chat_created(mut self, chat_created: impl Into<String>) -> Self {
+        self.payload.chat_created = Some(chat_created.into());
+        self
+    }
+
+    /// Retorna o payload construído
+    pub fn build(self) -> ChatGuruPayload {
+        self.payload
+    }
+
+    /// Retorna o payload como [`WebhookPayload`]
+    pub fn into_webhook(self) -> WebhookPayload {
+        WebhookPayload::ChatGuru(self.payload)
+    }
+}
@@ -0,0 +1,347 @@
+use crate::error::{ChatGuruError, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Código de país do Brasil
+const BRAZIL_COUNTRY_CODE: &str = "55";
+
+/// Número de telefone normalizado
+///
+/// Guarda apenas dígitos, já com código do país (formato usado pelo
+/// ChatGuru em `chat_number`). A conversão a partir de texto aceita as
+/// formas mais comuns de digitação:
+///
+/// - `+55 (11) 99999-9999`, `5511999999999`, `005511999999999`
+/// - Números nacionais brasileiros na forma explícita, com o DDD entre
+///   parênteses ou o prefixo `0`: `(11) 99999-9999`, `011 99999-9999`
+///   (recebem `55` automaticamente)
+/// - IDs do WhatsApp: `5511999999999@c.us`
+///
+/// Outros números só com dígitos são tratados como já tendo o código do país
+/// (`12125551234` é um número dos EUA, `6591234567` de Singapura). Para
+/// aceitar DDD + número sem formatação como número brasileiro, use
+/// [`PhoneNumber::parse_brazilian`].
+///
+/// `From<&str>` nunca falha para permitir `impl Into<PhoneNumber>` nas
+/// assinaturas do cliente; use [`PhoneNumber::parse`] ou
+/// [`PhoneNumber::validate`] para rejeitar números inválidos.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::types::PhoneNumber;
+///
+/// let phone = PhoneNumber::parse("(11) 99999-9999")?;
+/// assert_eq!(phone.to_e164(), "+5511999999999");
+/// assert_eq!(phone.brazil_ninth_digit_variants().len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PhoneNumber {
+    digits: String,
+}
+
+impl PhoneNumber {
+    /// Converte e valida um número de telefone
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se o número não tiver um tamanho válido
+    /// (E.164: até 15 dígitos) ou, para números brasileiros, se o DDD ou a
+    /// quantidade de dígitos forem inválidos.
+    pub fn parse(input: &str) -> Result<Self> {
+        let phone = Self::normalize(input);
+        phone.validate()?;
+        Ok(phone)
+    }
+
+    /// Converte e valida um número, tratando DDD + número como brasileiro
+    ///
+    /// Além das formas aceitas por [`PhoneNumber::parse`], números de 10 ou
+    /// 11 dígitos sem `+` nem `00` (ex: `11999999999`) recebem `55`.
+    ///
+    /// # Erros
+    ///
+    /// Os mesmos de [`PhoneNumber::parse`].
+    pub fn parse_brazilian(input: &str) -> Result<Self> {
+        let phone = Self::normalize_with(input, true);
+        phone.validate()?;
+        Ok(phone)
+    }
+
+    /// Normaliza o texto sem validar
+    fn normalize(input: &str) -> Self {
+        Self::normalize_with(input, false)
+    }
+
+    /// Normaliza o texto; com `assume_brazil`, números sem código do país
+    /// são tratados como nacionais brasileiros
+    fn normalize_with(input: &str, assume_brazil: bool) -> Self {
+        // IDs do WhatsApp: descartar o sufixo @c.us / @s.whatsapp.net
+        let number = input.split('@').next().unwrap_or_default().trim();
+        let explicit_plus = number.starts_with('+');
+
+        let mut digits: String = number.chars().filter(|c| c.is_ascii_digit()).collect();
+
+        if let Some(rest) = digits.strip_prefix("00") {
+            // Prefixo internacional
+            digits = rest.to_string();
+        } else if !explicit_plus {
+            let mut national = assume_brazil || has_area_code_parentheses(number);
+            if let Some(rest) = digits.strip_prefix('0') {
+                // Prefixo de longa distância nacional (ex: 011 99999-9999)
+                digits = rest.to_string();
+                national = true;
+            }
+            if national && (digits.len() == 10 || digits.len() == 11) {
+                // Número nacional brasileiro (DDD + número)
+                digits = format!("{}{}", BRAZIL_COUNTRY_CODE, digits);
+            }
+        }
+
+        Self { digits }
+    }
+
+    /// Valida tamanho e, para números brasileiros, DDD e nono dígito
+    pub fn validate(&self) -> Result<()> {
+        let len = self.digits.len();
+        if len == 0 {
+            return Err(ChatGuruError::ValidationError(
+                "Phone number is empty".to_string(),
+            ));
+        }
+        if !(8..=15).contains(&len) {
+            return Err(ChatGuruError::ValidationError(format!(
+                "Invalid phone number length ({} digits): {}",
+                len, self.digits
+            )));
+        }
+
+        if self.is_brazilian() {
+            if len != 12 && len != 13 {
+                return Err(ChatGuruError::ValidationError(format!(
+                    "Invalid Brazilian phone number (expected 12 or 13 digits with country code): {}",
+                    self.digits
+                )));
+            }
+
+            let ddd = &self.digits[2..4];
+            if ddd.starts_with('0') || ddd.ends_with('0') {
+                return Err(ChatGuruError::ValidationError(format!(
+                    "Invalid Brazilian area code (DDD) {}: {}",
+                    ddd, self.digits
+                )));
+            }
+
+            if len == 13 && !self.digits[4..].starts_with('9') {
+                return Err(ChatGuruError::ValidationError(format!(
+                    "Invalid Brazilian mobile number (9 digits must start with 9): {}",
+                    self.digits
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Indica se o número é válido
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Dígitos com código do país, sem `+` (formato do `chat_number`)
+    pub fn digits(&self) -> &str {
+        &self.digits
+    }
+
+    /// Número mascarado para logs (`5511*****9999`)
+    ///
+    /// Mantém o código do país, o DDD e os quatro últimos dígitos.
+    pub fn masked(&self) -> String {
+        let len = self.digits.len();
+        if len <= 8 {
+            return "*".repeat(len);
+        }
+        format!(
+            "{}{}{}",
+            &self.digits[..4],
+            "*".repeat(len - 8),
+            &self.digits[len - 4..]
+        )
+    }
+
+    /// Número no formato E.164 (`+5511999999999`)
+    pub fn to_e164(&self) -> String {
+        format!("+{}", self.digits)
+    }
+
+    /// Indica se o número é brasileiro (código de país 55)
+    pub fn is_brazilian(&self) -> bool {
+        self.digits.starts_with(BRAZIL_COUNTRY_CODE)
+    }
+
+    /// DDD do número brasileiro
+    pub fn ddd(&self) -> Option<&str> {
+        if self.is_brazilian() && self.digits.len() >= 4 {
+            Some(&self.digits[2..4])
+        } else {
+            None
+        }
+    }
+
+    /// Indica se é um celular brasileiro
+    pub fn is_brazilian_mobile(&self) -> bool {
+        if !self.is_brazilian() {
+            return false;
+        }
+        match self.digits.len() {
+            13 => self.digits[4..].starts_with('9'),
+            12 => matches!(self.digits.as_bytes()[4], b'6'..=b'9'),
+            _ => false,
+        }
+    }
+
+    /// Variantes do número com e sem o nono dígito
+    ///
+    /// Contas antigas do WhatsApp podem estar registradas sem o nono dígito
+    /// (`55 11 9999-9999`) mesmo que o contato informe o número atual
+    /// (`55 11 99999-9999`). Retorna o próprio número primeiro e, para
+    /// celulares brasileiros, a variante alternativa.
+    pub fn brazil_ninth_digit_variants(&self) -> Vec<PhoneNumber> {
+        let mut variants = vec![self.clone()];
+
+        if !self.is_brazilian_mobile() {
+            return variants;
+        }
+
+        let (prefix, subscriber) = self.digits.split_at(4);
+        let alternative = if self.digits.len() == 13 {
+            format!("{}{}", prefix, &subscriber[1..])
+        } else {
+            format!("{}9{}", prefix, subscriber)
+        };
+
+        variants.push(PhoneNumber {
+            digits: alternative,
+        });
+        variants
+    }
+}
+
+/// Indica se o número começa com o DDD entre parênteses (`(11)`, `(011)`)
+fn has_area_code_parentheses(number: &str) -> bool {
+    let Some(rest) = number.strip_prefix('(') else {
+        return false;
+    };
+    let Some((area_code, _)) = rest.split_once(')') else {
+        return false;
+    };
+    let area_code = area_code.trim();
+    let area_code = area_code.strip_prefix('0').unwrap_or(area_code);
+    area_code.len() == 2 && area_code.bytes().all(|b| b.is_ascii_digit())
+}
+
+impl fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.digits)
+    }
+}
+
+impl From<&str> for PhoneNumber {
+    fn from(value: &str) -> Self {
+        PhoneNumber::normalize(value)
+    }
+}
+
+impl From<String> for PhoneNumber {
+    fn from(value: String) -> Self {
+        PhoneNumber::normalize(&value)
+    }
+}
+
+impl From<&String> for PhoneNumber {
+    fn from(value: &String) -> Self {
+        PhoneNumber::normalize(value)
+    }
+}
+
+impl From<&PhoneNumber> for PhoneNumber {
+    fn from(value: &PhoneNumber) -> Self {
+        value.clone()
+    }
+}
+
+impl std::str::FromStr for PhoneNumber {
+    type Err = ChatGuruError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        PhoneNumber::parse(s)
+    }
+}
+
+impl Serialize for PhoneNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.digits)
+    }
+}
+
+impl<'de> Deserialize<'de> for PhoneNumber {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        Ok(PhoneNumber::normalize(&raw))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PhoneNumber;
+
+    #[test]
+    fn national_forms_get_brazil_country_code() {
+        for input in [
+            "(11) 99999-9999",
+            "(011) 99999-9999",
+            "011 99999-9999",
+            "+55 (11) 99999-9999",
+            "005511999999999",
+            "5511999999999@c.us",
+        ] {
+            assert_eq!(
+                PhoneNumber::from(input).digits(),
+                "5511999999999",
+                "{}",
+                input
+            );
+        }
+        assert_eq!(PhoneNumber::from("(21) 3333-4444").digits(), "552133334444");
+    }
+
+    #[test]
+    fn digits_with_country_code_are_kept() {
+        assert_eq!(
+            PhoneNumber::parse("6591234567").unwrap().digits(),
+            "6591234567"
+        );
+        assert_eq!(
+            PhoneNumber::parse("12125551234").unwrap().digits(),
+            "12125551234"
+        );
+        assert_eq!(
+            PhoneNumber::parse("+1 212 555 1234").unwrap().digits(),
+            "12125551234"
+        );
+        assert_eq!(PhoneNumber::from("11999999999").digits(), "11999999999");
+    }
+
+    #[test]
+    fn parse_brazilian_accepts_bare_national_numbers() {
+        let phone = PhoneNumber::parse_brazilian("11999999999").unwrap();
+        assert_eq!(phone.digits(), "5511999999999");
+        assert_eq!(
+            PhoneNumber::parse_brazilian("+1 212 555 1234")
+                .unwrap()
+                .digits(),
+            "12125551234"
+        );
+        assert!(PhoneNumber::parse_brazilian("(10) 99999-9999").is_err());
+    }
+}
@@ -0,0 +1,60 @@
+//! Reações (emoji) enviadas pelo contato a uma mensagem
+//!
+//! O ChatGuru informa a reação como objeto (`emoji` e o ID da mensagem
+//! reagida) ou apenas como o emoji em texto. [`Reaction`] aceita as duas
+//! formas.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+/// Reação do contato a uma mensagem do chat
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Reaction {
+    /// Emoji da reação (vazio quando a reação foi removida)
+    pub emoji: String,
+    /// ID da mensagem que recebeu a reação, quando informado
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+}
+
+impl Reaction {
+    /// Indica se o contato removeu a reação
+    pub fn is_removal(&self) -> bool {
+        self.emoji.is_empty()
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        let object = match value {
+            Value::String(emoji) => {
+                return Some(Reaction {
+                    emoji: emoji.trim().to_string(),
+                    message_id: None,
+                })
+            }
+            Value::Object(object) => object,
+            _ => return None,
+        };
+
+        let text = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|name| object.get(*name))
+                .and_then(Value::as_str)
+                .map(|s| s.trim().to_string())
+        };
+
+        Some(Reaction {
+            emoji: text(&["emoji", "reaction", "reacao", "text"])?,
+            message_id: text(&["message_id", "id_mensagem", "reacted_message_id"])
+                .filter(|id| !id.is_empty()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Reaction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        Self::from_value(&value)
+            .ok_or_else(|| serde::de::Error::custom("invalid reaction: expected an emoji"))
+    }
+}
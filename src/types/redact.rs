@@ -0,0 +1,321 @@
+//! Remoção de dados pessoais dos webhooks para logs e exportação
+//!
+//! [`Redactor`] produz uma cópia do [`WebhookPayload`] com nome, telefone e
+//! e-mail mascarados e com e-mails, telefones, CPFs e CNPJs citados no texto
+//! das mensagens substituídos por marcadores (`[email]`, `[telefone]`,
+//! `[cpf]`, `[cnpj]`). O tratamento de cada campo é configurável.
+
+use super::payload::{ChatGuruPayload, EventTypePayload, GenericPayload};
+use super::phone::PhoneNumber;
+use super::webhook::WebhookPayload;
+use regex::{Captures, Regex};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Grupos de campos do webhook com dados pessoais
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PiiField {
+    /// Nome do contato (`nome`, `lead_name`)
+    Name,
+    /// Telefone do contato (`celular`, `phone`)
+    Phone,
+    /// E-mail do contato (`email`)
+    Email,
+    /// Texto da mensagem, anotação ou opção de botão escolhida
+    MessageText,
+    /// Campos personalizados e campos extras (`campos_personalizados`, `custom_data`, `extra`)
+    CustomFields,
+    /// Nome e e-mail do atendente responsável
+    Agent,
+    /// Localização compartilhada
+    Location,
+    /// Contato compartilhado (vCard)
+    ContactCard,
+    /// URLs das mídias anexadas
+    MediaUrl,
+}
+
+/// Tratamento de um [`PiiField`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Redaction {
+    /// Mantém o valor original
+    Keep,
+    /// Mascara o valor (nomes, telefones e e-mails) ou remove os dados
+    /// pessoais encontrados no texto (demais campos)
+    Mask,
+    /// Remove o valor
+    Remove,
+}
+
+/// Configuração da remoção de dados pessoais
+///
+/// Padrão: nome, telefone, e-mail, texto, campos personalizados e atendente
+/// mascarados; localização e contato compartilhado removidos; URLs das
+/// mídias mantidas.
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::types::redact::{PiiField, Redaction, Redactor};
+/// use chatguru::types::WebhookPayload;
+///
+/// let payload = WebhookPayload::parse(
+///     r#"{"campanha_id": "1", "nome": "Maria Silva", "celular": "5511999999999",
+///         "email": "maria@exemplo.com", "texto_mensagem": "Meu CPF é 123.456.789-09"}"#,
+/// )
+/// .unwrap();
+///
+/// let safe = Redactor::new().redact(&payload);
+/// assert_eq!(safe.get_phone_number().as_deref(), Some("5511*****9999"));
+/// assert_eq!(safe.get_message_text().as_deref(), Some("Meu CPF é [cpf]"));
+///
+/// let no_text = Redactor::new().field(PiiField::MessageText, Redaction::Remove);
+/// assert_eq!(no_text.redact(&payload).get_message_text(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Redactor {
+    rules: HashMap<PiiField, Redaction>,
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Self {
+            rules: HashMap::from([
+                (PiiField::Location, Redaction::Remove),
+                (PiiField::ContactCard, Redaction::Remove),
+                (PiiField::MediaUrl, Redaction::Keep),
+            ]),
+        }
+    }
+}
+
+impl Redactor {
+    /// Configuração padrão
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Define o tratamento de um campo
+    pub fn field(mut self, field: PiiField, redaction: Redaction) -> Self {
+        self.rules.insert(field, redaction);
+        self
+    }
+
+    /// Tratamento configurado para o campo (padrão: [`Redaction::Mask`])
+    pub fn redaction(&self, field: PiiField) -> Redaction {
+        self.rules.get(&field).copied().unwrap_or(Redaction::Mask)
+    }
+
+    /// Cópia do payload sem os dados pessoais
+    pub fn redact(&self, payload: &WebhookPayload) -> WebhookPayload {
+        match payload {
+            WebhookPayload::ChatGuru(p) => WebhookPayload::ChatGuru(self.redact_chatguru(p)),
+            WebhookPayload::EventType(p) => WebhookPayload::EventType(self.redact_event(p)),
+            WebhookPayload::Generic(p) => WebhookPayload::Generic(self.redact_generic(p)),
+        }
+    }
+
+    fn redact_chatguru(&self, payload: &ChatGuruPayload) -> ChatGuruPayload {
+        let mut p = payload.clone();
+
+        p.nome = self.apply(PiiField::Name, &p.nome, mask_name);
+        p.celular = self.apply(PiiField::Phone, &p.celular, mask_phone);
+        p.email = self.apply(PiiField::Email, &p.email, mask_email);
+        p.texto_mensagem = self.apply(PiiField::MessageText, &p.texto_mensagem, redact_text);
+        if let Some(ref mut reply) = p.button_reply {
+            reply.title = self.apply(PiiField::MessageText, &reply.title, redact_text);
+        }
+        self.redact_map(&mut p.campos_personalizados);
+        p.responsavel_nome = self.apply_opt(PiiField::Agent, &p.responsavel_nome, mask_name);
+        p.responsavel_email = self.apply_opt(PiiField::Agent, &p.responsavel_email, mask_email);
+        if self.redaction(PiiField::Location) != Redaction::Keep {
+            p.location = None;
+        }
+        if self.redaction(PiiField::ContactCard) != Redaction::Keep {
+            p.contact_card = None;
+        }
+        if self.redaction(PiiField::MediaUrl) != Redaction::Keep {
+            p.media_url = None;
+            p.url_arquivo = None;
+        }
+        p
+    }
+
+    fn redact_event(&self, payload: &EventTypePayload) -> EventTypePayload {
+        let mut p = payload.clone();
+        let data = &mut p.data;
+
+        data.lead_name = self.apply_opt(PiiField::Name, &data.lead_name, mask_name);
+        data.phone = self.apply_opt(PiiField::Phone, &data.phone, mask_phone);
+        data.email = self.apply_opt(PiiField::Email, &data.email, mask_email);
+        data.annotation = self.apply_opt(PiiField::MessageText, &data.annotation, redact_text);
+        data.task_title = self.apply_opt(PiiField::MessageText, &data.task_title, redact_text);
+        self.redact_map(&mut data.custom_data);
+        self.redact_map(&mut data.extra);
+        p
+    }
+
+    fn redact_generic(&self, payload: &GenericPayload) -> GenericPayload {
+        let mut p = payload.clone();
+
+        p.nome = self.apply_opt(PiiField::Name, &p.nome, mask_name);
+        p.celular = self.apply_opt(PiiField::Phone, &p.celular, mask_phone);
+        p.email = self.apply_opt(PiiField::Email, &p.email, mask_email);
+        p.mensagem = self.apply_opt(PiiField::MessageText, &p.mensagem, redact_text);
+        self.redact_map(&mut p.extra);
+        p
+    }
+
+    fn apply(&self, field: PiiField, value: &str, mask: fn(&str) -> String) -> String {
+        match self.redaction(field) {
+            Redaction::Keep => value.to_string(),
+            Redaction::Mask => mask(value),
+            Redaction::Remove => String::new(),
+        }
+    }
+
+    fn apply_opt(
+        &self,
+        field: PiiField,
+        value: &Option<String>,
+        mask: fn(&str) -> String,
+    ) -> Option<String> {
+        match self.redaction(field) {
+            Redaction::Remove => None,
+            _ => value.as_deref().map(|value| self.apply(field, value, mask)),
+        }
+    }
+
+    fn redact_map(&self, map: &mut HashMap<String, Value>) {
+        match self.redaction(PiiField::CustomFields) {
+            Redaction::Keep => {}
+            Redaction::Mask => map.values_mut().for_each(redact_value),
+            Redaction::Remove => map.clear(),
+        }
+    }
+}
+
+impl WebhookPayload {
+    /// Cópia do payload sem dados pessoais, com a configuração padrão do [`Redactor`]
+    pub fn redacted(&self) -> WebhookPayload {
+        Redactor::default().redact(self)
+    }
+}
+
+/// Substitui e-mails, CNPJs, CPFs e telefones citados no texto por marcadores
+///
+/// Sequências de 11 dígitos sem pontuação só são tratadas como CPF quando os
+/// dígitos verificadores conferem; as demais seguem para a detecção de
+/// telefone.
+///
+/// ```rust
+/// use chatguru::types::redact::redact_text;
+///
+/// assert_eq!(
+///     redact_text("Me liga no (11) 99999-9999 ou manda para ana@exemplo.com"),
+///     "Me liga no [telefone] ou manda para [email]"
+/// );
+/// ```
+pub fn redact_text(text: &str) -> String {
+    let text = email_pattern().replace_all(text, "[email]");
+    let text = cnpj_pattern().replace_all(&text, "[cnpj]");
+    let text = cpf_pattern().replace_all(&text, |caps: &Captures| {
+        let matched = &caps[0];
+        if matched.contains(['.', '-']) || is_valid_cpf(matched) {
+            "[cpf]".to_string()
+        } else {
+            matched.to_string()
+        }
+    });
+    phone_pattern()
+        .replace_all(&text, |caps: &Captures| {
+            let matched = &caps[0];
+            let digits = matched.chars().filter(char::is_ascii_digit).count();
+            if (10..=13).contains(&digits) {
+                "[telefone]".to_string()
+            } else {
+                matched.to_string()
+            }
+        })
+        .into_owned()
+}
+
+/// Telefone mascarado (`5511*****9999`)
+pub fn mask_phone(phone: &str) -> String {
+    PhoneNumber::from(phone).masked()
+}
+
+/// E-mail mascarado, mantendo a primeira letra e o domínio (`m***@exemplo.com`)
+pub fn mask_email(email: &str) -> String {
+    match email.trim().split_once('@') {
+        Some((local, domain)) => match local.chars().next() {
+            Some(first) => format!("{}***@{}", first, domain),
+            None => format!("***@{}", domain),
+        },
+        None if email.trim().is_empty() => String::new(),
+        None => "***".to_string(),
+    }
+}
+
+/// Nome mascarado, mantendo a inicial de cada palavra (`M*** S***`)
+pub fn mask_name(name: &str) -> String {
+    name.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .map(|initial| format!("{}***", initial))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn redact_value(value: &mut Value) {
+    match value {
+        Value::String(text) => *text = redact_text(text),
+        Value::Array(items) => items.iter_mut().for_each(redact_value),
+        Value::Object(map) => map.values_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
+fn is_valid_cpf(text: &str) -> bool {
+    let digits: Vec<u32> = text.chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.len() != 11 || digits.iter().all(|d| *d == digits[0]) {
+        return false;
+    }
+
+    let check = |len: usize| {
+        let sum: u32 = digits[..len]
+            .iter()
+            .zip((2..=len as u32 + 1).rev())
+            .map(|(digit, weight)| digit * weight)
+            .sum();
+        (sum * 10 % 11) % 10
+    };
+    check(9) == digits[9] && check(10) == digits[10]
+}
+
+fn email_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}")
+            .expect("valid email pattern")
+    })
+}
+
+fn cnpj_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"\b\d{2}\.\d{3}\.\d{3}/\d{4}-\d{2}\b|\b\d{14}\b").expect("valid CNPJ pattern")
+    })
+}
+
+fn cpf_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"\b\d{3}\.\d{3}\.\d{3}-\d{2}\b|\b\d{11}\b").expect("valid CPF pattern")
+    })
+}
+
+fn phone_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r"\+?\(?\d[\d\s().-]{7,}\d").expect("valid phone pattern"))
+}
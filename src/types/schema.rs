@@ -0,0 +1,175 @@
+//! Versões de esquema dos webhooks e migração para o formato atual
+//!
+//! O ChatGuru já enviou webhooks em três formatos (veja [`WebhookPayload`]).
+//! [`WebhookPayload::migrate_to_latest`] converte qualquer um deles em um
+//! [`ChatGuruPayload`], para que os handlers trabalhem com uma única struct.
+
+use super::payload::{ChatGuruPayload, EventTypePayload, GenericPayload};
+use super::webhook::WebhookPayload;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Formato (versão de esquema) de um webhook, do mais antigo ao atual
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SchemaVersion {
+    /// Formato genérico/mínimo (`nome`, `celular`, `mensagem`)
+    Generic,
+    /// Formato legado com `event_type` e `data`
+    EventType,
+    /// Formato atual do ChatGuru (`campanha_id`, `campos_personalizados`, ...)
+    ChatGuru,
+}
+
+impl SchemaVersion {
+    /// Versão mais recente, para a qual [`WebhookPayload::migrate_to_latest`] converte
+    pub const LATEST: SchemaVersion = SchemaVersion::ChatGuru;
+
+    /// Indica se é a versão mais recente
+    pub fn is_latest(&self) -> bool {
+        *self == Self::LATEST
+    }
+}
+
+/// Resultado de [`WebhookPayload::migrate_to_latest`]
+#[derive(Debug, Clone)]
+pub struct MigratedPayload {
+    /// Payload no formato atual
+    pub payload: ChatGuruPayload,
+    /// Formato original do webhook
+    pub from: SchemaVersion,
+    /// Campos sem equivalente no formato atual
+    ///
+    /// Os valores são preservados em `campos_personalizados`, com o mesmo nome.
+    pub unmapped_fields: Vec<String>,
+}
+
+impl MigratedPayload {
+    /// Indica se todos os campos do payload original foram mapeados
+    pub fn is_lossless(&self) -> bool {
+        self.unmapped_fields.is_empty()
+    }
+}
+
+impl WebhookPayload {
+    /// Formato em que o webhook foi recebido
+    pub fn schema_version(&self) -> SchemaVersion {
+        match self {
+            WebhookPayload::ChatGuru(_) => SchemaVersion::ChatGuru,
+            WebhookPayload::EventType(_) => SchemaVersion::EventType,
+            WebhookPayload::Generic(_) => SchemaVersion::Generic,
+        }
+    }
+
+    /// Converte o webhook para o formato atual ([`ChatGuruPayload`])
+    ///
+    /// A conversão é de melhor esforço: campos sem equivalente direto são
+    /// copiados para `campos_personalizados` e listados em
+    /// [`MigratedPayload::unmapped_fields`].
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust,ignore
+    /// let migrated = payload.migrate_to_latest();
+    /// if !migrated.is_lossless() {
+    ///     tracing::debug!("Campos sem mapeamento: {:?}", migrated.unmapped_fields);
+    /// }
+    /// handle(migrated.payload).await;
+    /// ```
+    pub fn migrate_to_latest(self) -> MigratedPayload {
+        let from = self.schema_version();
+        let (payload, unmapped_fields) = match self {
+            WebhookPayload::ChatGuru(p) => (p, Vec::new()),
+            WebhookPayload::EventType(p) => migrate_event_type(p),
+            WebhookPayload::Generic(p) => migrate_generic(p),
+        };
+
+        MigratedPayload {
+            payload,
+            from,
+            unmapped_fields,
+        }
+    }
+}
+
+fn migrate_event_type(p: EventTypePayload) -> (ChatGuruPayload, Vec<String>) {
+    let data = p.data;
+    let mut payload = ChatGuruPayload {
+        nome: data.lead_name.unwrap_or_default(),
+        celular: data.phone.unwrap_or_default(),
+        email: data.email.unwrap_or_default(),
+        texto_mensagem: data.annotation.unwrap_or_default(),
+        chat_id: Some(p.id).filter(|id| !id.is_empty()),
+        chat_created: Some(p.timestamp).filter(|ts| !ts.is_empty()),
+        ..ChatGuruPayload::default()
+    };
+
+    let mut leftovers: Vec<(String, Value)> = vec![("event_type".to_string(), p.event_type.into())];
+    leftovers.extend(
+        [
+            ("project_name", data.project_name.map(Value::from)),
+            ("task_title", data.task_title.map(Value::from)),
+            ("amount", data.amount.map(Value::from)),
+            ("status", data.status.map(Value::from)),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| (name.to_string(), value))),
+    );
+    leftovers.extend(data.custom_data);
+    leftovers.extend(data.extra);
+
+    let unmapped = keep_as_custom_fields(&mut payload, leftovers);
+    (payload, unmapped)
+}
+
+fn migrate_generic(p: GenericPayload) -> (ChatGuruPayload, Vec<String>) {
+    let mut payload = ChatGuruPayload {
+        nome: p.nome.unwrap_or_default(),
+        celular: p.celular.unwrap_or_default(),
+        email: p.email.unwrap_or_default(),
+        texto_mensagem: p.mensagem.unwrap_or_default(),
+        ..ChatGuruPayload::default()
+    };
+
+    // Campos extras com nome de campo do formato atual são aplicados diretamente
+    let mut leftovers = Vec::new();
+    let extra: BTreeMap<String, Value> = p.extra.into_iter().collect();
+    for (name, value) in extra {
+        match with_field(&payload, &name, value.clone()) {
+            Some(updated) => payload = updated,
+            None => leftovers.push((name, value)),
+        }
+    }
+
+    let unmapped = keep_as_custom_fields(&mut payload, leftovers);
+    (payload, unmapped)
+}
+
+/// Aplica um campo ao payload, se ele existir no formato atual e o tipo for compatível
+fn with_field(payload: &ChatGuruPayload, name: &str, value: Value) -> Option<ChatGuruPayload> {
+    let Ok(Value::Object(mut fields)) = serde_json::to_value(payload) else {
+        return None;
+    };
+    if !fields.contains_key(name) {
+        return None;
+    }
+    fields.insert(name.to_string(), value);
+    serde_json::from_value(Value::Object(fields)).ok()
+}
+
+/// Copia os campos sem equivalente para `campos_personalizados`, retornando seus nomes
+fn keep_as_custom_fields(
+    payload: &mut ChatGuruPayload,
+    leftovers: impl IntoIterator<Item = (String, Value)>,
+) -> Vec<String> {
+    let mut names = Vec::new();
+    for (name, value) in leftovers {
+        payload
+            .campos_personalizados
+            .entry(name.clone())
+            .or_insert(value);
+        names.push(name);
+    }
+    names.sort();
+    names.dedup();
+    names
+}
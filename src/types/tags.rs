@@ -0,0 +1,126 @@
+//! Tags dos chats e mudanças entre webhooks consecutivos
+//!
+//! [`WebhookPayload::get_tags`](super::WebhookPayload::get_tags) lê as tags de
+//! qualquer formato; [`TagDiff::between`] compara as tags de dois webhooks do
+//! mesmo chat para reagir a tags adicionadas ou removidas.
+
+use super::custom_fields::normalize_key;
+use super::de;
+use super::payload::{EventTypePayload, GenericPayload};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Chaves das tags nos mapas `extra` dos formatos legados
+const TAG_KEYS: &[&str] = &["tags", "etiquetas", "labels"];
+
+/// Tags adicionadas e removidas entre dois webhooks do mesmo chat
+///
+/// As tags são comparadas ignorando caixa, acentos e espaços (veja
+/// [`normalize_key`]); cada lista mantém a grafia e a ordem do webhook de
+/// onde a tag veio.
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::types::{TagDiff, WebhookPayload};
+///
+/// let previous = WebhookPayload::parse(
+///     r#"{"campanha_id": "1", "nome": "Maria", "tags": ["lead", "Black Friday"]}"#,
+/// )
+/// .unwrap();
+/// let current = WebhookPayload::parse(
+///     r#"{"campanha_id": "1", "nome": "Maria", "tags": "black friday, VIP"}"#,
+/// )
+/// .unwrap();
+///
+/// let diff = TagDiff::between(&previous.get_tags(), &current.get_tags());
+/// assert_eq!(diff.added, vec!["VIP"]);
+/// assert_eq!(diff.removed, vec!["lead"]);
+/// assert!(diff.was_added("vip"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagDiff {
+    /// Tags presentes só no webhook atual
+    pub added: Vec<String>,
+    /// Tags presentes só no webhook anterior
+    pub removed: Vec<String>,
+}
+
+impl TagDiff {
+    /// Compara as tags do webhook anterior com as do atual
+    pub fn between<P, C>(previous: &[P], current: &[C]) -> Self
+    where
+        P: AsRef<str>,
+        C: AsRef<str>,
+    {
+        Self {
+            added: missing_from(current, previous),
+            removed: missing_from(previous, current),
+        }
+    }
+
+    /// Indica se as tags não mudaram
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+
+    /// Indica se a tag foi adicionada
+    pub fn was_added(&self, tag: &str) -> bool {
+        contains(&self.added, tag)
+    }
+
+    /// Indica se a tag foi removida
+    pub fn was_removed(&self, tag: &str) -> bool {
+        contains(&self.removed, tag)
+    }
+}
+
+/// Tags de `tags` ausentes em `other`, sem repetições
+fn missing_from<T: AsRef<str>, O: AsRef<str>>(tags: &[T], other: &[O]) -> Vec<String> {
+    let other: Vec<String> = other
+        .iter()
+        .map(|tag| normalize_key(tag.as_ref()))
+        .collect();
+    let mut seen = Vec::new();
+    let mut missing = Vec::new();
+
+    for tag in tags {
+        let tag = tag.as_ref().trim();
+        let key = normalize_key(tag);
+        if key.is_empty() || other.contains(&key) || seen.contains(&key) {
+            continue;
+        }
+        seen.push(key);
+        missing.push(tag.to_string());
+    }
+    missing
+}
+
+fn contains(tags: &[String], tag: &str) -> bool {
+    let tag = normalize_key(tag);
+    tags.iter().any(|t| normalize_key(t) == tag)
+}
+
+/// Tags lidas dos campos avulsos de um mapa (lista ou texto separado por vírgulas)
+fn tags_from_map(map: &HashMap<String, Value>) -> Vec<String> {
+    TAG_KEYS
+        .iter()
+        .find_map(|key| map.get(*key))
+        .and_then(|value| de::string_list(value).ok())
+        .unwrap_or_default()
+}
+
+impl EventTypePayload {
+    /// Tags do chat, lidas dos campos extras de `data`
+    pub fn tags(&self) -> Vec<String> {
+        tags_from_map(&self.data.extra)
+    }
+}
+
+impl GenericPayload {
+    /// Tags do chat, lidas dos campos extras
+    pub fn tags(&self) -> Vec<String> {
+        tags_from_map(&self.extra)
+    }
+}
@@ -0,0 +1,164 @@
+//! Contatos (vCards) compartilhados pelo WhatsApp
+//!
+//! O ChatGuru encaminha o vCard como texto, no campo `vcard` ou, em
+//! mensagens `contact`, no texto da mensagem. [`VCard`] extrai os campos
+//! usados nas integrações e guarda o texto original.
+
+use super::phone::PhoneNumber;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// Cartão de contato (vCard 2.1/3.0/4.0)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VCard {
+    /// Nome de exibição (`FN`, ou `N` quando `FN` não vem)
+    pub full_name: Option<String>,
+    /// Telefones (`TEL`), na ordem do cartão
+    pub phones: Vec<String>,
+    /// Emails (`EMAIL`)
+    pub emails: Vec<String>,
+    /// Empresa (`ORG`)
+    pub organization: Option<String>,
+    /// Texto original do vCard
+    pub raw: String,
+}
+
+impl VCard {
+    /// Lê um vCard a partir do texto
+    ///
+    /// Linhas dobradas são reunidas e valores escapados (`\,`, `\;`, `\n`)
+    /// são decodificados. Propriedades com grupo (`item1.TEL`) são aceitas.
+    ///
+    /// # Retorno
+    ///
+    /// `None` se o texto não contiver `BEGIN:VCARD`.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust
+    /// use chatguru::types::VCard;
+    ///
+    /// let card = VCard::parse(
+    ///     "BEGIN:VCARD\nVERSION:3.0\nFN:Maria Souza\n\
+    ///      TEL;type=CELL;waid=5511988887777:+55 11 98888-7777\nEND:VCARD",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(card.full_name.as_deref(), Some("Maria Souza"));
+    /// assert_eq!(card.phones, vec!["+55 11 98888-7777"]);
+    /// ```
+    pub fn parse(text: &str) -> Option<Self> {
+        let start = text.to_ascii_uppercase().find("BEGIN:VCARD")?;
+        let raw = text[start..].trim().to_string();
+
+        // Linhas iniciadas por espaço ou tab continuam a anterior
+        let mut lines: Vec<String> = Vec::new();
+        for line in raw.lines() {
+            let line = line.trim_end_matches('\r');
+            match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+                (Some(continuation), Some(last)) => last.push_str(continuation),
+                _ => lines.push(line.to_string()),
+            }
+        }
+
+        let mut card = VCard {
+            raw: raw.clone(),
+            ..VCard::default()
+        };
+        let mut structured_name = None;
+
+        for line in &lines {
+            let Some((property, value)) = line.split_once(':') else {
+                continue;
+            };
+            let name = property.split(';').next().unwrap_or_default();
+            let name = name
+                .rsplit('.')
+                .next()
+                .unwrap_or_default()
+                .to_ascii_uppercase();
+            let raw_value = value.trim();
+            let value = unescape(raw_value);
+            if value.is_empty() {
+                continue;
+            }
+
+            match name.as_str() {
+                "FN" => card.full_name = Some(value),
+                "N" => structured_name = Some(raw_value.to_string()),
+                "TEL" => card.phones.push(value),
+                "EMAIL" => card.emails.push(value),
+                "ORG" => {
+                    card.organization = raw_value
+                        .split(';')
+                        .next()
+                        .map(|org| unescape(org.trim()))
+                        .filter(|org| !org.is_empty())
+                }
+                "END" if value.eq_ignore_ascii_case("VCARD") => break,
+                _ => {}
+            }
+        }
+
+        // N: Sobrenome;Nome;Nomes adicionais;Prefixo;Sufixo
+        if card.full_name.is_none() {
+            card.full_name = structured_name.map(|n| {
+                let mut parts: Vec<String> = n.split(';').map(|p| unescape(p.trim())).collect();
+                if parts.len() > 1 {
+                    parts.swap(0, 1);
+                }
+                parts
+                    .into_iter()
+                    .filter(|part| !part.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            });
+        }
+
+        Some(card)
+    }
+
+    /// Primeiro telefone do cartão, normalizado
+    pub fn primary_phone(&self) -> Option<PhoneNumber> {
+        self.phones.first().map(PhoneNumber::from)
+    }
+}
+
+/// Decodifica os escapes de valores do vCard
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(escaped) => out.push(escaped),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+impl Serialize for VCard {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for VCard {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let card = match Value::deserialize(deserializer)? {
+            Value::String(text) => VCard::parse(&text),
+            // Objeto com o texto em `vcard` (ex: {"displayName": "...", "vcard": "BEGIN:VCARD..."})
+            Value::Object(object) => object
+                .get("vcard")
+                .and_then(Value::as_str)
+                .and_then(VCard::parse),
+            _ => None,
+        };
+        card.ok_or_else(|| serde::de::Error::custom("invalid contact card: expected a vCard"))
+    }
+}
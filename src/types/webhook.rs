@@ -1,4 +1,11 @@
+use super::button_reply::ButtonReply;
+use super::chat_link::ChatLink;
+use super::event::{Agent, Campaign};
+use super::location::GeoPoint;
+use super::media_kind::MediaTypeMap;
 use super::payload::{ChatGuruPayload, EventTypePayload, GenericPayload};
+use super::reaction::Reaction;
+use super::vcard::VCard;
 use serde::{Deserialize, Serialize};
 
 /// Estrutura flexível que aceita múltiplos formatos de webhook
@@ -28,6 +35,7 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)] // `ChatGuru` é o formato comum; evita um Box em cada webhook
 pub enum WebhookPayload {
     /// Formato ChatGuru (campanha_id, nome, etc)
     ChatGuru(ChatGuruPayload),
@@ -38,6 +46,24 @@ pub enum WebhookPayload {
 }
 
 impl WebhookPayload {
+    /// Normaliza os campos de mídia quando o payload é do formato ChatGuru
+    ///
+    /// Atalho para [`ChatGuruPayload::normalize_media_fields`]; os demais
+    /// formatos não possuem campos de mídia e ficam inalterados.
+    pub fn normalize_media_fields(&mut self) {
+        if let WebhookPayload::ChatGuru(p) = self {
+            p.normalize_media_fields();
+        }
+    }
+
+    /// Como [`normalize_media_fields`](Self::normalize_media_fields), com
+    /// mapeamentos `tipo_mensagem → MIME type` personalizados
+    pub fn normalize_media_fields_with(&mut self, media_types: &MediaTypeMap) {
+        if let WebhookPayload::ChatGuru(p) = self {
+            p.normalize_media_fields_with(media_types);
+        }
+    }
+
     /// Extrai o nome/título do contato do payload
     ///
     /// Útil para identificação rápida independente do formato do webhook.
@@ -108,6 +134,149 @@ impl WebhookPayload {
         }
     }
 
+    /// Extrai a campanha associada ao payload (se houver)
+    ///
+    /// Lê `campanha_id`/`campanha_nome`/`origem` no formato ChatGuru e os
+    /// mesmos campos (ou `campaign_id`/`campaign_name`/`origin`) nos campos
+    /// extras dos formatos legados.
+    ///
+    /// # Retorno
+    ///
+    /// `Some(Campaign)` se houver ID ou nome de campanha, ou `None` caso contrário.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust
+    /// use chatguru::types::WebhookPayload;
+    ///
+    /// let payload = WebhookPayload::parse(
+    ///     r#"{"event_type": "lead.created", "id": "chat_1", "timestamp": "2024-01-01T10:00:00Z",
+    ///         "data": {"campanha_id": 1001, "campanha_nome": "Black Friday", "origem": "instagram"}}"#,
+    /// )
+    /// .unwrap();
+    /// let campaign = payload.campaign().unwrap();
+    /// assert_eq!(campaign.id, "1001");
+    /// assert_eq!(campaign.name.as_deref(), Some("Black Friday"));
+    /// assert_eq!(campaign.origin.as_deref(), Some("instagram"));
+    /// ```
+    pub fn campaign(&self) -> Option<Campaign> {
+        match self {
+            WebhookPayload::ChatGuru(p) => p.campaign(),
+            WebhookPayload::EventType(p) => p.campaign(),
+            WebhookPayload::Generic(p) => p.campaign(),
+        }
+    }
+
+    /// Extrai as tags do chat
+    ///
+    /// Lê `tags` no formato ChatGuru e `tags` (ou `etiquetas`/`labels`) nos
+    /// campos extras dos formatos legados, como lista ou texto separado por
+    /// vírgulas.
+    ///
+    /// # Retorno
+    ///
+    /// As tags na ordem recebida (vazio se o payload não trouxer tags).
+    pub fn get_tags(&self) -> Vec<String> {
+        match self {
+            WebhookPayload::ChatGuru(p) => p.tags.clone(),
+            WebhookPayload::EventType(p) => p.tags(),
+            WebhookPayload::Generic(p) => p.tags(),
+        }
+    }
+
+    /// Extrai o atendente responsável pelo chat (se houver)
+    ///
+    /// Lê `responsavel_nome`/`responsavel_email` no formato ChatGuru e, nos
+    /// campos extras dos formatos legados, os mesmos campos, `agent_name`/
+    /// `agent_email` ou um objeto `responsavel`/`agent` com nome e e-mail.
+    ///
+    /// # Retorno
+    ///
+    /// `Some(Agent)` se houver nome ou e-mail do atendente, ou `None` caso contrário.
+    ///
+    /// # Exemplo
+    ///
+    /// ```rust
+    /// use chatguru::types::WebhookPayload;
+    ///
+    /// let payload = WebhookPayload::parse(
+    ///     r#"{"event_type": "task.assigned", "id": "chat_1", "timestamp": "2024-01-01T10:00:00Z",
+    ///         "data": {"responsavel": {"nome": "Ana", "email": "ana@exemplo.com"}}}"#,
+    /// )
+    /// .unwrap();
+    /// let agent = payload.get_agent().unwrap();
+    /// assert_eq!(agent.name.as_deref(), Some("Ana"));
+    /// assert_eq!(agent.email.as_deref(), Some("ana@exemplo.com"));
+    /// ```
+    pub fn get_agent(&self) -> Option<Agent> {
+        match self {
+            WebhookPayload::ChatGuru(p) => p.agent(),
+            WebhookPayload::EventType(p) => p.agent(),
+            WebhookPayload::Generic(p) => p.agent(),
+        }
+    }
+
+    /// Extrai o link do chat no painel do ChatGuru (se disponível)
+    ///
+    /// # Retorno
+    ///
+    /// `Some(ChatLink)` lido de `link_chat`, ou `None` se o payload não
+    /// trouxer um link válido.
+    pub fn get_chat_link(&self) -> Option<ChatLink> {
+        match self {
+            WebhookPayload::ChatGuru(p) => p.chat_link(),
+            _ => None,
+        }
+    }
+
+    /// Calcula uma chave estável que identifica o evento
+    ///
+    /// Combina o chat (chat_id ou telefone), o timestamp do evento (quando
+    /// disponível) e um hash do conteúdo da mensagem. Reentregas do mesmo
+    /// webhook produzem a mesma chave, permitindo descartá-las.
+    ///
+    /// # Retorno
+    ///
+    /// String no formato `{chat}:{timestamp}:{hash}`.
+    pub fn event_fingerprint(&self) -> String {
+        let chat = self
+            .get_chat_id()
+            .or_else(|| self.get_phone_number())
+            .unwrap_or_default();
+
+        let timestamp = match self {
+            WebhookPayload::ChatGuru(p) => p.chat_created.clone().unwrap_or_default(),
+            WebhookPayload::EventType(p) => p.timestamp.clone(),
+            WebhookPayload::Generic(_) => String::new(),
+        };
+
+        let content = match self {
+            WebhookPayload::ChatGuru(p) => format!(
+                "{}\n{}\n{}\n{}",
+                p.texto_mensagem,
+                self.get_media_url().unwrap_or_default(),
+                p.tipo_mensagem.as_deref().unwrap_or_default(),
+                p.campanha_id
+            ),
+            WebhookPayload::EventType(p) => {
+                format!(
+                    "{}\n{}",
+                    p.event_type,
+                    serde_json::to_string(&p.data).unwrap_or_default()
+                )
+            }
+            WebhookPayload::Generic(_) => serde_json::to_string(self).unwrap_or_default(),
+        };
+
+        let hash = crate::webhook::hmac::sha256(&[content.as_bytes()])
+            .iter()
+            .take(8)
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+
+        format!("{}:{}:{}", chat, timestamp, hash)
+    }
+
     /// Verifica se o payload contém mídia anexada
     ///
     /// # Retorno
@@ -132,6 +301,59 @@ impl WebhookPayload {
         }
     }
 
+    /// Extrai a localização compartilhada (se houver)
+    ///
+    /// # Retorno
+    ///
+    /// `Some(GeoPoint)` com as coordenadas, ou `None` se não houver.
+    pub fn get_location(&self) -> Option<GeoPoint> {
+        match self {
+            WebhookPayload::ChatGuru(p) => p.location(),
+            _ => None,
+        }
+    }
+
+    /// Extrai o cartão de contato (vCard) compartilhado (se houver)
+    ///
+    /// # Retorno
+    ///
+    /// `Some(VCard)` com os dados do contato, ou `None` se não houver.
+    pub fn get_contact_card(&self) -> Option<VCard> {
+        match self {
+            WebhookPayload::ChatGuru(p) => p.contact_card(),
+            _ => None,
+        }
+    }
+
+    /// Extrai a opção escolhida em botões ou lista (se houver)
+    ///
+    /// # Retorno
+    ///
+    /// `Some(ButtonReply)` com o ID e o título da opção, ou `None` se o
+    /// webhook não for uma resposta a mensagem interativa.
+    pub fn get_button_reply(&self) -> Option<ButtonReply> {
+        match self {
+            WebhookPayload::ChatGuru(p) => p.button_reply(),
+            _ => None,
+        }
+    }
+
+    /// Extrai o ID da mensagem citada, quando o contato respondeu a uma mensagem
+    pub fn get_quoted_message_id(&self) -> Option<String> {
+        match self {
+            WebhookPayload::ChatGuru(p) => p.quoted_message_id.clone(),
+            _ => None,
+        }
+    }
+
+    /// Extrai a reação do contato a uma mensagem (se houver)
+    pub fn get_reaction(&self) -> Option<Reaction> {
+        match self {
+            WebhookPayload::ChatGuru(p) => p.reaction.clone(),
+            _ => None,
+        }
+    }
+
     /// Extrai tipo da mídia anexada (se houver)
     ///
     /// # Retorno
@@ -143,12 +365,8 @@ impl WebhookPayload {
             WebhookPayload::ChatGuru(p) => {
                 p.media_type.clone().or_else(|| {
                     // Tentar derivar do tipo_mensagem
-                    p.tipo_mensagem.as_ref().map(|t| match t.as_str() {
-                        "image" => "image/jpeg".to_string(),
-                        "ptt" | "audio" => "audio/ogg".to_string(),
-                        "video" => "video/mp4".to_string(),
-                        "document" => "application/pdf".to_string(),
-                        other => format!("application/{}", other),
+                    p.media_kind().and_then(|kind| {
+                        kind.to_mime(p.media_url.as_deref().or(p.url_arquivo.as_deref()))
                     })
                 })
             }
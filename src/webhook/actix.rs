@@ -0,0 +1,88 @@
+//! Extractor [`ChatGuruWebhook`] para handlers actix-web (feature `actix`)
+//!
+//! O [`WebhookExtractor`] é lido de `app_data`, como `web::Data<WebhookExtractor>`
+//! ou diretamente; sem ele a requisição é rejeitada com 503, para não aceitar
+//! webhooks sem verificar a assinatura. O corpo é lido até o limite
+//! configurado e requisições rejeitadas respondem com o status de
+//! [`WebhookRejection::status_code`].
+//!
+//! # Exemplo
+//!
+//! ```rust,ignore
+//! use actix_web::{web, App, HttpResponse};
+//! use chatguru::webhook::{ChatGuruWebhook, WebhookExtractor};
+//!
+//! async fn handler(webhook: ChatGuruWebhook) -> HttpResponse {
+//!     println!("Contato: {}", webhook.get_contact_name());
+//!     HttpResponse::Ok().finish()
+//! }
+//!
+//! let extractor = WebhookExtractor::new().with_secret(std::env::var("WEBHOOK_SECRET")?);
+//! let app = App::new()
+//!     .app_data(web::Data::new(extractor))
+//!     .route("/webhook", web::post().to(handler));
+//! ```
+
+use super::{ChatGuruWebhook, WebhookExtractor, WebhookRejection};
+use ::actix_web::dev::Payload;
+use ::actix_web::http::{header, StatusCode};
+use ::actix_web::{web, FromRequest, HttpRequest, HttpResponse, ResponseError};
+use futures_core::Stream;
+use std::future::Future;
+use std::pin::Pin;
+
+impl FromRequest for ChatGuruWebhook {
+    type Error = WebhookRejection;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(request: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let extractor = request
+            .app_data::<web::Data<WebhookExtractor>>()
+            .map(|data| data.get_ref().clone())
+            .or_else(|| request.app_data::<WebhookExtractor>().cloned());
+        let headers = request.headers();
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+        let signature = extractor
+            .as_ref()
+            .and_then(|extractor| header(extractor.signature_header_name()))
+            .map(String::from);
+        let content_type = header(header::CONTENT_TYPE.as_str()).map(String::from);
+        let content_length = header(header::CONTENT_LENGTH.as_str()).map(String::from);
+        let mut payload = payload.take();
+
+        Box::pin(async move {
+            let Some(extractor) = extractor else {
+                tracing::error!("WebhookExtractor is not registered as actix-web app_data");
+                return Err(WebhookRejection::Unavailable(
+                    "Webhook extractor not configured".to_string(),
+                ));
+            };
+            extractor.check_content_type(content_type.as_deref())?;
+            extractor.check_content_length(content_length.as_deref())?;
+
+            let mut body = Vec::new();
+            while let Some(chunk) =
+                std::future::poll_fn(|cx| Pin::new(&mut payload).poll_next(cx)).await
+            {
+                let chunk = chunk.map_err(|e| {
+                    WebhookRejection::InvalidPayload(format!("Failed to read body: {}", e))
+                })?;
+                extractor.check_body_size(body.len() + chunk.len())?;
+                body.extend_from_slice(&chunk);
+            }
+
+            extractor.extract(&body, signature.as_deref())
+        })
+    }
+}
+
+impl ResponseError for WebhookRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(WebhookRejection::status_code(self))
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(ResponseError::status_code(self)).body(self.to_string())
+    }
+}
@@ -0,0 +1,68 @@
+//! Extractor [`ChatGuruWebhook`] para handlers axum (feature `axum`)
+//!
+//! O [`WebhookExtractor`] vem do estado da aplicação (diretamente ou por
+//! [`FromRef`]); requisições rejeitadas respondem com o status de
+//! [`WebhookRejection::status_code`].
+//!
+//! # Exemplo
+//!
+//! ```rust,ignore
+//! use axum::{routing::post, Router};
+//! use chatguru::webhook::{ChatGuruWebhook, WebhookExtractor};
+//!
+//! async fn handler(webhook: ChatGuruWebhook) -> &'static str {
+//!     println!("Contato: {}", webhook.get_contact_name());
+//!     "ok"
+//! }
+//!
+//! let extractor = WebhookExtractor::new().with_secret(std::env::var("WEBHOOK_SECRET")?);
+//! let app = Router::new()
+//!     .route("/webhook", post(handler))
+//!     .with_state(extractor);
+//! ```
+
+use super::{ChatGuruWebhook, WebhookExtractor, WebhookRejection};
+use ::axum::extract::{FromRef, FromRequest, Request};
+use ::axum::http::{header, StatusCode};
+use ::axum::response::{IntoResponse, Response};
+use futures_core::Stream;
+use std::pin::Pin;
+
+impl<S> FromRequest<S> for ChatGuruWebhook
+where
+    WebhookExtractor: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = WebhookRejection;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let extractor = WebhookExtractor::from_ref(state);
+        let headers = request.headers();
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+        extractor.check_content_type(header(header::CONTENT_TYPE.as_str()))?;
+        extractor.check_content_length(header(header::CONTENT_LENGTH.as_str()))?;
+        let signature = header(extractor.signature_header_name()).map(String::from);
+
+        let mut stream = request.into_body().into_data_stream();
+        let mut body = Vec::new();
+        while let Some(chunk) = std::future::poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await
+        {
+            let chunk = chunk.map_err(|e| {
+                WebhookRejection::InvalidPayload(format!("Failed to read body: {}", e))
+            })?;
+            extractor.check_body_size(body.len() + chunk.len())?;
+            body.extend_from_slice(&chunk);
+        }
+
+        extractor.extract(&body, signature.as_deref())
+    }
+}
+
+impl IntoResponse for WebhookRejection {
+    fn into_response(self) -> Response {
+        let status =
+            StatusCode::from_u16(self.status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        (status, self.to_string()).into_response()
+    }
+}
@@ -0,0 +1,121 @@
+//! Detecção de pedidos de descadastro ("parar", "sair") nas mensagens recebidas
+//!
+//! O [`ConsentClassifier`] reconhece as respostas de opt-out e de volta
+//! (opt-in) do contato; com um
+//! [`ConsentRegistry`](crate::client::ConsentRegistry) configurado no
+//! cliente, [`ChatGuruClient::record_consent_reply`](crate::ChatGuruClient::record_consent_reply)
+//! registra a decisão e os envios seguintes passam a respeitá-la.
+
+use crate::types::custom_fields::normalize_key;
+use crate::types::WebhookPayload;
+
+/// Palavras-chave padrão de descadastro
+pub const DEFAULT_OPT_OUT_KEYWORDS: &[&str] = &[
+    "parar",
+    "pare",
+    "sair",
+    "stop",
+    "descadastrar",
+    "cancelar inscricao",
+    "nao quero receber",
+];
+
+/// Palavras-chave padrão de volta ao recebimento
+pub const DEFAULT_OPT_IN_KEYWORDS: &[&str] = &["voltar", "start"];
+
+/// Decisão de consentimento expressa em uma mensagem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConsentIntent {
+    /// O contato não quer mais receber mensagens
+    OptOut,
+    /// O contato voltou a aceitar mensagens
+    OptIn,
+}
+
+/// Classificador de respostas de consentimento por palavra-chave
+///
+/// A mensagem inteira é comparada com as palavras-chave, ignorando caixa,
+/// acentos, pontuação e espaços (`"Parar!"`, `" PARAR "` e `"párar"` casam
+/// com `parar`). Frases que apenas contêm a palavra ("não quero sair agora")
+/// não são consideradas pedidos.
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::webhook::{ConsentClassifier, ConsentIntent};
+///
+/// let classifier = ConsentClassifier::default();
+/// assert_eq!(classifier.classify("Parar"), Some(ConsentIntent::OptOut));
+/// assert_eq!(classifier.classify("SAIR."), Some(ConsentIntent::OptOut));
+/// assert_eq!(classifier.classify("quero sair mais cedo hoje"), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConsentClassifier {
+    opt_out: Vec<String>,
+    opt_in: Vec<String>,
+}
+
+impl Default for ConsentClassifier {
+    fn default() -> Self {
+        Self {
+            opt_out: DEFAULT_OPT_OUT_KEYWORDS
+                .iter()
+                .map(|k| normalize_key(k))
+                .collect(),
+            opt_in: DEFAULT_OPT_IN_KEYWORDS
+                .iter()
+                .map(|k| normalize_key(k))
+                .collect(),
+        }
+    }
+}
+
+impl ConsentClassifier {
+    /// Classificador com as palavras-chave padrão
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Acrescenta palavras-chave de descadastro
+    pub fn opt_out_keywords<I, S>(mut self, keywords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.opt_out
+            .extend(keywords.into_iter().map(|k| normalize_key(k.as_ref())));
+        self
+    }
+
+    /// Acrescenta palavras-chave de volta ao recebimento
+    pub fn opt_in_keywords<I, S>(mut self, keywords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.opt_in
+            .extend(keywords.into_iter().map(|k| normalize_key(k.as_ref())));
+        self
+    }
+
+    /// Decisão expressa no texto, se for uma resposta de consentimento
+    pub fn classify(&self, text: &str) -> Option<ConsentIntent> {
+        let text = normalize_key(text);
+        if text.is_empty() {
+            None
+        } else if self.opt_out.contains(&text) {
+            Some(ConsentIntent::OptOut)
+        } else if self.opt_in.contains(&text) {
+            Some(ConsentIntent::OptIn)
+        } else {
+            None
+        }
+    }
+
+    /// Decisão expressa na mensagem do webhook
+    pub fn classify_payload(&self, payload: &WebhookPayload) -> Option<ConsentIntent> {
+        payload
+            .get_message_text()
+            .and_then(|text| self.classify(&text))
+    }
+}
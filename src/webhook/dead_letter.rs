@@ -0,0 +1,308 @@
+//! Fila de webhooks cujo processamento falhou
+//!
+//! Quando o [`WebhookRouter`] tem uma [`DeadLetterQueue`] configurada, o
+//! payload cujo handler continua falhando depois de todas as tentativas é
+//! guardado na fila com o erro, em vez de propagar o erro para o servidor
+//! HTTP. Os itens podem ser listados e reprocessados depois (ex: após
+//! corrigir a integração que estava fora do ar).
+//!
+//! # Exemplo
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//! use chatguru::webhook::{DeadLetterQueue, FileDeadLetterQueue, WebhookRouter};
+//!
+//! let queue = Arc::new(FileDeadLetterQueue::open("/var/lib/meu-servico/dlq.json")?);
+//!
+//! let router = WebhookRouter::new()
+//!     .on_message(handle_message)
+//!     .max_attempts(3)
+//!     .retry_delay(Duration::from_secs(1))
+//!     .dead_letter_queue(queue.clone());
+//!
+//! // Mais tarde, em uma rota administrativa:
+//! for item in queue.list().await? {
+//!     println!("{} ({:?}): {}", item.id, item.kind, item.error);
+//! }
+//! let report = queue.retry_all(&router).await?;
+//! ```
+
+use super::dispatcher::{EventKind, WebhookRouter};
+use crate::error::{ChatGuruError, Result};
+use crate::json_file::{JsonFileItem, JsonFileMap};
+use crate::types::WebhookPayload;
+use chrono::{DateTime, Utc};
+use futures_core::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Webhook cujo handler falhou em todas as tentativas
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeadLetter {
+    /// Identificador do item
+    pub id: String,
+    /// Categoria do handler que falhou
+    pub kind: EventKind,
+    /// Payload, como JSON
+    pub payload: Value,
+    /// Último erro retornado pelo handler
+    pub error: String,
+    /// Total de tentativas (incluindo os reprocessamentos pela fila)
+    pub attempts: u32,
+    /// Momento da primeira falha definitiva
+    pub first_failed_at: DateTime<Utc>,
+    /// Momento da última falha
+    pub last_failed_at: DateTime<Utc>,
+}
+
+impl DeadLetter {
+    /// Cria um item para o payload e o erro informados
+    pub fn new(
+        kind: EventKind,
+        payload: &WebhookPayload,
+        error: &ChatGuruError,
+        attempts: u32,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: next_dead_letter_id(),
+            kind,
+            payload: serde_json::to_value(payload).unwrap_or_default(),
+            error: error.to_string(),
+            attempts,
+            first_failed_at: now,
+            last_failed_at: now,
+        }
+    }
+
+    /// Desserializa o payload guardado
+    pub fn payload(&self) -> Result<WebhookPayload> {
+        let body = serde_json::to_vec(&self.payload)?;
+        WebhookPayload::parse_slice(&body)
+            .map_err(|e| ChatGuruError::SerializationError(e.to_string()))
+    }
+}
+
+fn next_dead_letter_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    format!(
+        "dlq-{:x}-{:x}",
+        Utc::now().timestamp_millis(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Resultado de [`DeadLetterQueue::retry_all`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeadLetterReport {
+    /// IDs reprocessados com sucesso (removidos da fila)
+    pub succeeded: Vec<String>,
+    /// IDs que falharam novamente, com o erro
+    pub failed: Vec<(String, String)>,
+}
+
+impl DeadLetterReport {
+    /// Indica se todos os itens foram reprocessados
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Armazenamento dos webhooks que falharam
+///
+/// [`InMemoryDeadLetterQueue`] perde os itens quando o processo termina;
+/// [`FileDeadLetterQueue`] grava os itens em disco. Implemente este trait
+/// para usar outro backend (ex: banco de dados).
+pub trait DeadLetterQueue: Send + Sync {
+    /// Insere ou substitui o item com o mesmo ID
+    fn push<'a>(&'a self, item: &'a DeadLetter) -> BoxFuture<'a, Result<()>>;
+
+    /// Busca um item pelo ID
+    fn get<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<DeadLetter>>>;
+
+    /// Lista os itens, do mais antigo ao mais recente
+    fn list(&self) -> BoxFuture<'_, Result<Vec<DeadLetter>>>;
+
+    /// Remove um item, retornando `true` se ele existia
+    fn remove<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool>>;
+
+    /// Reprocessa um item pelo handler da sua categoria no roteador
+    ///
+    /// Em caso de sucesso o item é removido; em caso de falha ele é
+    /// atualizado com o novo erro e o erro é retornado.
+    ///
+    /// # Erros
+    ///
+    /// Retorna `ValidationError` se o item não existir.
+    fn retry<'a>(&'a self, id: &'a str, router: &'a WebhookRouter) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let Some(mut item) = self.get(id).await? else {
+                return Err(ChatGuruError::ValidationError(format!(
+                    "Dead letter {} not found",
+                    id
+                )));
+            };
+
+            let result = match item.payload() {
+                Ok(payload) => router.dispatch_to(item.kind, payload).await,
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(()) => {
+                    self.remove(id).await?;
+                    tracing::info!("Dead letter {} reprocessed", id);
+                    Ok(())
+                }
+                Err(e) => {
+                    item.attempts += 1;
+                    item.error = e.to_string();
+                    item.last_failed_at = Utc::now();
+                    self.push(&item).await?;
+                    Err(e)
+                }
+            }
+        })
+    }
+
+    /// Reprocessa todos os itens, do mais antigo ao mais recente
+    ///
+    /// Só retorna erro se a fila não puder ser lida.
+    fn retry_all<'a>(
+        &'a self,
+        router: &'a WebhookRouter,
+    ) -> BoxFuture<'a, Result<DeadLetterReport>> {
+        Box::pin(async move {
+            let mut report = DeadLetterReport::default();
+            for item in self.list().await? {
+                match self.retry(&item.id, router).await {
+                    Ok(()) => report.succeeded.push(item.id),
+                    Err(e) => report.failed.push((item.id, e.to_string())),
+                }
+            }
+            Ok(report)
+        })
+    }
+}
+
+fn sorted(mut items: Vec<DeadLetter>) -> Vec<DeadLetter> {
+    items.sort_by(|a, b| {
+        a.first_failed_at
+            .cmp(&b.first_failed_at)
+            .then(a.id.cmp(&b.id))
+    });
+    items
+}
+
+/// Fila em memória
+#[derive(Debug, Default)]
+pub struct InMemoryDeadLetterQueue {
+    items: Mutex<HashMap<String, DeadLetter>>,
+}
+
+impl InMemoryDeadLetterQueue {
+    /// Cria uma fila vazia
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Quantidade de itens na fila
+    pub fn len(&self) -> usize {
+        self.items().len()
+    }
+
+    /// Indica se a fila está vazia
+    pub fn is_empty(&self) -> bool {
+        self.items().is_empty()
+    }
+
+    fn items(&self) -> std::sync::MutexGuard<'_, HashMap<String, DeadLetter>> {
+        self.items.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl DeadLetterQueue for InMemoryDeadLetterQueue {
+    fn push<'a>(&'a self, item: &'a DeadLetter) -> BoxFuture<'a, Result<()>> {
+        self.items().insert(item.id.clone(), item.clone());
+        Box::pin(async { Ok(()) })
+    }
+
+    fn get<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<DeadLetter>>> {
+        let item = self.items().get(id).cloned();
+        Box::pin(async move { Ok(item) })
+    }
+
+    fn list(&self) -> BoxFuture<'_, Result<Vec<DeadLetter>>> {
+        let items = sorted(self.items().values().cloned().collect());
+        Box::pin(async move { Ok(items) })
+    }
+
+    fn remove<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool>> {
+        let removed = self.items().remove(id).is_some();
+        Box::pin(async move { Ok(removed) })
+    }
+}
+
+/// Fila em arquivo JSON, que sobrevive a reinícios do processo
+///
+/// Mesmo funcionamento de [`FileOutboxStore`](crate::client::FileOutboxStore):
+/// os itens ficam em memória e o arquivo é regravado (via arquivo temporário
+/// e renomeação) a cada alteração.
+#[derive(Debug)]
+pub struct FileDeadLetterQueue {
+    items: JsonFileMap<DeadLetter>,
+}
+
+impl JsonFileItem for DeadLetter {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        a.first_failed_at
+            .cmp(&b.first_failed_at)
+            .then_with(|| a.id.cmp(&b.id))
+    }
+}
+
+impl FileDeadLetterQueue {
+    /// Abre (ou cria) o arquivo da fila, carregando os itens existentes
+    ///
+    /// # Erros
+    ///
+    /// Retorna `InternalError` se o arquivo existir mas não puder ser lido, e
+    /// `SerializationError` se o conteúdo não for uma fila válida.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self {
+            items: JsonFileMap::open(path, "dead letter queue")?,
+        })
+    }
+
+    /// Caminho do arquivo da fila
+    pub fn path(&self) -> &Path {
+        self.items.path()
+    }
+}
+
+impl DeadLetterQueue for FileDeadLetterQueue {
+    fn push<'a>(&'a self, item: &'a DeadLetter) -> BoxFuture<'a, Result<()>> {
+        Box::pin(self.items.insert(item))
+    }
+
+    fn get<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<DeadLetter>>> {
+        Box::pin(async move { Ok(self.items.get(id).await) })
+    }
+
+    fn list(&self) -> BoxFuture<'_, Result<Vec<DeadLetter>>> {
+        Box::pin(async move { Ok(self.items.values().await) })
+    }
+
+    fn remove<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool>> {
+        Box::pin(self.items.remove(id))
+    }
+}
@@ -0,0 +1,177 @@
+use crate::error::Result;
+use crate::types::WebhookPayload;
+use futures_core::future::BoxFuture;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Armazenamento das chaves de eventos já vistos
+///
+/// A implementação padrão é [`InMemoryDedupStore`]; implemente este trait
+/// para compartilhar o estado entre instâncias (ex: Redis com `SET NX EX`).
+pub trait DedupStore: Send + Sync {
+    /// Registra a chave e retorna `true` se ela ainda não tinha sido vista
+    /// dentro do `ttl`
+    fn insert_if_absent<'a>(&'a self, key: &'a str, ttl: Duration) -> BoxFuture<'a, Result<bool>>;
+
+    /// Esquece a chave, permitindo que ela seja registrada novamente
+    ///
+    /// A implementação padrão não faz nada (a chave expira pelo TTL).
+    fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<()>> {
+        let _ = key;
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Armazenamento em memória, limitado em tamanho e com expiração por TTL
+///
+/// Quando a capacidade é atingida, as chaves mais antigas são descartadas.
+#[derive(Debug)]
+pub struct InMemoryDedupStore {
+    capacity: usize,
+    state: Mutex<DedupState>,
+}
+
+#[derive(Debug, Default)]
+struct DedupState {
+    seen: HashMap<String, Instant>,
+    order: VecDeque<(String, Instant)>,
+}
+
+impl InMemoryDedupStore {
+    /// Cria um armazenamento com a capacidade máxima de chaves informada
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            state: Mutex::new(DedupState::default()),
+        }
+    }
+
+    /// Quantidade de chaves atualmente armazenadas
+    pub fn len(&self) -> usize {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .seen
+            .len()
+    }
+
+    /// Indica se não há chaves armazenadas
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn insert_sync(&self, key: &str, ttl: Duration) -> bool {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        // Remover entradas expiradas (a fila está em ordem de inserção)
+        while let Some((old_key, inserted_at)) = state.order.front().cloned() {
+            let expired = now.duration_since(inserted_at) >= ttl;
+            if !expired && state.order.len() < self.capacity {
+                break;
+            }
+            state.order.pop_front();
+            if state.seen.get(&old_key) == Some(&inserted_at) {
+                state.seen.remove(&old_key);
+            }
+        }
+
+        if let Some(inserted_at) = state.seen.get(key) {
+            if now.duration_since(*inserted_at) < ttl {
+                return false;
+            }
+        }
+
+        state.seen.insert(key.to_string(), now);
+        state.order.push_back((key.to_string(), now));
+        true
+    }
+}
+
+impl Default for InMemoryDedupStore {
+    fn default() -> Self {
+        Self::new(10_000)
+    }
+}
+
+impl DedupStore for InMemoryDedupStore {
+    fn insert_if_absent<'a>(&'a self, key: &'a str, ttl: Duration) -> BoxFuture<'a, Result<bool>> {
+        let is_new = self.insert_sync(key, ttl);
+        Box::pin(async move { Ok(is_new) })
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<()>> {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .seen
+            .remove(key);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Descarta webhooks reenviados pelo ChatGuru
+///
+/// Usa [`WebhookPayload::event_fingerprint`] como chave e considera
+/// duplicado qualquer payload com a mesma chave dentro do TTL (padrão: 10
+/// minutos).
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::webhook::Deduplicator;
+///
+/// let dedup = Deduplicator::in_memory();
+///
+/// if !dedup.is_new(&payload).await? {
+///     return Ok(()); // reentrega, já processado
+/// }
+/// router.dispatch(payload).await?;
+/// ```
+#[derive(Clone)]
+pub struct Deduplicator {
+    store: Arc<dyn DedupStore>,
+    ttl: Duration,
+}
+
+impl Deduplicator {
+    /// TTL padrão das chaves
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(600);
+
+    /// Cria um deduplicador com o armazenamento informado
+    pub fn new(store: Arc<dyn DedupStore>) -> Self {
+        Self {
+            store,
+            ttl: Self::DEFAULT_TTL,
+        }
+    }
+
+    /// Cria um deduplicador em memória com a capacidade padrão
+    pub fn in_memory() -> Self {
+        Self::new(Arc::new(InMemoryDedupStore::default()))
+    }
+
+    /// Define por quanto tempo um evento é lembrado
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Registra o payload e retorna `true` se ele ainda não tinha sido visto
+    pub async fn is_new(&self, payload: &WebhookPayload) -> Result<bool> {
+        let key = payload.event_fingerprint();
+        let is_new = self.store.insert_if_absent(&key, self.ttl).await?;
+
+        if !is_new {
+            tracing::info!("Ignoring duplicated webhook {}", key);
+        }
+
+        Ok(is_new)
+    }
+
+    /// Registra o payload e retorna `true` se ele já tinha sido visto
+    pub async fn is_duplicate(&self, payload: &WebhookPayload) -> Result<bool> {
+        self.is_new(payload).await.map(|is_new| !is_new)
+    }
+}
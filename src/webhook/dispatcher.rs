@@ -0,0 +1,491 @@
+use super::dead_letter::{DeadLetter, DeadLetterQueue};
+use super::filter::Filter;
+use crate::error::Result;
+use crate::types::WebhookPayload;
+use futures_core::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Quantidade padrão de chats com campos personalizados guardados pelo [`WebhookRouter`]
+pub const DEFAULT_CUSTOM_FIELDS_CAPACITY: usize = 10_000;
+
+/// Tempo padrão que o [`WebhookRouter`] guarda os campos personalizados de um chat (24 horas)
+pub const DEFAULT_CUSTOM_FIELDS_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Categoria de um webhook recebido, usada para escolher o handler
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EventKind {
+    /// Mensagem de texto enviada pelo contato
+    MessageReceived,
+    /// Mensagem com mídia anexada (imagem, áudio, vídeo, documento)
+    MediaReceived,
+    /// Evento de campanha/funil sem mensagem (inclui o formato legado `event_type`)
+    CampaignEvent,
+    /// Campos personalizados do chat mudaram desde o último webhook
+    CustomFieldsChanged,
+    /// Payload que não se encaixa em nenhuma categoria
+    Unknown,
+}
+
+impl EventKind {
+    /// Classifica um payload na sua categoria principal
+    ///
+    /// `CustomFieldsChanged` depende do histórico do chat e só é detectado
+    /// pelo [`WebhookRouter`].
+    pub fn classify(payload: &WebhookPayload) -> Self {
+        if payload.has_media() {
+            return EventKind::MediaReceived;
+        }
+        if payload.get_message_text().is_some() {
+            return EventKind::MessageReceived;
+        }
+        match payload {
+            WebhookPayload::ChatGuru(p) if !p.campanha_id.is_empty() => EventKind::CampaignEvent,
+            WebhookPayload::EventType(_) => EventKind::CampaignEvent,
+            WebhookPayload::Generic(p) if p.campaign().is_some() => EventKind::CampaignEvent,
+            _ => EventKind::Unknown,
+        }
+    }
+}
+
+type Handler = Arc<dyn Fn(WebhookPayload) -> BoxFuture<'static, Result<()>> + Send + Sync>;
+
+/// Resultado de [`WebhookRouter::dispatch`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DispatchOutcome {
+    /// Categoria principal do payload
+    pub kind: EventKind,
+    /// Categorias cujos handlers foram executados, na ordem de execução
+    pub handled: Vec<EventKind>,
+    /// Categorias cujos handlers falharam e tiveram o payload enviado para a
+    /// [`DeadLetterQueue`]
+    pub dead_lettered: Vec<EventKind>,
+}
+
+impl DispatchOutcome {
+    /// Indica se algum handler foi executado
+    pub fn was_handled(&self) -> bool {
+        !self.handled.is_empty()
+    }
+}
+
+/// Roteador de webhooks com handlers assíncronos por categoria de evento
+///
+/// Substitui os `match` sobre [`WebhookPayload`] espalhados pelos serviços:
+/// cada categoria ([`EventKind`]) recebe seu handler e o roteador classifica
+/// o payload e chama o handler correspondente.
+///
+/// Quando há handler para `CustomFieldsChanged`, o roteador guarda os
+/// campos personalizados vistos por chat e dispara o handler (além do
+/// handler da categoria principal) quando eles mudam. O histórico é
+/// limitado em quantidade de chats e em tempo
+/// ([`custom_fields_history`](Self::custom_fields_history)); um chat
+/// descartado volta a ser tratado como novo.
+///
+/// Rotas com [`Filter`] ([`route`](Self::route)) são avaliadas antes das
+/// categorias: o handler da primeira rota que aceitar o payload substitui o
+/// da categoria principal.
+///
+/// Handlers que falham podem ser repetidos ([`max_attempts`](Self::max_attempts))
+/// e, esgotadas as tentativas, o payload pode ir para uma
+/// [`DeadLetterQueue`] em vez de o erro ser propagado.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::webhook::{Filter, WebhookRouter};
+///
+/// let router = WebhookRouter::new()
+///     .on_message(|payload| async move {
+///         println!("Mensagem: {:?}", payload.get_message_text());
+///         Ok(())
+///     })
+///     .on_media(|payload| async move {
+///         println!("Mídia: {:?}", payload.get_media_url());
+///         Ok(())
+///     })
+///     .route(Filter::campaign("Black Friday").and(Filter::tag("vip")), |payload| async move {
+///         println!("Cliente VIP na Black Friday: {}", payload.get_contact_name());
+///         Ok(())
+///     })
+///     .fallback(|_| async { Ok(()) });
+///
+/// router.dispatch(payload).await?;
+/// ```
+#[derive(Clone, Default)]
+pub struct WebhookRouter {
+    handlers: HashMap<EventKind, Handler>,
+    routes: Vec<(Filter, Handler)>,
+    fallback: Option<Handler>,
+    custom_fields: Arc<Mutex<CustomFieldsHistory>>,
+    max_attempts: u32,
+    retry_delay: Duration,
+    dead_letters: Option<Arc<dyn DeadLetterQueue>>,
+}
+
+impl fmt::Debug for WebhookRouter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookRouter")
+            .field("handlers", &self.handlers.keys().collect::<Vec<_>>())
+            .field(
+                "routes",
+                &self
+                    .routes
+                    .iter()
+                    .map(|(filter, _)| filter)
+                    .collect::<Vec<_>>(),
+            )
+            .field("fallback", &self.fallback.is_some())
+            .field("max_attempts", &self.max_attempts.max(1))
+            .field("retry_delay", &self.retry_delay)
+            .field("dead_letter_queue", &self.dead_letters.is_some())
+            .finish()
+    }
+}
+
+impl WebhookRouter {
+    /// Cria um roteador sem handlers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registra o handler de uma categoria (substitui o anterior)
+    pub fn on<F, Fut>(mut self, kind: EventKind, handler: F) -> Self
+    where
+        F: Fn(WebhookPayload) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.handlers.insert(kind, boxed(handler));
+        self
+    }
+
+    /// Registra o handler dos payloads aceitos pelo filtro
+    ///
+    /// As rotas são avaliadas na ordem de registro, antes dos handlers por
+    /// categoria; só a primeira que aceitar o payload é executada.
+    pub fn route<F, Fut>(mut self, filter: Filter, handler: F) -> Self
+    where
+        F: Fn(WebhookPayload) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.routes.push((filter, boxed(handler)));
+        self
+    }
+
+    /// Handler para mensagens de texto
+    pub fn on_message<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(WebhookPayload) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.on(EventKind::MessageReceived, handler)
+    }
+
+    /// Handler para mensagens com mídia
+    pub fn on_media<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(WebhookPayload) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.on(EventKind::MediaReceived, handler)
+    }
+
+    /// Handler para eventos de campanha/funil
+    pub fn on_campaign<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(WebhookPayload) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.on(EventKind::CampaignEvent, handler)
+    }
+
+    /// Handler para mudanças nos campos personalizados de um chat
+    pub fn on_custom_fields_changed<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(WebhookPayload) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.on(EventKind::CustomFieldsChanged, handler)
+    }
+
+    /// Limita o histórico de campos personalizados usado por `CustomFieldsChanged`
+    ///
+    /// Guarda no máximo `capacity` chats (os mais antigos são descartados) e
+    /// esquece um chat `ttl` após o último webhook dele. Padrão:
+    /// [`DEFAULT_CUSTOM_FIELDS_CAPACITY`] chats por [`DEFAULT_CUSTOM_FIELDS_TTL`].
+    pub fn custom_fields_history(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.custom_fields = Arc::new(Mutex::new(CustomFieldsHistory::new(capacity, ttl)));
+        self
+    }
+
+    /// Handler chamado quando nenhum outro corresponde ao payload
+    pub fn fallback<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(WebhookPayload) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.fallback = Some(boxed(handler));
+        self
+    }
+
+    /// Define o número máximo de execuções de um handler que falha (padrão: 1)
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Define o intervalo entre as tentativas de um handler (padrão: nenhum)
+    pub fn retry_delay(mut self, delay: Duration) -> Self {
+        self.retry_delay = delay;
+        self
+    }
+
+    /// Envia para a fila os payloads cujo handler falhou em todas as tentativas
+    ///
+    /// Com a fila configurada, [`dispatch`](Self::dispatch) só retorna erro se
+    /// o item não puder ser gravado na fila.
+    pub fn dead_letter_queue(mut self, queue: Arc<dyn DeadLetterQueue>) -> Self {
+        self.dead_letters = Some(queue);
+        self
+    }
+
+    /// Classifica o payload e executa os handlers correspondentes
+    ///
+    /// Executa primeiro o handler da primeira rota que aceitar o payload (ou,
+    /// sem rota, o da categoria principal) e depois, se for o caso, o de
+    /// `CustomFieldsChanged`. Se nenhum handler corresponder, usa o
+    /// fallback. Sem [`DeadLetterQueue`], retorna o primeiro erro produzido
+    /// por um handler (após as tentativas configuradas).
+    pub async fn dispatch(&self, payload: WebhookPayload) -> Result<DispatchOutcome> {
+        let kind = EventKind::classify(&payload);
+        let mut to_run = Vec::new();
+        let mut dead_lettered = Vec::new();
+
+        if let Some(handler) = self.route_for(&payload).or(self.handlers.get(&kind)) {
+            to_run.push((kind, handler.clone()));
+        }
+        if let Some(handler) = self.handlers.get(&EventKind::CustomFieldsChanged) {
+            if self.custom_fields_changed(&payload) {
+                to_run.push((EventKind::CustomFieldsChanged, handler.clone()));
+            }
+        }
+
+        if to_run.is_empty() {
+            tracing::debug!("No webhook handler registered for {:?}", kind);
+            if let Some(ref fallback) = self.fallback {
+                if !self.run(kind, fallback, &payload).await? {
+                    dead_lettered.push(kind);
+                }
+            }
+            return Ok(DispatchOutcome {
+                kind,
+                handled: Vec::new(),
+                dead_lettered,
+            });
+        }
+
+        let mut handled = Vec::with_capacity(to_run.len());
+        for (handled_kind, handler) in to_run {
+            if self.run(handled_kind, &handler, &payload).await? {
+                handled.push(handled_kind);
+            } else {
+                dead_lettered.push(handled_kind);
+            }
+        }
+
+        Ok(DispatchOutcome {
+            kind,
+            handled,
+            dead_lettered,
+        })
+    }
+
+    /// Executa apenas o handler da rota que aceitar o payload ou da categoria
+    /// (ou o fallback, se nenhum corresponder), sem repetições nem fila
+    ///
+    /// Usado para reprocessar itens da [`DeadLetterQueue`].
+    pub async fn dispatch_to(&self, kind: EventKind, payload: WebhookPayload) -> Result<()> {
+        let handler = match kind {
+            EventKind::CustomFieldsChanged => self.handlers.get(&kind),
+            _ => self.route_for(&payload).or(self.handlers.get(&kind)),
+        };
+        match handler.or(self.fallback.as_ref()) {
+            Some(handler) => handler(payload).await,
+            None => {
+                tracing::debug!("No webhook handler registered for {:?}", kind);
+                Ok(())
+            }
+        }
+    }
+
+    /// Handler da primeira rota que aceitar o payload
+    fn route_for(&self, payload: &WebhookPayload) -> Option<&Handler> {
+        self.routes
+            .iter()
+            .find(|(filter, _)| filter.matches(payload))
+            .map(|(_, handler)| handler)
+    }
+
+    /// Executa o handler com as tentativas configuradas
+    ///
+    /// Retorna `Ok(false)` se o handler falhou e o payload foi para a fila.
+    async fn run(
+        &self,
+        kind: EventKind,
+        handler: &Handler,
+        payload: &WebhookPayload,
+    ) -> Result<bool> {
+        let max_attempts = self.max_attempts.max(1);
+        let mut attempt = 1;
+        let error = loop {
+            match handler(payload.clone()).await {
+                Ok(()) => return Ok(true),
+                Err(e) if attempt >= max_attempts => break e,
+                Err(e) => {
+                    tracing::warn!(
+                        "Webhook handler for {:?} failed (attempt {}/{}): {}",
+                        kind,
+                        attempt,
+                        max_attempts,
+                        e
+                    );
+                    attempt += 1;
+                    if !self.retry_delay.is_zero() {
+                        tokio::time::sleep(self.retry_delay).await;
+                    }
+                }
+            }
+        };
+
+        let Some(ref queue) = self.dead_letters else {
+            return Err(error);
+        };
+
+        let item = DeadLetter::new(kind, payload, &error, attempt);
+        if let Err(e) = queue.push(&item).await {
+            tracing::error!("Failed to store dead letter for {:?}: {}", kind, e);
+            return Err(error);
+        }
+        tracing::warn!(
+            "Webhook handler for {:?} failed after {} attempts, stored as dead letter {}: {}",
+            kind,
+            attempt,
+            item.id,
+            error
+        );
+        Ok(false)
+    }
+
+    /// Compara os campos personalizados com os últimos vistos para o chat
+    fn custom_fields_changed(&self, payload: &WebhookPayload) -> bool {
+        let WebhookPayload::ChatGuru(p) = payload else {
+            return false;
+        };
+        let Some(chat_key) = p.chat_id.clone().or_else(|| payload.get_phone_number()) else {
+            return false;
+        };
+
+        self.custom_fields
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .replace(chat_key, &p.campos_personalizados)
+    }
+}
+
+/// Últimos campos personalizados vistos por chat, limitado em tamanho e com TTL
+#[derive(Debug)]
+struct CustomFieldsHistory {
+    capacity: usize,
+    ttl: Duration,
+    seen: HashMap<String, (HashMap<String, Value>, Instant)>,
+    /// Chaves em ordem de gravação (pode conter gravações já substituídas)
+    order: VecDeque<(String, Instant)>,
+}
+
+impl Default for CustomFieldsHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_CUSTOM_FIELDS_CAPACITY, DEFAULT_CUSTOM_FIELDS_TTL)
+    }
+}
+
+impl CustomFieldsHistory {
+    fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            ttl,
+            seen: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Grava os campos do chat e retorna `true` se eles mudaram desde a última gravação
+    fn replace(&mut self, chat_key: String, fields: &HashMap<String, Value>) -> bool {
+        let now = Instant::now();
+
+        // Descarta gravações expiradas e, acima da capacidade, as mais antigas
+        while let Some((old_key, stored_at)) = self.order.front().cloned() {
+            let expired = now.duration_since(stored_at) >= self.ttl;
+            if !expired && self.order.len() < self.capacity {
+                break;
+            }
+            self.order.pop_front();
+            if matches!(self.seen.get(&old_key), Some((_, at)) if *at == stored_at) {
+                self.seen.remove(&old_key);
+            }
+        }
+
+        self.order.push_back((chat_key.clone(), now));
+        let previous = self.seen.insert(chat_key, (fields.clone(), now));
+        matches!(previous, Some((previous, _)) if previous != *fields)
+    }
+}
+
+fn boxed<F, Fut>(handler: F) -> Handler
+where
+    F: Fn(WebhookPayload) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<()>> + Send + 'static,
+{
+    Arc::new(move |payload| Box::pin(handler(payload)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(value: &str) -> HashMap<String, Value> {
+        HashMap::from([("Etapa".to_string(), Value::from(value))])
+    }
+
+    #[test]
+    fn history_detects_changes_per_chat() {
+        let mut history = CustomFieldsHistory::default();
+        assert!(!history.replace("a".to_string(), &fields("1")));
+        assert!(!history.replace("a".to_string(), &fields("1")));
+        assert!(history.replace("a".to_string(), &fields("2")));
+    }
+
+    #[test]
+    fn history_is_bounded_by_capacity() {
+        let mut history = CustomFieldsHistory::new(2, DEFAULT_CUSTOM_FIELDS_TTL);
+        for chat in 0..100 {
+            history.replace(chat.to_string(), &fields("1"));
+        }
+        assert!(history.seen.len() <= 2);
+        assert!(history.order.len() <= 2);
+        // O chat mais antigo foi descartado e volta como novo
+        assert!(!history.replace("0".to_string(), &fields("2")));
+    }
+
+    #[test]
+    fn history_forgets_expired_chats() {
+        let mut history = CustomFieldsHistory::new(10, Duration::ZERO);
+        history.replace("a".to_string(), &fields("1"));
+        assert!(!history.replace("a".to_string(), &fields("2")));
+        assert_eq!(history.seen.len(), 1);
+    }
+}
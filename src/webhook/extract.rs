@@ -0,0 +1,371 @@
+use super::verify::{self, HmacSha256, SignatureScheme, SIGNATURE_HEADER};
+use crate::error::ChatGuruError;
+use crate::types::{MediaTypeMap, ParsedWebhook, WebhookPayload};
+use std::fmt;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Tamanho máximo padrão do corpo de um webhook (1 MiB)
+pub const DEFAULT_MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// Motivo pelo qual uma requisição de webhook foi rejeitada
+///
+/// Cada variante tem um status HTTP sugerido em [`WebhookRejection::status_code`],
+/// para o handler responder ao ChatGuru sem lógica própria.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum WebhookRejection {
+    /// Corpo maior que o limite configurado
+    #[error("Webhook body too large: {size} bytes (limit {limit})")]
+    PayloadTooLarge { size: usize, limit: usize },
+
+    /// Content-Type diferente de JSON
+    #[error("Unsupported webhook content type: {0}")]
+    UnsupportedContentType(String),
+
+    /// Assinatura ausente ou inválida
+    #[error("Invalid webhook signature: {0}")]
+    InvalidSignature(String),
+
+    /// Corpo não corresponde a nenhum formato de webhook conhecido
+    #[error("Invalid webhook payload: {0}")]
+    InvalidPayload(String),
+
+    /// O webhook é válido, mas não pode ser aceito agora (ex: fila cheia)
+    #[error("Webhook receiver unavailable: {0}")]
+    Unavailable(String),
+
+    /// A fila de processamento está cheia; o ChatGuru deve reenviar mais tarde
+    #[error("Webhook queue is full ({depth} pending)")]
+    QueueFull { depth: usize },
+}
+
+impl WebhookRejection {
+    /// Status HTTP sugerido para responder à requisição rejeitada
+    pub fn status_code(&self) -> u16 {
+        match self {
+            WebhookRejection::PayloadTooLarge { .. } => 413,
+            WebhookRejection::UnsupportedContentType(_) => 415,
+            WebhookRejection::InvalidSignature(_) => 401,
+            WebhookRejection::InvalidPayload(_) => 400,
+            WebhookRejection::Unavailable(_) => 503,
+            WebhookRejection::QueueFull { .. } => 429,
+        }
+    }
+}
+
+impl From<WebhookRejection> for ChatGuruError {
+    fn from(rejection: WebhookRejection) -> Self {
+        match rejection {
+            WebhookRejection::InvalidPayload(_) => {
+                ChatGuruError::SerializationError(rejection.to_string())
+            }
+            WebhookRejection::Unavailable(_) | WebhookRejection::QueueFull { .. } => {
+                ChatGuruError::InternalError(rejection.to_string())
+            }
+            _ => ChatGuruError::ValidationError(rejection.to_string()),
+        }
+    }
+}
+
+/// Webhook do ChatGuru já verificado, desserializado e normalizado
+///
+/// Resultado de [`WebhookExtractor::extract`]. Derreferencia para
+/// [`WebhookPayload`].
+#[derive(Debug, Clone)]
+pub struct ChatGuruWebhook(pub WebhookPayload);
+
+impl ChatGuruWebhook {
+    /// Consome o wrapper e retorna o payload
+    pub fn into_inner(self) -> WebhookPayload {
+        self.0
+    }
+}
+
+impl std::ops::Deref for ChatGuruWebhook {
+    type Target = WebhookPayload;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Processa requisições de webhook recebidas por qualquer servidor HTTP
+///
+/// Concentra o trabalho repetido em todo serviço que recebe webhooks do
+/// ChatGuru: lê o corpo bruto, verifica a assinatura (se um segredo foi
+/// configurado), desserializa para [`WebhookPayload`] e chama
+/// `normalize_media_fields()`.
+///
+/// Também aplica um limite de tamanho do corpo (padrão: 1 MiB) e, quando os
+/// headers estão disponíveis, exige `Content-Type` JSON.
+///
+/// O extractor não depende de framework: o handler só precisa repassar o
+/// corpo bruto e uma função de leitura de headers.
+///
+/// # Exemplo (axum)
+///
+/// Com a feature `axum`, [`ChatGuruWebhook`] implementa `FromRequest`, lendo
+/// o `WebhookExtractor` do estado da aplicação (ou via `FromRef`). O corpo é
+/// lido até o limite configurado e requisições rejeitadas respondem com
+/// [`WebhookRejection::status_code`].
+///
+/// ```rust,ignore
+/// use axum::{routing::post, Router};
+/// use chatguru::webhook::{ChatGuruWebhook, WebhookExtractor};
+///
+/// async fn handler(webhook: ChatGuruWebhook) -> &'static str {
+///     println!("Contato: {}", webhook.get_contact_name());
+///     "ok"
+/// }
+///
+/// let app = Router::new()
+///     .route("/webhook", post(handler))
+///     .with_state(WebhookExtractor::new().with_secret(secret));
+/// ```
+///
+/// # Exemplo (actix-web)
+///
+/// Com a feature `actix`, [`ChatGuruWebhook`] implementa `FromRequest`, lendo
+/// o `WebhookExtractor` registrado com `app_data`. O limite de tamanho e o
+/// `Content-Type` são validados antes de ler o corpo.
+///
+/// ```rust,ignore
+/// use actix_web::{web, App, HttpResponse};
+/// use chatguru::webhook::{ChatGuruWebhook, WebhookExtractor};
+///
+/// async fn handler(webhook: ChatGuruWebhook) -> HttpResponse {
+///     println!("Contato: {}", webhook.get_contact_name());
+///     HttpResponse::Ok().finish()
+/// }
+///
+/// let app = App::new()
+///     .app_data(web::Data::new(WebhookExtractor::new().with_secret(secret)))
+///     .route("/webhook", web::post().to(handler));
+/// ```
+#[derive(Clone)]
+pub struct WebhookExtractor {
+    secret: Option<String>,
+    scheme: Arc<dyn SignatureScheme>,
+    signature_header: String,
+    normalize_media: bool,
+    media_types: MediaTypeMap,
+    max_body_size: usize,
+    require_json: bool,
+}
+
+impl Default for WebhookExtractor {
+    fn default() -> Self {
+        Self {
+            secret: None,
+            scheme: Arc::new(HmacSha256),
+            signature_header: SIGNATURE_HEADER.to_string(),
+            normalize_media: true,
+            media_types: MediaTypeMap::default(),
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            require_json: true,
+        }
+    }
+}
+
+impl fmt::Debug for WebhookExtractor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookExtractor")
+            .field("verifies_signature", &self.secret.is_some())
+            .field("scheme", &self.scheme.name())
+            .field("signature_header", &self.signature_header)
+            .field("normalize_media", &self.normalize_media)
+            .field("media_types", &self.media_types)
+            .field("max_body_size", &self.max_body_size)
+            .field("require_json", &self.require_json)
+            .finish()
+    }
+}
+
+impl WebhookExtractor {
+    /// Cria um extractor sem verificação de assinatura
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Exige assinatura válida com o segredo informado
+    pub fn with_secret(mut self, secret: impl Into<String>) -> Self {
+        self.secret = Some(secret.into());
+        self
+    }
+
+    /// Usa outro esquema de assinatura (padrão: HMAC-SHA256)
+    pub fn with_scheme(mut self, scheme: impl SignatureScheme + 'static) -> Self {
+        self.scheme = Arc::new(scheme);
+        self
+    }
+
+    /// Define o header lido para a assinatura (padrão: `X-ChatGuru-Signature`)
+    pub fn signature_header(mut self, name: impl Into<String>) -> Self {
+        self.signature_header = name.into();
+        self
+    }
+
+    /// Liga/desliga a chamada automática de `normalize_media_fields()`
+    pub fn normalize_media(mut self, enabled: bool) -> Self {
+        self.normalize_media = enabled;
+        self
+    }
+
+    /// Define o mapeamento `tipo_mensagem → MIME type` usado na normalização
+    pub fn media_types(mut self, media_types: MediaTypeMap) -> Self {
+        self.media_types = media_types;
+        self
+    }
+
+    /// Registra o MIME type de um `tipo_mensagem` (substitui o padrão)
+    pub fn media_type_mapping(mut self, tipo: &str, mime: impl Into<String>) -> Self {
+        self.media_types = self.media_types.register(tipo, mime);
+        self
+    }
+
+    /// Define o tamanho máximo aceito para o corpo (padrão: 1 MiB)
+    pub fn max_body_size(mut self, bytes: usize) -> Self {
+        self.max_body_size = bytes;
+        self
+    }
+
+    /// Liga/desliga a exigência de `Content-Type` JSON (padrão: ligada)
+    ///
+    /// Requisições sem o header `Content-Type` são aceitas.
+    pub fn require_json(mut self, enabled: bool) -> Self {
+        self.require_json = enabled;
+        self
+    }
+
+    /// Nome do header de assinatura configurado
+    pub fn signature_header_name(&self) -> &str {
+        &self.signature_header
+    }
+
+    /// Tamanho máximo configurado para o corpo, em bytes
+    ///
+    /// Use para configurar o limite do próprio framework (ex:
+    /// `web::PayloadConfig` no actix) e evitar ler corpos maiores.
+    pub fn body_limit(&self) -> usize {
+        self.max_body_size
+    }
+
+    /// Processa um webhook a partir do corpo bruto e da assinatura recebida
+    ///
+    /// # Erros
+    ///
+    /// * `PayloadTooLarge` - corpo maior que o limite configurado
+    /// * `InvalidSignature` - assinatura ausente ou inválida (quando há segredo)
+    /// * `InvalidPayload` - corpo não corresponde a nenhum formato conhecido
+    pub fn extract(
+        &self,
+        raw_body: &[u8],
+        signature: Option<&str>,
+    ) -> Result<ChatGuruWebhook, WebhookRejection> {
+        self.extract_with_raw(raw_body, signature)
+            .map(|parsed| ChatGuruWebhook(parsed.payload))
+    }
+
+    /// Como [`extract`](Self::extract), mantendo também o JSON original
+    ///
+    /// A normalização de mídia é aplicada apenas ao payload; `raw` fica como
+    /// recebido, para auditoria ou reprocessamento.
+    ///
+    /// # Erros
+    ///
+    /// Os mesmos de [`extract`](Self::extract).
+    pub fn extract_with_raw(
+        &self,
+        raw_body: &[u8],
+        signature: Option<&str>,
+    ) -> Result<ParsedWebhook, WebhookRejection> {
+        self.check_body_size(raw_body.len())?;
+
+        if let Some(ref secret) = self.secret {
+            verify::verify_with(
+                self.scheme.as_ref(),
+                raw_body,
+                signature.unwrap_or_default(),
+                secret.as_bytes(),
+            )
+            .map_err(|e| WebhookRejection::InvalidSignature(e.to_string()))?;
+        }
+
+        let mut parsed = WebhookPayload::parse_report(raw_body)
+            .map_err(|e| WebhookRejection::InvalidPayload(e.to_string()))?;
+
+        self.normalize(&mut parsed.payload);
+        Ok(parsed)
+    }
+
+    /// Normaliza os campos de mídia conforme a configuração do extractor
+    pub(crate) fn normalize(&self, payload: &mut WebhookPayload) {
+        if self.normalize_media {
+            payload.normalize_media_fields_with(&self.media_types);
+        }
+    }
+
+    /// Processa um webhook lendo os headers através de uma função de acesso
+    ///
+    /// `header` recebe o nome do header e retorna seu valor, permitindo usar
+    /// o tipo de headers de qualquer framework HTTP. Além das validações de
+    /// [`extract`](Self::extract), verifica o `Content-Type`.
+    pub fn extract_with_headers<'h, F>(
+        &self,
+        raw_body: &[u8],
+        header: F,
+    ) -> Result<ChatGuruWebhook, WebhookRejection>
+    where
+        F: Fn(&str) -> Option<&'h str>,
+    {
+        self.check_content_type(header("Content-Type"))?;
+        self.extract(raw_body, header(&self.signature_header))
+    }
+
+    /// Rejeita corpos maiores que o limite configurado
+    pub(crate) fn check_body_size(&self, size: usize) -> Result<(), WebhookRejection> {
+        if size > self.max_body_size {
+            return Err(WebhookRejection::PayloadTooLarge {
+                size,
+                limit: self.max_body_size,
+            });
+        }
+        Ok(())
+    }
+
+    /// Rejeita pelo `Content-Length` corpos maiores que o limite, antes de lê-los
+    #[cfg(any(feature = "axum", feature = "actix"))]
+    pub(crate) fn check_content_length(
+        &self,
+        content_length: Option<&str>,
+    ) -> Result<(), WebhookRejection> {
+        match content_length.and_then(|value| value.trim().parse::<usize>().ok()) {
+            Some(size) => self.check_body_size(size),
+            None => Ok(()),
+        }
+    }
+
+    /// Valida o `Content-Type` da requisição
+    pub fn check_content_type(&self, content_type: Option<&str>) -> Result<(), WebhookRejection> {
+        let Some(content_type) = content_type else {
+            return Ok(());
+        };
+
+        if !self.require_json {
+            return Ok(());
+        }
+
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+
+        if mime == "application/json" || mime.ends_with("+json") {
+            Ok(())
+        } else {
+            Err(WebhookRejection::UnsupportedContentType(mime))
+        }
+    }
+}
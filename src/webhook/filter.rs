@@ -0,0 +1,214 @@
+//! Filtros declarativos sobre os webhooks recebidos
+//!
+//! Um [`Filter`] descreve quais payloads interessam (campanha, mídia, tag,
+//! campo personalizado, ...) e pode ser combinado com `and`, `or` e `!`.
+//! É usado para rotear com [`WebhookRouter::route`](super::WebhookRouter::route)
+//! e para descartar webhooks antes do stream com
+//! [`WebhookSender::filter`](super::WebhookSender::filter), no lugar de
+//! cadeias de `if` sobre os campos do payload.
+
+use super::dispatcher::EventKind;
+use crate::types::custom_fields::normalize_key;
+use crate::types::WebhookPayload;
+use serde_json::Value;
+use std::fmt;
+use std::ops::Not;
+use std::sync::Arc;
+
+type Predicate = Arc<dyn Fn(&WebhookPayload) -> bool + Send + Sync>;
+
+#[derive(Clone)]
+enum Rule {
+    Any,
+    Campaign(String),
+    HasMedia,
+    Tag(String),
+    Kind(EventKind),
+    TextContains(String),
+    CustomField(String, String),
+    PhoneId(String),
+    Custom(Predicate),
+    And(Box<Filter>, Box<Filter>),
+    Or(Box<Filter>, Box<Filter>),
+    Not(Box<Filter>),
+}
+
+/// Condição sobre um [`WebhookPayload`]
+///
+/// Nomes de campanha, tags e campos personalizados são comparados ignorando
+/// caixa, acentos e pontuação (veja
+/// [`normalize_key`](crate::types::custom_fields::normalize_key)).
+///
+/// # Exemplo
+///
+/// ```rust
+/// use chatguru::types::WebhookPayload;
+/// use chatguru::webhook::Filter;
+///
+/// let filter = Filter::campaign("Black Friday")
+///     .and(Filter::has_media())
+///     .and(Filter::tag("vip"))
+///     .and(!Filter::custom_field("status", "cancelado"));
+///
+/// let payload = WebhookPayload::parse(
+///     r#"{"campanha_id": "42", "campanha_nome": "Black Friday", "nome": "Maria",
+///         "celular": "5511999999999", "tags": ["VIP"], "texto_mensagem": "",
+///         "url_arquivo": "https://exemplo.com/foto.jpg", "tipo_mensagem": "image"}"#,
+/// )
+/// .unwrap();
+/// assert!(filter.matches(&payload));
+/// assert!(!Filter::tag("atacado").matches(&payload));
+/// ```
+#[derive(Clone)]
+pub struct Filter {
+    rule: Rule,
+}
+
+impl Filter {
+    fn new(rule: Rule) -> Self {
+        Self { rule }
+    }
+
+    /// Aceita qualquer payload
+    pub fn any() -> Self {
+        Self::new(Rule::Any)
+    }
+
+    /// Payloads da campanha, pelo ID ou pelo nome
+    pub fn campaign(campaign: impl Into<String>) -> Self {
+        Self::new(Rule::Campaign(campaign.into()))
+    }
+
+    /// Payloads com mídia anexada
+    pub fn has_media() -> Self {
+        Self::new(Rule::HasMedia)
+    }
+
+    /// Payloads de chats com a tag
+    pub fn tag(tag: impl Into<String>) -> Self {
+        Self::new(Rule::Tag(normalize_key(&tag.into())))
+    }
+
+    /// Payloads da categoria (veja [`EventKind::classify`])
+    pub fn kind(kind: EventKind) -> Self {
+        Self::new(Rule::Kind(kind))
+    }
+
+    /// Payloads cuja mensagem contém o trecho, ignorando caixa
+    pub fn text_contains(text: impl Into<String>) -> Self {
+        Self::new(Rule::TextContains(text.into().to_lowercase()))
+    }
+
+    /// Payloads com o campo personalizado igual ao valor
+    ///
+    /// Textos são comparados ignorando caixa, acentos e espaços nas pontas;
+    /// números e booleanos, pela forma em texto (`"10"`, `"true"`).
+    pub fn custom_field(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::new(Rule::CustomField(name.into(), normalize_key(&value.into())))
+    }
+
+    /// Payloads recebidos pela linha (phone_id)
+    pub fn phone_id(phone_id: impl Into<String>) -> Self {
+        Self::new(Rule::PhoneId(phone_id.into()))
+    }
+
+    /// Condição própria sobre o payload
+    pub fn from_fn<F>(predicate: F) -> Self
+    where
+        F: Fn(&WebhookPayload) -> bool + Send + Sync + 'static,
+    {
+        Self::new(Rule::Custom(Arc::new(predicate)))
+    }
+
+    /// Exige as duas condições
+    pub fn and(self, other: Filter) -> Self {
+        Self::new(Rule::And(Box::new(self), Box::new(other)))
+    }
+
+    /// Exige uma das duas condições
+    pub fn or(self, other: Filter) -> Self {
+        Self::new(Rule::Or(Box::new(self), Box::new(other)))
+    }
+
+    /// Indica se o payload atende à condição
+    pub fn matches(&self, payload: &WebhookPayload) -> bool {
+        match self.rule {
+            Rule::Any => true,
+            Rule::Campaign(ref campaign) => matches_campaign(payload, campaign),
+            Rule::HasMedia => payload.has_media(),
+            Rule::Tag(ref tag) => payload.get_tags().iter().any(|t| normalize_key(t) == *tag),
+            Rule::Kind(kind) => EventKind::classify(payload) == kind,
+            Rule::TextContains(ref text) => payload
+                .get_message_text()
+                .is_some_and(|message| message.to_lowercase().contains(text.as_str())),
+            Rule::CustomField(ref name, ref value) => match payload {
+                WebhookPayload::ChatGuru(p) => p
+                    .get_custom(name)
+                    .and_then(value_text)
+                    .is_some_and(|field| normalize_key(&field) == *value),
+                _ => false,
+            },
+            Rule::PhoneId(ref phone_id) => match payload {
+                WebhookPayload::ChatGuru(p) => p.phone_id.as_deref() == Some(phone_id.as_str()),
+                _ => false,
+            },
+            Rule::Custom(ref predicate) => predicate(payload),
+            Rule::And(ref left, ref right) => left.matches(payload) && right.matches(payload),
+            Rule::Or(ref left, ref right) => left.matches(payload) || right.matches(payload),
+            Rule::Not(ref inner) => !inner.matches(payload),
+        }
+    }
+}
+
+impl Not for Filter {
+    type Output = Filter;
+
+    /// Exige que a condição não seja atendida
+    fn not(self) -> Filter {
+        Filter::new(Rule::Not(Box::new(self)))
+    }
+}
+
+impl fmt::Debug for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.rule {
+            Rule::Any => f.write_str("any"),
+            Rule::Campaign(ref campaign) => write!(f, "campaign({:?})", campaign),
+            Rule::HasMedia => f.write_str("has_media"),
+            Rule::Tag(ref tag) => write!(f, "tag({:?})", tag),
+            Rule::Kind(kind) => write!(f, "kind({:?})", kind),
+            Rule::TextContains(ref text) => write!(f, "text_contains({:?})", text),
+            Rule::CustomField(ref name, ref value) => {
+                write!(f, "custom_field({:?}, {:?})", name, value)
+            }
+            Rule::PhoneId(ref phone_id) => write!(f, "phone_id({:?})", phone_id),
+            Rule::Custom(_) => f.write_str("custom"),
+            Rule::And(ref left, ref right) => write!(f, "({:?} and {:?})", left, right),
+            Rule::Or(ref left, ref right) => write!(f, "({:?} or {:?})", left, right),
+            Rule::Not(ref inner) => write!(f, "not {:?}", inner),
+        }
+    }
+}
+
+/// Compara a campanha pelo ID exato ou pelo nome normalizado
+fn matches_campaign(payload: &WebhookPayload, campaign: &str) -> bool {
+    let campaign = campaign.trim();
+    if campaign.is_empty() {
+        return false;
+    }
+    payload.campaign().is_some_and(|found| {
+        found.id == campaign
+            || found
+                .name
+                .is_some_and(|name| normalize_key(&name) == normalize_key(campaign))
+    })
+}
+
+fn value_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
@@ -0,0 +1,55 @@
+//! Utilitários para receber webhooks do ChatGuru
+//!
+//! - [`verify`]: verificação de autenticidade (assinatura HMAC-SHA256)
+//! - [`extract`]: limites de tamanho, Content-Type, verificação, desserialização
+//!   e normalização em uma chamada (usável com axum, actix-web ou hyper;
+//!   com as features `axum` e `actix`, [`ChatGuruWebhook`] é um extractor do
+//!   axum e do actix-web)
+//! - [`dispatcher`]: roteamento de webhooks para handlers por categoria de evento
+//! - [`filter`]: condições declarativas sobre os payloads, para rotas e streams
+//! - [`dedup`]: descarte de webhooks reenviados
+//! - [`dead_letter`]: fila de webhooks cujo processamento falhou, para reprocessamento
+//! - [`replay`]: gravação dos webhooks recebidos e reprocessamento após quedas
+//! - [`stream`]: webhooks recebidos como `Stream` de eventos, com backpressure
+//! - [`ordering`]: processamento em série por chat, na ordem de chegada
+//! - [`worker_pool`]: processamento concorrente com fila limitada e ordem por chat
+//! - [`consent`]: detecção dos pedidos de descadastro ("parar", "sair")
+
+#[cfg(feature = "actix")]
+mod actix;
+#[cfg(feature = "axum")]
+mod axum;
+pub mod consent;
+pub mod dead_letter;
+pub mod dedup;
+pub mod dispatcher;
+pub mod extract;
+pub mod filter;
+pub(crate) mod hmac;
+pub mod ordering;
+pub mod replay;
+pub mod stream;
+pub mod verify;
+pub mod worker_pool;
+
+pub use consent::{
+    ConsentClassifier, ConsentIntent, DEFAULT_OPT_IN_KEYWORDS, DEFAULT_OPT_OUT_KEYWORDS,
+};
+pub use dead_letter::{
+    DeadLetter, DeadLetterQueue, DeadLetterReport, FileDeadLetterQueue, InMemoryDeadLetterQueue,
+};
+pub use dedup::{DedupStore, Deduplicator, InMemoryDedupStore};
+pub use dispatcher::{
+    DispatchOutcome, EventKind, WebhookRouter, DEFAULT_CUSTOM_FIELDS_CAPACITY,
+    DEFAULT_CUSTOM_FIELDS_TTL,
+};
+pub use extract::{ChatGuruWebhook, WebhookExtractor, WebhookRejection};
+pub use filter::Filter;
+pub use ordering::ChatSerializer;
+pub use replay::{
+    FileRecordingSink, InMemoryRecordingSink, RecordedWebhook, RecordingSink, ReplayReport,
+    WebhookRecorder, WebhookReplayer,
+};
+pub use stream::{WebhookSender, WebhookStream};
+pub use verify::{verify_signature, HmacSha256, SignatureScheme, SIGNATURE_HEADER};
+pub use worker_pool::WebhookWorkerPool;
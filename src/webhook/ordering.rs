@@ -0,0 +1,191 @@
+//! Ordem de processamento por chat
+//!
+//! O ChatGuru pode entregar webhooks do mesmo chat em paralelo; se forem
+//! processados fora de ordem, o bot pode responder a uma mensagem já
+//! superada. [`ChatSerializer`] envolve qualquer função assíncrona e garante
+//! que webhooks do mesmo chat rodem um de cada vez, na ordem de chegada,
+//! enquanto chats diferentes seguem em paralelo.
+
+use crate::types::WebhookPayload;
+use futures_core::future::BoxFuture;
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+type Handler<T> = Arc<dyn Fn(WebhookPayload) -> BoxFuture<'static, T> + Send + Sync>;
+type LockMap = Arc<Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>>;
+
+/// Travas assíncronas por chave, descartadas quando ninguém mais as usa
+#[derive(Clone, Default)]
+pub(crate) struct KeyedLocks {
+    locks: LockMap,
+}
+
+impl KeyedLocks {
+    /// Espera a vez da chave; a vez termina quando o guard é descartado
+    ///
+    /// A trava da chave sai do mapa quando o último guard é descartado, mesmo
+    /// que o future seja cancelado durante a espera ou o chamador entre em
+    /// panic.
+    pub(crate) async fn lock(&self, key: String) -> KeyGuard {
+        let lock = self.map().entry(key.clone()).or_default().clone();
+        let mut guard = KeyGuard {
+            locks: self.locks.clone(),
+            key,
+            lock: Some(lock.clone()),
+            turn: None,
+        };
+        guard.turn = Some(lock.lock_owned().await);
+        guard
+    }
+
+    /// Quantidade de chaves em uso ou aguardando
+    pub(crate) fn len(&self) -> usize {
+        self.map().len()
+    }
+
+    fn map(&self) -> std::sync::MutexGuard<'_, HashMap<String, Arc<tokio::sync::Mutex<()>>>> {
+        self.locks.lock().unwrap_or_else(|p| p.into_inner())
+    }
+}
+
+/// Vez de uma chave em [`KeyedLocks`]
+pub(crate) struct KeyGuard {
+    locks: LockMap,
+    key: String,
+    lock: Option<Arc<tokio::sync::Mutex<()>>>,
+    turn: Option<tokio::sync::OwnedMutexGuard<()>>,
+}
+
+impl Drop for KeyGuard {
+    fn drop(&mut self) {
+        drop(self.turn.take());
+
+        // Descarta a trava da chave quando ninguém mais está esperando por ela
+        let mut locks = self.locks.lock().unwrap_or_else(|p| p.into_inner());
+        drop(self.lock.take());
+        if locks
+            .get(&self.key)
+            .is_some_and(|lock| Arc::strong_count(lock) == 1)
+        {
+            locks.remove(&self.key);
+        }
+    }
+}
+
+/// Chave de ordenação do payload: `chat_id`, telefone ou, sem nenhum dos
+/// dois, a impressão digital do evento (sem ordenação com outros eventos)
+pub(crate) fn chat_key(payload: &WebhookPayload) -> String {
+    payload
+        .get_chat_id()
+        .or_else(|| payload.get_phone_number())
+        .unwrap_or_else(|| payload.event_fingerprint())
+}
+
+/// Executa uma função assíncrona por webhook, em série por chat
+///
+/// A ordem de chegada é a ordem em que as chamadas a
+/// [`process`](Self::process) começam a ser aguardadas. Clonável; os clones
+/// compartilham a ordenação.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::webhook::ChatSerializer;
+///
+/// let serializer = ChatSerializer::new(|payload| async move {
+///     responder(payload).await
+/// });
+///
+/// // No handler HTTP: requisições do mesmo chat esperam a anterior terminar
+/// let result = serializer.process(webhook.into_inner()).await;
+/// ```
+pub struct ChatSerializer<T> {
+    handler: Handler<T>,
+    locks: KeyedLocks,
+}
+
+impl<T> Clone for ChatSerializer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            handler: self.handler.clone(),
+            locks: self.locks.clone(),
+        }
+    }
+}
+
+impl<T> ChatSerializer<T> {
+    /// Quantidade de chats com webhooks em processamento ou aguardando
+    pub fn active_chats(&self) -> usize {
+        self.locks.len()
+    }
+}
+
+impl<T> fmt::Debug for ChatSerializer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChatSerializer")
+            .field("active_chats", &self.active_chats())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Send + 'static> ChatSerializer<T> {
+    /// Envolve a função de processamento
+    pub fn new<F, Fut>(handler: F) -> Self
+    where
+        F: Fn(WebhookPayload) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        Self {
+            handler: Arc::new(move |payload| Box::pin(handler(payload))),
+            locks: KeyedLocks::default(),
+        }
+    }
+
+    /// Processa o webhook depois que os anteriores do mesmo chat terminarem
+    pub async fn process(&self, payload: WebhookPayload) -> T {
+        let _turn = self.locks.lock(chat_key(&payload)).await;
+        (self.handler)(payload).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyedLocks;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn cancelled_turns_release_the_key() {
+        let locks = KeyedLocks::default();
+        let held = locks.lock("chat".to_string()).await;
+
+        // Cancelado enquanto espera a vez
+        let waiting = tokio::time::timeout(Duration::from_millis(10), locks.lock("chat".into()));
+        assert!(waiting.await.is_err());
+        assert_eq!(locks.len(), 1);
+
+        // Cancelado durante o processamento
+        drop(held);
+        let processing = tokio::time::timeout(Duration::from_millis(10), async {
+            let _turn = locks.lock("chat".to_string()).await;
+            std::future::pending::<()>().await
+        });
+        assert!(processing.await.is_err());
+        assert_eq!(locks.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn panicking_holder_releases_the_key() {
+        let locks = KeyedLocks::default();
+        let task = tokio::spawn({
+            let locks = locks.clone();
+            async move {
+                let _turn = locks.lock("chat".to_string()).await;
+                panic!("handler failed");
+            }
+        });
+        assert!(task.await.is_err());
+        assert_eq!(locks.len(), 0);
+    }
+}
@@ -0,0 +1,443 @@
+//! Gravação e reprocessamento de webhooks
+//!
+//! [`WebhookRecorder`] grava cada webhook recebido (corpo, headers e horário
+//! de recebimento) em um [`RecordingSink`]; [`WebhookReplayer`] lê as
+//! gravações e as entrega novamente a um handler, para reprocessar eventos
+//! perdidos durante uma queda ou um deploy.
+//!
+//! # Exemplo
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//! use chatguru::webhook::{FileRecordingSink, WebhookRecorder, WebhookReplayer};
+//!
+//! let sink = Arc::new(FileRecordingSink::new("/var/lib/meu-servico/webhooks.jsonl"));
+//! let recorder = WebhookRecorder::new(sink.clone());
+//!
+//! // No handler HTTP, antes de processar:
+//! recorder
+//!     .record_with_headers(&body, |name| headers.get(name).and_then(|v| v.to_str().ok()))
+//!     .await?;
+//!
+//! // Depois da queda, reprocessa o que chegou a partir de um horário:
+//! let report = WebhookReplayer::new(sink)
+//!     .since(deploy_started_at)
+//!     .replay_into(&router)
+//!     .await?;
+//! println!("{} reprocessados, {} falhas", report.replayed, report.failed.len());
+//! ```
+
+use super::dispatcher::WebhookRouter;
+use super::verify::SIGNATURE_HEADER;
+use crate::error::{ChatGuruError, Result};
+use crate::types::WebhookPayload;
+use chrono::{DateTime, Utc};
+use futures_core::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::future::Future;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Webhook gravado por [`WebhookRecorder`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedWebhook {
+    /// Momento em que o webhook foi recebido
+    pub received_at: DateTime<Utc>,
+    /// Headers capturados (nomes em minúsculas)
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// Corpo recebido, exatamente como chegou
+    ///
+    /// Guardado sem reserializar, para que a assinatura continue válida no
+    /// reprocessamento. Bytes que não são UTF-8 válido são substituídos.
+    /// Gravações antigas, com o corpo como objeto JSON, são lidas com o
+    /// objeto convertido em texto.
+    #[serde(deserialize_with = "deserialize_body")]
+    pub body: String,
+}
+
+/// Aceita o corpo como texto ou, em gravações antigas, como valor JSON
+fn deserialize_body<'de, D>(deserializer: D) -> std::result::Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(match Value::deserialize(deserializer)? {
+        Value::String(body) => body,
+        other => other.to_string(),
+    })
+}
+
+impl RecordedWebhook {
+    /// Cria uma gravação a partir do corpo bruto, com o horário atual
+    pub fn new(body: &[u8], headers: BTreeMap<String, String>) -> Self {
+        Self {
+            received_at: Utc::now(),
+            headers,
+            body: String::from_utf8_lossy(body).into_owned(),
+        }
+    }
+
+    /// Valor de um header capturado (nome sem diferenciar maiúsculas)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// Desserializa o corpo gravado
+    ///
+    /// # Erros
+    ///
+    /// Retorna `SerializationError` se o corpo não for um webhook válido.
+    pub fn payload(&self) -> Result<WebhookPayload> {
+        WebhookPayload::parse(&self.body)
+            .map_err(|e| ChatGuruError::SerializationError(e.to_string()))
+    }
+}
+
+/// Destino das gravações de webhooks
+///
+/// [`InMemoryRecordingSink`] serve para testes; [`FileRecordingSink`] grava
+/// uma linha JSON por webhook. Implemente este trait para usar outro
+/// destino (ex: bucket, tabela de banco de dados).
+pub trait RecordingSink: Send + Sync {
+    /// Acrescenta uma gravação
+    fn append<'a>(&'a self, record: &'a RecordedWebhook) -> BoxFuture<'a, Result<()>>;
+
+    /// Lê todas as gravações, na ordem em que foram acrescentadas
+    fn read_all(&self) -> BoxFuture<'_, Result<Vec<RecordedWebhook>>>;
+}
+
+/// Gravações em memória
+#[derive(Debug, Default)]
+pub struct InMemoryRecordingSink {
+    records: Mutex<Vec<RecordedWebhook>>,
+}
+
+impl InMemoryRecordingSink {
+    /// Cria um destino vazio
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Quantidade de gravações
+    pub fn len(&self) -> usize {
+        self.records().len()
+    }
+
+    /// Indica se não há gravações
+    pub fn is_empty(&self) -> bool {
+        self.records().is_empty()
+    }
+
+    fn records(&self) -> std::sync::MutexGuard<'_, Vec<RecordedWebhook>> {
+        self.records.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl RecordingSink for InMemoryRecordingSink {
+    fn append<'a>(&'a self, record: &'a RecordedWebhook) -> BoxFuture<'a, Result<()>> {
+        self.records().push(record.clone());
+        Box::pin(async { Ok(()) })
+    }
+
+    fn read_all(&self) -> BoxFuture<'_, Result<Vec<RecordedWebhook>>> {
+        let records = self.records().clone();
+        Box::pin(async move { Ok(records) })
+    }
+}
+
+/// Gravações em arquivo JSON lines (uma gravação por linha)
+///
+/// O arquivo é aberto em modo de acréscimo a cada gravação, então pode ser
+/// rotacionado externamente. Linhas inválidas são ignoradas na leitura (com
+/// um aviso no log), para que um registro truncado por uma queda não impeça
+/// o reprocessamento dos demais.
+#[derive(Debug)]
+pub struct FileRecordingSink {
+    path: PathBuf,
+    lock: tokio::sync::Mutex<()>,
+}
+
+impl FileRecordingSink {
+    /// Usa o arquivo informado (criado na primeira gravação)
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Caminho do arquivo de gravações
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl RecordingSink for FileRecordingSink {
+    fn append<'a>(&'a self, record: &'a RecordedWebhook) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let mut line = serde_json::to_vec(record)?;
+            line.push(b'\n');
+
+            let _guard = self.lock.lock().await;
+            let path = self.path.clone();
+            let write = tokio::task::spawn_blocking(move || {
+                std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&path)?
+                    .write_all(&line)
+            })
+            .await
+            .map_err(|e| ChatGuruError::InternalError(e.to_string()))?;
+
+            write.map_err(|e| {
+                ChatGuruError::InternalError(format!(
+                    "Failed to write webhook recording {}: {}",
+                    self.path.display(),
+                    e
+                ))
+            })
+        })
+    }
+
+    fn read_all(&self) -> BoxFuture<'_, Result<Vec<RecordedWebhook>>> {
+        Box::pin(async move {
+            let bytes = match tokio::fs::read(&self.path).await {
+                Ok(bytes) => bytes,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+                Err(e) => {
+                    return Err(ChatGuruError::InternalError(format!(
+                        "Failed to read webhook recordings {}: {}",
+                        self.path.display(),
+                        e
+                    )))
+                }
+            };
+
+            let mut records = Vec::new();
+            for (index, line) in bytes.split(|b| *b == b'\n').enumerate() {
+                if line.iter().all(u8::is_ascii_whitespace) {
+                    continue;
+                }
+                match serde_json::from_slice(line) {
+                    Ok(record) => records.push(record),
+                    Err(e) => tracing::warn!(
+                        "Skipping invalid webhook recording at {}:{}: {}",
+                        self.path.display(),
+                        index + 1,
+                        e
+                    ),
+                }
+            }
+            Ok(records)
+        })
+    }
+}
+
+/// Grava os webhooks recebidos em um [`RecordingSink`]
+///
+/// Por padrão captura os headers `Content-Type` e `X-ChatGuru-Signature`;
+/// use [`capture_header`](Self::capture_header) para incluir outros.
+#[derive(Clone)]
+pub struct WebhookRecorder {
+    sink: Arc<dyn RecordingSink>,
+    headers: Vec<String>,
+}
+
+impl fmt::Debug for WebhookRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookRecorder")
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebhookRecorder {
+    /// Cria um gravador para o destino informado
+    pub fn new(sink: Arc<dyn RecordingSink>) -> Self {
+        Self {
+            sink,
+            headers: vec!["Content-Type".to_string(), SIGNATURE_HEADER.to_string()],
+        }
+    }
+
+    /// Inclui um header na captura de [`record_with_headers`](Self::record_with_headers)
+    pub fn capture_header(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        if !self.headers.iter().any(|h| h.eq_ignore_ascii_case(&name)) {
+            self.headers.push(name);
+        }
+        self
+    }
+
+    /// Grava um webhook com os headers já coletados
+    pub async fn record<I, K, V>(&self, raw_body: &[u8], headers: I) -> Result<RecordedWebhook>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        let headers = headers
+            .into_iter()
+            .map(|(name, value)| (name.as_ref().to_ascii_lowercase(), value.into()))
+            .collect();
+        let record = RecordedWebhook::new(raw_body, headers);
+        self.sink.append(&record).await?;
+        Ok(record)
+    }
+
+    /// Grava um webhook lendo os headers configurados através de uma função de acesso
+    ///
+    /// Mesma convenção de [`WebhookExtractor::extract_with_headers`](super::WebhookExtractor::extract_with_headers).
+    pub async fn record_with_headers<'h, F>(
+        &self,
+        raw_body: &[u8],
+        header: F,
+    ) -> Result<RecordedWebhook>
+    where
+        F: Fn(&str) -> Option<&'h str>,
+    {
+        let headers: Vec<(&str, &str)> = self
+            .headers
+            .iter()
+            .filter_map(|name| header(name).map(|value| (name.as_str(), value)))
+            .collect();
+        self.record(raw_body, headers).await
+    }
+}
+
+/// Resultado de [`WebhookReplayer::replay`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReplayReport {
+    /// Gravações entregues ao handler com sucesso
+    pub replayed: usize,
+    /// Gravações fora do intervalo configurado
+    pub skipped: usize,
+    /// Gravações que falharam (posição na leitura e erro)
+    pub failed: Vec<(usize, String)>,
+}
+
+impl ReplayReport {
+    /// Indica se todas as gravações do intervalo foram reprocessadas
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Reentrega webhooks gravados a um handler
+///
+/// Uma falha (payload inválido ou erro do handler) não interrompe o
+/// reprocessamento: ela é registrada em [`ReplayReport::failed`] e a
+/// gravação seguinte é processada.
+#[derive(Clone)]
+pub struct WebhookReplayer {
+    sink: Arc<dyn RecordingSink>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+}
+
+impl fmt::Debug for WebhookReplayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookReplayer")
+            .field("since", &self.since)
+            .field("until", &self.until)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebhookReplayer {
+    /// Cria um reprocessador que lê do destino informado
+    pub fn new(sink: Arc<dyn RecordingSink>) -> Self {
+        Self {
+            sink,
+            since: None,
+            until: None,
+        }
+    }
+
+    /// Só reprocessa webhooks recebidos a partir deste momento (inclusive)
+    pub fn since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Só reprocessa webhooks recebidos antes deste momento
+    pub fn until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Lê as gravações dentro do intervalo configurado
+    pub async fn records(&self) -> Result<Vec<RecordedWebhook>> {
+        let records = self.sink.read_all().await?;
+        Ok(records
+            .into_iter()
+            .filter(|record| self.in_range(record))
+            .collect())
+    }
+
+    /// Entrega cada gravação do intervalo ao handler, em ordem
+    ///
+    /// Só retorna erro se as gravações não puderem ser lidas.
+    pub async fn replay<F, Fut>(&self, handler: F) -> Result<ReplayReport>
+    where
+        F: Fn(WebhookPayload) -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        let records = self.sink.read_all().await?;
+        let mut report = ReplayReport::default();
+
+        for (index, record) in records.iter().enumerate() {
+            if !self.in_range(record) {
+                report.skipped += 1;
+                continue;
+            }
+
+            let result = match record.payload() {
+                Ok(mut payload) => {
+                    payload.normalize_media_fields();
+                    handler(payload).await
+                }
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(()) => report.replayed += 1,
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to replay webhook received at {}: {}",
+                        record.received_at,
+                        e
+                    );
+                    report.failed.push((index, e.to_string()));
+                }
+            }
+        }
+
+        tracing::info!(
+            "Webhook replay finished: {} replayed, {} failed, {} skipped",
+            report.replayed,
+            report.failed.len(),
+            report.skipped
+        );
+        Ok(report)
+    }
+
+    /// Reprocessa as gravações através de um [`WebhookRouter`]
+    pub async fn replay_into(&self, router: &WebhookRouter) -> Result<ReplayReport> {
+        self.replay(|payload| async move { router.dispatch(payload).await.map(|_| ()) })
+            .await
+    }
+
+    fn in_range(&self, record: &RecordedWebhook) -> bool {
+        let too_early = matches!(self.since, Some(since) if record.received_at < since);
+        let too_late = matches!(self.until, Some(until) if record.received_at >= until);
+        !too_early && !too_late
+    }
+}
@@ -0,0 +1,224 @@
+//! Webhooks recebidos como `Stream` de eventos
+//!
+//! [`WebhookStream::channel`] cria um par: o [`WebhookSender`] fica no
+//! handler HTTP (qualquer framework) e recebe os corpos brutos; o
+//! [`WebhookStream`] entrega os [`ChatEvent`]s já verificados e convertidos,
+//! para o consumidor usar combinadores de stream (`filter`,
+//! `buffer_unordered`, ...) em vez de handlers por callback.
+//!
+//! A fila entre os dois é limitada: quando o consumidor atrasa,
+//! [`WebhookSender::push`] espera por espaço (e o handler HTTP demora a
+//! responder), enquanto [`WebhookSender::try_push`] rejeita na hora com
+//! `429`, para o ChatGuru reenviar depois.
+//!
+//! # Exemplo (axum)
+//!
+//! ```rust,ignore
+//! use chatguru::webhook::{WebhookExtractor, WebhookSender, WebhookStream};
+//! use futures::StreamExt;
+//!
+//! let (sender, events) = WebhookStream::with_extractor(
+//!     WebhookExtractor::new().with_secret(secret),
+//!     256,
+//! );
+//!
+//! async fn handler(State(sender): State<WebhookSender>, headers: HeaderMap, body: Bytes) -> StatusCode {
+//!     let signature = headers.get(SIGNATURE_HEADER).and_then(|v| v.to_str().ok());
+//!     match sender.push(&body, signature).await {
+//!         Ok(()) => StatusCode::OK,
+//!         Err(rejection) => StatusCode::from_u16(rejection.status_code()).unwrap(),
+//!     }
+//! }
+//!
+//! tokio::spawn(
+//!     events
+//!         .filter(|event| futures::future::ready(event.kind() == "media_received"))
+//!         .map(|event| async move { transcrever(event).await })
+//!         .buffer_unordered(8)
+//!         .for_each(|_| async {}),
+//! );
+//! ```
+
+use super::extract::{WebhookExtractor, WebhookRejection};
+use super::filter::Filter;
+use crate::types::{ChatEvent, WebhookPayload};
+use futures_core::Stream;
+use std::fmt;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::sync::mpsc;
+
+/// Stream dos eventos recebidos por um [`WebhookSender`]
+///
+/// Termina quando todos os senders forem descartados.
+pub struct WebhookStream {
+    receiver: mpsc::Receiver<ChatEvent>,
+}
+
+impl fmt::Debug for WebhookStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookStream").finish_non_exhaustive()
+    }
+}
+
+impl WebhookStream {
+    /// Cria o par sender/stream com fila de até `capacity` eventos
+    ///
+    /// Usa um [`WebhookExtractor`] padrão (sem verificação de assinatura).
+    pub fn channel(capacity: usize) -> (WebhookSender, WebhookStream) {
+        Self::with_extractor(WebhookExtractor::default(), capacity)
+    }
+
+    /// Cria o par sender/stream validando os corpos com o extractor informado
+    pub fn with_extractor(
+        extractor: WebhookExtractor,
+        capacity: usize,
+    ) -> (WebhookSender, WebhookStream) {
+        let (sender, receiver) = mpsc::channel(capacity.max(1));
+        (
+            WebhookSender {
+                extractor,
+                filter: None,
+                sender,
+            },
+            WebhookStream { receiver },
+        )
+    }
+}
+
+impl Stream for WebhookStream {
+    type Item = ChatEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().receiver.poll_recv(cx)
+    }
+}
+
+/// Lado de entrada de um [`WebhookStream`], usado no handler HTTP
+///
+/// Clonável; cada clone alimenta o mesmo stream.
+#[derive(Clone)]
+pub struct WebhookSender {
+    extractor: WebhookExtractor,
+    filter: Option<Filter>,
+    sender: mpsc::Sender<ChatEvent>,
+}
+
+impl fmt::Debug for WebhookSender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookSender")
+            .field("extractor", &self.extractor)
+            .field("filter", &self.filter)
+            .field("available", &self.sender.capacity())
+            .finish()
+    }
+}
+
+impl WebhookSender {
+    /// Envia ao stream apenas os webhooks aceitos pelo filtro
+    ///
+    /// Os demais são validados e aceitos (o ChatGuru não reenvia), mas
+    /// descartados sem ocupar a fila.
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Extractor usado para validar os corpos recebidos
+    pub fn extractor(&self) -> &WebhookExtractor {
+        &self.extractor
+    }
+
+    /// Valida o corpo bruto e envia o evento ao stream, esperando espaço na fila
+    ///
+    /// # Erros
+    ///
+    /// * As rejeições de [`WebhookExtractor::extract`]
+    /// * `Unavailable` - o stream foi descartado
+    pub async fn push(
+        &self,
+        raw_body: &[u8],
+        signature: Option<&str>,
+    ) -> Result<(), WebhookRejection> {
+        let Some(event) = self.parse(raw_body, signature)? else {
+            return Ok(());
+        };
+        self.sender
+            .send(event)
+            .await
+            .map_err(|_| WebhookRejection::Unavailable("webhook stream closed".to_string()))
+    }
+
+    /// Como [`push`](Self::push), mas rejeita na hora se a fila estiver cheia
+    ///
+    /// # Erros
+    ///
+    /// * As rejeições de [`WebhookExtractor::extract`]
+    /// * `QueueFull` - fila cheia (HTTP 429)
+    /// * `Unavailable` - o stream foi descartado
+    pub fn try_push(
+        &self,
+        raw_body: &[u8],
+        signature: Option<&str>,
+    ) -> Result<(), WebhookRejection> {
+        let Some(event) = self.parse(raw_body, signature)? else {
+            return Ok(());
+        };
+        self.sender.try_send(event).map_err(|e| match e {
+            mpsc::error::TrySendError::Full(_) => WebhookRejection::QueueFull {
+                depth: self.sender.max_capacity(),
+            },
+            mpsc::error::TrySendError::Closed(_) => {
+                WebhookRejection::Unavailable("webhook stream closed".to_string())
+            }
+        })
+    }
+
+    /// Envia ao stream um payload já desserializado (ex: pelo extractor do framework)
+    ///
+    /// Os campos de mídia são normalizados conforme o extractor, como em
+    /// [`push`](Self::push).
+    ///
+    /// # Erros
+    ///
+    /// * `InvalidPayload` - o payload não pôde ser convertido em evento
+    /// * `Unavailable` - o stream foi descartado
+    pub async fn push_payload(&self, mut payload: WebhookPayload) -> Result<(), WebhookRejection> {
+        self.extractor.normalize(&mut payload);
+        if !self.accepts(&payload) {
+            return Ok(());
+        }
+        let event = ChatEvent::try_from(&payload)
+            .map_err(|e| WebhookRejection::InvalidPayload(e.to_string()))?;
+        self.sender
+            .send(event)
+            .await
+            .map_err(|_| WebhookRejection::Unavailable("webhook stream closed".to_string()))
+    }
+
+    /// Indica se o payload passa pelo filtro configurado
+    fn accepts(&self, payload: &WebhookPayload) -> bool {
+        let accepted = self
+            .filter
+            .as_ref()
+            .is_none_or(|filter| filter.matches(payload));
+        if !accepted {
+            tracing::debug!("Webhook discarded by stream filter");
+        }
+        accepted
+    }
+
+    fn parse(
+        &self,
+        raw_body: &[u8],
+        signature: Option<&str>,
+    ) -> Result<Option<ChatEvent>, WebhookRejection> {
+        let webhook = self.extractor.extract(raw_body, signature)?;
+        if !self.accepts(&webhook) {
+            return Ok(None);
+        }
+        ChatEvent::try_from(&*webhook)
+            .map(Some)
+            .map_err(|e| WebhookRejection::InvalidPayload(e.to_string()))
+    }
+}
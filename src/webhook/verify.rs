@@ -0,0 +1,145 @@
+use super::hmac::hmac_sha256;
+use crate::error::{ChatGuruError, Result};
+use base64::Engine;
+
+/// Header onde a assinatura do webhook é enviada por padrão
+pub const SIGNATURE_HEADER: &str = "X-ChatGuru-Signature";
+
+/// Esquema de assinatura de webhooks
+///
+/// Implemente este trait para suportar esquemas diferentes do HMAC-SHA256
+/// (por exemplo, um token compartilhado em header ou outro algoritmo de hash).
+pub trait SignatureScheme: Send + Sync {
+    /// Nome do esquema, usado em logs
+    fn name(&self) -> &'static str;
+
+    /// Verifica se `header_value` é uma assinatura válida de `raw_body`
+    fn verify(&self, raw_body: &[u8], header_value: &str, secret: &[u8]) -> bool;
+}
+
+/// Assinatura HMAC-SHA256 do corpo bruto da requisição
+///
+/// Aceita o valor do header em hexadecimal (`a1b2...`), com prefixo
+/// (`sha256=a1b2...`) ou em base64.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HmacSha256;
+
+impl SignatureScheme for HmacSha256 {
+    fn name(&self) -> &'static str {
+        "hmac-sha256"
+    }
+
+    fn verify(&self, raw_body: &[u8], header_value: &str, secret: &[u8]) -> bool {
+        let expected = hmac_sha256(secret, raw_body);
+
+        let value = header_value.trim();
+        let value = value
+            .strip_prefix("sha256=")
+            .or_else(|| value.strip_prefix("SHA256="))
+            .unwrap_or(value);
+
+        match decode_signature(value) {
+            Some(provided) => constant_time_eq(&expected, &provided),
+            None => false,
+        }
+    }
+}
+
+/// Segredo compartilhado enviado diretamente no header (sem hash)
+///
+/// Útil para integrações configuradas apenas com um token fixo na URL ou
+/// em um header customizado.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SharedToken;
+
+impl SignatureScheme for SharedToken {
+    fn name(&self) -> &'static str {
+        "shared-token"
+    }
+
+    fn verify(&self, _raw_body: &[u8], header_value: &str, secret: &[u8]) -> bool {
+        constant_time_eq(header_value.trim().as_bytes(), secret)
+    }
+}
+
+/// Verifica a assinatura HMAC-SHA256 de um webhook
+///
+/// # Parâmetros
+///
+/// * `raw_body` - Corpo bruto da requisição, exatamente como recebido
+/// * `header_value` - Valor do header de assinatura (hex, `sha256=hex` ou base64)
+/// * `secret` - Segredo compartilhado configurado no ChatGuru
+///
+/// # Erros
+///
+/// Retorna `ValidationError` se a assinatura estiver ausente ou não conferir.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::webhook::verify::verify_signature;
+///
+/// verify_signature(&body, signature_header, &secret)?;
+/// let payload: WebhookPayload = serde_json::from_slice(&body)?;
+/// ```
+pub fn verify_signature(raw_body: &[u8], header_value: &str, secret: &str) -> Result<()> {
+    verify_with(&HmacSha256, raw_body, header_value, secret.as_bytes())
+}
+
+/// Verifica a assinatura de um webhook usando o esquema informado
+pub fn verify_with(
+    scheme: &dyn SignatureScheme,
+    raw_body: &[u8],
+    header_value: &str,
+    secret: &[u8],
+) -> Result<()> {
+    if header_value.trim().is_empty() {
+        return Err(ChatGuruError::ValidationError(
+            "Missing webhook signature".to_string(),
+        ));
+    }
+
+    if scheme.verify(raw_body, header_value, secret) {
+        Ok(())
+    } else {
+        tracing::warn!("Rejected webhook with invalid {} signature", scheme.name());
+        Err(ChatGuruError::ValidationError(
+            "Invalid webhook signature".to_string(),
+        ))
+    }
+}
+
+/// Gera a assinatura HMAC-SHA256 (hex) de um corpo
+///
+/// Útil para assinar webhooks em testes ou ao reencaminhar eventos.
+pub fn sign(raw_body: &[u8], secret: &str) -> String {
+    hmac_sha256(secret.as_bytes(), raw_body)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Compara dois buffers em tempo constante (em relação ao conteúdo)
+///
+/// Evita que o tempo de resposta revele quantos bytes da assinatura conferem.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn decode_signature(value: &str) -> Option<Vec<u8>> {
+    if value.len() == 64 && value.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return (0..value.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&value[i..i + 2], 16).ok())
+            .collect();
+    }
+
+    base64::engine::general_purpose::STANDARD
+        .decode(value)
+        .or_else(|_| base64::engine::general_purpose::URL_SAFE.decode(value))
+        .ok()
+}
@@ -0,0 +1,172 @@
+//! Processamento concorrente de webhooks com fila limitada
+//!
+//! [`WebhookWorkerPool`] recebe os payloads no handler HTTP e os processa em
+//! segundo plano, respondendo ao ChatGuru sem esperar o processamento. A fila
+//! é limitada: quando cheia, [`submit`](WebhookWorkerPool::submit) rejeita com
+//! `429`, para o ChatGuru reenviar depois em vez de a memória crescer sem
+//! limite.
+//!
+//! Webhooks do mesmo chat são processados em sequência, na ordem de chegada:
+//! cada chat é sempre atribuído ao mesmo worker.
+
+use super::extract::WebhookRejection;
+use super::ordering::chat_key;
+use crate::error::Result;
+use crate::types::WebhookPayload;
+use futures_core::future::BoxFuture;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+type Handler = Arc<dyn Fn(WebhookPayload) -> BoxFuture<'static, Result<()>> + Send + Sync>;
+
+/// Pool de workers que processa webhooks em paralelo, mantendo a ordem por chat
+///
+/// Os payloads são distribuídos entre `concurrency` workers pelo chat
+/// (`chat_id` ou telefone); um chat lento atrasa apenas os chats que caem no
+/// mesmo worker. Falhas e panics do handler são registrados em log e não
+/// interrompem o worker.
+///
+/// Deve ser criado dentro de um runtime Tokio.
+///
+/// # Exemplo
+///
+/// ```rust,ignore
+/// use chatguru::webhook::WebhookWorkerPool;
+///
+/// let pool = Arc::new(WebhookWorkerPool::new(
+///     |payload| async move { processar(payload).await },
+///     8,    // workers
+///     1000, // webhooks aguardando na fila
+/// ));
+///
+/// // No handler HTTP
+/// match pool.submit(webhook.into_inner()) {
+///     Ok(()) => StatusCode::OK,
+///     Err(rejection) => StatusCode::from_u16(rejection.status_code()).unwrap(),
+/// }
+/// ```
+pub struct WebhookWorkerPool {
+    shards: Vec<mpsc::UnboundedSender<WebhookPayload>>,
+    workers: Vec<JoinHandle<()>>,
+    queued: Arc<AtomicUsize>,
+    queue_depth: usize,
+}
+
+impl fmt::Debug for WebhookWorkerPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookWorkerPool")
+            .field("concurrency", &self.shards.len())
+            .field("queued", &self.queued())
+            .field("queue_depth", &self.queue_depth)
+            .finish()
+    }
+}
+
+impl WebhookWorkerPool {
+    /// Inicia `concurrency` workers que processam até `queue_depth` webhooks enfileirados
+    pub fn new<F, Fut>(handler: F, concurrency: usize, queue_depth: usize) -> Self
+    where
+        F: Fn(WebhookPayload) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let handler: Handler = Arc::new(move |payload| Box::pin(handler(payload)));
+        let queued = Arc::new(AtomicUsize::new(0));
+
+        let (shards, workers) = (0..concurrency.max(1))
+            .map(|index| {
+                let (sender, receiver) = mpsc::unbounded_channel();
+                let worker =
+                    tokio::spawn(run_worker(index, receiver, handler.clone(), queued.clone()));
+                (sender, worker)
+            })
+            .unzip();
+
+        Self {
+            shards,
+            workers,
+            queued,
+            queue_depth: queue_depth.max(1),
+        }
+    }
+
+    /// Enfileira um webhook para processamento, sem esperar
+    ///
+    /// # Erros
+    ///
+    /// * `QueueFull` - a fila atingiu `queue_depth` (HTTP 429)
+    /// * `Unavailable` - o pool foi encerrado
+    pub fn submit(&self, payload: WebhookPayload) -> std::result::Result<(), WebhookRejection> {
+        let reserved = self
+            .queued
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |queued| {
+                (queued < self.queue_depth).then_some(queued + 1)
+            });
+        if reserved.is_err() {
+            return Err(WebhookRejection::QueueFull {
+                depth: self.queue_depth,
+            });
+        }
+
+        let shard = &self.shards[shard_index(&payload, self.shards.len())];
+        shard.send(payload).map_err(|_| {
+            self.queued.fetch_sub(1, Ordering::SeqCst);
+            WebhookRejection::Unavailable("webhook worker pool stopped".to_string())
+        })
+    }
+
+    /// Quantidade de webhooks aguardando processamento
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::SeqCst)
+    }
+
+    /// Para de aceitar webhooks e espera os já enfileirados serem processados
+    pub async fn shutdown(self) {
+        drop(self.shards);
+        for worker in self.workers {
+            if let Err(e) = worker.await {
+                tracing::error!("Webhook worker panicked: {}", e);
+            }
+        }
+    }
+}
+
+async fn run_worker(
+    index: usize,
+    mut receiver: mpsc::UnboundedReceiver<WebhookPayload>,
+    handler: Handler,
+    queued: Arc<AtomicUsize>,
+) {
+    while let Some(payload) = receiver.recv().await {
+        queued.fetch_sub(1, Ordering::SeqCst);
+        let chat = payload.get_chat_id().unwrap_or_default();
+        // Cada chamada roda em sua própria task: um panic do handler não derruba o worker
+        let handler = handler.clone();
+        match tokio::spawn(async move { handler(payload).await }).await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                tracing::warn!("Webhook worker {} failed for chat {}: {}", index, chat, e);
+            }
+            Err(e) => {
+                tracing::error!(
+                    "Webhook handler panicked on worker {} for chat {}: {}",
+                    index,
+                    chat,
+                    e
+                );
+            }
+        }
+    }
+}
+
+/// Worker responsável pelo chat do payload
+fn shard_index(payload: &WebhookPayload, shards: usize) -> usize {
+    let mut hasher = DefaultHasher::new();
+    chat_key(payload).hash(&mut hasher);
+    (hasher.finish() % shards as u64) as usize
+}